@@ -0,0 +1,500 @@
+//! # api
+//!
+//! The public library API which enables to load makefiles and run tasks from other rust
+//! applications, without invoking the cargo-make executable.<br>
+//! The flow runs in the current process, which means the process env vars and working directory
+//! are modified the same way as when running the cargo-make executable.<br>
+//! Loading a makefile, creating an execution plan and running a flow are serialized within the
+//! process, and the process env vars and working directory are restored once done, so the
+//! application and other makefiles do not see the env of the makefile.
+//!
+
+#[cfg(test)]
+#[path = "api_test.rs"]
+mod api_test;
+
+use crate::descriptor;
+use crate::environment;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
+use crate::events;
+use crate::events::{Event, EventType};
+use crate::execution_plan;
+use crate::output;
+use crate::output::OutputSink;
+use crate::profile;
+use crate::runner;
+use crate::types::{CliArgs, Config, ExecutionPlan};
+use indexmap::IndexMap;
+use regex::Regex;
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime};
+
+/// Serializes the makefile loading, planning and flow runs of this process
+static API_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone)]
+/// Holds the makefile load options
+pub struct LoadOptions {
+    /// The working directory (defaults to the current working directory)
+    pub cwd: Option<String>,
+    /// The profile name (defaults to development)
+    pub profile: Option<String>,
+    /// Additional env vars (in the KEY=VALUE format)
+    pub env: Option<Vec<String>>,
+    /// Allows access to unsupported experimental predefined tasks
+    pub experimental: bool,
+}
+
+impl LoadOptions {
+    /// Creates and returns a new instance.
+    pub fn new() -> LoadOptions {
+        LoadOptions {
+            cwd: None,
+            profile: None,
+            env: None,
+            experimental: false,
+        }
+    }
+}
+
+#[derive(Clone)]
+/// Holds the run options
+pub struct RunOptions {
+    /// The task arguments
+    pub arguments: Option<Vec<String>>,
+    /// Disable workspace support (tasks are triggered on workspace and not on members)
+    pub disable_workspace: bool,
+    /// Disable the on error task even if defined in the config section
+    pub disable_on_error: bool,
+    /// Allow invocation of private tasks
+    pub allow_private: bool,
+    /// Skip the init and end tasks
+    pub skip_init_end_tasks: bool,
+    /// Skip all tasks that match the provided regex
+    pub skip_tasks_pattern: Option<String>,
+    /// The maximum amount of tasks to run in parallel (overrides the config parallelism value)
+    pub jobs: Option<usize>,
+    /// Run all tasks even if their inputs did not change since their last run
+    pub force_rerun: bool,
+    /// Receives the output of the task commands and scripts (defaults to the process stdout/stderr)
+    pub output: Option<Arc<dyn OutputSink>>,
+}
+
+impl RunOptions {
+    /// Creates and returns a new instance.
+    pub fn new() -> RunOptions {
+        RunOptions {
+            arguments: None,
+            disable_workspace: false,
+            disable_on_error: false,
+            allow_private: false,
+            skip_init_end_tasks: false,
+            skip_tasks_pattern: None,
+            jobs: None,
+            force_rerun: false,
+            output: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The step result status
+pub enum StepStatus {
+    /// The step finished successfully
+    Succeeded,
+    /// The step failed (holds the exit code if available)
+    Failed(Option<i32>),
+    /// The step was skipped (holds the reason)
+    Skipped(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds the result of a single invoked step
+pub struct StepResult {
+    /// The task name
+    pub name: String,
+    /// The step result status
+    pub status: StepStatus,
+    /// The step duration
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone)]
+/// Holds the flow result
+pub struct RunResult {
+    /// The results of all invoked steps, in the order they were started
+    pub steps: Vec<StepResult>,
+    /// The error which stopped the flow (if any)
+    pub error: Option<CargoMakeError>,
+}
+
+impl RunResult {
+    /// Returns true if the flow finished without any error
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+fn get_process_env() -> HashMap<OsString, OsString> {
+    task_env::with_env_lock(|| env::vars_os().collect())
+}
+
+/// Returns the env vars which were added or modified since the provided snapshot was taken
+fn get_modified_env(original: &HashMap<OsString, OsString>) -> IndexMap<String, String> {
+    let mut modified_env = IndexMap::new();
+
+    for (key, value) in get_process_env() {
+        if original.get(&key) != Some(&value) {
+            modified_env.insert(
+                key.to_string_lossy().into_owned(),
+                value.to_string_lossy().into_owned(),
+            );
+        }
+    }
+
+    modified_env.sort_keys();
+
+    modified_env
+}
+
+fn restore_process_env(original: &HashMap<OsString, OsString>) {
+    task_env::with_env_lock(|| {
+        for (key, value) in env::vars_os() {
+            match original.get(&key) {
+                Some(original_value) if original_value == &value => (),
+                Some(original_value) => env::set_var(&key, original_value),
+                None => env::remove_var(&key),
+            }
+        }
+
+        for (key, value) in original {
+            if env::var_os(key).is_none() {
+                env::set_var(key, value);
+            }
+        }
+    });
+}
+
+/// Locks the api calls of this process
+fn lock_api() -> MutexGuard<'static, ()> {
+    // a panic in a previous call does not leave the process in an invalid state as it is restored
+    API_LOCK.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// Invokes the action while the provided working directory and env vars are applied to the
+/// process, after which the process working directory and env vars are restored.<br>
+/// The action gets the cargo home directory based on the provided working directory.<br>
+/// Returns the action output and the env vars it added or modified.
+fn isolate<T, F>(
+    directory: &PathBuf,
+    env_vars: &IndexMap<String, String>,
+    action: F,
+) -> Result<(T, IndexMap<String, String>), CargoMakeError>
+where
+    F: FnOnce(Option<PathBuf>) -> Result<T, CargoMakeError>,
+{
+    let _lock = lock_api();
+
+    isolate_locked(directory, env_vars, action)
+}
+
+/// Same as [isolate] for callers which already hold the api lock.
+fn isolate_locked<T, F>(
+    directory: &PathBuf,
+    env_vars: &IndexMap<String, String>,
+    action: F,
+) -> Result<(T, IndexMap<String, String>), CargoMakeError>
+where
+    F: FnOnce(Option<PathBuf>) -> Result<T, CargoMakeError>,
+{
+    if !directory.is_dir() {
+        return Err(CargoMakeError::Other(format!(
+            "Unable to set current working directory to: {}",
+            directory.display()
+        )));
+    }
+
+    let original_env = get_process_env();
+    let original_directory = env::current_dir().map_err(|error| {
+        CargoMakeError::Other(format!(
+            "Unable to read current working directory: {:#?}",
+            error
+        ))
+    })?;
+
+    task_env::with_env_lock(|| {
+        for (key, value) in env_vars {
+            env::set_var(key, value);
+        }
+    });
+
+    let result = environment::setup_cwd(Some(&directory.to_string_lossy())).and_then(action);
+
+    let modified_env = get_modified_env(&original_env);
+    restore_process_env(&original_env);
+    let restored = env::set_current_dir(&original_directory);
+
+    let output = result?;
+    match restored {
+        Ok(_) => Ok((output, modified_env)),
+        Err(error) => Err(CargoMakeError::Other(format!(
+            "Unable to restore current working directory to: {} {:#?}",
+            original_directory.display(),
+            error
+        ))),
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A loaded makefile (including its extended makefiles and the core tasks)
+pub struct Makefile {
+    /// The makefile path (relative values are resolved from the working directory)
+    file: String,
+    /// The working directory used to load the makefile and run its flows
+    cwd: PathBuf,
+    /// The loaded config
+    config: Config,
+    /// The normalized profile name
+    profile: String,
+    /// The cargo home directory
+    home: Option<PathBuf>,
+    /// The env vars set while loading the makefile (for example CARGO_MAKE_MAKEFILE_PATH), which
+    /// are applied again when planning or running a flow
+    env: IndexMap<String, String>,
+}
+
+impl Makefile {
+    /// Loads the makefile and all the makefiles it extends.
+    pub fn load(file: &str, options: &LoadOptions) -> Result<Makefile, CargoMakeError> {
+        let cwd = environment::get_directory_path(options.cwd.as_deref());
+
+        let ((config, normalized_profile_name, home), env) =
+            isolate(&cwd, &IndexMap::new(), |home| {
+                let profile_name = options
+                    .profile
+                    .clone()
+                    .unwrap_or(profile::DEFAULT_PROFILE.to_string());
+                let normalized_profile_name = profile::set(&profile_name);
+
+                let config =
+                    descriptor::load(file, true, options.env.clone(), options.experimental)?;
+
+                set_additional_profiles(&config);
+
+                Ok((config, normalized_profile_name, home))
+            })?;
+
+        Ok(Makefile {
+            file: file.to_string(),
+            cwd,
+            config,
+            profile: normalized_profile_name,
+            home,
+            env,
+        })
+    }
+
+    /// Returns the loaded config
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Creates the execution plan of the provided task
+    pub fn plan(&self, task: &str) -> Result<ExecutionPlan, CargoMakeError> {
+        let (execution_plan, _) = isolate(&self.cwd, &self.env, |_| {
+            execution_plan::create(&self.config, task, false, false, false, &None)
+        })?;
+
+        Ok(execution_plan)
+    }
+
+    /// Runs the provided task and returns the results of all invoked steps.<br>
+    /// Flows of other makefiles wait for the flow to finish before they start.
+    pub fn run(&self, task: &str, options: &RunOptions) -> RunResult {
+        // the output sink and collected events are process wide, so they are set up and taken
+        // while holding the lock as well
+        let _lock = lock_api();
+        let start_time = SystemTime::now();
+
+        output::set_sink(options.output.clone());
+        events::start_collecting();
+
+        let result = self.run_flow(task, options, start_time);
+
+        let steps = create_step_results(&events::stop_collecting());
+        output::set_sink(None);
+
+        RunResult {
+            steps,
+            error: result.err(),
+        }
+    }
+
+    fn run_flow(
+        &self,
+        task: &str,
+        options: &RunOptions,
+        start_time: SystemTime,
+    ) -> Result<(), CargoMakeError> {
+        if let Some(ref pattern) = options.skip_tasks_pattern {
+            if Regex::new(pattern).is_err() {
+                return Err(CargoMakeError::Other(format!(
+                    "Invalid skip tasks pattern provided: {}",
+                    pattern
+                )));
+            }
+        }
+
+        isolate_locked(&self.cwd, &self.env, |_| {
+            self.run_flow_in_cwd(task, options, start_time)
+        })?;
+
+        Ok(())
+    }
+
+    fn run_flow_in_cwd(
+        &self,
+        task: &str,
+        options: &RunOptions,
+        start_time: SystemTime,
+    ) -> Result<(), CargoMakeError> {
+        let mut cli_args = CliArgs::new();
+        cli_args.command = "cargo make".to_string();
+        cli_args.build_file = Some(self.file.clone());
+        cli_args.task = task.to_string();
+        cli_args.profile = Some(self.profile.clone());
+        cli_args.disable_check_for_updates = true;
+        cli_args.arguments = options.arguments.clone();
+        cli_args.disable_workspace = options.disable_workspace;
+        cli_args.allow_private = options.allow_private;
+        cli_args.skip_init_end_tasks = options.skip_init_end_tasks;
+        cli_args.skip_tasks_pattern = options.skip_tasks_pattern.clone();
+        cli_args.jobs = options.jobs;
+        cli_args.force_rerun = options.force_rerun;
+        // the on error task is invoked in this process and not via a cargo-make sub process
+        cli_args.disable_on_error = true;
+
+        profile::set(&self.profile);
+        set_additional_profiles(&self.config);
+
+        let env_info = environment::setup_env(&cli_args, &self.config, task, self.home.clone())?;
+
+        // ensure profile env was not overridden
+        profile::set(&self.profile);
+
+        let result = runner::run(
+            self.config.clone(),
+            task,
+            env_info.clone(),
+            &cli_args,
+            start_time,
+            vec![],
+        );
+
+        match (result, &self.config.config.on_error_task) {
+            (Err(error), Some(ref on_error_task)) if !options.disable_on_error => {
+                runner::run(
+                    self.config.clone(),
+                    on_error_task,
+                    env_info,
+                    &cli_args,
+                    start_time,
+                    vec![],
+                )?;
+
+                Err(error)
+            }
+            (result, _) => result,
+        }
+    }
+}
+
+fn set_additional_profiles(config: &Config) {
+    match config.config.additional_profiles {
+        Some(ref profiles) => profile::set_additional(profiles),
+        None => profile::set_additional(&vec![]),
+    };
+}
+
+/// Creates the steps results from the flow events
+fn create_step_results(flow_events: &Vec<Event>) -> Vec<StepResult> {
+    // holds the step results and whether the step is still running
+    let mut steps: Vec<(StepResult, bool)> = vec![];
+
+    for event in flow_events {
+        let running_step = steps
+            .iter_mut()
+            .rev()
+            .find(|(step, running)| *running && step.name == event.task);
+
+        match event.event {
+            EventType::TaskStarted => steps.push((
+                StepResult {
+                    name: event.task.clone(),
+                    status: StepStatus::Succeeded,
+                    duration: Duration::from_millis(0),
+                },
+                true,
+            )),
+            EventType::TaskFinished => {
+                let status = match event.exit_code {
+                    Some(0) | None => StepStatus::Succeeded,
+                    Some(exit_code) => StepStatus::Failed(Some(exit_code)),
+                };
+                let duration = Duration::from_millis(event.duration.unwrap_or(0) as u64);
+
+                match running_step {
+                    Some((step, running)) => {
+                        step.status = status;
+                        step.duration = duration;
+                        *running = false;
+                    }
+                    None => steps.push((
+                        StepResult {
+                            name: event.task.clone(),
+                            status,
+                            duration,
+                        },
+                        false,
+                    )),
+                }
+            }
+            EventType::TaskSkipped => {
+                let status = StepStatus::Skipped(event.fail_message.clone().unwrap_or_default());
+
+                match running_step {
+                    Some((step, running)) => {
+                        step.status = status;
+                        *running = false;
+                    }
+                    None => steps.push((
+                        StepResult {
+                            name: event.task.clone(),
+                            status,
+                            duration: Duration::from_millis(0),
+                        },
+                        false,
+                    )),
+                }
+            }
+            EventType::FlowFinished => (),
+        }
+    }
+
+    steps
+        .into_iter()
+        .map(|(mut step, running)| {
+            // steps which never finished were aborted by an error
+            if running {
+                step.status = StepStatus::Failed(None);
+            }
+
+            step
+        })
+        .collect()
+}
@@ -0,0 +1,290 @@
+use super::*;
+use crate::error::{EXIT_CODE_MAKEFILE_ERROR, EXIT_CODE_TASK_FAILED};
+use std::sync::Mutex;
+
+struct TestSink {
+    stdout: Mutex<Vec<u8>>,
+}
+
+impl OutputSink for TestSink {
+    fn write_stdout(&self, data: &[u8]) {
+        self.stdout.lock().unwrap().extend_from_slice(data);
+    }
+
+    fn write_stderr(&self, _data: &[u8]) {}
+}
+
+fn create_event(event: EventType, task: &str, exit_code: Option<i32>) -> Event {
+    Event {
+        event,
+        task: task.to_string(),
+        timestamp: 0,
+        level: 0,
+        member: None,
+        fail_message: None,
+        exit_code,
+        stderr: None,
+        duration: None,
+        success: None,
+    }
+}
+
+#[test]
+fn makefile_plan() {
+    let makefile =
+        Makefile::load("./src/lib/test/makefiles/api.toml", &LoadOptions::new()).unwrap();
+
+    let execution_plan = makefile.plan("api-flow").unwrap();
+
+    let names: Vec<String> = execution_plan
+        .steps
+        .iter()
+        .map(|step| step.name.clone())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["init", "api-first", "api-skipped", "api-flow", "end"]
+    );
+}
+
+#[test]
+fn makefile_plan_task_not_found() {
+    let makefile =
+        Makefile::load("./src/lib/test/makefiles/api.toml", &LoadOptions::new()).unwrap();
+
+    let error = makefile.plan("api-missing").unwrap_err();
+
+    assert_eq!(
+        error,
+        CargoMakeError::TaskNotFound("Task api-missing not found".to_string())
+    );
+}
+
+#[test]
+fn makefile_load_not_found() {
+    let error =
+        Makefile::load("./src/lib/test/makefiles/missing.toml", &LoadOptions::new()).unwrap_err();
+
+    assert_eq!(error.exit_code(), EXIT_CODE_MAKEFILE_ERROR);
+}
+
+#[test]
+fn makefile_load_invalid_cwd() {
+    let mut options = LoadOptions::new();
+    options.cwd = Some("./src/lib/test/makefiles/missing".to_string());
+
+    let error = Makefile::load("api.toml", &options).unwrap_err();
+
+    match error {
+        CargoMakeError::Other(message) => {
+            assert!(message.starts_with("Unable to set current working directory to: "))
+        }
+        _ => panic!("expected a working directory error"),
+    };
+}
+
+#[test]
+#[ignore]
+fn makefile_load_and_run_with_cwd() {
+    let directory = env::current_dir().unwrap();
+    let mut load_options = LoadOptions::new();
+    load_options.cwd = Some("./src/lib/test/makefiles".to_string());
+
+    let makefile = Makefile::load("api.toml", &load_options).unwrap();
+
+    assert_eq!(env::current_dir().unwrap(), directory);
+
+    let execution_plan = makefile.plan("api-flow").unwrap();
+    assert_eq!(execution_plan.steps.len(), 5);
+    assert_eq!(env::current_dir().unwrap(), directory);
+
+    let mut run_options = RunOptions::new();
+    run_options.disable_workspace = true;
+    let result = makefile.run("api-flow", &run_options);
+
+    assert!(result.is_success());
+    assert_eq!(env::current_dir().unwrap(), directory);
+}
+
+#[test]
+#[ignore]
+fn makefile_run_valid() {
+    let makefile =
+        Makefile::load("./src/lib/test/makefiles/api.toml", &LoadOptions::new()).unwrap();
+    let sink = Arc::new(TestSink {
+        stdout: Mutex::new(vec![]),
+    });
+    let mut options = RunOptions::new();
+    options.output = Some(sink.clone());
+
+    let result = makefile.run("api-flow", &options);
+
+    assert!(result.is_success());
+    assert_eq!(result.steps.len(), 3);
+    assert_eq!(result.steps[0].name, "api-first");
+    assert_eq!(result.steps[0].status, StepStatus::Succeeded);
+    assert_eq!(result.steps[1].name, "api-skipped");
+    assert!(matches!(result.steps[1].status, StepStatus::Skipped(_)));
+    assert_eq!(result.steps[2].name, "api-flow");
+    assert_eq!(result.steps[2].status, StepStatus::Succeeded);
+
+    let stdout = String::from_utf8_lossy(&sink.stdout.lock().unwrap()).into_owned();
+    assert!(stdout.contains("api-first"));
+    assert!(stdout.contains("api-flow"));
+    assert!(!stdout.contains("api-skipped"));
+}
+
+#[test]
+#[ignore]
+fn makefile_run_failed() {
+    let makefile =
+        Makefile::load("./src/lib/test/makefiles/api.toml", &LoadOptions::new()).unwrap();
+    let mut options = RunOptions::new();
+    options.output = Some(Arc::new(TestSink {
+        stdout: Mutex::new(vec![]),
+    }));
+
+    let result = makefile.run("api-fail", &options);
+
+    assert!(!result.is_success());
+    assert_eq!(result.error.unwrap().exit_code(), EXIT_CODE_TASK_FAILED);
+    assert_eq!(result.steps.len(), 2);
+    assert_eq!(result.steps[0].status, StepStatus::Succeeded);
+    assert_eq!(result.steps[1].name, "api-fail");
+    assert_eq!(result.steps[1].status, StepStatus::Failed(Some(3)));
+}
+
+#[test]
+#[ignore]
+fn makefile_run_concurrent() {
+    let makefile =
+        Makefile::load("./src/lib/test/makefiles/api.toml", &LoadOptions::new()).unwrap();
+
+    let handles: Vec<_> = vec!["api-flow", "api-fail"]
+        .into_iter()
+        .map(|task| {
+            let makefile = makefile.clone();
+            std::thread::spawn(move || {
+                let sink = Arc::new(TestSink {
+                    stdout: Mutex::new(vec![]),
+                });
+                let mut options = RunOptions::new();
+                options.output = Some(sink.clone());
+
+                let result = makefile.run(task, &options);
+                let stdout = String::from_utf8_lossy(&sink.stdout.lock().unwrap()).into_owned();
+
+                (result, stdout)
+            })
+        })
+        .collect();
+    let mut results: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+
+    let (fail_result, fail_stdout) = results.pop().unwrap();
+    let (flow_result, flow_stdout) = results.pop().unwrap();
+
+    assert!(flow_result.is_success());
+    let flow_steps: Vec<&str> = flow_result
+        .steps
+        .iter()
+        .map(|step| step.name.as_str())
+        .collect();
+    assert_eq!(flow_steps, vec!["api-first", "api-skipped", "api-flow"]);
+    assert!(flow_stdout.contains("api-flow"));
+
+    assert!(!fail_result.is_success());
+    let fail_steps: Vec<&str> = fail_result
+        .steps
+        .iter()
+        .map(|step| step.name.as_str())
+        .collect();
+    assert_eq!(fail_steps, vec!["api-first", "api-fail"]);
+    assert!(!fail_stdout.contains("api-flow"));
+}
+
+#[test]
+#[ignore]
+fn makefile_run_isolated_env() {
+    let profile = env::var("CARGO_MAKE_PROFILE").ok();
+    let makefile_a = Makefile::load(
+        "./src/lib/test/makefiles/api_env_a.toml",
+        &LoadOptions::new(),
+    )
+    .unwrap();
+    let makefile_b = Makefile::load(
+        "./src/lib/test/makefiles/api_env_b.toml",
+        &LoadOptions::new(),
+    )
+    .unwrap();
+    let mut options = RunOptions::new();
+    options.output = Some(Arc::new(TestSink {
+        stdout: Mutex::new(vec![]),
+    }));
+
+    for makefile in vec![&makefile_a, &makefile_b, &makefile_a] {
+        let result = makefile.run("api-env", &options);
+
+        assert!(result.is_success());
+        assert_eq!(result.steps.len(), 1);
+        assert_eq!(result.steps[0].status, StepStatus::Succeeded);
+
+        assert!(env::var("CARGO_MAKE_API_TEST_ENV_A").is_err());
+        assert!(env::var("CARGO_MAKE_API_TEST_ENV_B").is_err());
+        assert_eq!(env::var("CARGO_MAKE_PROFILE").ok(), profile);
+    }
+}
+
+#[test]
+fn create_step_results_empty() {
+    let steps = create_step_results(&vec![]);
+
+    assert!(steps.is_empty());
+}
+
+#[test]
+fn create_step_results_mixed() {
+    let mut skipped = create_event(EventType::TaskSkipped, "skipped", None);
+    skipped.fail_message = Some("condition".to_string());
+    let mut finished = create_event(EventType::TaskFinished, "first", Some(0));
+    finished.duration = Some(10);
+
+    let steps = create_step_results(&vec![
+        create_event(EventType::TaskStarted, "first", None),
+        finished,
+        create_event(EventType::TaskStarted, "skipped", None),
+        skipped,
+        create_event(EventType::TaskStarted, "failed", None),
+        create_event(EventType::TaskFinished, "failed", Some(2)),
+        create_event(EventType::TaskStarted, "aborted", None),
+        create_event(EventType::FlowFinished, "flow", None),
+    ]);
+
+    assert_eq!(
+        steps,
+        vec![
+            StepResult {
+                name: "first".to_string(),
+                status: StepStatus::Succeeded,
+                duration: Duration::from_millis(10),
+            },
+            StepResult {
+                name: "skipped".to_string(),
+                status: StepStatus::Skipped("condition".to_string()),
+                duration: Duration::from_millis(0),
+            },
+            StepResult {
+                name: "failed".to_string(),
+                status: StepStatus::Failed(Some(2)),
+                duration: Duration::from_millis(0),
+            },
+            StepResult {
+                name: "aborted".to_string(),
+                status: StepStatus::Failed(None),
+                duration: Duration::from_millis(0),
+            },
+        ]
+    );
+}
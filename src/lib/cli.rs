@@ -10,7 +10,10 @@ mod cli_test;
 use crate::cli_commands;
 use crate::config;
 use crate::descriptor;
+use crate::descriptor::extend_source;
 use crate::environment;
+use crate::error::CargoMakeError;
+use crate::events;
 use crate::logger;
 use crate::logger::LoggerOptions;
 use crate::profile;
@@ -30,14 +33,22 @@ static DEFAULT_LOG_LEVEL: &str = "info";
 static DEFAULT_TASK_NAME: &str = "default";
 static DEFAULT_OUTPUT_FORMAT: &str = "default";
 
-fn run(cli_args: CliArgs, global_config: &GlobalConfig) {
+fn run(cli_args: CliArgs, global_config: &GlobalConfig) -> Result<(), CargoMakeError> {
     let start_time = SystemTime::now();
 
     recursion_level::increment();
 
+    // the schema is printed to the stdout so only errors are logged
+    let log_level = if cli_args.print_schema {
+        "error".to_string()
+    } else {
+        cli_args.log_level.clone()
+    };
+    // the json events are printed to the stdout so the log is written to the stderr
     logger::init(&LoggerOptions {
-        level: cli_args.log_level.clone(),
+        level: log_level,
         color: !cli_args.disable_color,
+        to_stderr: cli_args.output_format == "json-events" || events::is_json_events(),
     });
 
     if recursion_level::is_top() {
@@ -76,7 +87,7 @@ fn run(cli_args: CliArgs, global_config: &GlobalConfig) {
         Some(ref value) => Some(value.as_ref()),
         None => None,
     };
-    let home = environment::setup_cwd(cwd);
+    let home = environment::setup_cwd(cwd)?;
 
     let force_makefile = cli_args.build_file.is_some();
     let build_file = &cli_args
@@ -90,26 +101,31 @@ fn run(cli_args: CliArgs, global_config: &GlobalConfig) {
         .unwrap_or(profile::DEFAULT_PROFILE.to_string());
     let normalized_profile_name = profile::set(&profile_name);
 
-    environment::load_env_file(cli_args.env_file.clone());
+    environment::load_env_file(cli_args.env_file.clone())?;
 
     let env = cli_args.env.clone();
 
     let experimental = cli_args.experimental;
-    let descriptor_load_result = descriptor::load(&build_file, force_makefile, env, experimental);
-
-    let config = match descriptor_load_result {
-        Ok(config) => config,
-        Err(ref min_version) => {
-            error!(
-                "{} version: {} does not meet minimum required version: {}",
-                &cli_args.command, &VERSION, &min_version
-            );
-            panic!(
-                "{} version: {} does not meet minimum required version: {}",
-                &cli_args.command, &VERSION, &min_version
-            );
-        }
-    };
+
+    if cli_args.offline {
+        envmnt::set_bool(extend_source::OFFLINE_ENV_VAR_NAME, true);
+    }
+
+    if cli_args.print_schema {
+        return cli_commands::print_schema::print(&cli_args.output_file);
+    }
+
+    if cli_args.check_makefile {
+        return cli_commands::check_makefile::run(
+            &build_file,
+            force_makefile,
+            env,
+            experimental,
+            &cli_args.skip_tasks_pattern,
+        );
+    }
+
+    let config = descriptor::load(&build_file, force_makefile, env, experimental)?;
     let mut time_summary_vec = vec![];
     time_summary::add(
         &mut time_summary_vec,
@@ -123,7 +139,7 @@ fn run(cli_args: CliArgs, global_config: &GlobalConfig) {
         None => profile::set_additional(&vec![]),
     };
 
-    let env_info = environment::setup_env(&cli_args, &config, &task, home);
+    let env_info = environment::setup_env(&cli_args, &config, &task, home)?;
     time_summary::add(&mut time_summary_vec, "[Setup Env]", step_time);
 
     let crate_name = envmnt::get_or("CARGO_MAKE_CRATE_NAME", "");
@@ -143,10 +159,12 @@ fn run(cli_args: CliArgs, global_config: &GlobalConfig) {
             &cli_args.output_format,
             &cli_args.output_file,
             cli_args.list_category_steps,
-        );
+        )?;
     } else if cli_args.diff_execution_plan {
         let default_config = descriptor::load_internal_descriptors(true, experimental, None);
-        cli_commands::diff_steps::run(&default_config, &config, &task, &cli_args);
+        cli_commands::diff_steps::run(&default_config, &config, &task, &cli_args)?;
+    } else if cli_args.dry_run {
+        cli_commands::dry_run::run(config, &task, env_info, &cli_args)?;
     } else if cli_args.print_only {
         cli_commands::print_steps::print(
             &config,
@@ -154,7 +172,7 @@ fn run(cli_args: CliArgs, global_config: &GlobalConfig) {
             &cli_args.output_format,
             cli_args.disable_workspace,
             cli_args.skip_tasks_pattern,
-        );
+        )?;
     } else {
         runner::run(
             config,
@@ -163,8 +181,10 @@ fn run(cli_args: CliArgs, global_config: &GlobalConfig) {
             &cli_args,
             start_time,
             time_summary_vec,
-        );
+        )?;
     }
+
+    Ok(())
 }
 
 /// Handles the command line arguments and executes the runner.
@@ -234,6 +254,17 @@ fn run_for_args(
     cli_args.print_time_summary =
         cmd_matches.is_present("time-summary") || envmnt::is("CARGO_MAKE_PRINT_TIME_SUMMARY");
 
+    cli_args.jobs = match cmd_matches.value_of("jobs") {
+        Some(value) => match value.parse::<usize>() {
+            Ok(jobs) => Some(jobs),
+            Err(_) => None,
+        },
+        None => None,
+    };
+
+    cli_args.force_rerun =
+        cmd_matches.is_present("force-rerun") || envmnt::is("CARGO_MAKE_FORCE_RERUN");
+
     cli_args.env_file = match cmd_matches.value_of("envfile") {
         Some(value) => Some(value.to_string()),
         None => None,
@@ -249,6 +280,16 @@ fn run_for_args(
         None => None,
     };
 
+    cli_args.events_file = match cmd_matches.value_of("events-file") {
+        Some(value) => Some(value.to_string()),
+        None => None,
+    };
+
+    cli_args.report = match cmd_matches.value_of("report") {
+        Some(value) => Some(value.to_string()),
+        None => None,
+    };
+
     cli_args.output_file = match cmd_matches.value_of("output_file") {
         Some(value) => Some(value.to_string()),
         None => None,
@@ -262,18 +303,28 @@ fn run_for_args(
     cli_args.disable_check_for_updates = cmd_matches.is_present("disable-check-for-updates");
     cli_args.experimental = cmd_matches.is_present("experimental");
     cli_args.print_only = cmd_matches.is_present("print-steps");
+    cli_args.dry_run = cmd_matches.is_present("dry-run");
+    cli_args.explain_skips = cmd_matches.is_present("explain-skips");
     cli_args.disable_workspace = cmd_matches.is_present("no-workspace");
     cli_args.disable_on_error = cmd_matches.is_present("no-on-error");
     cli_args.allow_private = cmd_matches.is_present("allow-private");
     cli_args.skip_init_end_tasks = cmd_matches.is_present("skip-init-end-tasks");
     cli_args.list_all_steps = cmd_matches.is_present("list-steps");
     cli_args.diff_execution_plan = cmd_matches.is_present("diff-steps");
+    cli_args.check_makefile = cmd_matches.is_present("check-makefile");
+    cli_args.print_schema = cmd_matches.is_present("print-schema");
+    cli_args.offline = cmd_matches.is_present("offline");
 
     cli_args.skip_tasks_pattern = match cmd_matches.value_of("skip-tasks-pattern") {
         Some(value) => Some(value.to_string()),
         None => None,
     };
 
+    cli_args.affected_since = match cmd_matches.value_of("affected-since") {
+        Some(value) => Some(value.to_string()),
+        None => None,
+    };
+
     let default_task_name = match global_config.default_task_name {
         Some(ref value) => value.as_str(),
         None => &DEFAULT_TASK_NAME,
@@ -293,7 +344,9 @@ fn run_for_args(
     cli_args.task = task.to_string();
     cli_args.arguments = arguments;
 
-    run(cli_args, global_config);
+    if let Err(error) = run(cli_args, global_config) {
+        logger::exit_with_error(&error);
+    }
 }
 
 fn create_cli<'a>(
@@ -364,6 +417,12 @@ fn create_cli<'a>(
         .arg(Arg::new("no-workspace").long("--no-workspace").help(
             "Disable workspace support (tasks are triggered on workspace and not on members)",
         ))
+        .arg(
+            Arg::new("affected-since")
+                .long("--affected-since")
+                .value_name("GIT_REF")
+                .help("Only run the workspace flow on members affected by changes since the provided git ref"),
+        )
         .arg(
             Arg::new("no-on-error")
                 .long("--no-on-error")
@@ -427,6 +486,19 @@ fn create_cli<'a>(
                 .long("--time-summary")
                 .help("Print task level time summary at end of flow"),
         )
+        .arg(
+            Arg::new("jobs")
+                .long("--jobs")
+                .short('j')
+                .value_name("JOBS")
+                .validator(|value| value.parse::<usize>())
+                .help("The maximum amount of tasks to run in parallel (default 1)"),
+        )
+        .arg(
+            Arg::new("force-rerun")
+                .long("--force-rerun")
+                .help("Runs all tasks even if their inputs did not change since their last run"),
+        )
         .arg(
             Arg::new("experimental")
                 .long("--experimental")
@@ -441,9 +513,28 @@ fn create_cli<'a>(
             Arg::new("output-format")
                 .long("--output-format")
                 .value_name("OUTPUT FORMAT")
-                .possible_values(&["default", "short-description", "markdown", "markdown-single-page", "markdown-sub-section", "autocomplete"])
+                .possible_values(&["default", "short-description", "markdown", "markdown-single-page", "markdown-sub-section", "autocomplete", "json-events", "dot", "mermaid"])
                 .default_value(DEFAULT_OUTPUT_FORMAT)
-                .help("The print/list steps format (some operations do not support all formats)"),
+                .help("The print/list steps format (some operations do not support all formats) or json-events to print the flow events"),
+        )
+        .arg(
+            Arg::new("events-file")
+                .long("--events-file")
+                .value_name("FILE")
+                .help("Appends the flow events (JSON lines) to the provided file"),
+        )
+        .arg(
+            Arg::new("report")
+                .long("--report")
+                .value_name("FORMAT=FILE")
+                .validator(|value| {
+                    if value.starts_with("junit=") && value.len() > "junit=".len() {
+                        Ok(())
+                    } else {
+                        Err("Expected format: junit=<file>".to_string())
+                    }
+                })
+                .help("Writes a report of the flow to the provided file (supported formats: junit)"),
         )
         .arg(
             Arg::new("output_file")
@@ -455,6 +546,15 @@ fn create_cli<'a>(
             "Only prints the steps of the build in the order they will \
              be invoked but without invoking them",
         ))
+        .arg(Arg::new("dry-run").long("--dry-run").help(
+            "Prints the fully resolved command or script of each task \
+             without invoking them",
+        ))
+        .arg(
+            Arg::new("explain-skips")
+                .long("--explain-skips")
+                .help("Prints the condition criterion which caused each skipped task to be skipped"),
+        )
         .arg(
             Arg::new("list-steps")
                 .long("--list-all-steps")
@@ -471,6 +571,21 @@ fn create_cli<'a>(
                 .long("--diff-steps")
                 .help("Runs diff between custom flow and prebuilt flow (requires git)"),
         )
+        .arg(
+            Arg::new("check-makefile")
+                .long("--check-makefile")
+                .help("Validates the makefile and all the makefiles it extends and reports all problems found"),
+        )
+        .arg(
+            Arg::new("print-schema")
+                .long("--print-schema")
+                .help("Prints the makefile JSON schema (can be written to a file via --output-file)"),
+        )
+        .arg(
+            Arg::new("offline")
+                .long("--offline")
+                .help("Only use the cached git/url extended makefiles and never fetch them"),
+        )
         .arg(Arg::new("TASK_CMD")
                 .multiple_occurrences(true)
                 .help("The task to execute, potentially including arguments which can be accessed in the task itself.")
@@ -485,7 +600,10 @@ fn create_cli<'a>(
 
 /// Handles the command line arguments and executes the runner.
 pub(crate) fn run_cli(command_name: String, sub_command: bool) {
-    let global_config = config::load();
+    let global_config = match config::load() {
+        Ok(global_config) => global_config,
+        Err(error) => logger::exit_with_error(&error),
+    };
 
     let app = create_cli(&global_config, &command_name, sub_command);
 
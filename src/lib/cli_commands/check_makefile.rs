@@ -0,0 +1,645 @@
+//! # check_makefile
+//!
+//! Validates the makefile and all the makefiles it extends and reports all problems found.
+//!
+
+#[cfg(test)]
+#[path = "check_makefile_test.rs"]
+mod check_makefile_test;
+
+use crate::descriptor;
+use crate::descriptor::descriptor_deserializer;
+use crate::descriptor::extend_source;
+use crate::error::CargoMakeError;
+use crate::functions;
+use crate::io;
+use crate::parameters;
+use crate::retry;
+use crate::timeout;
+use crate::types::{Config, DependencyIdentifier, Extend, RunTaskInfo, RunTaskName, Task};
+use fsio::path::from_path::FromPath;
+use indexmap::IndexMap;
+use regex::Regex;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds a single problem found in a makefile
+struct Problem {
+    /// The makefile path
+    file: String,
+    /// The line and column (1 based) of the problem (if found)
+    position: Option<(usize, usize)>,
+    /// The problem description
+    message: String,
+}
+
+impl Problem {
+    fn new(file: &str, position: Option<(usize, usize)>, message: &str) -> Problem {
+        Problem {
+            file: file.to_string(),
+            position,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.position {
+            Some((line, column)) => write!(
+                formatter,
+                "{}:{}:{}: {}",
+                &self.file, line, column, &self.message
+            ),
+            None => write!(formatter, "{}: {}", &self.file, &self.message),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Holds a makefile from the extend chain
+struct MakefileInfo {
+    /// The makefile path
+    file: String,
+    /// The makefile text
+    content: String,
+    /// The task names defined in the makefile
+    task_names: Vec<String>,
+}
+
+/// Splits a table header (for example [tasks."my.task"]) to its keys
+fn parse_table_header(line: &str) -> Option<Vec<String>> {
+    let trimmed = line.trim();
+    let header = if trimmed.starts_with("[[") {
+        trimmed[2..].splitn(2, "]]").next()
+    } else if trimmed.starts_with('[') {
+        trimmed[1..].splitn(2, ']').next()
+    } else {
+        None
+    }?;
+
+    let mut keys = vec![];
+    let mut key = String::new();
+    let mut quote = None;
+    for character in header.chars() {
+        match quote {
+            Some(quote_character) => {
+                if character == quote_character {
+                    quote = None;
+                } else {
+                    key.push(character);
+                }
+            }
+            None => match character {
+                '"' | '\'' => quote = Some(character),
+                '.' => {
+                    keys.push(key.trim().to_string());
+                    key.clear();
+                }
+                _ => key.push(character),
+            },
+        }
+    }
+    keys.push(key.trim().to_string());
+
+    Some(keys)
+}
+
+/// Returns the line index of the first table header which starts with the provided keys
+fn find_table(content: &str, keys: &[String]) -> Option<usize> {
+    content
+        .lines()
+        .position(|line| match parse_table_header(line) {
+            Some(header_keys) => header_keys.starts_with(keys),
+            None => false,
+        })
+}
+
+/// Returns the position of the key within the table which starts at the provided line index
+fn find_key(content: &str, table_line: Option<usize>, key: &str) -> Option<(usize, usize)> {
+    let (start, table_keys) = match table_line {
+        Some(index) => (
+            index + 1,
+            content.lines().nth(index).and_then(parse_table_header),
+        ),
+        None => (0, None),
+    };
+
+    for (index, line) in content.lines().enumerate().skip(start) {
+        let trimmed = line.trim_start();
+        if let Some(ref table_keys) = table_keys {
+            if trimmed.starts_with('[') {
+                // sub tables of the same table are searched as well
+                match parse_table_header(line) {
+                    Some(ref header_keys) if header_keys.starts_with(table_keys) => {
+                        if header_keys
+                            .get(table_keys.len())
+                            .map(|value| value.as_str())
+                            == Some(key)
+                        {
+                            return Some((index + 1, line.len() - trimmed.len() + 1));
+                        }
+
+                        continue;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+
+        let key_part = trimmed.splitn(2, '=').next().unwrap_or("").trim();
+        let key_part = key_part.trim_matches(|character| character == '"' || character == '\'');
+        if trimmed.contains('=') && key_part == key {
+            return Some((index + 1, line.len() - trimmed.len() + 1));
+        }
+    }
+
+    None
+}
+
+/// Returns the position of the provided keys path (for example tasks.build.command)
+fn find_position(content: &str, path: &[String]) -> Option<(usize, usize)> {
+    if path.is_empty() {
+        return None;
+    }
+
+    // find the longest table header matching the path and search the next key in it
+    for length in (1..path.len() + 1).rev() {
+        let table_keys = &path[0..length];
+        if let Some(table_line) = find_table(content, table_keys) {
+            if length == path.len() {
+                let line = content.lines().nth(table_line).unwrap_or("");
+                let column = line.len() - line.trim_start().len() + 1;
+                return Some((table_line + 1, column));
+            }
+
+            return find_key(content, Some(table_line), &path[length])
+                .or(Some((table_line + 1, 1)));
+        }
+    }
+
+    find_key(content, None, &path[0])
+}
+
+fn find_task_key_position(content: &str, task_name: &str, key: &str) -> Option<(usize, usize)> {
+    find_position(
+        content,
+        &vec!["tasks".to_string(), task_name.to_string(), key.to_string()],
+    )
+}
+
+fn get_extend_files(extend: &Extend) -> Vec<(Result<String, String>, bool)> {
+    match extend {
+        Extend::Path(file) => vec![(Ok(file.to_string()), true)],
+        Extend::Options(options) => vec![(
+            extend_source::get_file(options),
+            !options.optional.unwrap_or(false),
+        )],
+        Extend::List(list) => list
+            .iter()
+            .map(|options| {
+                (
+                    extend_source::get_file(options),
+                    !options.optional.unwrap_or(false),
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Loads the makefile and all the makefiles it extends while collecting parsing problems.<br>
+/// Returns false if any of the makefiles could not be loaded.
+fn load_makefiles(
+    file_path: &PathBuf,
+    extend_chain: &mut Vec<String>,
+    makefiles: &mut Vec<MakefileInfo>,
+    problems: &mut Vec<Problem>,
+) -> bool {
+    let file: String = FromPath::from_path(file_path);
+    let absolute_file = io::canonicalize_to_string(&file);
+    if extend_chain.contains(&absolute_file) {
+        problems.push(Problem::new(
+            &file,
+            None,
+            "Makefile extends itself (extend cycle).",
+        ));
+        return false;
+    } else if makefiles
+        .iter()
+        .any(|makefile| io::canonicalize_to_string(&makefile.file) == absolute_file)
+    {
+        // already checked via another extend path
+        return true;
+    }
+
+    let content = match io::read_text_file(file_path) {
+        Ok(value) => value,
+        Err(error) => {
+            problems.push(Problem::new(&file, None, &error.to_string()));
+            return false;
+        }
+    };
+
+    let (external_config, unknown_keys) =
+        match descriptor_deserializer::load_external_config_with_unknown_keys(&content) {
+            Ok(value) => value,
+            Err(error) => {
+                let position = error
+                    .line_col()
+                    .map(|(line, column)| (line + 1, column + 1));
+                problems.push(Problem::new(
+                    &file,
+                    position,
+                    &format!("Unable to parse makefile: {}", error),
+                ));
+                return false;
+            }
+        };
+
+    for unknown_key in unknown_keys {
+        // optional values are represented as ? in the keys path
+        let path: Vec<String> = unknown_key
+            .split('.')
+            .filter(|key| *key != "?")
+            .map(|key| key.to_string())
+            .collect();
+        problems.push(Problem::new(
+            &file,
+            find_position(&content, &path),
+            &format!("Unknown key: {}", path.join(".")),
+        ));
+    }
+
+    let task_names = match external_config.tasks {
+        Some(ref tasks) => tasks.keys().map(|name| name.to_string()).collect(),
+        None => vec![],
+    };
+    makefiles.push(MakefileInfo {
+        file: file.clone(),
+        content: content.clone(),
+        task_names,
+    });
+
+    let mut loaded = true;
+    if let Some(ref extend) = external_config.extend {
+        extend_chain.push(absolute_file);
+
+        let parent_path = file_path.parent().unwrap_or(Path::new("."));
+
+        for (extend_file, force) in get_extend_files(extend) {
+            let extend_file = match extend_file {
+                Ok(value) => value,
+                Err(message) => {
+                    if force {
+                        loaded = false;
+                        problems.push(Problem::new(
+                            &file,
+                            find_position(&content, &vec!["extend".to_string()]),
+                            &message,
+                        ));
+                    }
+                    continue;
+                }
+            };
+            let extend_path = parent_path.join(&extend_file);
+
+            if extend_path.is_file() {
+                loaded = load_makefiles(&extend_path, extend_chain, makefiles, problems) && loaded;
+            } else if force {
+                loaded = false;
+                problems.push(Problem::new(
+                    &file,
+                    find_position(&content, &vec!["extend".to_string()]),
+                    &format!("Extended makefile: {} not found.", &extend_file),
+                ));
+            }
+        }
+
+        extend_chain.pop();
+    }
+
+    loaded
+}
+
+fn get_run_task_names(run_task: &RunTaskInfo) -> Vec<(String, Option<String>, bool)> {
+    let names_from = |name: &RunTaskName| match name {
+        RunTaskName::Single(value) => vec![value.to_string()],
+        RunTaskName::Multiple(values) => values.clone(),
+    };
+
+    // (task name, cleanup task, fork)
+    match run_task {
+        RunTaskInfo::Name(name) => vec![(name.to_string(), None, false)],
+        RunTaskInfo::Details(details) => names_from(&details.name)
+            .into_iter()
+            .map(|name| {
+                (
+                    name,
+                    details.cleanup_task.clone(),
+                    details.fork.unwrap_or(false),
+                )
+            })
+            .collect(),
+        RunTaskInfo::Routing(routing_list) => routing_list
+            .iter()
+            .flat_map(|routing| {
+                names_from(&routing.name).into_iter().map(move |name| {
+                    (
+                        name,
+                        routing.cleanup_task.clone(),
+                        routing.fork.unwrap_or(false),
+                    )
+                })
+            })
+            .collect(),
+    }
+}
+
+fn get_alias_for_platform(task: &Task, platform: &str) -> Option<String> {
+    let platform_alias = match platform {
+        "linux" => task.linux_alias.clone(),
+        "windows" => task.windows_alias.clone(),
+        "mac" => task.mac_alias.clone(),
+        _ => None,
+    };
+
+    platform_alias.or(task.alias.clone())
+}
+
+/// Returns the alias chain problem (missing task or cycle) of the task for the given platform
+fn check_alias_chain(config: &Config, task_name: &str, platform: &str) -> Option<String> {
+    let mut chain = vec![task_name.to_string()];
+
+    loop {
+        let current = chain.last().unwrap().to_string();
+        let alias = match config.tasks.get(&current) {
+            Some(task) => get_alias_for_platform(task, platform),
+            None => return Some(format!("Alias: {} points to a missing task.", &current)),
+        };
+
+        match alias {
+            Some(alias) => {
+                if chain.contains(&alias) {
+                    chain.push(alias);
+                    return Some(format!("Alias cycle detected: {}", chain.join(" -> ")));
+                }
+
+                chain.push(alias);
+            }
+            None => return None,
+        }
+    }
+}
+
+fn check_task(
+    config: &Config,
+    makefile: &MakefileInfo,
+    task_name: &str,
+    task: &Task,
+    problems: &mut Vec<Problem>,
+) {
+    let content = &makefile.content;
+    let mut add_problem = |key: &str, message: String| {
+        problems.push(Problem::new(
+            &makefile.file,
+            find_task_key_position(content, task_name, key),
+            &message,
+        ));
+    };
+
+    // the task as defined and the task after applying the current platform overrides
+    let normalized_task = task.clone().get_normalized_task();
+    let all_tasks = vec![task.clone(), normalized_task.clone()];
+
+    for current_task in &all_tasks {
+        if !current_task.is_valid() {
+            let key = if current_task.script.is_some() {
+                "script"
+            } else {
+                "command"
+            };
+            add_problem(
+                key,
+                format!(
+                    "Task: {} contains multiple actions (command, script, run_task).",
+                    task_name
+                ),
+            );
+            break;
+        }
+    }
+
+    if let Some(ref dependencies) = normalized_task.dependencies {
+        for dependency in dependencies {
+            let is_local = match dependency {
+                DependencyIdentifier::Definition(identifier) => identifier.path.is_none(),
+                DependencyIdentifier::Name(_) => true,
+            };
+
+            if is_local && !config.tasks.contains_key(dependency.name()) {
+                add_problem(
+                    "dependencies",
+                    format!(
+                        "Task: {} dependency: {} not found.",
+                        task_name,
+                        dependency.name()
+                    ),
+                );
+            }
+        }
+    }
+
+    if let Some(ref run_task) = normalized_task.run_task {
+        for (name, cleanup_task, fork) in get_run_task_names(run_task) {
+            if !config.tasks.contains_key(&name) {
+                add_problem(
+                    "run_task",
+                    format!("Task: {} run_task: {} not found.", task_name, &name),
+                );
+            }
+
+            if let Some(cleanup_task) = cleanup_task {
+                if !fork {
+                    add_problem(
+                        "run_task",
+                        format!(
+                            "Task: {} defines a cleanup_task without fork=true.",
+                            task_name
+                        ),
+                    );
+                } else if !config.tasks.contains_key(&cleanup_task) {
+                    add_problem(
+                        "run_task",
+                        format!(
+                            "Task: {} cleanup_task: {} not found.",
+                            task_name, &cleanup_task
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    if let Some(ref value) = normalized_task.timeout {
+        if timeout::parse(value).is_none() {
+            add_problem(
+                "timeout",
+                format!("Task: {} has an invalid timeout: {}", task_name, value),
+            );
+        }
+    }
+
+    if let Some(ref options) = normalized_task.retries {
+        if let Err(error) = retry::validate(options) {
+            add_problem(
+                "retries",
+                format!("Task: {} has invalid retries options: {}", task_name, error),
+            );
+        }
+    }
+
+    if let Some(ref task_parameters) = normalized_task.parameters {
+        for (name, parameter) in task_parameters {
+            if let Err(error) = parameters::validate(name, parameter) {
+                add_problem(
+                    "parameters",
+                    format!("Task: {} has invalid parameters: {}", task_name, error),
+                );
+            }
+        }
+    }
+
+    let mut alias_problems = vec![];
+    for platform in &["", "linux", "windows", "mac"] {
+        if let Some(message) = check_alias_chain(config, task_name, platform) {
+            if !alias_problems.contains(&message) {
+                alias_problems.push(message);
+            }
+        }
+    }
+    for message in alias_problems {
+        add_problem("alias", message);
+    }
+
+    // functions are only invoked in the task args
+    let mut unknown_functions = vec![];
+    for current_task in &all_tasks {
+        if let Some(ref args) = current_task.args {
+            for arg in args {
+                if let Some(function_name) = functions::get_invoked_function_name(arg) {
+                    if !functions::is_function_defined(&function_name)
+                        && !unknown_functions.contains(&function_name)
+                    {
+                        unknown_functions.push(function_name);
+                    }
+                }
+            }
+        }
+    }
+    for function_name in unknown_functions {
+        add_problem(
+            "args",
+            format!(
+                "Task: {} uses unknown function: @@{}",
+                task_name, &function_name
+            ),
+        );
+    }
+}
+
+/// Checks the tasks defined in the makefiles using the fully loaded (merged) config
+fn check_tasks(config: &Config, makefiles: &Vec<MakefileInfo>, problems: &mut Vec<Problem>) {
+    let mut checked_tasks: IndexMap<String, bool> = IndexMap::new();
+
+    // the first makefile defining the task (the extending one) is used for the problem location
+    for makefile in makefiles {
+        for task_name in &makefile.task_names {
+            if checked_tasks.contains_key(task_name) {
+                continue;
+            }
+            checked_tasks.insert(task_name.to_string(), true);
+
+            if let Some(task) = config.tasks.get(task_name) {
+                check_task(config, makefile, task_name, task, problems);
+            }
+        }
+    }
+}
+
+fn check_skip_tasks_pattern(skip_tasks_pattern: &Option<String>, problems: &mut Vec<Problem>) {
+    if let Some(ref pattern) = skip_tasks_pattern {
+        if let Err(error) = Regex::new(pattern) {
+            problems.push(Problem::new(
+                "--skip-tasks",
+                None,
+                &format!("Invalid skip tasks pattern: {} error: {}", pattern, error),
+            ));
+        }
+    }
+}
+
+fn get_problems(
+    file_name: &str,
+    force: bool,
+    env: Option<Vec<String>>,
+    experimental: bool,
+    skip_tasks_pattern: &Option<String>,
+) -> Vec<Problem> {
+    let mut problems = vec![];
+
+    check_skip_tasks_pattern(skip_tasks_pattern, &mut problems);
+
+    let file_path = PathBuf::from(file_name);
+    let mut makefiles = vec![];
+    let loaded = if file_path.is_file() {
+        load_makefiles(&file_path, &mut vec![], &mut makefiles, &mut problems)
+    } else if force {
+        problems.push(Problem::new(file_name, None, "Makefile not found."));
+        false
+    } else {
+        true
+    };
+
+    // the full config can only be loaded if all makefiles were parsed
+    if loaded {
+        match descriptor::load(file_name, force, env, experimental) {
+            Ok(config) => check_tasks(&config, &makefiles, &mut problems),
+            Err(CargoMakeError::VersionTooOld(min_version)) => problems.push(Problem::new(
+                file_name,
+                None,
+                &format!("Makefile requires a newer version: {}", &min_version),
+            )),
+            Err(error) => problems.push(Problem::new(file_name, None, &error.to_string())),
+        }
+    }
+
+    problems
+}
+
+/// Checks the makefile and its extend chain, prints all problems found and fails if any problem was found
+pub(crate) fn run(
+    file_name: &str,
+    force: bool,
+    env: Option<Vec<String>>,
+    experimental: bool,
+    skip_tasks_pattern: &Option<String>,
+) -> Result<(), CargoMakeError> {
+    let problems = get_problems(file_name, force, env, experimental, skip_tasks_pattern);
+
+    for problem in &problems {
+        println!("{}", problem);
+    }
+
+    if problems.is_empty() {
+        info!("No problems found.");
+
+        Ok(())
+    } else {
+        Err(CargoMakeError::DescriptorParseError(format!(
+            "Found {} problem(s) in makefile: {}",
+            problems.len(),
+            file_name
+        )))
+    }
+}
@@ -0,0 +1,301 @@
+use super::*;
+use crate::types::ConfigSection;
+
+static TEST_DIRECTORY: &str = "./src/lib/test/makefiles/check_makefile";
+
+fn get_messages(file_name: &str) -> Vec<String> {
+    let problems = get_problems(
+        &format!("{}/{}", TEST_DIRECTORY, file_name),
+        true,
+        None,
+        false,
+        &None,
+    );
+
+    problems.iter().map(|problem| problem.to_string()).collect()
+}
+
+#[test]
+fn problem_to_string_with_position() {
+    let problem = Problem::new("Makefile.toml", Some((2, 3)), "message");
+
+    assert_eq!(problem.to_string(), "Makefile.toml:2:3: message");
+}
+
+#[test]
+fn problem_to_string_without_position() {
+    let problem = Problem::new("Makefile.toml", None, "message");
+
+    assert_eq!(problem.to_string(), "Makefile.toml: message");
+}
+
+#[test]
+fn parse_table_header_simple() {
+    let keys = parse_table_header("  [tasks.build]  ").unwrap();
+
+    assert_eq!(keys, vec!["tasks", "build"]);
+}
+
+#[test]
+fn parse_table_header_quoted() {
+    let keys = parse_table_header(r#"[tasks."my.task".linux]"#).unwrap();
+
+    assert_eq!(keys, vec!["tasks", "my.task", "linux"]);
+}
+
+#[test]
+fn parse_table_header_array() {
+    let keys = parse_table_header("[[tasks.build.list]]").unwrap();
+
+    assert_eq!(keys, vec!["tasks", "build", "list"]);
+}
+
+#[test]
+fn parse_table_header_not_header() {
+    let keys = parse_table_header("command = \"echo\"");
+
+    assert!(keys.is_none());
+}
+
+#[test]
+fn find_position_key_in_table() {
+    let content = r#"
+[tasks.other]
+command = "echo"
+
+[tasks.build]
+description = "test"
+  command = "echo"
+"#;
+
+    let position = find_position(
+        content,
+        &vec![
+            "tasks".to_string(),
+            "build".to_string(),
+            "command".to_string(),
+        ],
+    );
+
+    assert_eq!(position, Some((7, 3)));
+}
+
+#[test]
+fn find_position_key_in_sub_table() {
+    let content = r#"
+[tasks.build]
+description = "test"
+
+[tasks.build.linux]
+command = "echo"
+"#;
+
+    let position = find_position(
+        content,
+        &vec![
+            "tasks".to_string(),
+            "build".to_string(),
+            "linux".to_string(),
+            "command".to_string(),
+        ],
+    );
+
+    assert_eq!(position, Some((6, 1)));
+}
+
+#[test]
+fn find_position_key_not_found() {
+    let content = r#"
+[tasks.build]
+description = "test"
+
+[tasks.other]
+command = "echo"
+"#;
+
+    let position = find_position(
+        content,
+        &vec![
+            "tasks".to_string(),
+            "build".to_string(),
+            "command".to_string(),
+        ],
+    );
+
+    assert_eq!(position, Some((2, 1)));
+}
+
+#[test]
+fn find_position_top_level_key() {
+    let content = r#"extend = "./base.toml"
+
+[tasks.build]
+"#;
+
+    let position = find_position(content, &vec!["extend".to_string()]);
+
+    assert_eq!(position, Some((1, 1)));
+}
+
+#[test]
+fn check_alias_chain_cycle() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+    let mut task1 = Task::new();
+    task1.alias = Some("task2".to_string());
+    let mut task2 = Task::new();
+    task2.linux_alias = Some("task1".to_string());
+    config.tasks.insert("task1".to_string(), task1);
+    config.tasks.insert("task2".to_string(), task2);
+
+    assert!(check_alias_chain(&config, "task1", "").is_none());
+    assert_eq!(
+        check_alias_chain(&config, "task1", "linux").unwrap(),
+        "Alias cycle detected: task1 -> task2 -> task1"
+    );
+}
+
+#[test]
+fn get_problems_valid() {
+    let messages = get_messages("valid.toml");
+
+    assert!(messages.is_empty());
+}
+
+#[test]
+fn get_problems_invalid() {
+    let messages = get_messages("invalid.toml");
+
+    let file = format!("{}/invalid.toml", TEST_DIRECTORY);
+    let base_file = format!("{}/./invalid_base.toml", TEST_DIRECTORY);
+    assert_eq!(
+        messages,
+        vec![
+            format!("{}:2:1: Unknown key: config.unknown_config_key", &base_file),
+            format!("{}:5:1: Unknown key: tasks.unknown-key.comand", &base_file),
+            format!(
+                "{}:5:1: Task: multiple-actions contains multiple actions (command, script, run_task).",
+                &file
+            ),
+            format!(
+                "{}:8:1: Task: missing-dependency dependency: missing not found.",
+                &file
+            ),
+            format!("{}:11:1: Task: missing-run-task run_task: missing not found.", &file),
+            format!(
+                "{}:14:1: Task: cleanup-without-fork defines a cleanup_task without fork=true.",
+                &file
+            ),
+            format!(
+                "{}:17:1: Alias cycle detected: alias1 -> alias2 -> alias1",
+                &file
+            ),
+            format!(
+                "{}:20:1: Alias cycle detected: alias2 -> alias1 -> alias2",
+                &file
+            ),
+            format!(
+                "{}:24:1: Task: unknown-function uses unknown function: @@unknown",
+                &file
+            ),
+            format!(
+                "{}:28:1: Task: invalid-timeout has an invalid timeout: 10 minutes",
+                &file
+            ),
+            format!(
+                "{}:32:1: Task: invalid-retries has invalid retries options: Invalid retries backoff: random (supported values: fixed, linear, exponential)",
+                &file
+            ),
+            format!(
+                "{}:36:1: Task: invalid-parameters has invalid parameters: Enum parameter: mode does not define any values",
+                &file
+            ),
+            format!(
+                "{}:36:1: Task: invalid-parameters has invalid parameters: Invalid value: one for parameter: --count (expected an integer)",
+                &file
+            ),
+        ]
+    );
+}
+
+#[test]
+fn get_problems_syntax_error() {
+    let messages = get_messages("syntax_error.toml");
+
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].starts_with(&format!("{}/syntax_error.toml:2:", TEST_DIRECTORY)));
+    assert!(messages[0].contains("Unable to parse makefile"));
+}
+
+#[test]
+fn get_problems_missing_extend() {
+    let messages = get_messages("missing_extend.toml");
+
+    assert_eq!(
+        messages,
+        vec![format!(
+            "{}/missing_extend.toml:1:1: Extended makefile: ./missing.toml not found.",
+            TEST_DIRECTORY
+        )]
+    );
+}
+
+#[test]
+fn get_problems_extend_cycle() {
+    let messages = get_messages("cycle.toml");
+
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].ends_with("Makefile extends itself (extend cycle)."));
+}
+
+#[test]
+fn get_problems_invalid_skip_tasks_pattern() {
+    let problems = get_problems(
+        &format!("{}/valid.toml", TEST_DIRECTORY),
+        true,
+        None,
+        false,
+        &Some("[".to_string()),
+    );
+
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].file, "--skip-tasks");
+}
+
+#[test]
+fn run_with_problems() {
+    let result = run(
+        &format!("{}/invalid.toml", TEST_DIRECTORY),
+        true,
+        None,
+        false,
+        &None,
+    );
+
+    match result {
+        Err(error) => {
+            assert_eq!(error.exit_code(), 3);
+            assert!(error.to_string().starts_with("Found "));
+        }
+        _ => panic!("expected problems to be found"),
+    };
+}
+
+#[test]
+fn run_without_problems() {
+    let result = run(
+        &format!("{}/valid.toml", TEST_DIRECTORY),
+        true,
+        None,
+        false,
+        &None,
+    );
+
+    assert!(result.is_ok());
+}
@@ -8,6 +8,7 @@
 mod diff_steps_test;
 
 use crate::command;
+use crate::error::CargoMakeError;
 use crate::execution_plan::create as create_execution_plan;
 use crate::io::{create_file, delete_file};
 use crate::types::{CliArgs, Config, ExecutionPlan};
@@ -27,7 +28,7 @@ pub(crate) fn run(
     external_config: &Config,
     task: &str,
     cli_args: &CliArgs,
-) {
+) -> Result<(), CargoMakeError> {
     let skip_tasks_pattern = match cli_args.skip_tasks_pattern {
         Some(ref pattern) => match Regex::new(pattern) {
             Ok(reg) => Some(reg),
@@ -46,7 +47,7 @@ pub(crate) fn run(
         true,
         false,
         &skip_tasks_pattern,
-    );
+    )?;
 
     let external_execution_plan = create_execution_plan(
         external_config,
@@ -55,16 +56,16 @@ pub(crate) fn run(
         true,
         false,
         &skip_tasks_pattern,
-    );
+    )?;
 
     let internal_file = create_file(
         &move |file: &mut File| write_as_string(&internal_execution_plan, &file),
         "toml",
-    );
+    )?;
     let external_file = create_file(
         &move |file: &mut File| write_as_string(&external_execution_plan, &file),
         "toml",
-    );
+    )?;
 
     info!("Printing diff...");
     command::run_command(
@@ -77,10 +78,12 @@ pub(crate) fn run(
             external_file.to_string(),
         ]),
         false,
-    );
+    )?;
 
     delete_file(&internal_file);
     delete_file(&external_file);
 
     info!("Done");
+
+    Ok(())
 }
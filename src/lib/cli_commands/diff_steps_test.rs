@@ -19,7 +19,7 @@ fn run_same() {
 
     let config2 = config1.clone();
 
-    run(&config1, &config2, "test", &CliArgs::new());
+    run(&config1, &config2, "test", &CliArgs::new()).unwrap();
 }
 
 #[test]
@@ -53,7 +53,7 @@ fn run_different() {
     config2.tasks.insert("end".to_string(), Task::new());
     config2.tasks.insert("test".to_string(), Task::new());
 
-    run(&config1, &config2, "test", &CliArgs::new());
+    run(&config1, &config2, "test", &CliArgs::new()).unwrap();
 }
 
 #[test]
@@ -90,11 +90,10 @@ fn run_different_with_skip() {
     let mut cli_args = CliArgs::new();
     cli_args.skip_tasks_pattern = Some("test".to_string());
 
-    run(&config1, &config2, "test", &cli_args);
+    run(&config1, &config2, "test", &cli_args).unwrap();
 }
 
 #[test]
-#[should_panic]
 fn run_missing_task_in_first_config() {
     let mut config1 = Config {
         config: ConfigSection::new(),
@@ -121,11 +120,15 @@ fn run_missing_task_in_first_config() {
     config2.tasks.insert("end".to_string(), Task::new());
     config2.tasks.insert("test".to_string(), Task::new());
 
-    run(&config1, &config2, "test", &CliArgs::new());
+    let output = run(&config1, &config2, "test", &CliArgs::new());
+
+    assert_eq!(
+        output.unwrap_err(),
+        CargoMakeError::TaskNotFound("Task test not found".to_string())
+    );
 }
 
 #[test]
-#[should_panic]
 fn run_missing_task_in_second_config() {
     let mut config1 = Config {
         config: ConfigSection::new(),
@@ -152,5 +155,10 @@ fn run_missing_task_in_second_config() {
     config2.tasks.insert("init".to_string(), Task::new());
     config2.tasks.insert("end".to_string(), Task::new());
 
-    run(&config1, &config2, "test", &CliArgs::new());
+    let output = run(&config1, &config2, "test", &CliArgs::new());
+
+    assert_eq!(
+        output.unwrap_err(),
+        CargoMakeError::TaskNotFound("Task test not found".to_string())
+    );
 }
@@ -0,0 +1,321 @@
+//! # dry_run
+//!
+//! Walks the execution plan and prints the fully resolved actions of each task without
+//! invoking them.<br>
+//! The task env, functions and conditions are evaluated the same as in a normal run and the
+//! sub flows invoked via run_task are followed recursively.
+//!
+
+#[cfg(test)]
+#[path = "dry_run_test.rs"]
+mod dry_run_test;
+
+use crate::condition;
+use crate::environment;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
+use crate::execution_plan::create as create_execution_plan;
+use crate::functions;
+use crate::installer;
+use crate::runner;
+use crate::scriptengine;
+use crate::toolchain;
+use crate::types::{CliArgs, CommandSpec, Config, EnvInfo, FlowInfo, Step};
+
+fn add_text(lines: &mut Vec<String>, indent: usize, text: &str) {
+    let prefix = " ".repeat(indent);
+    for line in text.lines() {
+        lines.push(format!("{}{}", &prefix, line).trim_end().to_string());
+    }
+}
+
+/// Returns the command line, quoting arguments which contain whitespace
+fn get_command_line(command_spec: &CommandSpec) -> String {
+    let mut command_line = command_spec.command.clone();
+
+    if let Some(ref args) = command_spec.args {
+        for arg in args {
+            command_line.push(' ');
+
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                command_line.push_str(&format!("\"{}\"", arg.replace('"', "\\\"")));
+            } else {
+                command_line.push_str(arg);
+            }
+        }
+    }
+
+    command_line
+}
+
+fn get_working_directory(step: &Step) -> Result<String, CargoMakeError> {
+    let directory = match runner::get_task_working_directory(&step)? {
+        Some(directory) => directory,
+        None => task_env::get_working_directory().unwrap_or(".".to_string()),
+    };
+
+    Ok(directory)
+}
+
+/// Adds the install, script or command lines of the (already resolved) task, once for each
+/// of the task toolchains in case multiple toolchains are defined
+fn add_action_lines(
+    step: &Step,
+    indent: usize,
+    lines: &mut Vec<String>,
+) -> Result<(), CargoMakeError> {
+    match step.config.toolchain {
+        Some(ref task_toolchain) => {
+            let toolchains = task_toolchain.get_toolchains();
+            let multiple = toolchains.len() > 1;
+
+            for toolchain in toolchains {
+                add_text(lines, indent, &format!("Toolchain: {}", &toolchain));
+
+                let mut toolchain_step = step.clone();
+                toolchain_step.config.toolchain = Some(toolchain.into());
+
+                let action_indent = if multiple { indent + 2 } else { indent };
+                add_toolchain_action_lines(&toolchain_step, action_indent, lines)?;
+            }
+        }
+        None => add_toolchain_action_lines(step, indent, lines)?,
+    };
+
+    Ok(())
+}
+
+/// Adds the install, script or command lines of the task with a single (or no) toolchain
+fn add_toolchain_action_lines(
+    step: &Step,
+    indent: usize,
+    lines: &mut Vec<String>,
+) -> Result<(), CargoMakeError> {
+    let task = &step.config;
+
+    if let Some(description) = installer::describe(&task)? {
+        add_text(lines, indent, &format!("Install: {}", description));
+    }
+
+    match task.script {
+        Some(ref script) => {
+            let engine_type =
+                scriptengine::get_engine_type(&script, &task.script_runner, &task.script_extension);
+
+            let mut header = format!("Script (engine: {:?}", engine_type);
+            if let Some(ref script_runner) = task.script_runner {
+                header.push_str(&format!(", runner: {}", script_runner));
+                if let Some(ref script_runner_args) = task.script_runner_args {
+                    if !script_runner_args.is_empty() {
+                        header.push(' ');
+                        header.push_str(&script_runner_args.join(" "));
+                    }
+                }
+            }
+            header.push_str("):");
+
+            add_text(lines, indent, &header);
+            add_text(
+                lines,
+                indent + 2,
+                &scriptengine::get_script_text(&script)?.join("\n"),
+            );
+        }
+        None => match task.command {
+            Some(ref command) => {
+                let command_spec = match task.get_toolchain() {
+                    Some(ref toolchain) => {
+                        toolchain::create_wrapped_command(&toolchain, &command, &task.args)
+                    }
+                    None => CommandSpec {
+                        command: command.to_string(),
+                        args: task.args.clone(),
+                    },
+                };
+
+                add_text(
+                    lines,
+                    indent,
+                    &format!("Command: {}", get_command_line(&command_spec)),
+                );
+            }
+            None => (),
+        },
+    };
+
+    Ok(())
+}
+
+fn add_task_lines(
+    flow_info: &FlowInfo,
+    step: &Step,
+    indent: usize,
+    lines: &mut Vec<String>,
+) -> Result<(), CargoMakeError> {
+    if !step.config.is_actionable() {
+        debug!("Ignoring Empty Task: {}", &step.name);
+        return Ok(());
+    }
+
+    let _meta_info_guard = task_env::set_current_meta_info(&step);
+
+    if let Err(failure) = runner::explain_condition(&flow_info, &step)? {
+        let fail_message = runner::get_skip_message(&step, &failure);
+
+        add_text(
+            lines,
+            indent,
+            &format!("Skipping Task: {} {}", &step.name, &fail_message),
+        );
+
+        return Ok(());
+    }
+
+    if !step.config.is_valid() {
+        return Err(CargoMakeError::InvalidTask(format!(
+            "Invalid task: {}, contains multiple actions.\n{:#?}",
+            &step.name, &step.config
+        )));
+    }
+
+    let (step_env, updated_step) = task_env::create(&step, || {
+        let updated_step = functions::run(&step)?;
+        Ok(environment::expand_env(&updated_step))
+    })?;
+    let updated_step = updated_step?;
+
+    if step.config.export_env.unwrap_or(false) {
+        task_env::export(&step_env);
+    }
+
+    add_text(lines, indent, &format!("Task: {}", &step.name));
+
+    let working_directory = get_working_directory(&updated_step)?;
+    add_text(
+        lines,
+        indent + 2,
+        &format!("Working Directory: {}", &working_directory),
+    );
+
+    // the task meta info env vars (CARGO_MAKE_CURRENT_TASK_*) are not printed
+    let env_vars: Vec<(&String, &String)> = step_env
+        .vars
+        .iter()
+        .filter(|(key, _)| !key.starts_with("CARGO_MAKE_CURRENT_TASK_"))
+        .collect();
+    if !env_vars.is_empty() {
+        add_text(lines, indent + 2, "Env:");
+        for (key, value) in env_vars {
+            add_text(lines, indent + 4, &format!("{}={}", key, value));
+        }
+    }
+
+    let _task_env_guard = task_env::set_current(Some(step_env));
+
+    let _cwd_guard = task_env::set_current_cwd(Some(working_directory));
+    add_action_lines(&updated_step, indent + 2, lines)?;
+
+    match step.config.run_task {
+        Some(ref sub_task) => {
+            let (task_names, fork, parallel, cleanup_task) =
+                runner::get_sub_task_info(&flow_info, &sub_task);
+
+            match task_names {
+                Some(names) => {
+                    let mut options = vec![];
+                    if fork {
+                        options.push("fork");
+                    }
+                    if parallel {
+                        options.push("parallel");
+                    }
+                    let options_text = if options.is_empty() {
+                        "".to_string()
+                    } else {
+                        format!(" ({})", options.join(", "))
+                    };
+
+                    add_text(
+                        lines,
+                        indent + 2,
+                        &format!("Run Task: {}{}", names.join(", "), options_text),
+                    );
+                    if let Some(ref cleanup_task_name) = cleanup_task {
+                        add_text(
+                            lines,
+                            indent + 2,
+                            &format!("Cleanup Task: {}", cleanup_task_name),
+                        );
+                    }
+
+                    for name in names {
+                        let mut sub_flow_info = flow_info.clone();
+                        sub_flow_info.task = name;
+
+                        add_flow_lines(&sub_flow_info, true, indent + 4, lines)?;
+                    }
+                }
+                None => add_text(
+                    lines,
+                    indent + 2,
+                    "Run Task: none (no routing condition met)",
+                ),
+            };
+        }
+        None => (),
+    };
+
+    Ok(())
+}
+
+/// Adds the lines of all the tasks in the flow execution plan
+fn add_flow_lines(
+    flow_info: &FlowInfo,
+    sub_flow: bool,
+    indent: usize,
+    lines: &mut Vec<String>,
+) -> Result<(), CargoMakeError> {
+    let allow_private = sub_flow || flow_info.allow_private;
+
+    let execution_plan = create_execution_plan(
+        &flow_info.config,
+        &flow_info.task,
+        flow_info.disable_workspace,
+        allow_private,
+        sub_flow,
+        &flow_info.skip_tasks_pattern,
+    )?;
+    debug!("Created execution plan: {:#?}", &execution_plan);
+
+    for step in &execution_plan.steps {
+        add_task_lines(&flow_info, &step, indent, lines)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the resolved actions of all the tasks in the flow
+fn create_lines(flow_info: &FlowInfo) -> Result<Vec<String>, CargoMakeError> {
+    let mut lines = vec![];
+    add_flow_lines(&flow_info, false, 0, &mut lines)?;
+
+    Ok(lines)
+}
+
+pub(crate) fn run(
+    config: Config,
+    task: &str,
+    env_info: EnvInfo,
+    cli_args: &CliArgs,
+) -> Result<(), CargoMakeError> {
+    condition::init(&cli_args);
+
+    let flow_info = runner::create_flow_info(config, task, env_info, cli_args);
+
+    let lines = create_lines(&flow_info)?;
+    for line in lines {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
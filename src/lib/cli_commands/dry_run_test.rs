@@ -0,0 +1,276 @@
+use super::*;
+use crate::io;
+use crate::test::create_empty_flow_info;
+use crate::types::{
+    EnvValue, InstallCrate, RunTaskDetails, RunTaskInfo, RunTaskName, ScriptValue, Task,
+    TaskCondition, TaskToolchain,
+};
+use indexmap::IndexMap;
+use std::env;
+
+fn create_flow_info(tasks: Vec<(&str, Task)>) -> FlowInfo {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.disable_workspace = true;
+
+    for (name, task) in tasks {
+        flow_info.config.tasks.insert(name.to_string(), task);
+    }
+
+    flow_info
+}
+
+/// Returns the dry run lines without the profile env var, which is added to the task env in
+/// case the test process env does not define it
+fn get_lines(flow_info: &FlowInfo) -> Vec<String> {
+    let lines: Vec<String> = create_lines(&flow_info)
+        .unwrap()
+        .into_iter()
+        .filter(|line| !line.trim_start().starts_with("CARGO_MAKE_PROFILE="))
+        .collect();
+
+    let mut filtered_lines = vec![];
+    for (index, line) in lines.iter().enumerate() {
+        let env_vars_found = match lines.get(index + 1) {
+            Some(next_line) => next_line.starts_with(&format!("{}  ", line.replace("Env:", ""))),
+            None => false,
+        };
+
+        if !line.ends_with("Env:") || env_vars_found {
+            filtered_lines.push(line.clone());
+        }
+    }
+
+    filtered_lines
+}
+
+fn create_command_task(command: &str, args: Vec<&str>) -> Task {
+    let mut task = Task::new();
+    task.command = Some(command.to_string());
+    task.args = Some(args.iter().map(|arg| arg.to_string()).collect());
+
+    task
+}
+
+#[test]
+fn get_command_line_no_args() {
+    let command_line = get_command_line(&CommandSpec {
+        command: "cargo".to_string(),
+        args: None,
+    });
+
+    assert_eq!(command_line, "cargo");
+}
+
+#[test]
+fn get_command_line_quoted_args() {
+    let command_line = get_command_line(&CommandSpec {
+        command: "echo".to_string(),
+        args: Some(vec![
+            "1".to_string(),
+            "2 3".to_string(),
+            "".to_string(),
+            "say \"hi\"".to_string(),
+        ]),
+    });
+
+    assert_eq!(command_line, "echo 1 \"2 3\" \"\" \"say \\\"hi\\\"\"");
+}
+
+#[test]
+fn create_lines_command_with_env_and_toolchain() {
+    let mut task = create_command_task("cargo", vec!["build", "${DRY_RUN_TEST_PROFILE}"]);
+    let mut env = IndexMap::new();
+    env.insert(
+        "DRY_RUN_TEST_PROFILE".to_string(),
+        EnvValue::Value("--release".to_string()),
+    );
+    task.env = Some(env);
+    task.toolchain = Some("dry-run-chain".into());
+    task.install_crate = Some(InstallCrate::Enabled(false));
+    task.cwd = Some("./src".to_string());
+
+    let flow_info = create_flow_info(vec![("test", task)]);
+
+    let lines = get_lines(&flow_info);
+
+    assert_eq!(
+        lines,
+        vec![
+            "Task: test".to_string(),
+            format!(
+                "  Working Directory: {}",
+                io::canonicalize_to_string(
+                    &env::current_dir().unwrap().join("src").to_string_lossy()
+                )
+            ),
+            "  Env:".to_string(),
+            "    DRY_RUN_TEST_PROFILE=--release".to_string(),
+            "  Toolchain: dry-run-chain".to_string(),
+            "  Command: rustup run dry-run-chain cargo build --release".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn create_lines_multiple_toolchains() {
+    let mut task = create_command_task("cargo", vec!["test"]);
+    task.toolchain = Some(TaskToolchain::Multiple(vec![
+        "stable".into(),
+        "nightly".into(),
+    ]));
+    task.install_crate = Some(InstallCrate::Enabled(false));
+
+    let flow_info = create_flow_info(vec![("test", task)]);
+
+    let lines = get_lines(&flow_info);
+
+    assert_eq!(lines[0], "Task: test");
+    assert_eq!(
+        lines[2..].to_vec(),
+        vec![
+            "  Toolchain: stable",
+            "    Command: rustup run stable cargo test",
+            "  Toolchain: nightly",
+            "    Command: rustup run nightly cargo test",
+        ]
+    );
+}
+
+#[test]
+fn create_lines_script_and_install() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "echo 1".to_string(),
+        "echo 2".to_string(),
+    ]));
+    task.script_runner = Some("@duckscript".to_string());
+    task.install_crate = Some(InstallCrate::Value("cargo-dry-run".to_string()));
+    task.args = Some(vec!["dry-run".to_string()]);
+
+    let flow_info = create_flow_info(vec![("test", task)]);
+
+    let lines = get_lines(&flow_info);
+
+    assert_eq!(lines[0], "Task: test");
+    assert_eq!(
+        lines[2..].to_vec(),
+        vec![
+            "  Install: cargo plugin: cargo-dry-run (cargo dry-run), if not installed",
+            "  Script (engine: Duckscript, runner: @duckscript):",
+            "    echo 1",
+            "    echo 2",
+        ]
+    );
+}
+
+#[test]
+fn create_lines_condition_not_met() {
+    let mut task = create_command_task("echo", vec!["test"]);
+    task.condition = Some(TaskCondition {
+        fail_message: Some("not set".to_string()),
+        profiles: None,
+        platforms: None,
+        channels: None,
+        env_set: Some(vec!["DRY_RUN_TEST_NOT_DEFINED".to_string()]),
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
+    });
+
+    let flow_info = create_flow_info(vec![("test", task)]);
+
+    let lines = get_lines(&flow_info);
+
+    assert_eq!(lines, vec!["Skipping Task: test not set"]);
+}
+
+#[test]
+fn create_lines_sub_flows() {
+    let mut task = Task::new();
+    task.run_task = Some(RunTaskInfo::Details(RunTaskDetails {
+        name: RunTaskName::Multiple(vec!["sub1".to_string(), "sub2".to_string()]),
+        fork: Some(true),
+        parallel: Some(true),
+        cleanup_task: Some("cleanup".to_string()),
+    }));
+
+    let mut sub_task = Task::new();
+    sub_task.run_task = Some(RunTaskInfo::Name("sub1".to_string()));
+
+    let flow_info = create_flow_info(vec![
+        ("test", task),
+        ("sub1", create_command_task("echo", vec!["sub1"])),
+        ("sub2", sub_task),
+        ("cleanup", create_command_task("echo", vec!["cleanup"])),
+    ]);
+
+    let lines = get_lines(&flow_info);
+    let lines: Vec<String> = lines
+        .into_iter()
+        .filter(|line| !line.contains("Working Directory:"))
+        .collect();
+
+    assert_eq!(
+        lines,
+        vec![
+            "Task: test",
+            "  Run Task: sub1, sub2 (fork, parallel)",
+            "  Cleanup Task: cleanup",
+            "    Task: sub1",
+            "      Command: echo sub1",
+            "    Task: sub2",
+            "      Run Task: sub1",
+            "        Task: sub1",
+            "          Command: echo sub1",
+        ]
+    );
+}
+
+#[test]
+fn create_lines_empty_task() {
+    let flow_info = create_flow_info(vec![("test", Task::new())]);
+
+    let lines = get_lines(&flow_info);
+
+    assert!(lines.is_empty());
+}
+
+#[test]
+fn create_lines_invalid_task() {
+    let mut task = create_command_task("echo", vec!["test"]);
+    task.script = Some(ScriptValue::SingleLine("echo test".to_string()));
+
+    let flow_info = create_flow_info(vec![("test", task)]);
+
+    let result = create_lines(&flow_info);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn create_lines_invalid_working_directory() {
+    let mut task = create_command_task("echo", vec!["test"]);
+    task.cwd = Some("./bad_directory".to_string());
+
+    let flow_info = create_flow_info(vec![("test", task)]);
+
+    let result = create_lines(&flow_info);
+
+    assert!(matches!(result, Err(CargoMakeError::InvalidTask(_))));
+}
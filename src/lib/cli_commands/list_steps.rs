@@ -8,6 +8,7 @@
 #[path = "list_steps_test.rs"]
 mod list_steps_test;
 
+use crate::error::CargoMakeError;
 use crate::execution_plan;
 use crate::io;
 use crate::types::{Config, DeprecationInfo};
@@ -18,8 +19,8 @@ pub(crate) fn run(
     output_format: &str,
     output_file: &Option<String>,
     category: Option<String>,
-) -> u32 {
-    let (output, count) = create_list(&config, output_format, category);
+) -> Result<u32, CargoMakeError> {
+    let (output, count) = create_list(&config, output_format, category)?;
 
     match output_file {
         Some(file) => {
@@ -29,14 +30,14 @@ pub(crate) fn run(
         None => print!("{}", output),
     }
 
-    count
+    Ok(count)
 }
 
 pub(crate) fn create_list(
     config: &Config,
     output_format: &str,
     category_filter: Option<String>,
-) -> (String, u32) {
+) -> Result<(String, u32), CargoMakeError> {
     let mut count = 0;
     let mut buffer = String::new();
 
@@ -53,7 +54,7 @@ pub(crate) fn create_list(
     }
 
     for key in config.tasks.keys() {
-        let task = execution_plan::get_normalized_task(&config, &key, true);
+        let task = execution_plan::get_normalized_task(&config, &key, true)?;
 
         let is_private = match task.private {
             Some(private) => private,
@@ -150,5 +151,5 @@ pub(crate) fn create_list(
         }
     }
 
-    (buffer, count)
+    Ok((buffer, count))
 }
@@ -19,7 +19,7 @@ fn run_empty() {
         plugins: None,
     };
 
-    let count = run(&config, "default", &None, None);
+    let count = run(&config, "default", &None, None).unwrap();
 
     assert_eq!(count, 0);
 }
@@ -46,7 +46,7 @@ fn run_all_public() {
         plugins: None,
     };
 
-    let count = run(&config, "default", &None, None);
+    let count = run(&config, "default", &None, None).unwrap();
 
     assert_eq!(count, 2);
 }
@@ -73,7 +73,7 @@ fn run_all_public_markdown() {
         plugins: None,
     };
 
-    let count = run(&config, "markdown", &None, None);
+    let count = run(&config, "markdown", &None, None).unwrap();
 
     assert_eq!(count, 2);
 }
@@ -100,7 +100,7 @@ fn run_all_public_markdown_sub_section() {
         plugins: None,
     };
 
-    let count = run(&config, "markdown-sub-section", &None, None);
+    let count = run(&config, "markdown-sub-section", &None, None).unwrap();
 
     assert_eq!(count, 2);
 }
@@ -127,7 +127,7 @@ fn run_all_public_markdown_single_page() {
         plugins: None,
     };
 
-    let count = run(&config, "markdown-single-page", &None, None);
+    let count = run(&config, "markdown-single-page", &None, None).unwrap();
 
     assert_eq!(count, 2);
 }
@@ -156,7 +156,7 @@ fn run_all_private() {
         plugins: None,
     };
 
-    let count = run(&config, "default", &None, None);
+    let count = run(&config, "default", &None, None).unwrap();
 
     assert_eq!(count, 0);
 }
@@ -192,7 +192,7 @@ fn run_mixed() {
         plugins: None,
     };
 
-    let count = run(&config, "default", &None, None);
+    let count = run(&config, "default", &None, None).unwrap();
 
     assert_eq!(count, 3);
 }
@@ -225,14 +225,15 @@ fn run_write_to_file() {
         "markdown-single-page",
         &Some(file.to_string()),
         None,
-    );
+    )
+    .unwrap();
 
     assert_eq!(count, 2);
 
     let mut path = PathBuf::new();
     path.push(&file);
 
-    let text = io::read_text_file(&path);
+    let text = io::read_text_file(&path).unwrap();
     io::delete_file(&file);
 
     assert!(text.contains("# Task List"));
@@ -266,7 +267,7 @@ fn run_category_public() {
         plugins: None,
     };
 
-    let count = run(&config, "default", &None, Some("TestCategory1".to_owned()));
+    let count = run(&config, "default", &None, Some("TestCategory1".to_owned())).unwrap();
 
     assert_eq!(count, 2);
 }
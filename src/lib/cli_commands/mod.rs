@@ -3,6 +3,9 @@
 //! Wrappers for each CLI sub command.
 //!
 
+pub(crate) mod check_makefile;
 pub(crate) mod diff_steps;
+pub(crate) mod dry_run;
 pub(crate) mod list_steps;
+pub(crate) mod print_schema;
 pub(crate) mod print_steps;
@@ -0,0 +1,58 @@
+//! # print_schema
+//!
+//! Prints the JSON schema of the makefile.<br>
+//! The schema is derived from the makefile types (including the serde attributes), so the field
+//! doc comments are used as the schema descriptions.
+//!
+
+#[cfg(test)]
+#[path = "print_schema_test.rs"]
+mod print_schema_test;
+
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::types::ExternalConfig;
+use schemars::gen::SchemaSettings;
+use schemars::schema::RootSchema;
+
+/// Creates the makefile JSON schema
+fn create_schema() -> RootSchema {
+    // toml has no null values so optional attributes can only be omitted
+    let generator = SchemaSettings::draft07()
+        .with(|settings| settings.option_add_null_type = false)
+        .into_generator();
+
+    let mut schema = generator.into_root_schema_for::<ExternalConfig>();
+    schema.schema.metadata().title = Some("cargo-make makefile".to_string());
+
+    schema
+}
+
+/// Prints the makefile JSON schema to the stdout or the provided output file
+pub(crate) fn print(output_file: &Option<String>) -> Result<(), CargoMakeError> {
+    let schema = create_schema();
+
+    let output = match serde_json::to_string_pretty(&schema) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(CargoMakeError::Other(format!(
+                "Unable to create makefile schema, error: {}",
+                error
+            )))
+        }
+    };
+
+    match output_file {
+        Some(file) => {
+            if !io::write_text_file(&file, &output) {
+                return Err(CargoMakeError::Other(format!(
+                    "Unable to write makefile schema to file: {}",
+                    &file
+                )));
+            }
+        }
+        None => println!("{}", output),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use super::*;
+use serde_json::Value;
+
+fn create_schema_value() -> Value {
+    serde_json::to_value(&create_schema()).unwrap()
+}
+
+#[test]
+fn create_schema_makefile_types() {
+    let schema = create_schema_value();
+
+    assert_eq!(schema["title"], "cargo-make makefile");
+    assert_eq!(
+        schema["properties"]["tasks"]["additionalProperties"]["$ref"],
+        "#/definitions/Task"
+    );
+
+    let definitions = schema["definitions"].as_object().unwrap();
+    for name in &[
+        "Task",
+        "ConfigSection",
+        "EnvValue",
+        "InstallCrate",
+        "RunTaskInfo",
+        "Plugins",
+        "DependencyIdentifier",
+    ] {
+        assert!(definitions.contains_key(*name), "Missing: {}", name);
+    }
+
+    assert_eq!(
+        schema["definitions"]["Task"]["properties"]["command"]["description"],
+        "The command to execute"
+    );
+
+    let dependency_variants = schema["definitions"]["DependencyIdentifier"]["anyOf"]
+        .as_array()
+        .unwrap();
+    assert_eq!(dependency_variants.len(), 2);
+}
+
+#[test]
+fn create_schema_serde_attributes() {
+    let schema = create_schema_value();
+
+    // renamed fields
+    assert!(schema["definitions"]["TaskParameter"]["properties"]["type"].is_object());
+    assert!(schema["definitions"]["Plugins"]["properties"]["impl"].is_object());
+
+    // optional attributes
+    assert_eq!(
+        schema["definitions"]["Task"]["properties"]["args"]["type"],
+        "array"
+    );
+
+    // custom deserializer which accepts a string or a list of strings
+    let test_arg_variants = schema["definitions"]["TestArg"]["anyOf"]
+        .as_array()
+        .unwrap();
+    assert_eq!(test_arg_variants.len(), 2);
+}
+
+#[test]
+fn print_to_file() {
+    let file = "./target/_cargo_make_temp/print_schema/schema.json";
+
+    print(&Some(file.to_string())).unwrap();
+
+    let output = fsio::file::read_text_file(file).unwrap();
+    let schema: Value = serde_json::from_str(&output).unwrap();
+    assert!(schema["definitions"]["Task"].is_object());
+}
@@ -1,15 +1,21 @@
 //! # print_steps
 //!
-//! Prints the execution plan in multiple formats.
+//! Prints the execution plan in multiple formats.<br>
+//! The graph formats (dot and mermaid) also include the sub flows invoked via run_task, the
+//! tasks in other makefiles and the disabled dependencies.
 //!
 
 #[cfg(test)]
 #[path = "print_steps_test.rs"]
 mod print_steps_test;
 
+use crate::error::CargoMakeError;
 use crate::execution_plan::create as create_execution_plan;
-use crate::types::{Config, ExecutionPlan};
+use crate::execution_plan::get_normalized_task;
+use crate::types::{Config, ExecutionPlan, RunTaskInfo, RunTaskName, Step, Task, TaskIdentifier};
+use indexmap::IndexMap;
 use regex::Regex;
+use std::collections::{HashSet, VecDeque};
 
 #[derive(Debug)]
 enum PrintFormat {
@@ -17,6 +23,10 @@ enum PrintFormat {
     Default,
     /// Prints a short description of the task
     ShortDescription,
+    /// Prints the tasks graph in the graphviz dot format
+    Dot,
+    /// Prints the tasks graph in the mermaid flowchart format
+    Mermaid,
 }
 
 impl PartialEq for PrintFormat {
@@ -30,6 +40,14 @@ impl PartialEq for PrintFormat {
                 PrintFormat::ShortDescription => true,
                 _ => false,
             },
+            PrintFormat::Dot => match other {
+                PrintFormat::Dot => true,
+                _ => false,
+            },
+            PrintFormat::Mermaid => match other {
+                PrintFormat::Mermaid => true,
+                _ => false,
+            },
         }
     }
 }
@@ -37,6 +55,10 @@ impl PartialEq for PrintFormat {
 fn get_format_type(output_format: &str) -> PrintFormat {
     if output_format == "short-description" {
         PrintFormat::ShortDescription
+    } else if output_format == "dot" {
+        PrintFormat::Dot
+    } else if output_format == "mermaid" {
+        PrintFormat::Mermaid
     } else {
         PrintFormat::Default
     }
@@ -60,6 +82,388 @@ fn print_default(execution_plan: &ExecutionPlan) {
     println!("{:#?}", &execution_plan);
 }
 
+#[derive(Debug, Clone, PartialEq)]
+/// Holds a single task in the graph
+struct GraphNode {
+    /// The displayed text
+    label: String,
+    /// True if the task is disabled and will not be invoked
+    disabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds a single edge in the graph
+struct GraphEdge {
+    /// The source task name
+    from: String,
+    /// The target task name
+    to: String,
+    /// The run_task label (dependency edges have no label)
+    label: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// Holds the tasks graph
+struct Graph {
+    /// The tasks by name
+    nodes: IndexMap<String, GraphNode>,
+    /// The dependency (dependency to task) and run_task (task to invoked task) edges
+    edges: Vec<GraphEdge>,
+}
+
+impl Graph {
+    fn add_node(&mut self, name: &str, label: String, disabled: bool) {
+        if !self.nodes.contains_key(name) {
+            self.nodes
+                .insert(name.to_string(), GraphNode { label, disabled });
+        }
+    }
+
+    fn add_edge(&mut self, from: &str, to: &str, label: Option<String>) {
+        let edge = GraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            label,
+        };
+
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+}
+
+fn get_node_label(name: &str, markers: &Vec<&str>) -> String {
+    if markers.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} ({})", name, markers.join(", "))
+    }
+}
+
+fn get_step_markers<'a>(config: &Config, step: &Step) -> Vec<&'a str> {
+    let mut markers = vec![];
+
+    if config.config.init_task.as_ref() == Some(&step.name) {
+        markers.push("init");
+    }
+    if config.config.end_task.as_ref() == Some(&step.name) {
+        markers.push("end");
+    }
+    if step.config.private.unwrap_or(false) {
+        markers.push("private");
+    }
+
+    markers
+}
+
+fn is_predefined_step(config: &Config, step: &Step) -> bool {
+    config.config.init_task.as_ref() == Some(&step.name)
+        || config.config.legacy_migration_task.as_ref() == Some(&step.name)
+}
+
+/// Returns true if the step depends (directly or indirectly) on the dependency step
+fn depends_on(execution_plan: &ExecutionPlan, index: usize, dependency: usize) -> bool {
+    let mut pending = vec![index];
+    let mut visited = HashSet::new();
+
+    while let Some(current) = pending.pop() {
+        for next in &execution_plan.steps_dependencies[current] {
+            if *next == dependency {
+                return true;
+            }
+            if visited.insert(*next) {
+                pending.push(*next);
+            }
+        }
+    }
+
+    false
+}
+
+/// Adds the execution plan steps and dependencies to the graph.<br>
+/// The init and end tasks dependencies which are already implied by other dependencies are
+/// omitted to keep the graph readable.
+fn add_execution_plan(graph: &mut Graph, config: &Config, execution_plan: &ExecutionPlan) {
+    for step in &execution_plan.steps {
+        graph.add_node(
+            &step.name,
+            get_node_label(&step.name, &get_step_markers(config, step)),
+            false,
+        );
+    }
+
+    for (index, dependencies) in execution_plan.steps_dependencies.iter().enumerate() {
+        let step = &execution_plan.steps[index];
+        let is_end_step = config.config.end_task.as_ref() == Some(&step.name);
+
+        for dependency in dependencies {
+            let implied = (is_end_step
+                || is_predefined_step(config, &execution_plan.steps[*dependency]))
+                && dependencies.iter().any(|other| {
+                    other != dependency && depends_on(execution_plan, *other, *dependency)
+                });
+
+            if !implied {
+                graph.add_edge(&execution_plan.steps[*dependency].name, &step.name, None);
+            }
+        }
+    }
+}
+
+fn get_run_task_names(name: &RunTaskName) -> Vec<String> {
+    match name {
+        RunTaskName::Single(name) => vec![name.to_string()],
+        RunTaskName::Multiple(names) => names.clone(),
+    }
+}
+
+fn get_run_task_label(name: &str, fork: Option<bool>, parallel: Option<bool>) -> String {
+    let mut options = vec![];
+    if fork.unwrap_or(false) {
+        options.push("fork");
+    }
+    if parallel.unwrap_or(false) {
+        options.push("parallel");
+    }
+
+    get_node_label(name, &options)
+}
+
+/// Returns the tasks invoked via run_task (including all routing alternatives and cleanup tasks)
+/// and the matching edge labels
+fn get_run_task_targets(task: &Task) -> Vec<(String, String)> {
+    let mut targets = vec![];
+
+    match task.run_task {
+        Some(RunTaskInfo::Name(ref name)) => {
+            targets.push((name.to_string(), "run_task".to_string()))
+        }
+        Some(RunTaskInfo::Details(ref details)) => {
+            let label = get_run_task_label("run_task", details.fork, details.parallel);
+            for name in get_run_task_names(&details.name) {
+                targets.push((name, label.clone()));
+            }
+            if let Some(ref cleanup_task) = details.cleanup_task {
+                targets.push((cleanup_task.to_string(), "cleanup_task".to_string()));
+            }
+        }
+        Some(RunTaskInfo::Routing(ref routing_info)) => {
+            for (index, routing) in routing_info.iter().enumerate() {
+                let name = if routing_info.len() > 1 {
+                    format!("run_task alternative {}", index + 1)
+                } else {
+                    "run_task".to_string()
+                };
+                let label = get_run_task_label(&name, routing.fork, routing.parallel);
+
+                for name in get_run_task_names(&routing.name) {
+                    targets.push((name, label.clone()));
+                }
+                if let Some(ref cleanup_task) = routing.cleanup_task {
+                    targets.push((cleanup_task.to_string(), "cleanup_task".to_string()));
+                }
+            }
+        }
+        None => (),
+    };
+
+    targets
+}
+
+fn is_skipped(skip_tasks_pattern: &Option<Regex>, name: &str) -> bool {
+    match skip_tasks_pattern {
+        Some(ref pattern) => pattern.is_match(name),
+        None => false,
+    }
+}
+
+/// Returns the graph nodes of the task, which are the task matrix steps in case the task defines a
+/// matrix
+fn get_task_nodes(graph: &Graph, name: &str) -> Vec<String> {
+    let matrix_prefix = format!("{}[", name);
+
+    graph
+        .nodes
+        .keys()
+        .filter(|node| *node == name || (node.starts_with(&matrix_prefix) && node.ends_with(']')))
+        .cloned()
+        .collect()
+}
+
+/// Creates the tasks graph from the execution plan, including the disabled dependencies, the
+/// tasks in other makefiles and the sub flows invoked via run_task
+fn create_graph(
+    config: &Config,
+    execution_plan: &ExecutionPlan,
+    skip_tasks_pattern: &Option<Regex>,
+) -> Result<Graph, CargoMakeError> {
+    let mut graph = Graph::default();
+    add_execution_plan(&mut graph, config, execution_plan);
+
+    let mut pending: VecDeque<Step> = execution_plan.steps.iter().cloned().collect();
+    let mut visited = HashSet::new();
+
+    while let Some(step) = pending.pop_front() {
+        if !visited.insert(step.name.clone()) {
+            continue;
+        }
+
+        if let Some(ref dependencies) = step.config.dependencies {
+            for dependency in dependencies {
+                let identifier: TaskIdentifier = dependency.clone().into();
+                if is_skipped(skip_tasks_pattern, &identifier.name) {
+                    continue;
+                }
+
+                match identifier.path {
+                    Some(_) => {
+                        let proxy_name = format!("{}_proxy", &identifier.name);
+                        if let Some(node) = graph.nodes.get_mut(&proxy_name) {
+                            node.label = get_node_label(&identifier.to_string(), &vec!["external"]);
+                        }
+                    }
+                    None => {
+                        let task = get_normalized_task(config, &identifier.name, true)?;
+                        if task.disabled.unwrap_or(false) {
+                            graph.add_node(
+                                &identifier.name,
+                                get_node_label(&identifier.name, &vec!["disabled"]),
+                                true,
+                            );
+                            graph.add_edge(&identifier.name, &step.name, None);
+                        }
+                    }
+                }
+            }
+        }
+
+        for (name, label) in get_run_task_targets(&step.config) {
+            if is_skipped(skip_tasks_pattern, &name) {
+                continue;
+            }
+
+            if get_task_nodes(&graph, &name).is_empty() {
+                let task = get_normalized_task(config, &name, true)?;
+
+                if task.disabled.unwrap_or(false) {
+                    graph.add_node(&name, get_node_label(&name, &vec!["disabled"]), true);
+                } else {
+                    let sub_execution_plan =
+                        create_execution_plan(config, &name, true, true, true, skip_tasks_pattern)?;
+                    add_execution_plan(&mut graph, config, &sub_execution_plan);
+                    pending.extend(sub_execution_plan.steps);
+                }
+            }
+
+            for node in get_task_nodes(&graph, &name) {
+                graph.add_edge(&step.name, &node, Some(label.clone()));
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace("\\", "\\\\").replace("\"", "\\\"")
+}
+
+fn create_dot(graph: &Graph, task: &str) -> String {
+    let mut lines = vec![format!("digraph \"{}\" {{", escape_dot(task))];
+
+    for (name, node) in &graph.nodes {
+        let style = if node.disabled { ", style=dashed" } else { "" };
+        lines.push(format!(
+            "    \"{}\" [label=\"{}\"{}];",
+            escape_dot(name),
+            escape_dot(&node.label),
+            style
+        ));
+    }
+
+    for edge in &graph.edges {
+        let attributes = match edge.label {
+            Some(ref label) => format!(" [label=\"{}\", style=dashed]", escape_dot(label)),
+            None => "".to_string(),
+        };
+        lines.push(format!(
+            "    \"{}\" -> \"{}\"{};",
+            escape_dot(&edge.from),
+            escape_dot(&edge.to),
+            attributes
+        ));
+    }
+
+    lines.push("}".to_string());
+
+    lines.join("\n")
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value.replace("\"", "#quot;")
+}
+
+fn create_mermaid(graph: &Graph) -> String {
+    let mut lines = vec!["flowchart TD".to_string()];
+
+    let node_ids: IndexMap<&String, String> = graph
+        .nodes
+        .keys()
+        .enumerate()
+        .map(|(index, name)| (name, format!("n{}", index)))
+        .collect();
+
+    for (name, node) in &graph.nodes {
+        let class = if node.disabled { ":::disabled" } else { "" };
+        lines.push(format!(
+            "    {}[\"{}\"]{}",
+            node_ids[name],
+            escape_mermaid(&node.label),
+            class
+        ));
+    }
+
+    for edge in &graph.edges {
+        let from = &node_ids[&edge.from];
+        let to = &node_ids[&edge.to];
+
+        match edge.label {
+            Some(ref label) => lines.push(format!(
+                "    {} -.->|\"{}\"| {}",
+                from,
+                escape_mermaid(label),
+                to
+            )),
+            None => lines.push(format!("    {} --> {}", from, to)),
+        }
+    }
+
+    if graph.nodes.values().any(|node| node.disabled) {
+        lines.push("    classDef disabled stroke-dasharray: 5 5".to_string());
+    }
+
+    lines.join("\n")
+}
+
+fn print_graph(
+    config: &Config,
+    execution_plan: &ExecutionPlan,
+    task: &str,
+    print_format: &PrintFormat,
+    skip_tasks_pattern: &Option<Regex>,
+) -> Result<(), CargoMakeError> {
+    let graph = create_graph(config, execution_plan, skip_tasks_pattern)?;
+
+    let output = if *print_format == PrintFormat::Dot {
+        create_dot(&graph, task)
+    } else {
+        create_mermaid(&graph)
+    };
+    println!("{}", output);
+
+    Ok(())
+}
+
 /// Only prints the execution plan
 pub(crate) fn print(
     config: &Config,
@@ -67,7 +471,7 @@ pub(crate) fn print(
     output_format: &str,
     disable_workspace: bool,
     skip_tasks_pattern: Option<String>,
-) {
+) -> Result<(), CargoMakeError> {
     let skip_tasks_pattern_regex = match skip_tasks_pattern {
         Some(ref pattern) => match Regex::new(pattern) {
             Ok(reg) => Some(reg),
@@ -86,7 +490,7 @@ pub(crate) fn print(
         false,
         false,
         &skip_tasks_pattern_regex,
-    );
+    )?;
     debug!("Created execution plan: {:#?}", &execution_plan);
 
     let print_format = get_format_type(&output_format);
@@ -94,5 +498,14 @@ pub(crate) fn print(
     match print_format {
         PrintFormat::ShortDescription => print_short_description(&execution_plan),
         PrintFormat::Default => print_default(&execution_plan),
+        PrintFormat::Dot | PrintFormat::Mermaid => print_graph(
+            &config,
+            &execution_plan,
+            &task,
+            &print_format,
+            &skip_tasks_pattern_regex,
+        )?,
     };
+
+    Ok(())
 }
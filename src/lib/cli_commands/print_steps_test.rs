@@ -1,5 +1,5 @@
 use super::*;
-use crate::types::{ConfigSection, ExecutionPlan, Step, Task};
+use crate::types::{ConfigSection, ExecutionPlan, RunTaskRoutingInfo, Step, Task, TaskMatrix};
 use indexmap::IndexMap;
 
 #[test]
@@ -35,11 +35,10 @@ fn print_default_format() {
     config.tasks.insert("end".to_string(), Task::new());
     config.tasks.insert("test".to_string(), Task::new());
 
-    print(&config, "test", "default", false, None);
+    print(&config, "test", "default", false, None).unwrap();
 }
 
 #[test]
-#[should_panic]
 fn print_task_not_found() {
     let mut config = Config {
         config: ConfigSection::new(),
@@ -53,7 +52,12 @@ fn print_task_not_found() {
     config.tasks.insert("init".to_string(), Task::new());
     config.tasks.insert("end".to_string(), Task::new());
 
-    print(&config, "test", "default", false, None);
+    let output = print(&config, "test", "default", false, None);
+
+    assert_eq!(
+        output.unwrap_err(),
+        CargoMakeError::TaskNotFound("Task test not found".to_string())
+    );
 }
 
 #[test]
@@ -70,7 +74,7 @@ fn print_task_not_found_but_skipped() {
     config.tasks.insert("init".to_string(), Task::new());
     config.tasks.insert("end".to_string(), Task::new());
 
-    print(&config, "test", "default", false, Some("test".to_string()));
+    print(&config, "test", "default", false, Some("test".to_string())).unwrap();
 }
 
 #[test]
@@ -80,7 +84,10 @@ fn print_default_valid() {
         config: Task::new(),
     };
     let steps = vec![step];
-    let execution_plan = ExecutionPlan { steps };
+    let execution_plan = ExecutionPlan {
+        steps,
+        steps_dependencies: vec![vec![]],
+    };
 
     print_default(&execution_plan);
 }
@@ -92,7 +99,234 @@ fn print_short_description_valid() {
         config: Task::new(),
     };
     let steps = vec![step];
-    let execution_plan = ExecutionPlan { steps };
+    let execution_plan = ExecutionPlan {
+        steps,
+        steps_dependencies: vec![vec![]],
+    };
 
     print_short_description(&execution_plan);
 }
+
+#[test]
+fn get_format_type_dot() {
+    let output = get_format_type("dot");
+    assert_eq!(output, PrintFormat::Dot);
+}
+
+#[test]
+fn get_format_type_mermaid() {
+    let output = get_format_type("mermaid");
+    assert_eq!(output, PrintFormat::Mermaid);
+}
+
+fn create_graph_config() -> Config {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+    config.config.init_task = Some("init".to_string());
+    config.config.end_task = Some("end".to_string());
+
+    let mut private_task = Task::new();
+    private_task.private = Some(true);
+    let mut disabled_task = Task::new();
+    disabled_task.disabled = Some(true);
+    let mut build_task = Task::new();
+    build_task.dependencies = Some(vec!["private".into(), "disabled".into()]);
+    let mut flow_task = Task::new();
+    flow_task.dependencies = Some(vec!["build".into()]);
+    flow_task.run_task = Some(RunTaskInfo::Routing(vec![
+        RunTaskRoutingInfo {
+            name: RunTaskName::Single("sub".to_string()),
+            fork: None,
+            parallel: None,
+            cleanup_task: None,
+            condition: None,
+            condition_script: None,
+        },
+        RunTaskRoutingInfo {
+            name: RunTaskName::Multiple(vec!["disabled".to_string()]),
+            fork: Some(true),
+            parallel: None,
+            cleanup_task: Some("cleanup".to_string()),
+            condition: None,
+            condition_script: None,
+        },
+    ]));
+    let mut sub_task = Task::new();
+    sub_task.dependencies = Some(vec!["private".into()]);
+
+    config.tasks.insert("init".to_string(), Task::new());
+    config.tasks.insert("end".to_string(), Task::new());
+    config.tasks.insert("private".to_string(), private_task);
+    config.tasks.insert("disabled".to_string(), disabled_task);
+    config.tasks.insert("build".to_string(), build_task);
+    config.tasks.insert("flow".to_string(), flow_task);
+    config.tasks.insert("sub".to_string(), sub_task);
+    config.tasks.insert("cleanup".to_string(), Task::new());
+
+    config
+}
+
+#[test]
+fn create_graph_all_edges() {
+    let config = create_graph_config();
+    let execution_plan = create_execution_plan(&config, "flow", true, false, false, &None).unwrap();
+
+    let graph = create_graph(&config, &execution_plan, &None).unwrap();
+
+    let nodes: Vec<(&str, &str, bool)> = graph
+        .nodes
+        .iter()
+        .map(|(name, node)| (name.as_str(), node.label.as_str(), node.disabled))
+        .collect();
+    assert_eq!(
+        nodes,
+        vec![
+            ("init", "init (init)", false),
+            ("private", "private (private)", false),
+            ("build", "build", false),
+            ("flow", "flow", false),
+            ("end", "end (end)", false),
+            ("disabled", "disabled (disabled)", true),
+            ("sub", "sub", false),
+            ("cleanup", "cleanup", false),
+        ]
+    );
+
+    let edges: Vec<(&str, &str, Option<&str>)> = graph
+        .edges
+        .iter()
+        .map(|edge| {
+            (
+                edge.from.as_str(),
+                edge.to.as_str(),
+                edge.label.as_ref().map(|label| label.as_str()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            ("init", "private", None),
+            ("private", "build", None),
+            ("build", "flow", None),
+            ("flow", "end", None),
+            ("disabled", "build", None),
+            ("private", "sub", None),
+            ("flow", "sub", Some("run_task alternative 1")),
+            ("flow", "disabled", Some("run_task alternative 2 (fork)")),
+            ("flow", "cleanup", Some("cleanup_task")),
+        ]
+    );
+}
+
+#[test]
+fn create_graph_skipped_tasks() {
+    let config = create_graph_config();
+    let skip_tasks_pattern = Some(Regex::new("sub|disabled").unwrap());
+    let execution_plan =
+        create_execution_plan(&config, "flow", true, false, false, &skip_tasks_pattern).unwrap();
+
+    let graph = create_graph(&config, &execution_plan, &skip_tasks_pattern).unwrap();
+
+    assert!(!graph.nodes.contains_key("sub"));
+    assert!(!graph.nodes.contains_key("disabled"));
+    assert!(graph.nodes.contains_key("cleanup"));
+}
+
+#[test]
+fn create_graph_matrix_sub_flow() {
+    let mut config = create_graph_config();
+    let mut matrix_task = Task::new();
+    matrix_task.matrix = Some(TaskMatrix {
+        exclude: None,
+        include: None,
+        values: vec![(
+            "features".to_string(),
+            vec!["a".to_string(), "b".to_string()],
+        )]
+        .into_iter()
+        .collect(),
+    });
+    config.tasks.insert("sub".to_string(), matrix_task);
+    let execution_plan = create_execution_plan(&config, "flow", true, false, false, &None).unwrap();
+
+    let graph = create_graph(&config, &execution_plan, &None).unwrap();
+
+    assert!(graph.nodes.contains_key("sub[features=a]"));
+    assert!(graph.nodes.contains_key("sub[features=b]"));
+    assert!(!graph.nodes.contains_key("sub"));
+    let sub_edges: Vec<&str> = graph
+        .edges
+        .iter()
+        .filter(|edge| edge.from == "flow" && edge.to.starts_with("sub"))
+        .map(|edge| edge.to.as_str())
+        .collect();
+    assert_eq!(sub_edges, vec!["sub[features=a]", "sub[features=b]"]);
+
+    let mermaid = create_mermaid(&graph);
+    assert!(mermaid.contains("[\"sub[features=a]\"]"));
+}
+
+fn create_test_graph() -> Graph {
+    let mut graph = Graph::default();
+    graph.add_node("build", "build (private)".to_string(), false);
+    graph.add_node("old \"task\"", "old \"task\" (disabled)".to_string(), true);
+    graph.add_node("sub", "sub".to_string(), false);
+    graph.add_edge("old \"task\"", "build", None);
+    graph.add_edge("build", "sub", Some("run_task".to_string()));
+    graph.add_edge("build", "sub", Some("run_task".to_string()));
+
+    graph
+}
+
+#[test]
+fn create_dot_valid() {
+    let output = create_dot(&create_test_graph(), "flow");
+
+    assert_eq!(
+        output,
+        r#"digraph "flow" {
+    "build" [label="build (private)"];
+    "old \"task\"" [label="old \"task\" (disabled)", style=dashed];
+    "sub" [label="sub"];
+    "old \"task\"" -> "build";
+    "build" -> "sub" [label="run_task", style=dashed];
+}"#
+    );
+}
+
+#[test]
+fn create_mermaid_valid() {
+    let output = create_mermaid(&create_test_graph());
+
+    assert_eq!(
+        output,
+        r#"flowchart TD
+    n0["build (private)"]
+    n1["old #quot;task#quot; (disabled)"]:::disabled
+    n2["sub"]
+    n1 --> n0
+    n0 -.->|"run_task"| n2
+    classDef disabled stroke-dasharray: 5 5"#
+    );
+}
+
+#[test]
+fn print_dot_format() {
+    let config = create_graph_config();
+
+    print(&config, "flow", "dot", true, None).unwrap();
+}
+
+#[test]
+fn print_mermaid_format() {
+    let config = create_graph_config();
+
+    print(&config, "flow", "mermaid", true, None).unwrap();
+}
@@ -27,6 +27,8 @@ fn run_makefile_not_found() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -35,9 +37,18 @@ fn run_makefile_not_found() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -63,6 +74,8 @@ fn run_empty_task() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -71,9 +84,18 @@ fn run_empty_task() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -99,6 +121,8 @@ fn print_empty_task() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: true,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -107,9 +131,65 @@ fn print_empty_task() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
+}
+
+#[test]
+#[ignore]
+fn dry_run_empty_task() {
+    let global_config = GlobalConfig::new();
+
+    run(
+        CliArgs {
+            command: "cargo make".to_string(),
+            build_file: None,
+            task: "empty".to_string(),
+            profile: None,
+            log_level: "error".to_string(),
+            disable_color: true,
+            cwd: None,
+            env: None,
+            env_file: None,
+            disable_workspace: false,
+            disable_on_error: false,
+            allow_private: false,
+            skip_init_end_tasks: false,
+            skip_tasks_pattern: None,
+            disable_check_for_updates: true,
+            print_only: false,
+            dry_run: true,
+            explain_skips: false,
+            list_all_steps: false,
+            list_category_steps: None,
+            diff_execution_plan: false,
+            experimental: false,
+            arguments: None,
+            output_format: "default".to_string(),
+            output_file: None,
+            print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
+        },
+        &global_config,
+    )
+    .unwrap();
 }
 
 #[test]
@@ -135,6 +215,8 @@ fn list_empty_task() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: true,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -143,9 +225,18 @@ fn list_empty_task() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -171,6 +262,8 @@ fn run_file_and_task() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -179,9 +272,18 @@ fn run_file_and_task() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -210,6 +312,8 @@ fn run_cwd_with_file() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -218,9 +322,18 @@ fn run_cwd_with_file() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -247,6 +360,8 @@ fn run_file_not_go_to_project_root() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -255,9 +370,18 @@ fn run_file_not_go_to_project_root() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -284,6 +408,8 @@ fn run_cwd_go_to_project_root_current_dir() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -292,9 +418,18 @@ fn run_cwd_go_to_project_root_current_dir() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -324,6 +459,8 @@ fn run_cwd_go_to_project_root_child_dir() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -332,9 +469,18 @@ fn run_cwd_go_to_project_root_child_dir() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -364,6 +510,8 @@ fn run_cwd_task_not_found() {
             skip_tasks_pattern: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -372,9 +520,18 @@ fn run_cwd_task_not_found() {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         },
         &global_config,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -569,6 +726,31 @@ fn run_for_args_print_only() {
     run_for_args(matches, &global_config, &"make".to_string(), true);
 }
 
+#[test]
+#[ignore]
+fn run_for_args_dry_run() {
+    let global_config = GlobalConfig::new();
+    let app = create_cli(&global_config, &"make".to_string(), true);
+
+    let matches = app.get_matches_from(vec![
+        "cargo",
+        "make",
+        "--makefile",
+        "./examples/dependencies.toml",
+        "--skip-tasks",
+        "ABCtest.*",
+        "-t",
+        "A",
+        "-l",
+        "error",
+        "--no-workspace",
+        "--no-on-error",
+        "--dry-run",
+    ]);
+
+    run_for_args(matches, &global_config, &"make".to_string(), true);
+}
+
 #[test]
 #[ignore]
 fn run_for_args_diff_steps() {
@@ -591,6 +773,73 @@ fn run_for_args_diff_steps() {
     run_for_args(matches, &global_config, &"make".to_string(), true);
 }
 
+#[test]
+#[ignore]
+fn run_for_args_parallel_jobs() {
+    let global_config = GlobalConfig::new();
+    let app = create_cli(&global_config, &"make".to_string(), true);
+
+    let matches = app.get_matches_from(vec![
+        "cargo",
+        "make",
+        "--makefile",
+        "./examples/dependencies.toml",
+        "-t",
+        "A",
+        "-l",
+        "error",
+        "--no-workspace",
+        "--jobs",
+        "4",
+        "--report",
+        "junit=./target/_cargo_make_temp/report/parallel_jobs.xml",
+    ]);
+
+    fsio::file::delete_ignore_error("./target/_cargo_make_temp/report/parallel_jobs.xml");
+    // the report is only written by the top level cargo-make process
+    envmnt::remove("CARGO_MAKE_INTERNAL_RECURSION_LEVEL");
+    run_for_args(matches, &global_config, &"make".to_string(), true);
+
+    let jobs = envmnt::get_or("CARGO_MAKE_CLI_JOBS", "");
+    envmnt::remove("CARGO_MAKE_CLI_JOBS");
+    assert_eq!(jobs, "4");
+
+    let report =
+        fsio::file::read_text_file("./target/_cargo_make_temp/report/parallel_jobs.xml").unwrap();
+    for task in vec!["A", "B", "C", "D"] {
+        assert!(report.contains(&format!("<testcase name=\"{}\"", task)));
+    }
+}
+
+#[test]
+#[ignore]
+fn run_for_args_junit_report() {
+    let global_config = GlobalConfig::new();
+    let app = create_cli(&global_config, &"make".to_string(), true);
+
+    let matches = app.get_matches_from(vec![
+        "cargo",
+        "make",
+        "--makefile",
+        "./examples/dependencies.toml",
+        "-t",
+        "A",
+        "-l",
+        "error",
+        "--no-workspace",
+        "--report",
+        "junit=./target/_cargo_make_temp/report/junit.xml",
+    ]);
+
+    fsio::file::delete_ignore_error("./target/_cargo_make_temp/report/junit.xml");
+    // the report is only written by the top level cargo-make process
+    envmnt::remove("CARGO_MAKE_INTERNAL_RECURSION_LEVEL");
+    run_for_args(matches, &global_config, &"make".to_string(), true);
+
+    let report = fsio::file::read_text_file("./target/_cargo_make_temp/report/junit.xml").unwrap();
+    assert!(report.contains("<testcase name=\"A\""));
+}
+
 #[test]
 #[ignore]
 #[should_panic]
@@ -7,59 +7,232 @@
 #[path = "command_test.rs"]
 mod command_test;
 
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
+use crate::events;
 use crate::logger;
+use crate::output;
+use crate::report;
+use crate::timeout;
 use crate::toolchain;
 use crate::types::{CommandSpec, Step};
 use envmnt;
 use run_script;
 use run_script::{IoOptions, ScriptError, ScriptOptions};
+use std::collections::VecDeque;
 use std::io;
-use std::io::Error;
-use std::process::{Command, ExitStatus, Output, Stdio};
-
-/// Returns the exit code (-1 if no exit code found)
-pub(crate) fn get_exit_code(exit_status: Result<ExitStatus, Error>, force: bool) -> i32 {
+use std::io::{BufRead, BufReader, Error, Read};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// The amount of stderr lines kept for the reports
+const STDERR_TAIL_LINES: usize = 20;
+/// The interval (in millies) in which a child process is checked while waiting for it to finish
+const WAIT_POLL_INTERVAL: u64 = 50;
+
+/// Returns the exit code (-1 if no exit code found).<br>
+/// In case the command could not be executed, an error is returned unless forced.
+pub(crate) fn get_exit_code(
+    exit_status: Result<ExitStatus, Error>,
+    force: bool,
+) -> Result<i32, CargoMakeError> {
     match exit_status {
         Ok(code) => {
             if !code.success() {
                 match code.code() {
-                    Some(value) => value,
-                    None => -1,
+                    Some(value) => Ok(value),
+                    None => Ok(-1),
                 }
             } else {
-                0
+                Ok(0)
             }
         }
         Err(error) => {
-            if !force {
-                error!("Error while executing command, error: {:#?}", error);
+            if force {
+                Ok(-1)
+            } else {
+                Err(CargoMakeError::TaskFailed(format!(
+                    "Error while executing command, error: {:#?}",
+                    error
+                )))
             }
-
-            -1
         }
     }
 }
 
-pub(crate) fn get_exit_code_from_output(output: &io::Result<Output>, force: bool) -> i32 {
+pub(crate) fn get_exit_code_from_output(
+    output: &io::Result<Output>,
+    force: bool,
+) -> Result<i32, CargoMakeError> {
     match output {
         &Ok(ref output_struct) => get_exit_code(Ok(output_struct.status), force),
         &Err(ref error) => {
-            if !force {
-                error!("Error while executing command, error: {:#?}", error);
+            if force {
+                Ok(-1)
+            } else {
+                Err(CargoMakeError::TaskFailed(format!(
+                    "Error while executing command, error: {:#?}",
+                    error
+                )))
             }
-
-            -1
         }
     }
 }
 
-/// Validates the exit code code and if not 0 or unable to validate it, panic.
-pub(crate) fn validate_exit_code(code: i32) {
-    if code == -1 {
-        error!("Error while executing command, unable to extract exit code.");
+/// Validates the exit code code and returns an error if not 0 or unable to validate it.
+pub(crate) fn validate_exit_code(code: i32) -> Result<(), CargoMakeError> {
+    if code != 0 {
+        events::set_failed_exit_code(code);
+    }
+
+    if let Some(message) = timeout::take_timed_out_message() {
+        Err(CargoMakeError::TaskFailed(message))
+    } else if code == -1 {
+        Err(CargoMakeError::TaskFailed(
+            "Error while executing command, unable to extract exit code.".to_string(),
+        ))
     } else if code != 0 {
-        error!("Error while executing command, exit code: {}", code);
+        Err(CargoMakeError::TaskFailed(format!(
+            "Error while executing command, exit code: {}",
+            code
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn get_child_process_ids(process_id: u32) -> Vec<u32> {
+    let mut process_ids = vec![];
+
+    match Command::new("pgrep")
+        .arg("-P")
+        .arg(process_id.to_string())
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+    {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            for line in stdout.lines() {
+                if let Ok(child_process_id) = line.trim().parse::<u32>() {
+                    process_ids.push(child_process_id);
+                    process_ids.extend(get_child_process_ids(child_process_id));
+                }
+            }
+        }
+        Err(error) => debug!("Unable to list child processes, error: {:#?}", error),
+    };
+
+    process_ids
+}
+
+/// Kills the provided child process and all of its sub processes.
+pub(crate) fn kill_process_tree(child: &mut Child) {
+    let process_id = child.id();
+
+    let result = if cfg!(windows) {
+        Command::new("taskkill")
+            .args(&["/F", "/T", "/PID", &process_id.to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+    } else {
+        let mut args = vec!["-KILL".to_string(), process_id.to_string()];
+        for child_process_id in get_child_process_ids(process_id) {
+            args.push(child_process_id.to_string());
+        }
+
+        Command::new("kill")
+            .args(&args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+    };
+
+    if let Err(error) = result {
+        debug!("Unable to kill process tree, error: {:#?}", error);
     }
+
+    // make sure the process itself is killed and does not remain as zombie
+    child.kill().unwrap_or(());
+    child.wait().ok();
+}
+
+/// Waits for the child process to finish.<br>
+/// In case the current task timeout is exceeded, the child process tree is killed.
+fn wait_for_child(child: &mut Child) -> io::Result<ExitStatus> {
+    loop {
+        let remaining = match timeout::get_remaining() {
+            Some(value) => value,
+            None => return child.wait(),
+        };
+
+        if let Some(exit_status) = child.try_wait()? {
+            return Ok(exit_status);
+        }
+
+        if remaining.as_millis() == 0 {
+            kill_process_tree(child);
+            timeout::set_timed_out();
+
+            return child.wait();
+        }
+
+        thread::sleep(remaining.min(Duration::from_millis(WAIT_POLL_INTERVAL)));
+    }
+}
+
+/// Copies the given output to the stdout/stderr (or the output sink) while keeping its last lines.
+fn forward_output<R: Read + Send + 'static>(reader: R, to_stderr: bool) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        let mut tail = VecDeque::new();
+        let mut line = vec![];
+
+        while let Ok(size) = reader.read_until(b'\n', &mut line) {
+            if size == 0 {
+                break;
+            }
+
+            output::write(&line, to_stderr);
+
+            if tail.len() == STDERR_TAIL_LINES {
+                tail.pop_front();
+            }
+            tail.push_back(String::from_utf8_lossy(&line).into_owned());
+            line.clear();
+        }
+
+        tail.into_iter().collect::<Vec<String>>().concat()
+    })
+}
+
+/// Waits for the child process while forwarding its piped output to the stdout/stderr.<br>
+/// The last stderr lines are kept so they can be attached to the report of a failed task.
+fn wait_with_stderr_tail(mut child: Child) -> io::Result<ExitStatus> {
+    let stdout_handle = child
+        .stdout
+        .take()
+        .map(|stdout| forward_output(stdout, false));
+    let stderr_handle = child
+        .stderr
+        .take()
+        .map(|stderr| forward_output(stderr, true));
+
+    let exit_status = wait_for_child(&mut child);
+
+    if let Some(handle) = stdout_handle {
+        handle.join().ok();
+    }
+    if let Some(handle) = stderr_handle {
+        if let Ok(tail) = handle.join() {
+            events::set_stderr_tail(tail);
+        }
+    }
+
+    exit_status
 }
 
 fn is_silent() -> bool {
@@ -126,16 +299,57 @@ pub(crate) fn run_script_get_output(
         options.input_redirection = IoOptions::Pipe;
     }
 
-    run_script::run(script_lines.join("\n").as_str(), cli_arguments, &options)
+    let script = script_lines.join("\n");
+
+    let capture_stderr = !capture_output
+        && (report::should_capture_stderr() || output::is_redirected())
+        && !is_silent();
+    // the script process can be killed if the task timeout is exceeded
+    let wait_with_tail = capture_stderr || (!capture_output && timeout::get_remaining().is_some());
+    if capture_stderr {
+        // output is piped and forwarded so the stderr tail is available for the report
+        options.output_redirection = IoOptions::Pipe;
+    } else if wait_with_tail && is_silent() {
+        options.output_redirection = IoOptions::Null;
+    }
+
+    // the script process inherits the process env, so it is spawned while holding the env lock
+    // to prevent it from inheriting env vars temporarily set by tasks running in other threads
+    let child = task_env::spawn_script(|env_vars, working_directory| {
+        options.env_vars = env_vars;
+        options.working_directory = working_directory;
+
+        run_script::spawn(&script, cli_arguments, &options)
+    })?;
+
+    if wait_with_tail {
+        match wait_with_stderr_tail(child) {
+            Ok(exit_status) => Ok((
+                get_exit_code(Ok(exit_status), true).unwrap_or(-1),
+                "".to_string(),
+                "".to_string(),
+            )),
+            Err(error) => Err(ScriptError::IOError(error)),
+        }
+    } else {
+        match child.wait_with_output() {
+            Ok(output) => Ok((
+                get_exit_code(Ok(output.status), true).unwrap_or(-1),
+                String::from_utf8_lossy(&output.stdout).into_owned(),
+                String::from_utf8_lossy(&output.stderr).into_owned(),
+            )),
+            Err(error) => Err(ScriptError::IOError(error)),
+        }
+    }
 }
 
-/// Runs the requested script text and panics in case of any script error.
+/// Runs the requested script text and returns an error in case of any script error.
 pub(crate) fn run_script_get_exit_code(
     script_lines: &Vec<String>,
     script_runner: Option<String>,
     cli_arguments: &Vec<String>,
     validate: bool,
-) -> i32 {
+) -> Result<i32, CargoMakeError> {
     let output = run_script_get_output(&script_lines, script_runner, cli_arguments, false, None);
 
     let exit_code = match output {
@@ -144,10 +358,24 @@ pub(crate) fn run_script_get_exit_code(
     };
 
     if validate {
-        validate_exit_code(exit_code);
+        validate_exit_code(exit_code)?;
     }
 
-    exit_code
+    Ok(exit_code)
+}
+
+/// Returns the command program, arguments and working directory for logging.<br>
+/// The command env is not included as it may hold secrets.
+pub(crate) fn get_command_description(command: &Command) -> String {
+    let mut description = format!("{:?}", command.get_program());
+    for arg in command.get_args() {
+        description.push_str(&format!(" {:?}", arg));
+    }
+    if let Some(directory) = command.get_current_dir() {
+        description.push_str(&format!(" (cwd: {:?})", directory));
+    }
+
+    description
 }
 
 /// Runs the requested command and return its output.
@@ -166,29 +394,67 @@ pub(crate) fn run_command_get_output(
         None => debug!("No command args defined."),
     };
 
+    task_env::apply_to_command(&mut command);
+
     command.stdin(Stdio::inherit());
-    if !capture_output {
+    let redirect_output = !capture_output && output::is_redirected();
+    let capture_stderr = !capture_output && report::should_capture_stderr();
+    if redirect_output {
+        // all output is piped and forwarded to the output sink
+        command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    } else if capture_stderr {
+        // stderr is piped and forwarded so its tail is available for the report
+        command.stdout(Stdio::inherit()).stderr(Stdio::piped());
+    } else if !capture_output {
         command.stdout(Stdio::inherit()).stderr(Stdio::inherit());
     }
-    info!("Execute Command: {:#?}", &command);
-
-    let output = command.output();
+    info!("Execute Command: {}", get_command_description(&command));
+
+    let output = if redirect_output
+        || capture_stderr
+        || (!capture_output && timeout::get_remaining().is_some())
+    {
+        task_env::spawn(&mut command)
+            .and_then(|child| wait_with_stderr_tail(child))
+            .map(|status| Output {
+                status,
+                stdout: vec![],
+                stderr: vec![],
+            })
+    } else if capture_output {
+        task_env::output(&mut command)
+    } else {
+        task_env::spawn(&mut command).and_then(|child| child.wait_with_output())
+    };
     debug!("Output: {:#?}", &output);
 
     output
 }
 
-/// Runs the requested command and panics in case of any error.
-pub(crate) fn run_command(command_string: &str, args: &Option<Vec<String>>, validate: bool) -> i32 {
+/// Runs the requested command and returns an error in case of any error.
+pub(crate) fn run_command(
+    command_string: &str,
+    args: &Option<Vec<String>>,
+    validate: bool,
+) -> Result<i32, CargoMakeError> {
     let output = run_command_get_output(&command_string, &args, false);
 
-    let exit_code = get_exit_code_from_output(&output, !validate);
+    let exit_code = get_exit_code_from_output(&output, true)?;
 
     if validate {
-        validate_exit_code(exit_code);
+        if let Err(ref error) = output {
+            events::set_failed_exit_code(exit_code);
+
+            return Err(CargoMakeError::TaskFailed(format!(
+                "Error while executing command, error: {:#?}",
+                error
+            )));
+        }
+
+        validate_exit_code(exit_code)?;
     }
 
-    exit_code
+    Ok(exit_code)
 }
 
 /// Runs the requested command and returns the stdout if exit code is valid.
@@ -198,7 +464,7 @@ pub(crate) fn run_command_get_output_string(
 ) -> Option<String> {
     let output = run_command_get_output(&command_string, &args, true);
 
-    let exit_code = get_exit_code_from_output(&output, true);
+    let exit_code = get_exit_code_from_output(&output, true).unwrap_or(-1);
 
     if exit_code == 0 {
         match output {
@@ -213,15 +479,15 @@ pub(crate) fn run_command_get_output_string(
     }
 }
 
-/// Runs the given task command.
-pub(crate) fn run(step: &Step) {
+/// Runs the given task command and returns its exit code.
+pub(crate) fn run(step: &Step) -> Result<i32, CargoMakeError> {
     let validate = !step.config.should_ignore_errors();
 
     match step.config.command {
         Some(ref command_string) => {
-            let command_spec = match step.config.toolchain {
+            let command_spec = match step.config.get_toolchain() {
                 Some(ref toolchain) => {
-                    toolchain::wrap_command(&toolchain, &command_string, &step.config.args)
+                    toolchain::wrap_command(&toolchain, &command_string, &step.config.args)?
                 }
                 None => CommandSpec {
                     command: command_string.to_string(),
@@ -229,8 +495,12 @@ pub(crate) fn run(step: &Step) {
                 },
             };
 
-            run_command(&command_spec.command, &command_spec.args, validate);
+            run_command(&command_spec.command, &command_spec.args, validate)
         }
-        None => debug!("No command defined."),
-    };
+        None => {
+            debug!("No command defined.");
+
+            Ok(0)
+        }
+    }
 }
@@ -2,30 +2,37 @@ use super::*;
 use crate::test;
 use crate::types::Task;
 use std::io::ErrorKind;
+use std::time::Instant;
 
 #[test]
 #[should_panic]
 fn validate_exit_code_unable_to_fetch() {
-    validate_exit_code(-1);
+    validate_exit_code(-1).unwrap();
 }
 
 #[test]
 #[should_panic]
 fn validate_exit_code_not_zero() {
-    validate_exit_code(1);
+    validate_exit_code(1).unwrap();
 }
 
 #[test]
 fn validate_exit_code_zero() {
-    validate_exit_code(0);
+    validate_exit_code(0).unwrap();
 }
 
 #[test]
-#[should_panic]
 fn get_exit_code_error() {
-    test::on_test_startup();
+    let result = get_exit_code(Err(Error::new(ErrorKind::Other, "test")), false);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn get_exit_code_error_force() {
+    let exit_code = get_exit_code(Err(Error::new(ErrorKind::Other, "test")), true).unwrap();
 
-    get_exit_code(Err(Error::new(ErrorKind::Other, "test")), false);
+    assert_eq!(exit_code, -1);
 }
 
 #[test]
@@ -85,7 +92,7 @@ fn run_no_command() {
         config: task,
     };
 
-    run(&step);
+    run(&step).unwrap();
 }
 
 #[test]
@@ -99,7 +106,7 @@ fn run_command() {
         config: task,
     };
 
-    run(&step);
+    run(&step).unwrap();
 }
 
 #[test]
@@ -117,7 +124,7 @@ fn run_command_for_toolchain() {
             config: task,
         };
 
-        run(&step);
+        run(&step).unwrap();
     }
 }
 
@@ -134,7 +141,7 @@ fn run_command_error() {
         config: task,
     };
 
-    run(&step);
+    run(&step).unwrap();
 }
 
 #[test]
@@ -148,23 +155,23 @@ fn run_command_error_ignore_errors() {
         config: task,
     };
 
-    run(&step);
+    run(&step).unwrap();
 }
 
 #[test]
 fn run_script_get_exit_code_valid() {
-    run_script_get_exit_code(&vec!["echo 1".to_string()], None, &vec![], true);
+    run_script_get_exit_code(&vec!["echo 1".to_string()], None, &vec![], true).unwrap();
 }
 
 #[test]
 #[should_panic]
 fn run_script_get_exit_code_error() {
-    run_script_get_exit_code(&vec!["exit 1".to_string()], None, &vec![], true);
+    run_script_get_exit_code(&vec!["exit 1".to_string()], None, &vec![], true).unwrap();
 }
 
 #[test]
 fn run_script_get_exit_code_error_force() {
-    run_script_get_exit_code(&vec!["exit 1".to_string()], None, &vec![], false);
+    run_script_get_exit_code(&vec!["exit 1".to_string()], None, &vec![], false).unwrap();
 }
 
 #[test]
@@ -175,7 +182,8 @@ fn run_script_get_exit_code_custom_runner() {
         Some("bash".to_string()),
         &vec![],
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -186,7 +194,8 @@ fn run_script_get_exit_code_cli_args_valid() {
         None,
         &vec!["0".to_string()],
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -198,5 +207,111 @@ fn run_script_get_exit_code_cli_args_error() {
         None,
         &vec!["1".to_string()],
         true,
+    )
+    .unwrap();
+}
+
+#[test]
+fn run_command_timeout() {
+    timeout::start("test", &Some("200ms".to_string())).unwrap();
+    let start_time = Instant::now();
+
+    let exit_code = super::run_command("sleep", &Some(vec!["10".to_string()]), false).unwrap();
+
+    assert_ne!(exit_code, 0);
+    assert!(start_time.elapsed() < Duration::from_secs(5));
+    assert_eq!(timeout::end().unwrap(), "Task test timed out after 200ms");
+}
+
+#[test]
+fn run_command_within_timeout() {
+    timeout::start("test", &Some("10s".to_string())).unwrap();
+
+    let exit_code = super::run_command("echo", &Some(vec!["test".to_string()]), false).unwrap();
+
+    assert_eq!(exit_code, 0);
+    assert!(timeout::end().is_none());
+}
+
+#[test]
+fn run_script_get_exit_code_timeout() {
+    timeout::start("test", &Some("200ms".to_string())).unwrap();
+    let start_time = Instant::now();
+
+    let exit_code =
+        run_script_get_exit_code(&vec!["sleep 10".to_string()], None, &vec![], false).unwrap();
+
+    assert_ne!(exit_code, 0);
+    assert!(start_time.elapsed() < Duration::from_secs(5));
+    assert_eq!(timeout::end().unwrap(), "Task test timed out after 200ms");
+}
+
+#[test]
+#[should_panic]
+fn run_script_get_exit_code_timeout_error() {
+    test::on_test_startup();
+
+    timeout::start("test", &Some("200ms".to_string())).unwrap();
+
+    run_script_get_exit_code(&vec!["sleep 10".to_string()], None, &vec![], true).unwrap();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn run_script_get_exit_code_timeout_kills_process_tree() {
+    let directory = "./target/_cargo_make_temp/command/timeout_tree";
+    let pid_file = format!("{}/pid", directory);
+    std::fs::create_dir_all(directory).unwrap();
+    std::fs::remove_file(&pid_file).unwrap_or(());
+
+    timeout::start("test", &Some("500ms".to_string())).unwrap();
+
+    let exit_code = run_script_get_exit_code(
+        &vec![format!("sleep 30 &\necho $! > {}\nwait", &pid_file)],
+        None,
+        &vec![],
+        false,
+    )
+    .unwrap();
+    timeout::end();
+
+    assert_ne!(exit_code, 0);
+
+    let pid = std::fs::read_to_string(&pid_file).unwrap();
+    // the sub process is either gone or a zombie waiting to be reaped
+    let is_killed = || {
+        let state =
+            std::fs::read_to_string(format!("/proc/{}/stat", pid.trim())).unwrap_or_default();
+        state.is_empty() || state.contains(") Z ")
+    };
+    let start_time = Instant::now();
+    while !is_killed() && start_time.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(50));
+    }
+    assert!(is_killed());
+}
+
+#[test]
+fn get_command_description_without_env() {
+    envmnt::set(
+        "CARGO_MAKE_TEST_COMMAND_DESCRIPTION_SECRET",
+        "process-secret",
+    );
+    let mut task_env = task_env::TaskEnv::default();
+    task_env.vars.insert(
+        "CARGO_MAKE_TEST_COMMAND_DESCRIPTION_TOKEN".to_string(),
+        "task-secret".to_string(),
     );
+    let _env_guard = task_env::set_current(Some(task_env));
+    let _cwd_guard = task_env::set_current_cwd(Some("./src".to_string()));
+
+    let mut command = Command::new("echo");
+    command.args(&["arg1", "arg2"]);
+    task_env::apply_to_command(&mut command);
+
+    let description = get_command_description(&command);
+    envmnt::remove("CARGO_MAKE_TEST_COMMAND_DESCRIPTION_SECRET");
+
+    assert_eq!(description, "\"echo\" \"arg1\" \"arg2\" (cwd: \"./src\")");
+    assert!(!description.contains("secret"));
 }
@@ -9,119 +9,206 @@ mod condition_test;
 
 use crate::command;
 use crate::environment;
+use crate::environment::crateinfo;
+use crate::environment::task_env;
+use crate::git;
+use crate::installer::rustup_component_installer;
+use crate::io;
 use crate::profile;
 use crate::types;
-use crate::types::{FlowInfo, RustVersionCondition, Step, TaskCondition};
+use crate::types::{
+    CliArgs, FilesChangedSinceCondition, FlowInfo, RustVersionCondition, Step, TaskCondition,
+};
 use crate::version::is_newer;
 use envmnt;
+use git_info;
+use git_info::types::GitInfo;
+use glob::{MatchOptions, Pattern};
 use indexmap::IndexMap;
 use rust_info;
 use rust_info::types::{RustChannel, RustInfo};
-use std::path::Path;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds the condition criterion which was not met and the actual value which failed it
+pub(crate) struct ConditionFailure {
+    /// The criterion name (for example env_set or profiles)
+    pub(crate) criterion: String,
+    /// Describes the actual value compared to the expected value
+    pub(crate) reason: String,
+}
+
+impl ConditionFailure {
+    /// Creates and returns a new instance.
+    pub(crate) fn new(criterion: &str, reason: &str) -> ConditionFailure {
+        ConditionFailure {
+            criterion: criterion.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
 
-fn validate_env_map(env: Option<IndexMap<String, String>>, equal: bool) -> bool {
+impl fmt::Display for ConditionFailure {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}: {}", &self.criterion, &self.reason)
+    }
+}
+
+/// The result of a condition evaluation, holding the first criterion failure (if any)
+pub(crate) type ConditionResult = Result<(), ConditionFailure>;
+
+fn format_env_value(key: &str) -> String {
+    match task_env::get_var(key) {
+        Some(value) => format!("'{}'", value),
+        None => "not defined".to_string(),
+    }
+}
+
+fn validate_env_map(
+    criterion: &str,
+    env: Option<IndexMap<String, String>>,
+    equal: bool,
+) -> ConditionResult {
     match env {
         Some(env_vars) => {
-            let mut all_valid = true;
-
             for (key, current_value) in env_vars.iter() {
-                if (equal && !envmnt::is_equal(key, current_value))
-                    || (!equal && !envmnt::contains_ignore_case(key, current_value))
-                {
-                    all_valid = false;
-                    break;
+                let valid = match task_env::get_var(key) {
+                    Some(value) => {
+                        if equal {
+                            &value == current_value
+                        } else {
+                            value.to_lowercase().contains(&current_value.to_lowercase())
+                        }
+                    }
+                    None => false,
+                };
+
+                if !valid {
+                    let expected = if equal {
+                        "expected"
+                    } else {
+                        "expected to contain"
+                    };
+
+                    return Err(ConditionFailure::new(
+                        criterion,
+                        &format!(
+                            "env var: {} {}: '{}', actual: {}",
+                            key,
+                            expected,
+                            current_value,
+                            format_env_value(key)
+                        ),
+                    ));
                 }
             }
 
-            all_valid
+            Ok(())
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_env(condition: &TaskCondition) -> bool {
-    validate_env_map(condition.env.clone(), true)
+fn validate_env(condition: &TaskCondition) -> ConditionResult {
+    validate_env_map("env", condition.env.clone(), true)
 }
 
-fn validate_env_contains(condition: &TaskCondition) -> bool {
-    validate_env_map(condition.env_contains.clone(), false)
+fn validate_env_contains(condition: &TaskCondition) -> ConditionResult {
+    validate_env_map("env_contains", condition.env_contains.clone(), false)
 }
 
-fn validate_env_set(condition: &TaskCondition) -> bool {
+fn validate_env_set(condition: &TaskCondition) -> ConditionResult {
     let env = condition.env_set.clone();
 
     match env {
         Some(env_vars) => {
-            let mut all_valid = true;
-
             for key in env_vars.iter() {
-                if !envmnt::exists(key) {
-                    all_valid = false;
-                    break;
+                if task_env::get_var(key).is_none() {
+                    return Err(ConditionFailure::new(
+                        "env_set",
+                        &format!("env var: {} is not defined", key),
+                    ));
                 }
             }
 
-            all_valid
+            Ok(())
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_env_not_set(condition: &TaskCondition) -> bool {
+fn validate_env_not_set(condition: &TaskCondition) -> ConditionResult {
     let env = condition.env_not_set.clone();
 
     match env {
         Some(env_vars) => {
-            let mut all_valid = true;
-
             for key in env_vars.iter() {
-                if envmnt::exists(key) {
-                    all_valid = false;
-                    break;
+                if task_env::get_var(key).is_some() {
+                    return Err(ConditionFailure::new(
+                        "env_not_set",
+                        &format!(
+                            "env var: {} is defined, actual: {}",
+                            key,
+                            format_env_value(key)
+                        ),
+                    ));
                 }
             }
 
-            all_valid
+            Ok(())
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_env_bool(condition: &TaskCondition, truthy: bool) -> bool {
-    let env = if truthy {
-        condition.env_true.clone()
+fn validate_env_bool(condition: &TaskCondition, truthy: bool) -> ConditionResult {
+    let (criterion, env) = if truthy {
+        ("env_true", condition.env_true.clone())
     } else {
-        condition.env_false.clone()
+        ("env_false", condition.env_false.clone())
     };
 
     match env {
         Some(env_vars) => {
-            let mut all_valid = true;
-
             for key in env_vars.iter() {
-                let is_true = envmnt::is_or(key, !truthy);
+                let is_true = match task_env::get_var(key) {
+                    Some(value) => {
+                        let value = value.to_lowercase();
+                        !value.is_empty() && value != "false" && value != "no" && value != "0"
+                    }
+                    None => !truthy,
+                };
 
                 if is_true != truthy {
-                    all_valid = false;
-                    break;
+                    return Err(ConditionFailure::new(
+                        criterion,
+                        &format!(
+                            "env var: {} is not {}, actual: {}",
+                            key,
+                            truthy,
+                            format_env_value(key)
+                        ),
+                    ));
                 }
             }
 
-            all_valid
+            Ok(())
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_platform(condition: &TaskCondition) -> bool {
+fn validate_platform(condition: &TaskCondition) -> ConditionResult {
     let platforms = condition.platforms.clone();
     match platforms {
         Some(platform_names) => {
             let platform_name = types::get_platform_name();
+            let platform_family_name = types::get_platform_family_name();
 
             let index = platform_names
                 .iter()
-                .position(|value| *value == platform_name);
+                .position(|value| *value == platform_name || *value == platform_family_name);
 
             match index {
                 None => {
@@ -129,16 +216,148 @@ fn validate_platform(condition: &TaskCondition) -> bool {
                         "Failed platform condition, current platform: {}",
                         &platform_name
                     );
-                    false
+                    Err(ConditionFailure::new(
+                        "platforms",
+                        &format!(
+                            "current platform: {} is not one of: {}",
+                            &platform_name,
+                            platform_names.join(", ")
+                        ),
+                    ))
+                }
+                _ => Ok(()),
+            }
+        }
+        None => Ok(()),
+    }
+}
+
+fn validate_arch(condition: &TaskCondition) -> ConditionResult {
+    match condition.arch {
+        Some(ref arch_names) => {
+            let arch_name = types::get_arch_name();
+
+            if arch_names.contains(&arch_name) {
+                Ok(())
+            } else {
+                debug!("Failed arch condition, current arch: {}", &arch_name);
+                Err(ConditionFailure::new(
+                    "arch",
+                    &format!(
+                        "current arch: {} is not one of: {}",
+                        &arch_name,
+                        arch_names.join(", ")
+                    ),
+                ))
+            }
+        }
+        None => Ok(()),
+    }
+}
+
+fn validate_target_triples(
+    condition: &TaskCondition,
+    flow_info: Option<&FlowInfo>,
+) -> ConditionResult {
+    match condition.target_triples {
+        Some(ref target_triples) => {
+            let default_target_triple = match flow_info {
+                Some(info) => info.env_info.rust_info.target_triple.clone(),
+                None => rust_info::get().target_triple,
+            };
+            let home = task_env::get_var("CARGO_MAKE_CARGO_HOME").map(PathBuf::from);
+
+            match crateinfo::crate_target_triple(default_target_triple, home) {
+                Some(target_triple) => {
+                    if target_triples.contains(&target_triple) {
+                        Ok(())
+                    } else {
+                        debug!(
+                            "Failed target triples condition, current target triple: {}",
+                            &target_triple
+                        );
+                        Err(ConditionFailure::new(
+                            "target_triples",
+                            &format!(
+                                "current target triple: {} is not one of: {}",
+                                &target_triple,
+                                target_triples.join(", ")
+                            ),
+                        ))
+                    }
                 }
-                _ => true,
+                None => Err(ConditionFailure::new(
+                    "target_triples",
+                    "unable to detect the target triple",
+                )),
             }
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_profile(condition: &TaskCondition) -> bool {
+fn validate_commands_exist(condition: &TaskCondition) -> ConditionResult {
+    match condition.commands_exist {
+        Some(ref commands) => {
+            let path_value = task_env::get_var("PATH");
+
+            for command in commands {
+                let expanded_command = environment::expand_value(command);
+                let command_path = if Path::new(&expanded_command).components().count() > 1 {
+                    task_env::resolve_path(&expanded_command)
+                        .to_string_lossy()
+                        .into_owned()
+                } else {
+                    expanded_command.clone()
+                };
+
+                if io::find_command(&command_path, &path_value).is_none() {
+                    return Err(ConditionFailure::new(
+                        "commands_exist",
+                        &format!("command: {} not found", &expanded_command),
+                    ));
+                }
+            }
+
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+fn validate_rustup_components(
+    condition: &TaskCondition,
+    flow_info: Option<&FlowInfo>,
+) -> ConditionResult {
+    match condition.rustup_components {
+        Some(ref components) => {
+            let installed_components = rustup_component_installer::get_installed_components()
+                .map_err(|error| ConditionFailure::new("rustup_components", &error))?;
+            let target_triple = match flow_info {
+                Some(info) => info.env_info.rust_info.target_triple.clone(),
+                None => rust_info::get().target_triple,
+            };
+
+            for component in components {
+                if !rustup_component_installer::is_component_in_list(
+                    &installed_components,
+                    component,
+                    &target_triple,
+                ) {
+                    return Err(ConditionFailure::new(
+                        "rustup_components",
+                        &format!("component: {} is not installed", component),
+                    ));
+                }
+            }
+
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+fn validate_profile(condition: &TaskCondition) -> ConditionResult {
     let profiles = condition.profiles.clone();
     match profiles {
         Some(profile_names) => {
@@ -154,85 +373,118 @@ fn validate_profile(condition: &TaskCondition) -> bool {
                         "Failed profile condition, current profile: {}",
                         &profile_name
                     );
-                    false
+                    Err(ConditionFailure::new(
+                        "profiles",
+                        &format!(
+                            "current profile: {} is not one of: {}",
+                            &profile_name,
+                            profile_names.join(", ")
+                        ),
+                    ))
                 }
-                _ => true,
+                _ => Ok(()),
             }
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_channel(condition: &TaskCondition, flow_info_option: Option<&FlowInfo>) -> bool {
+fn validate_channel(
+    condition: &TaskCondition,
+    flow_info_option: Option<&FlowInfo>,
+) -> ConditionResult {
     match flow_info_option {
         Some(flow_info) => {
             let channels = condition.channels.clone();
             match channels {
                 Some(channel_names) => match flow_info.env_info.rust_info.channel {
                     Some(value) => {
-                        let index = match value {
-                            RustChannel::Stable => channel_names
-                                .iter()
-                                .position(|value| *value == "stable".to_string()),
-                            RustChannel::Beta => channel_names
-                                .iter()
-                                .position(|value| *value == "beta".to_string()),
-                            RustChannel::Nightly => channel_names
-                                .iter()
-                                .position(|value| *value == "nightly".to_string()),
+                        let channel_name = match value {
+                            RustChannel::Stable => "stable",
+                            RustChannel::Beta => "beta",
+                            RustChannel::Nightly => "nightly",
                         };
+                        let index = channel_names
+                            .iter()
+                            .position(|value| *value == channel_name.to_string());
 
                         match index {
                             None => {
                                 debug!("Failed channel condition");
-                                false
+                                Err(ConditionFailure::new(
+                                    "channels",
+                                    &format!(
+                                        "current channel: {} is not one of: {}",
+                                        channel_name,
+                                        channel_names.join(", ")
+                                    ),
+                                ))
                             }
-                            _ => true,
+                            _ => Ok(()),
                         }
                     }
-                    None => false,
+                    None => Err(ConditionFailure::new(
+                        "channels",
+                        "unable to detect the current rust channel",
+                    )),
                 },
-                None => true,
+                None => Ok(()),
             }
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_rust_version_condition(rustinfo: RustInfo, condition: RustVersionCondition) -> bool {
+fn validate_rust_version_condition(
+    rustinfo: RustInfo,
+    condition: RustVersionCondition,
+) -> ConditionResult {
     if rustinfo.version.is_some() {
         let current_version = rustinfo.version.unwrap();
 
-        let mut valid = match condition.min {
-            Some(version) => {
-                version == current_version || is_newer(&version, &current_version, true)
+        if let Some(version) = condition.min {
+            if !(version == current_version || is_newer(&version, &current_version, true)) {
+                return Err(ConditionFailure::new(
+                    "rust_version",
+                    &format!(
+                        "current version: {} is older than min version: {}",
+                        &current_version, &version
+                    ),
+                ));
             }
-            None => true,
-        };
+        }
 
-        if valid {
-            valid = match condition.max {
-                Some(version) => {
-                    version == current_version || is_newer(&current_version, &version, true)
-                }
-                None => true,
-            };
+        if let Some(version) = condition.max {
+            if !(version == current_version || is_newer(&current_version, &version, true)) {
+                return Err(ConditionFailure::new(
+                    "rust_version",
+                    &format!(
+                        "current version: {} is newer than max version: {}",
+                        &current_version, &version
+                    ),
+                ));
+            }
         }
 
-        if valid {
-            valid = match condition.equal {
-                Some(version) => version == current_version,
-                None => true,
-            };
+        if let Some(version) = condition.equal {
+            if version != current_version {
+                return Err(ConditionFailure::new(
+                    "rust_version",
+                    &format!(
+                        "current version: {} is not equal to version: {}",
+                        &current_version, &version
+                    ),
+                ));
+            }
         }
 
-        valid
+        Ok(())
     } else {
-        true
+        Ok(())
     }
 }
 
-fn validate_rust_version(condition: &TaskCondition) -> bool {
+fn validate_rust_version(condition: &TaskCondition) -> ConditionResult {
     let rust_version = condition.rust_version.clone();
     match rust_version {
         Some(rust_version_condition) => {
@@ -240,81 +492,360 @@ fn validate_rust_version(condition: &TaskCondition) -> bool {
 
             validate_rust_version_condition(rustinfo, rust_version_condition)
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_files(file_paths: &Vec<String>, exist: bool) -> bool {
+fn validate_files(criterion: &str, file_paths: &Vec<String>, exist: bool) -> ConditionResult {
     for file_path in file_paths.iter() {
         let expanded_file_path = environment::expand_value(file_path);
-        let path = Path::new(&expanded_file_path);
+        let path = task_env::resolve_path(&expanded_file_path);
 
         if path.exists() != exist {
-            return false;
+            let reason = if exist {
+                format!("file: {} does not exist", path.display())
+            } else {
+                format!("file: {} exists", path.display())
+            };
+
+            return Err(ConditionFailure::new(criterion, &reason));
         }
     }
 
-    true
+    Ok(())
 }
 
-fn validate_files_exist(condition: &TaskCondition) -> bool {
+fn validate_files_exist(condition: &TaskCondition) -> ConditionResult {
     let files = condition.files_exist.clone();
     match files {
-        Some(ref file_paths) => validate_files(file_paths, true),
-        None => true,
+        Some(ref file_paths) => validate_files("files_exist", file_paths, true),
+        None => Ok(()),
     }
 }
 
-fn validate_files_not_exist(condition: &TaskCondition) -> bool {
+fn validate_files_not_exist(condition: &TaskCondition) -> ConditionResult {
     let files = condition.files_not_exist.clone();
     match files {
-        Some(ref file_paths) => validate_files(file_paths, false),
-        None => true,
+        Some(ref file_paths) => validate_files("files_not_exist", file_paths, false),
+        None => Ok(()),
     }
 }
 
-fn validate_criteria(flow_info: Option<&FlowInfo>, condition: &Option<TaskCondition>) -> bool {
+/// Returns the git info collected on startup or loads it in case there is no flow context
+fn get_git_info(flow_info: Option<&FlowInfo>) -> GitInfo {
+    match flow_info {
+        Some(info) => info.env_info.git_info.clone(),
+        None => git_info::get(),
+    }
+}
+
+fn validate_git_branches(
+    condition: &TaskCondition,
+    flow_info: Option<&FlowInfo>,
+) -> ConditionResult {
+    match condition.git_branches {
+        Some(ref branches) => match get_git_info(flow_info).current_branch {
+            Some(ref current_branch) => {
+                let found = branches.iter().any(|branch| match Pattern::new(branch) {
+                    Ok(pattern) => pattern.matches(current_branch),
+                    Err(_) => branch == current_branch,
+                });
+
+                if found {
+                    Ok(())
+                } else {
+                    debug!(
+                        "Failed git branches condition, current branch: {}",
+                        &current_branch
+                    );
+                    Err(ConditionFailure::new(
+                        "git_branches",
+                        &format!(
+                            "current branch: {} does not match any of: {}",
+                            &current_branch,
+                            branches.join(", ")
+                        ),
+                    ))
+                }
+            }
+            None => Err(ConditionFailure::new(
+                "git_branches",
+                "unable to detect the current branch",
+            )),
+        },
+        None => Ok(()),
+    }
+}
+
+fn validate_git_dirty(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> ConditionResult {
+    match condition.git_dirty {
+        Some(expected_dirty) => match get_git_info(flow_info).dirty {
+            Some(dirty) => {
+                if dirty == expected_dirty {
+                    Ok(())
+                } else if dirty {
+                    Err(ConditionFailure::new(
+                        "git_dirty",
+                        "the working tree has uncommitted changes",
+                    ))
+                } else {
+                    Err(ConditionFailure::new(
+                        "git_dirty",
+                        "the working tree has no uncommitted changes",
+                    ))
+                }
+            }
+            None => Err(ConditionFailure::new(
+                "git_dirty",
+                "unable to detect the working tree status",
+            )),
+        },
+        None => Ok(()),
+    }
+}
+
+fn validate_git_tag_on_head(condition: &TaskCondition) -> ConditionResult {
+    match condition.git_tag_on_head {
+        Some(expected_tagged) => match git::get_head_tags(&task_env::resolve_path(".")) {
+            Ok(tags) => {
+                if tags.is_empty() == expected_tagged {
+                    let reason = if expected_tagged {
+                        "HEAD has no tags".to_string()
+                    } else {
+                        format!("HEAD is tagged: {}", tags.join(", "))
+                    };
+
+                    Err(ConditionFailure::new("git_tag_on_head", &reason))
+                } else {
+                    Ok(())
+                }
+            }
+            Err(error) => Err(ConditionFailure::new("git_tag_on_head", &error)),
+        },
+        None => Ok(()),
+    }
+}
+
+/// Returns true if the (absolute) changed file path matches any of the globs.<br>
+/// Relative globs are matched against the file path relative to the given directory.
+fn is_changed_file_matched(
+    directory: &Path,
+    file: &Path,
+    globs: &Vec<String>,
+) -> Result<bool, String> {
+    let options = MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+    let relative_file = file.strip_prefix(directory).ok();
+
+    for glob in globs {
+        let pattern = match Pattern::new(glob) {
+            Ok(pattern) => pattern,
+            Err(error) => return Err(format!("invalid glob: {}, {}", glob, error)),
+        };
+
+        let matched = if Path::new(glob).is_absolute() {
+            pattern.matches_path_with(file, options)
+        } else {
+            match relative_file {
+                Some(relative_file) => pattern.matches_path_with(relative_file, options),
+                None => false,
+            }
+        };
+
+        if matched {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+fn validate_files_changed_since_condition(
+    condition: &FilesChangedSinceCondition,
+) -> ConditionResult {
+    let git_ref = environment::expand_value(&condition.git_ref);
+    let globs: Vec<String> = match condition.globs {
+        Some(ref globs) => globs
+            .iter()
+            .map(|glob| environment::expand_value(glob))
+            .collect(),
+        None => vec![],
+    };
+    let directory = task_env::resolve_path(".");
+    let directory = PathBuf::from(io::canonicalize_to_string(&directory.to_string_lossy()));
+
+    let changed_files = git::get_changed_files(&directory, &git_ref)
+        .map_err(|error| ConditionFailure::new("files_changed_since", &error))?;
+    debug!("Changed files since: {} {:#?}", &git_ref, &changed_files);
+
+    for file in &changed_files {
+        if globs.is_empty()
+            || is_changed_file_matched(&directory, file, &globs)
+                .map_err(|error| ConditionFailure::new("files_changed_since", &error))?
+        {
+            return Ok(());
+        }
+    }
+
+    let reason = if globs.is_empty() {
+        format!("no files changed since: {}", &git_ref)
+    } else {
+        format!(
+            "no files matching: {} changed since: {}",
+            globs.join(", "),
+            &git_ref
+        )
+    };
+
+    Err(ConditionFailure::new("files_changed_since", &reason))
+}
+
+fn validate_files_changed_since(condition: &TaskCondition) -> ConditionResult {
+    match condition.files_changed_since {
+        Some(ref files_changed_since) => {
+            validate_files_changed_since_condition(files_changed_since)
+        }
+        None => Ok(()),
+    }
+}
+
+fn validate_any(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> ConditionResult {
+    match condition.any {
+        Some(ref conditions) => {
+            let mut reasons = vec![];
+
+            for (index, nested_condition) in conditions.iter().enumerate() {
+                match validate_condition_struct(flow_info, nested_condition) {
+                    Ok(_) => return Ok(()),
+                    Err(failure) => reasons.push(format!("[{}] {}", index, failure)),
+                }
+            }
+
+            Err(ConditionFailure::new(
+                "any",
+                &format!("none of the conditions are met: {}", reasons.join("; ")),
+            ))
+        }
+        None => Ok(()),
+    }
+}
+
+fn validate_all(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> ConditionResult {
+    match condition.all {
+        Some(ref conditions) => {
+            for (index, nested_condition) in conditions.iter().enumerate() {
+                if let Err(failure) = validate_condition_struct(flow_info, nested_condition) {
+                    return Err(ConditionFailure::new(
+                        &format!("all[{}].{}", index, &failure.criterion),
+                        &failure.reason,
+                    ));
+                }
+            }
+
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+fn validate_not(condition: &TaskCondition, flow_info: Option<&FlowInfo>) -> ConditionResult {
+    match condition.not {
+        Some(ref nested_condition) => {
+            match validate_condition_struct(flow_info, nested_condition) {
+                Ok(_) => Err(ConditionFailure::new("not", "the negated condition is met")),
+                Err(_) => Ok(()),
+            }
+        }
+        None => Ok(()),
+    }
+}
+
+/// Validates all the condition criteria, including the nested any/all/not conditions (all
+/// criteria must be met)
+fn validate_condition_struct(
+    flow_info: Option<&FlowInfo>,
+    condition: &TaskCondition,
+) -> ConditionResult {
+    validate_platform(&condition)?;
+    validate_arch(&condition)?;
+    validate_profile(&condition)?;
+    validate_channel(&condition, flow_info)?;
+    validate_env(&condition)?;
+    validate_env_set(&condition)?;
+    validate_env_not_set(&condition)?;
+    validate_env_bool(&condition, true)?;
+    validate_env_bool(&condition, false)?;
+    validate_env_contains(&condition)?;
+    validate_rust_version(&condition)?;
+    validate_files_exist(&condition)?;
+    validate_files_not_exist(&condition)?;
+    validate_target_triples(&condition, flow_info)?;
+    validate_commands_exist(&condition)?;
+    validate_rustup_components(&condition, flow_info)?;
+    validate_git_branches(&condition, flow_info)?;
+    validate_git_dirty(&condition, flow_info)?;
+    validate_git_tag_on_head(&condition)?;
+    validate_files_changed_since(&condition)?;
+    validate_any(&condition, flow_info)?;
+    validate_all(&condition, flow_info)?;
+    validate_not(&condition, flow_info)
+}
+
+fn validate_criteria(
+    flow_info: Option<&FlowInfo>,
+    condition: &Option<TaskCondition>,
+) -> ConditionResult {
     match condition {
         Some(ref condition_struct) => {
             debug!("Checking task condition structure.");
 
-            validate_platform(&condition_struct)
-                && validate_profile(&condition_struct)
-                && validate_channel(&condition_struct, flow_info)
-                && validate_env(&condition_struct)
-                && validate_env_set(&condition_struct)
-                && validate_env_not_set(&condition_struct)
-                && validate_env_bool(&condition_struct, true)
-                && validate_env_bool(&condition_struct, false)
-                && validate_env_contains(&condition_struct)
-                && validate_rust_version(&condition_struct)
-                && validate_files_exist(&condition_struct)
-                && validate_files_not_exist(&condition_struct)
+            validate_condition_struct(flow_info, &condition_struct)
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
-fn validate_script(condition_script: &Option<Vec<String>>, script_runner: Option<String>) -> bool {
+fn validate_script(
+    condition_script: &Option<Vec<String>>,
+    script_runner: Option<String>,
+) -> ConditionResult {
     match condition_script {
         Some(ref script) => {
             debug!("Checking task condition script.");
 
             let exit_code =
-                command::run_script_get_exit_code(&script, script_runner, &vec![], false);
+                command::run_script_get_exit_code(&script, script_runner, &vec![], false)
+                    .unwrap_or(-1);
 
             if exit_code == 0 {
-                true
+                Ok(())
             } else {
-                false
+                Err(ConditionFailure::new(
+                    "condition_script",
+                    &format!("script exited with code: {}", exit_code),
+                ))
             }
         }
-        None => true,
+        None => Ok(()),
     }
 }
 
 pub(crate) fn validate_conditions_without_context(condition: TaskCondition) -> bool {
-    validate_criteria(None, &Some(condition))
+    validate_criteria(None, &Some(condition)).is_ok()
+}
+
+/// Evaluates the condition and condition script and returns the first criterion which was not met
+pub(crate) fn explain_conditions(
+    flow_info: &FlowInfo,
+    condition: &Option<TaskCondition>,
+    condition_script: &Option<Vec<String>>,
+    script_runner: Option<String>,
+) -> ConditionResult {
+    validate_criteria(Some(&flow_info), &condition)?;
+    validate_script(&condition_script, script_runner)
 }
 
 pub(crate) fn validate_conditions(
@@ -323,15 +854,25 @@ pub(crate) fn validate_conditions(
     condition_script: &Option<Vec<String>>,
     script_runner: Option<String>,
 ) -> bool {
-    validate_criteria(Some(&flow_info), &condition)
-        && validate_script(&condition_script, script_runner)
+    explain_conditions(&flow_info, &condition, &condition_script, script_runner).is_ok()
 }
 
-pub(crate) fn validate_condition_for_step(flow_info: &FlowInfo, step: &Step) -> bool {
-    validate_conditions(
+pub(crate) fn explain_condition_for_step(flow_info: &FlowInfo, step: &Step) -> ConditionResult {
+    explain_conditions(
         &flow_info,
         &step.config.condition,
         &step.config.condition_script,
         step.config.script_runner.clone(),
     )
 }
+
+pub(crate) fn init(cli_args: &CliArgs) {
+    if cli_args.explain_skips {
+        envmnt::set_bool("CARGO_MAKE_EXPLAIN_SKIPS", true);
+    }
+}
+
+/// Returns true if the reason of skipped tasks should be printed
+pub(crate) fn should_explain_skips() -> bool {
+    envmnt::is_or("CARGO_MAKE_EXPLAIN_SKIPS", false)
+}
@@ -7,6 +7,7 @@ use ci_info;
 use git_info::types::GitInfo;
 use indexmap::IndexMap;
 use rust_info::types::{RustChannel, RustInfo};
+use std::env;
 
 #[test]
 fn validate_env_set_empty() {
@@ -24,11 +25,22 @@ fn validate_env_set_empty() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -51,11 +63,22 @@ fn validate_env_set_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -74,11 +97,22 @@ fn validate_env_set_invalid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -105,11 +139,22 @@ fn validate_env_set_invalid_partial_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_set(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -128,11 +173,22 @@ fn validate_env_not_set_empty() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -152,11 +208,22 @@ fn validate_env_not_set_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -179,11 +246,22 @@ fn validate_env_not_set_invalid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -210,11 +288,22 @@ fn validate_env_not_set_invalid_partial_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_not_set(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -233,11 +322,22 @@ fn validate_env_bool_true_empty() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -260,11 +360,22 @@ fn validate_env_bool_true_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -287,11 +398,22 @@ fn validate_env_bool_true_invalid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -314,11 +436,22 @@ fn validate_env_bool_true_invalid_partial_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -341,11 +474,22 @@ fn validate_env_bool_true_invalid_partial_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, true);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -364,11 +508,22 @@ fn validate_env_bool_false_empty() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -391,11 +546,22 @@ fn validate_env_bool_false_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -418,11 +584,22 @@ fn validate_env_bool_false_invalid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -445,11 +622,22 @@ fn validate_env_bool_false_invalid_partial_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -472,11 +660,22 @@ fn validate_env_bool_false_invalid_partial_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_bool(&condition, false);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -495,11 +694,22 @@ fn validate_env_empty() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -526,11 +736,22 @@ fn validate_env_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -554,11 +775,22 @@ fn validate_env_invalid_not_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -583,11 +815,22 @@ fn validate_env_invalid_not_equal() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -614,11 +857,22 @@ fn validate_env_invalid_partial_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -645,11 +899,22 @@ fn validate_env_contains_valid_same() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -676,11 +941,22 @@ fn validate_env_contains_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -704,11 +980,22 @@ fn validate_env_contains_invalid_not_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -733,11 +1020,22 @@ fn validate_env_contains_invalid_not_equal() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -764,32 +1062,43 @@ fn validate_env_contains_invalid_partial_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_env_contains(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
 fn validate_script_empty() {
     let enabled = validate_script(&None, None);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
 fn validate_script_valid() {
     let enabled = validate_script(&Some(vec!["exit 0".to_string()]), None);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
 fn validate_script_invalid() {
     let enabled = validate_script(&Some(vec!["exit 1".to_string()]), None);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -815,11 +1124,22 @@ fn validate_profile_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_profile(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -841,11 +1161,22 @@ fn validate_profile_invalid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_profile(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -868,11 +1199,22 @@ fn validate_platform_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_platform(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -891,11 +1233,22 @@ fn validate_platform_invalid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_platform(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -944,9 +1297,20 @@ fn validate_channel_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
     let mut enabled = validate_channel(&condition, Some(&flow_info));
-    assert!(enabled);
+    assert!(enabled.is_ok());
 
     flow_info.env_info.rust_info.channel = Some(RustChannel::Beta);
     condition = TaskCondition {
@@ -967,10 +1331,21 @@ fn validate_channel_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
     enabled = validate_channel(&condition, Some(&flow_info));
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 
     flow_info.env_info.rust_info.channel = Some(RustChannel::Nightly);
     condition = TaskCondition {
@@ -991,10 +1366,21 @@ fn validate_channel_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
     enabled = validate_channel(&condition, Some(&flow_info));
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1039,10 +1425,21 @@ fn validate_channel_invalid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
     let enabled = validate_channel(&condition, Some(&flow_info));
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1063,11 +1460,22 @@ fn validate_file_exists_valid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
         ]),
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1089,11 +1497,22 @@ fn validate_file_exists_partial_invalid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string(),
         ]),
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1114,11 +1533,22 @@ fn validate_file_exists_invalid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
         ]),
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_files_exist(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1139,11 +1569,22 @@ fn validate_file_not_exists_valid() {
         files_not_exist: Some(vec![
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
         ]),
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1165,11 +1606,22 @@ fn validate_file_not_exists_partial_invalid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string(),
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string(),
         ]),
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1190,11 +1642,22 @@ fn validate_file_not_exists_invalid() {
         files_not_exist: Some(vec![
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
         ]),
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_files_not_exist(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1240,10 +1703,21 @@ fn validate_criteria_empty() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1293,10 +1767,21 @@ fn validate_criteria_valid_platform() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1342,10 +1827,21 @@ fn validate_criteria_invalid_platform() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1392,10 +1888,21 @@ fn validate_criteria_valid_profile() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1442,10 +1949,21 @@ fn validate_criteria_invalid_profile() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1496,10 +2014,21 @@ fn validate_criteria_valid_channel() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 
     flow_info.env_info.rust_info.channel = Some(RustChannel::Beta);
     enabled = validate_criteria(
@@ -1522,10 +2051,21 @@ fn validate_criteria_valid_channel() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 
     flow_info.env_info.rust_info.channel = Some(RustChannel::Nightly);
     enabled = validate_criteria(
@@ -1548,10 +2088,21 @@ fn validate_criteria_valid_channel() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1598,10 +2149,21 @@ fn validate_criteria_invalid_channel() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1649,10 +2211,21 @@ fn validate_criteria_valid_file_exists() {
                 "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
             ]),
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1700,10 +2273,21 @@ fn validate_criteria_invalid_file_exists() {
                 "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
             ]),
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1751,10 +2335,21 @@ fn validate_criteria_valid_file_not_exists() {
             files_not_exist: Some(vec![
                 "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
             ]),
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -1802,10 +2397,21 @@ fn validate_criteria_invalid_file_not_exists() {
             files_not_exist: Some(vec![
                 "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
             ]),
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -1858,10 +2464,21 @@ fn validate_condition_for_step_both_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(enabled);
 }
@@ -1916,10 +2533,21 @@ fn validate_condition_for_step_valid_script_invalid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 1".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -1970,10 +2598,21 @@ fn validate_condition_for_step_invalid_script_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -2025,10 +2664,21 @@ fn validate_condition_for_step_invalid_env_set() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -2082,10 +2732,21 @@ fn validate_condition_for_step_invalid_env_not_set() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -2144,10 +2805,21 @@ fn validate_condition_for_step_valid_env() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(enabled);
 }
@@ -2203,10 +2875,21 @@ fn validate_condition_for_step_invalid_env_not_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -2265,10 +2948,21 @@ fn validate_condition_for_step_invalid_env_not_equal() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -2327,10 +3021,21 @@ fn validate_condition_for_step_valid_env_contains_same() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(enabled);
 }
@@ -2389,10 +3094,21 @@ fn validate_condition_for_step_valid_env_contains() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(enabled);
 }
@@ -2448,10 +3164,21 @@ fn validate_condition_for_step_invalid_env_contains_not_found() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -2510,10 +3237,21 @@ fn validate_condition_for_step_invalid_env_contains_not_contains() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
     step.config.condition_script = Some(vec!["exit 0".to_string()]);
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -2571,9 +3309,20 @@ fn validate_condition_for_step_valid_rust_version() {
         }),
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(enabled);
 }
@@ -2632,9 +3381,20 @@ fn validate_condition_for_step_invalid_rust_version() {
         }),
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
 
-    let enabled = validate_condition_for_step(&flow_info, &step);
+    let enabled = explain_condition_for_step(&flow_info, &step).is_ok();
 
     assert!(!enabled);
 }
@@ -2652,7 +3412,7 @@ fn validate_rust_version_condition_no_rustinfo() {
         },
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2669,7 +3429,7 @@ fn validate_rust_version_condition_empty_condition() {
         },
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2686,7 +3446,7 @@ fn validate_rust_version_condition_min_enabled() {
         },
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2703,7 +3463,7 @@ fn validate_rust_version_condition_min_same() {
         },
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2720,7 +3480,7 @@ fn validate_rust_version_condition_min_disabled_major() {
         },
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -2737,7 +3497,7 @@ fn validate_rust_version_condition_min_disabled_minor() {
         },
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -2754,7 +3514,7 @@ fn validate_rust_version_condition_min_disabled_patch() {
         },
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -2771,7 +3531,7 @@ fn validate_rust_version_condition_max_enabled() {
         },
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2788,7 +3548,7 @@ fn validate_rust_version_condition_max_same() {
         },
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2805,7 +3565,7 @@ fn validate_rust_version_condition_max_disabled_major() {
         },
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -2822,7 +3582,7 @@ fn validate_rust_version_condition_max_disabled_minor() {
         },
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -2839,7 +3599,7 @@ fn validate_rust_version_condition_max_disabled_patch() {
         },
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -2856,7 +3616,7 @@ fn validate_rust_version_condition_equal_same() {
         },
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2873,7 +3633,7 @@ fn validate_rust_version_condition_equal_not_same() {
         },
     );
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
 }
 
 #[test]
@@ -2890,7 +3650,7 @@ fn validate_rust_version_condition_all_enabled() {
         },
     );
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2909,11 +3669,22 @@ fn validate_rust_version_no_condition() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_rust_version(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2939,11 +3710,22 @@ fn validate_rust_version_with_valid_condition() {
         }),
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_rust_version(&condition);
 
-    assert!(enabled);
+    assert!(enabled.is_ok());
 }
 
 #[test]
@@ -2970,9 +3752,726 @@ fn validate_rust_version_with_invalid_condition() {
         }),
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let enabled = validate_rust_version(&condition);
 
-    assert!(!enabled);
+    assert!(enabled.is_err());
+}
+
+#[test]
+fn condition_failure_display() {
+    let failure = ConditionFailure::new("env_set", "env var: TEST is not defined");
+
+    assert_eq!(failure.to_string(), "env_set: env var: TEST is not defined");
+}
+
+#[test]
+fn validate_env_set_invalid_explanation() {
+    let condition = TaskCondition {
+        fail_message: None,
+        profiles: None,
+        platforms: None,
+        channels: None,
+        env_set: Some(vec!["CONDITION_EXPLAIN_NOT_DEFINED".to_string()]),
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
+    };
+
+    let failure = validate_env_set(&condition).unwrap_err();
+
+    assert_eq!(
+        failure,
+        ConditionFailure::new(
+            "env_set",
+            "env var: CONDITION_EXPLAIN_NOT_DEFINED is not defined"
+        )
+    );
+}
+
+#[test]
+fn validate_env_invalid_explanation() {
+    let mut env_values = IndexMap::<String, String>::new();
+    env_values.insert(
+        "CONDITION_EXPLAIN_NOT_DEFINED".to_string(),
+        "value".to_string(),
+    );
+
+    let condition = TaskCondition {
+        fail_message: None,
+        profiles: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: Some(env_values),
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
+    };
+
+    let failure = validate_env(&condition).unwrap_err();
+
+    assert_eq!(
+        failure.reason,
+        "env var: CONDITION_EXPLAIN_NOT_DEFINED expected: 'value', actual: not defined"
+    );
+}
+
+#[test]
+fn validate_platform_invalid_explanation() {
+    let condition = TaskCondition {
+        fail_message: None,
+        profiles: None,
+        platforms: Some(vec!["bad1".to_string(), "bad2".to_string()]),
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
+    };
+
+    let failure = validate_criteria(None, &Some(condition)).unwrap_err();
+
+    assert_eq!(failure.criterion, "platforms");
+    assert_eq!(
+        failure.reason,
+        format!(
+            "current platform: {} is not one of: bad1, bad2",
+            types::get_platform_name()
+        )
+    );
+}
+
+#[test]
+fn validate_rust_version_condition_explanations() {
+    let mut rustinfo = RustInfo::new();
+    rustinfo.version = Some("2.0.0".to_string());
+
+    let min_failure = validate_rust_version_condition(
+        rustinfo.clone(),
+        RustVersionCondition {
+            min: Some("3.0.0".to_string()),
+            max: None,
+            equal: None,
+        },
+    )
+    .unwrap_err();
+    let max_failure = validate_rust_version_condition(
+        rustinfo.clone(),
+        RustVersionCondition {
+            min: None,
+            max: Some("1.0.0".to_string()),
+            equal: None,
+        },
+    )
+    .unwrap_err();
+    let equal_failure = validate_rust_version_condition(
+        rustinfo,
+        RustVersionCondition {
+            min: None,
+            max: None,
+            equal: Some("2.0.1".to_string()),
+        },
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        min_failure.to_string(),
+        "rust_version: current version: 2.0.0 is older than min version: 3.0.0"
+    );
+    assert_eq!(
+        max_failure.to_string(),
+        "rust_version: current version: 2.0.0 is newer than max version: 1.0.0"
+    );
+    assert_eq!(
+        equal_failure.to_string(),
+        "rust_version: current version: 2.0.0 is not equal to version: 2.0.1"
+    );
+}
+
+#[test]
+fn validate_files_exist_invalid_explanation() {
+    let condition = TaskCondition {
+        fail_message: None,
+        profiles: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: Some(vec!["./condition_explain_missing.toml".to_string()]),
+        files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
+    };
+
+    let failure = validate_files_exist(&condition).unwrap_err();
+
+    assert_eq!(failure.criterion, "files_exist");
+    assert!(failure
+        .reason
+        .ends_with("condition_explain_missing.toml does not exist"));
+}
+
+#[test]
+fn validate_script_invalid_explanation() {
+    let failure = validate_script(&Some(vec!["exit 1".to_string()]), None).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        "condition_script: script exited with code: 1"
+    );
+}
+
+#[test]
+#[ignore]
+fn init_explain_skips() {
+    envmnt::remove("CARGO_MAKE_EXPLAIN_SKIPS");
+
+    let mut cli_args = CliArgs::new();
+    init(&cli_args);
+    assert!(!should_explain_skips());
+
+    cli_args.explain_skips = true;
+    init(&cli_args);
+    assert!(should_explain_skips());
+
+    envmnt::remove("CARGO_MAKE_EXPLAIN_SKIPS");
+}
+
+fn create_condition_from_toml(value: &str) -> TaskCondition {
+    toml::from_str(value).unwrap()
+}
+
+#[test]
+fn validate_criteria_composition_parse() {
+    let condition = create_condition_from_toml(
+        r#"
+        any = [ { platforms = ["bad"] }, { env_set = ["CONDITION_COMPOSITION_NOT_DEFINED"] } ]
+        all = [ { env_not_set = ["CONDITION_COMPOSITION_NOT_DEFINED"] } ]
+        not = { platforms = ["bad"] }
+        "#,
+    );
+
+    assert_eq!(condition.any.unwrap().len(), 2);
+    assert_eq!(condition.all.unwrap().len(), 1);
+    assert_eq!(
+        condition.not.unwrap().platforms.unwrap(),
+        vec!["bad".to_string()]
+    );
+}
+
+#[test]
+fn validate_criteria_any_valid() {
+    let condition = create_condition_from_toml(&format!(
+        r#"any = [ {{ platforms = ["bad"] }}, {{ platforms = ["{}"] }} ]"#,
+        types::get_platform_name()
+    ));
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(enabled.is_ok());
+}
+
+#[test]
+fn validate_criteria_any_invalid() {
+    let condition = create_condition_from_toml(
+        r#"any = [ { platforms = ["bad"] }, { env_set = ["CONDITION_COMPOSITION_NOT_DEFINED"] } ]"#,
+    );
+
+    let failure = validate_criteria(None, &Some(condition)).unwrap_err();
+
+    assert_eq!(failure.criterion, "any");
+    assert_eq!(
+        failure.reason,
+        format!(
+            "none of the conditions are met: [0] platforms: current platform: {} is not one of: bad; [1] env_set: env var: CONDITION_COMPOSITION_NOT_DEFINED is not defined",
+            types::get_platform_name()
+        )
+    );
+}
+
+#[test]
+fn validate_criteria_any_empty() {
+    let condition = create_condition_from_toml("any = []");
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(enabled.is_err());
+}
+
+#[test]
+fn validate_criteria_all_valid() {
+    let condition = create_condition_from_toml(&format!(
+        r#"all = [ {{ platforms = ["{}"] }}, {{ env_not_set = ["CONDITION_COMPOSITION_NOT_DEFINED"] }} ]"#,
+        types::get_platform_name()
+    ));
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(enabled.is_ok());
+}
+
+#[test]
+fn validate_criteria_all_invalid() {
+    let condition = create_condition_from_toml(&format!(
+        r#"all = [ {{ platforms = ["{}"] }}, {{ env_set = ["CONDITION_COMPOSITION_NOT_DEFINED"] }} ]"#,
+        types::get_platform_name()
+    ));
+
+    let failure = validate_criteria(None, &Some(condition)).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        "all[1].env_set: env var: CONDITION_COMPOSITION_NOT_DEFINED is not defined"
+    );
+}
+
+#[test]
+fn validate_criteria_not_valid() {
+    let condition = create_condition_from_toml(r#"not = { platforms = ["bad"] }"#);
+
+    let enabled = validate_criteria(None, &Some(condition));
+
+    assert!(enabled.is_ok());
+}
+
+#[test]
+fn validate_criteria_not_invalid() {
+    let condition = create_condition_from_toml(&format!(
+        r#"not = {{ platforms = ["{}"] }}"#,
+        types::get_platform_name()
+    ));
+
+    let failure = validate_criteria(None, &Some(condition)).unwrap_err();
+
+    assert_eq!(failure.to_string(), "not: the negated condition is met");
+}
+
+#[test]
+fn validate_criteria_nested_composition_with_criteria() {
+    let platform_name = types::get_platform_name();
+    let condition = create_condition_from_toml(&format!(
+        r#"
+        env_not_set = ["CONDITION_COMPOSITION_NOT_DEFINED"]
+        any = [ {{ platforms = ["bad"] }}, {{ not = {{ any = [ {{ platforms = ["bad"] }} ] }} }} ]
+        all = [ {{ platforms = ["{}"] }} ]
+        "#,
+        &platform_name
+    ));
+
+    let enabled = validate_criteria(None, &Some(condition.clone()));
+    assert!(enabled.is_ok());
+
+    let mut invalid_condition = condition;
+    invalid_condition.env_set = Some(vec!["CONDITION_COMPOSITION_NOT_DEFINED".to_string()]);
+    let enabled = validate_criteria(None, &Some(invalid_condition));
+    assert!(enabled.is_err());
+}
+
+fn create_git_flow_info(current_branch: Option<&str>, dirty: Option<bool>) -> FlowInfo {
+    let mut flow_info = crate::test::create_empty_flow_info();
+    flow_info.env_info.git_info.current_branch = current_branch.map(|value| value.to_string());
+    flow_info.env_info.git_info.dirty = dirty;
+
+    flow_info
+}
+
+#[test]
+fn validate_criteria_git_parse() {
+    let condition = create_condition_from_toml(
+        r#"
+        git_branches = ["main", "release/*"]
+        git_dirty = false
+        git_tag_on_head = true
+        files_changed_since = { ref = "origin/main", globs = ["crates/foo/**"] }
+        "#,
+    );
+
+    assert_eq!(
+        condition.git_branches.unwrap(),
+        vec!["main".to_string(), "release/*".to_string()]
+    );
+    assert!(!condition.git_dirty.unwrap());
+    assert!(condition.git_tag_on_head.unwrap());
+    let files_changed_since = condition.files_changed_since.unwrap();
+    assert_eq!(files_changed_since.git_ref, "origin/main");
+    assert_eq!(
+        files_changed_since.globs.unwrap(),
+        vec!["crates/foo/**".to_string()]
+    );
+}
+
+#[test]
+fn validate_git_branches_valid() {
+    let condition = create_condition_from_toml(r#"git_branches = ["main", "release/*"]"#);
+
+    let flow_info = create_git_flow_info(Some("main"), None);
+    assert!(validate_git_branches(&condition, Some(&flow_info)).is_ok());
+
+    let flow_info = create_git_flow_info(Some("release/1.0"), None);
+    assert!(validate_git_branches(&condition, Some(&flow_info)).is_ok());
+}
+
+#[test]
+fn validate_git_branches_invalid() {
+    let condition = create_condition_from_toml(r#"git_branches = ["main", "release/*"]"#);
+    let flow_info = create_git_flow_info(Some("feature/test"), None);
+
+    let failure = validate_git_branches(&condition, Some(&flow_info)).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        "git_branches: current branch: feature/test does not match any of: main, release/*"
+    );
+}
+
+#[test]
+fn validate_git_branches_unknown_branch() {
+    let condition = create_condition_from_toml(r#"git_branches = ["main"]"#);
+    let flow_info = create_git_flow_info(None, None);
+
+    let failure = validate_git_branches(&condition, Some(&flow_info)).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        "git_branches: unable to detect the current branch"
+    );
+}
+
+#[test]
+fn validate_git_dirty_valid() {
+    let flow_info = create_git_flow_info(None, Some(true));
+    let condition = create_condition_from_toml("git_dirty = true");
+
+    assert!(validate_git_dirty(&condition, Some(&flow_info)).is_ok());
+
+    let flow_info = create_git_flow_info(None, Some(false));
+    let condition = create_condition_from_toml("git_dirty = false");
+
+    assert!(validate_git_dirty(&condition, Some(&flow_info)).is_ok());
+}
+
+#[test]
+fn validate_git_dirty_invalid() {
+    let flow_info = create_git_flow_info(None, Some(true));
+    let condition = create_condition_from_toml("git_dirty = false");
+
+    let failure = validate_git_dirty(&condition, Some(&flow_info)).unwrap_err();
+    assert_eq!(
+        failure.to_string(),
+        "git_dirty: the working tree has uncommitted changes"
+    );
+
+    let flow_info = create_git_flow_info(None, Some(false));
+    let condition = create_condition_from_toml("git_dirty = true");
+
+    let failure = validate_git_dirty(&condition, Some(&flow_info)).unwrap_err();
+    assert_eq!(
+        failure.to_string(),
+        "git_dirty: the working tree has no uncommitted changes"
+    );
+}
+
+#[test]
+fn validate_git_dirty_unknown_status() {
+    let flow_info = create_git_flow_info(None, None);
+    let condition = create_condition_from_toml("git_dirty = false");
+
+    let enabled = validate_git_dirty(&condition, Some(&flow_info));
+
+    assert!(enabled.is_err());
+}
+
+#[test]
+fn validate_git_tag_on_head_tagged() {
+    let directory = crate::test::create_git_repo("validate_git_tag_on_head_tagged");
+    crate::test::run_git_commands(&directory, vec![vec!["tag", "v1.0.0"]]);
+    let _cwd_guard = task_env::set_current_cwd(Some(directory.to_string_lossy().into_owned()));
+
+    let condition = create_condition_from_toml("git_tag_on_head = true");
+    assert!(validate_git_tag_on_head(&condition).is_ok());
+
+    let condition = create_condition_from_toml("git_tag_on_head = false");
+    let failure = validate_git_tag_on_head(&condition).unwrap_err();
+    assert_eq!(
+        failure.to_string(),
+        "git_tag_on_head: HEAD is tagged: v1.0.0"
+    );
+}
+
+#[test]
+fn validate_git_tag_on_head_not_tagged() {
+    let directory = crate::test::create_git_repo("validate_git_tag_on_head_not_tagged");
+    let _cwd_guard = task_env::set_current_cwd(Some(directory.to_string_lossy().into_owned()));
+
+    let condition = create_condition_from_toml("git_tag_on_head = false");
+    assert!(validate_git_tag_on_head(&condition).is_ok());
+
+    let condition = create_condition_from_toml("git_tag_on_head = true");
+    let failure = validate_git_tag_on_head(&condition).unwrap_err();
+    assert_eq!(failure.to_string(), "git_tag_on_head: HEAD has no tags");
+}
+
+#[test]
+fn validate_files_changed_since_matched() {
+    let directory = crate::test::create_git_repo("validate_files_changed_since_matched");
+    fsio::file::write_text_file(&directory.join("crates/foo/src/lib.rs"), "").unwrap();
+    let _cwd_guard = task_env::set_current_cwd(Some(directory.to_string_lossy().into_owned()));
+
+    let condition = create_condition_from_toml(
+        r#"files_changed_since = { ref = "HEAD", globs = ["docs/*", "crates/foo/**"] }"#,
+    );
+    assert!(validate_files_changed_since(&condition).is_ok());
+
+    let condition = create_condition_from_toml(r#"files_changed_since = { ref = "HEAD" }"#);
+    assert!(validate_files_changed_since(&condition).is_ok());
+}
+
+#[test]
+fn validate_files_changed_since_not_matched() {
+    let directory = crate::test::create_git_repo("validate_files_changed_since_not_matched");
+    fsio::file::write_text_file(&directory.join("crates/foo/src/lib.rs"), "").unwrap();
+    let _cwd_guard = task_env::set_current_cwd(Some(directory.to_string_lossy().into_owned()));
+
+    let condition = create_condition_from_toml(
+        r#"files_changed_since = { ref = "HEAD", globs = ["crates/bar/**", "crates/*.rs"] }"#,
+    );
+
+    let failure = validate_files_changed_since(&condition).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        "files_changed_since: no files matching: crates/bar/**, crates/*.rs changed since: HEAD"
+    );
+}
+
+#[test]
+fn validate_files_changed_since_relative_to_cwd() {
+    let directory = crate::test::create_git_repo("validate_files_changed_since_relative_to_cwd");
+    fsio::file::write_text_file(&directory.join("crates/foo/src/lib.rs"), "").unwrap();
+    let _cwd_guard = task_env::set_current_cwd(Some(
+        directory.join("crates").to_string_lossy().into_owned(),
+    ));
+
+    let condition =
+        create_condition_from_toml(r#"files_changed_since = { ref = "HEAD", globs = ["foo/**"] }"#);
+
+    assert!(validate_files_changed_since(&condition).is_ok());
+}
+
+#[test]
+fn validate_files_changed_since_no_changes() {
+    let directory = crate::test::create_git_repo("validate_files_changed_since_no_changes");
+    let _cwd_guard = task_env::set_current_cwd(Some(directory.to_string_lossy().into_owned()));
+
+    let condition = create_condition_from_toml(r#"files_changed_since = { ref = "HEAD" }"#);
+
+    let failure = validate_files_changed_since(&condition).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        "files_changed_since: no files changed since: HEAD"
+    );
+}
+
+#[test]
+fn validate_files_changed_since_invalid_ref() {
+    let directory = crate::test::create_git_repo("validate_files_changed_since_invalid_ref");
+    let _cwd_guard = task_env::set_current_cwd(Some(directory.to_string_lossy().into_owned()));
+
+    let condition = create_condition_from_toml(r#"files_changed_since = { ref = "no-such-ref" }"#);
+
+    let failure = validate_files_changed_since(&condition).unwrap_err();
+
+    assert_eq!(failure.criterion, "files_changed_since");
+    assert!(failure.reason.starts_with("git merge-base"));
+}
+
+#[test]
+fn validate_criteria_git_in_composition() {
+    let condition = create_condition_from_toml(
+        r#"any = [ { git_branches = ["main"] }, { git_dirty = true } ]"#,
+    );
+
+    let flow_info = create_git_flow_info(Some("feature"), Some(true));
+    assert!(validate_criteria(Some(&flow_info), &Some(condition.clone())).is_ok());
+
+    let flow_info = create_git_flow_info(Some("feature"), Some(false));
+    assert!(validate_criteria(Some(&flow_info), &Some(condition)).is_err());
+}
+
+#[test]
+fn validate_platform_family() {
+    let condition = create_condition_from_toml(&format!(
+        r#"platforms = ["bad", "{}"]"#,
+        types::get_platform_family_name()
+    ));
+
+    let enabled = validate_platform(&condition);
+
+    assert!(enabled.is_ok());
+}
+
+#[test]
+fn validate_arch_valid() {
+    let condition =
+        create_condition_from_toml(&format!(r#"arch = ["bad", "{}"]"#, types::get_arch_name()));
+
+    let enabled = validate_arch(&condition);
+
+    assert!(enabled.is_ok());
+}
+
+#[test]
+fn validate_arch_invalid() {
+    let condition = create_condition_from_toml(r#"arch = ["bad1", "bad2"]"#);
+
+    let failure = validate_arch(&condition).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        format!(
+            "arch: current arch: {} is not one of: bad1, bad2",
+            types::get_arch_name()
+        )
+    );
+}
+
+fn create_target_triple_flow_info(target_triple: &str) -> FlowInfo {
+    let mut flow_info = crate::test::create_empty_flow_info();
+    flow_info.env_info.rust_info.target_triple = Some(target_triple.to_string());
+
+    flow_info
+}
+
+#[test]
+fn validate_target_triples_valid() {
+    let flow_info = create_target_triple_flow_info("test-triple");
+    let condition = create_condition_from_toml(r#"target_triples = ["bad", "test-triple"]"#);
+
+    let enabled = validate_target_triples(&condition, Some(&flow_info));
+
+    assert!(enabled.is_ok());
+}
+
+#[test]
+fn validate_target_triples_invalid() {
+    let flow_info = create_target_triple_flow_info("test-triple");
+    let condition = create_condition_from_toml(r#"target_triples = ["bad"]"#);
+
+    let failure = validate_target_triples(&condition, Some(&flow_info)).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        "target_triples: current target triple: test-triple is not one of: bad"
+    );
+}
+
+#[test]
+fn validate_commands_exist_valid() {
+    let condition = create_condition_from_toml(&format!(
+        r#"commands_exist = ["cargo", "{}"]"#,
+        env::current_exe()
+            .unwrap()
+            .to_string_lossy()
+            .replace('\\', "/")
+    ));
+
+    let enabled = validate_commands_exist(&condition);
+
+    assert!(enabled.is_ok());
+}
+
+#[test]
+fn validate_commands_exist_invalid() {
+    let condition = create_condition_from_toml(r#"commands_exist = ["cargo", "cargo_bad"]"#);
+
+    let failure = validate_commands_exist(&condition).unwrap_err();
+
+    assert_eq!(
+        failure.to_string(),
+        "commands_exist: command: cargo_bad not found"
+    );
+}
+
+#[test]
+fn validate_rustup_components_invalid() {
+    let condition = create_condition_from_toml(r#"rustup_components = ["bad_component"]"#);
+
+    let enabled = validate_rustup_components(&condition, None);
+
+    assert!(enabled.is_err());
 }
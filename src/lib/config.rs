@@ -7,6 +7,7 @@
 #[path = "config_test.rs"]
 mod config_test;
 
+use crate::error::CargoMakeError;
 use crate::storage;
 use crate::types::GlobalConfig;
 use dirs_next;
@@ -22,7 +23,7 @@ fn get_config_directory() -> Option<PathBuf> {
     storage::get_storage_directory(os_directory, CONFIG_FILE, true)
 }
 
-fn load_from_path(directory: PathBuf) -> GlobalConfig {
+fn load_from_path(directory: PathBuf) -> Result<GlobalConfig, CargoMakeError> {
     let file_path = Path::new(&directory).join(CONFIG_FILE);
     debug!("Loading config from: {:#?}", &file_path);
 
@@ -31,27 +32,32 @@ fn load_from_path(directory: PathBuf) -> GlobalConfig {
             Ok(config_str) => {
                 let mut global_config: GlobalConfig = match toml::from_str(&config_str) {
                     Ok(value) => value,
-                    Err(error) => panic!("Unable to parse global configuration file, {}", error),
+                    Err(error) => {
+                        return Err(CargoMakeError::Other(format!(
+                            "Unable to parse global configuration file, {}",
+                            error
+                        )))
+                    }
                 };
 
                 global_config.file_name = Some(FromPath::from_path(&file_path));
 
-                global_config
+                Ok(global_config)
             }
-            Err(error) => panic!(
+            Err(error) => Err(CargoMakeError::Other(format!(
                 "Unable to read config file: {:?} error: {}",
                 &file_path, error
-            ),
+            ))),
         }
     } else {
-        GlobalConfig::new()
+        Ok(GlobalConfig::new())
     }
 }
 
 /// Returns the configuration
-pub(crate) fn load() -> GlobalConfig {
+pub(crate) fn load() -> Result<GlobalConfig, CargoMakeError> {
     match get_config_directory() {
         Some(directory) => load_from_path(directory),
-        None => GlobalConfig::new(),
+        None => Ok(GlobalConfig::new()),
     }
 }
@@ -6,7 +6,7 @@ use std::path::PathBuf;
 #[test]
 fn load_from_path_exists() {
     let path = PathBuf::from("examples/cargo-make");
-    let global_config = load_from_path(path);
+    let global_config = load_from_path(path).unwrap();
 
     assert!(global_config.file_name.is_some());
     assert_eq!(global_config.log_level.unwrap(), "error".to_string());
@@ -24,7 +24,7 @@ fn load_from_path_exists() {
 #[test]
 fn load_from_path_not_exists() {
     let path = PathBuf::from("examples2/.cargo-make");
-    let global_config = load_from_path(path);
+    let global_config = load_from_path(path).unwrap();
 
     assert!(global_config.file_name.is_none());
     assert!(global_config.log_level.is_none());
@@ -39,7 +39,7 @@ fn load_with_cargo_home() {
     let path = env::current_dir().unwrap();
     let directory = path.join("examples/cargo-make");
     envmnt::set("CARGO_MAKE_HOME", directory.to_str().unwrap());
-    let global_config = load();
+    let global_config = load().unwrap();
 
     assert!(global_config.file_name.is_some());
     assert_eq!(global_config.log_level.unwrap(), "error".to_string());
@@ -58,7 +58,7 @@ fn load_with_cargo_home() {
 #[ignore]
 fn load_without_cargo_home() {
     envmnt::remove("CARGO_MAKE_HOME");
-    let global_config = load();
+    let global_config = load().unwrap();
 
     assert!(global_config.search_project_root.is_some());
 }
@@ -6,6 +6,7 @@
 #[path = "descriptor_deserializer_test.rs"]
 mod descriptor_deserializer_test;
 
+use crate::error::CargoMakeError;
 use crate::types::{Config, ExternalConfig};
 use serde_ignored;
 use toml;
@@ -16,6 +17,7 @@ pub(crate) fn load_config(descriptor_string: &str, validate: bool) -> Config {
 
         match serde_ignored::deserialize(deserializer, |path| {
             error!("Found unknown key: {}", path);
+            panic!("Found unknown key: {} in internal descriptor", path);
         }) {
             Ok(value) => value,
             Err(error) => {
@@ -36,18 +38,33 @@ pub(crate) fn load_config(descriptor_string: &str, validate: bool) -> Config {
     config
 }
 
-pub(crate) fn load_external_config(descriptor_string: &str, file: &str) -> ExternalConfig {
+pub(crate) fn load_external_config(
+    descriptor_string: &str,
+    file: &str,
+) -> Result<ExternalConfig, CargoMakeError> {
     let deserializer = &mut toml::de::Deserializer::new(descriptor_string);
 
-    let config: ExternalConfig = match serde_ignored::deserialize(deserializer, |path| {
+    match serde_ignored::deserialize(deserializer, |path| {
         warn!("Found unknown key: {} in file: {}", path, file);
     }) {
-        Ok(value) => value,
-        Err(error) => {
-            error!("Unable to parse external file: {:#?}, {}", &file, error);
-            panic!("Unable to parse external file: {:#?}, {}", &file, error);
-        }
-    };
+        Ok(value) => Ok(value),
+        Err(error) => Err(CargoMakeError::DescriptorParseError(format!(
+            "Unable to parse external file: {:#?}, {}",
+            &file, error
+        ))),
+    }
+}
 
-    config
+/// Deserializes the external config and returns it with the paths of all unknown keys.
+pub(crate) fn load_external_config_with_unknown_keys(
+    descriptor_string: &str,
+) -> Result<(ExternalConfig, Vec<String>), toml::de::Error> {
+    let deserializer = &mut toml::de::Deserializer::new(descriptor_string);
+
+    let mut unknown_keys = vec![];
+    let config: ExternalConfig = serde_ignored::deserialize(deserializer, |path| {
+        unknown_keys.push(path.to_string());
+    })?;
+
+    Ok((config, unknown_keys))
 }
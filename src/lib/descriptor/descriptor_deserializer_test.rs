@@ -70,7 +70,42 @@ description = "Empty Task"
 category2 = "Tools"
     "#,
         "somefile",
-    );
+    )
+    .unwrap();
 
     assert!(config.tasks.unwrap().contains_key("empty"));
 }
+
+#[test]
+fn load_external_config_with_unknown_keys_found() {
+    let (config, unknown_keys) = load_external_config_with_unknown_keys(
+        r#"
+[tasks.empty]
+description = "Empty Task"
+category2 = "Tools"
+    "#,
+    )
+    .unwrap();
+
+    assert!(config.tasks.unwrap().contains_key("empty"));
+    assert_eq!(unknown_keys, vec!["tasks.?.empty.category2"]);
+}
+
+#[test]
+fn load_external_config_with_unknown_keys_invalid() {
+    let result = load_external_config_with_unknown_keys("[tasks.empty");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn load_external_config_invalid() {
+    let output = load_external_config("[tasks.empty", "somefile");
+
+    match output.unwrap_err() {
+        CargoMakeError::DescriptorParseError(message) => {
+            assert!(message.starts_with("Unable to parse external file: \"somefile\""))
+        }
+        error => panic!("Unexpected error: {:#?}", error),
+    }
+}
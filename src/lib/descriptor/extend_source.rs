@@ -0,0 +1,296 @@
+//! # extend_source
+//!
+//! Resolves the makefile extend sources.<br>
+//! Local paths are used as is, while git and url sources are fetched once into the cargo-make
+//! cache directory and loaded from the cache on later invocations.<br>
+//! Each cached makefile is pinned to the checksum it had when fetched, so any later change
+//! to the cached file is detected.<br>
+//! Git revisions are resolved only once, so a cached branch is not updated with new commits
+//! until its cache entry is deleted.
+//!
+
+#[cfg(test)]
+#[path = "extend_source_test.rs"]
+mod extend_source_test;
+
+use crate::cache;
+use crate::types::ExtendOptions;
+use envmnt;
+use fsio;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Env var which enables the offline mode (only cached sources are used)
+pub(crate) static OFFLINE_ENV_VAR_NAME: &str = "CARGO_MAKE_OFFLINE";
+
+static CACHE_DIRECTORY_NAME: &str = "extend";
+static DEFAULT_MAKEFILE_NAME: &str = "Makefile.toml";
+static CHECKSUM_FILE_EXTENSION: &str = "sha256";
+
+/// Returns true if remote extend sources should only be loaded from the cache
+pub(crate) fn is_offline() -> bool {
+    envmnt::is_or(OFFLINE_ENV_VAR_NAME, false)
+}
+
+fn get_checksum(content: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content);
+
+    format!("{:x}", hasher.finalize())
+}
+
+/// Returns a file system safe name for the provided source
+fn get_cache_key(source: &str) -> String {
+    get_checksum(source.as_bytes())[0..16].to_string()
+}
+
+/// Returns the provided path with the extra extension appended (for example Makefile.toml.tmp)
+fn append_extension(path: &Path, extension: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(extension);
+
+    path.with_file_name(file_name)
+}
+
+fn run_git(args: &[&str]) -> Result<(), String> {
+    match Command::new("git").args(args).output() {
+        Ok(output) => {
+            if output.status.success() {
+                Ok(())
+            } else {
+                Err(format!(
+                    "git {} failed: {}",
+                    args.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ))
+            }
+        }
+        Err(error) => Err(format!("Unable to run git, error: {}", error)),
+    }
+}
+
+fn fetch_git(directory: &Path, git: &str, rev: &Option<String>) -> Result<(), String> {
+    info!("Fetching extended makefiles from git repository: {}", git);
+
+    let temporary_directory = append_extension(directory, "tmp");
+    if temporary_directory.exists() {
+        fs::remove_dir_all(&temporary_directory).unwrap_or(());
+    }
+    let temporary_directory_string = temporary_directory.to_string_lossy().to_string();
+
+    let mut result = run_git(&[
+        "clone",
+        "--quiet",
+        "--no-checkout",
+        git,
+        &temporary_directory_string,
+    ]);
+    if result.is_ok() {
+        // branches other than the default branch only exist as remote tracking branches
+        let revision = match rev {
+            Some(ref rev) => {
+                let remote_branch = format!("origin/{}", rev);
+                let is_remote_branch = run_git(&[
+                    "-C",
+                    &temporary_directory_string,
+                    "rev-parse",
+                    "--verify",
+                    "--quiet",
+                    &format!("refs/remotes/{}", &remote_branch),
+                ])
+                .is_ok();
+
+                if is_remote_branch {
+                    remote_branch
+                } else {
+                    rev.to_string()
+                }
+            }
+            None => "HEAD".to_string(),
+        };
+        result = run_git(&[
+            "-C",
+            &temporary_directory_string,
+            "checkout",
+            "--quiet",
+            "--detach",
+            &revision,
+        ]);
+    }
+
+    // the cache entry is only created after a successful fetch
+    result = result.and_then(|_| {
+        fs::rename(&temporary_directory, directory)
+            .map_err(|error| format!("Unable to update cache, error: {}", error))
+    });
+    if result.is_err() {
+        fs::remove_dir_all(&temporary_directory).unwrap_or(());
+    }
+
+    result
+}
+
+fn fetch_url(file: &Path, url: &str) -> Result<(), String> {
+    info!("Fetching extended makefile from url: {}", url);
+
+    let temporary_file = append_extension(file, "tmp");
+    let temporary_file_string = temporary_file.to_string_lossy().to_string();
+
+    let result = match Command::new("curl")
+        .args(&[
+            "--fail",
+            "--silent",
+            "--show-error",
+            "--location",
+            "--create-dirs",
+            "--output",
+            &temporary_file_string,
+            url,
+        ])
+        .output()
+    {
+        Ok(output) => {
+            if output.status.success() {
+                fs::rename(&temporary_file, file)
+                    .map_err(|error| format!("Unable to update cache, error: {}", error))
+            } else {
+                Err(format!(
+                    "Unable to download: {} error: {}",
+                    url,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ))
+            }
+        }
+        Err(error) => Err(format!("Unable to run curl, error: {}", error)),
+    };
+
+    if result.is_err() {
+        fs::remove_file(&temporary_file).unwrap_or(());
+    }
+
+    result
+}
+
+/// Validates the cached file against the checksum pinned when it was fetched and the
+/// optional checksum defined in the makefile.
+fn verify_checksum(file: &Path, expected_checksum: &Option<String>) -> Result<(), String> {
+    let content = match fs::read(file) {
+        Ok(value) => value,
+        Err(_) => return Err(format!("Extended makefile: {:?} not found.", file)),
+    };
+    let checksum = get_checksum(&content);
+
+    let checksum_file = append_extension(file, CHECKSUM_FILE_EXTENSION);
+    if checksum_file.exists() {
+        let pinned_checksum = fsio::file::read_text_file(&checksum_file).unwrap_or_default();
+
+        if pinned_checksum.trim() != checksum {
+            return Err(format!(
+                "Cached makefile: {:?} was modified (checksum mismatch), delete it to fetch it again.",
+                file
+            ));
+        }
+    } else if let Err(error) = fsio::file::write_text_file(&checksum_file, &checksum) {
+        return Err(format!(
+            "Unable to write checksum file: {:?} error: {}",
+            &checksum_file, error
+        ));
+    }
+
+    match expected_checksum {
+        Some(expected_checksum) if !expected_checksum.eq_ignore_ascii_case(&checksum) => {
+            Err(format!(
+                "Checksum mismatch for extended makefile: {:?} expected: {} actual: {}",
+                file, expected_checksum, checksum
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the cached makefile of the provided git/url source, fetching it if not cached yet.
+fn get_cached_file(
+    cache_directory: &Path,
+    options: &ExtendOptions,
+    offline: bool,
+) -> Result<PathBuf, String> {
+    let (file, source) = match (&options.git, &options.url) {
+        (Some(git), None) => {
+            let revision = options.rev.clone().unwrap_or_default();
+            let directory = cache_directory
+                .join(CACHE_DIRECTORY_NAME)
+                .join("git")
+                .join(get_cache_key(&format!("{}#{}", git, &revision)));
+
+            if !directory.exists() {
+                if offline {
+                    return Err(format!(
+                        "Git repository: {} is not cached and offline mode is enabled.",
+                        git
+                    ));
+                }
+
+                fs::create_dir_all(directory.parent().unwrap_or(cache_directory)).map_err(
+                    |error| format!("Unable to create cache directory, error: {}", error),
+                )?;
+                fetch_git(&directory, git, &options.rev)?;
+            }
+
+            let path = options
+                .path
+                .clone()
+                .unwrap_or(DEFAULT_MAKEFILE_NAME.to_string());
+            (directory.join(path), git)
+        }
+        (None, Some(url)) => {
+            let file = cache_directory
+                .join(CACHE_DIRECTORY_NAME)
+                .join("url")
+                .join(get_cache_key(url))
+                .join(DEFAULT_MAKEFILE_NAME);
+
+            if !file.exists() {
+                if offline {
+                    return Err(format!(
+                        "Url: {} is not cached and offline mode is enabled.",
+                        url
+                    ));
+                }
+
+                fetch_url(&file, url)?;
+            }
+
+            (file, url)
+        }
+        _ => {
+            return Err("Extend options must define only one of git or url.".to_string());
+        }
+    };
+
+    debug!("Using cached makefile: {:?} for: {}", &file, source);
+
+    verify_checksum(&file, &options.checksum)?;
+
+    Ok(file)
+}
+
+/// Returns the makefile path of the provided extend options.<br>
+/// Git and url sources are fetched into the cargo-make cache directory (unless in offline mode)
+/// and the cached file path is returned.
+pub(crate) fn get_file(options: &ExtendOptions) -> Result<String, String> {
+    if options.git.is_none() && options.url.is_none() {
+        return match options.path {
+            Some(ref path) => Ok(path.to_string()),
+            None => Err("Extend options must define a path, git or url.".to_string()),
+        };
+    }
+
+    match cache::get_cache_directory(false) {
+        Some(cache_directory) => get_cached_file(&cache_directory, options, is_offline())
+            .map(|file| file.to_string_lossy().to_string()),
+        None => Err("Unable to find cargo-make cache directory.".to_string()),
+    }
+}
@@ -0,0 +1,327 @@
+use super::*;
+use std::env;
+
+fn create_directory(name: &str) -> PathBuf {
+    let directory = env::current_dir()
+        .unwrap()
+        .join("target/_cargo_make_temp/extend_source")
+        .join(name);
+    if directory.exists() {
+        fs::remove_dir_all(&directory).unwrap();
+    }
+    fs::create_dir_all(&directory).unwrap();
+
+    directory
+}
+
+fn git(directory: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(directory)
+        .args(&[
+            "-c",
+            "user.name=test",
+            "-c",
+            "user.email=test@test.com",
+            "-c",
+            "commit.gpgsign=false",
+        ])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+
+    assert!(status.success());
+}
+
+fn commit(directory: &Path, file: &str, content: &str) {
+    fsio::file::write_text_file(&directory.join(file), content).unwrap();
+    git(directory, &["add", "-A"]);
+    git(directory, &["commit", "--quiet", "-m", "update"]);
+}
+
+/// Creates a bare git repository with 2 commits (v1 tag on the first one)
+fn create_bare_repository(directory: &Path) -> String {
+    let repository = directory.join("repository");
+    fs::create_dir_all(&repository).unwrap();
+    git(&repository, &["init", "--quiet"]);
+    commit(&repository, "Makefile.toml", "[tasks.v1]\n");
+    git(&repository, &["tag", "v1"]);
+    commit(&repository, "Makefile.toml", "[tasks.v2]\n");
+    commit(&repository, "sub/other.toml", "[tasks.other]\n");
+
+    let bare_repository = directory.join("repository.git");
+    git(
+        directory,
+        &[
+            "clone",
+            "--quiet",
+            "--bare",
+            repository.to_str().unwrap(),
+            bare_repository.to_str().unwrap(),
+        ],
+    );
+
+    bare_repository.to_string_lossy().to_string()
+}
+
+fn create_options(git: Option<String>, url: Option<String>) -> ExtendOptions {
+    ExtendOptions {
+        path: None,
+        optional: None,
+        git,
+        rev: None,
+        url,
+        checksum: None,
+    }
+}
+
+fn get_file_url(file: &Path) -> String {
+    format!("file://{}", file.to_string_lossy())
+}
+
+#[test]
+fn get_checksum_empty() {
+    let checksum = get_checksum(b"");
+
+    assert_eq!(
+        checksum,
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+}
+
+#[test]
+fn get_cache_key_different_sources() {
+    let key1 = get_cache_key("https://example.com/1.toml");
+    let key2 = get_cache_key("https://example.com/2.toml");
+
+    assert_eq!(key1.len(), 16);
+    assert_ne!(key1, key2);
+}
+
+#[test]
+fn append_extension_file() {
+    let output = append_extension(Path::new("/cache/Makefile.toml"), "sha256");
+
+    assert_eq!(output, PathBuf::from("/cache/Makefile.toml.sha256"));
+}
+
+#[test]
+fn get_file_local_path() {
+    let mut options = create_options(None, None);
+    options.path = Some("./base.toml".to_string());
+
+    let file = get_file(&options).unwrap();
+
+    assert_eq!(file, "./base.toml");
+}
+
+#[test]
+fn get_file_missing_source() {
+    let options = create_options(None, None);
+
+    let output = get_file(&options);
+
+    assert!(output.is_err());
+}
+
+#[test]
+fn get_cached_file_git_and_url() {
+    let directory = create_directory("git_and_url");
+    let options = create_options(
+        Some("https://example.com/repo.git".to_string()),
+        Some("https://example.com/Makefile.toml".to_string()),
+    );
+
+    let output = get_cached_file(&directory, &options, true);
+
+    assert!(output.is_err());
+}
+
+#[test]
+fn get_cached_file_git_default_revision() {
+    let directory = create_directory("git_default_revision");
+    let repository = create_bare_repository(&directory);
+    let cache_directory = directory.join("cache");
+    let options = create_options(Some(repository), None);
+
+    let file = get_cached_file(&cache_directory, &options, false).unwrap();
+
+    assert!(file.starts_with(&cache_directory));
+    assert_eq!(fs::read_to_string(&file).unwrap(), "[tasks.v2]\n");
+    assert!(append_extension(&file, CHECKSUM_FILE_EXTENSION).exists());
+}
+
+#[test]
+fn get_cached_file_git_revision_and_path() {
+    let directory = create_directory("git_revision_and_path");
+    let repository = create_bare_repository(&directory);
+    let cache_directory = directory.join("cache");
+
+    let mut options = create_options(Some(repository.clone()), None);
+    options.rev = Some("v1".to_string());
+    let file = get_cached_file(&cache_directory, &options, false).unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "[tasks.v1]\n");
+
+    let mut options = create_options(Some(repository), None);
+    options.path = Some("sub/other.toml".to_string());
+    let file = get_cached_file(&cache_directory, &options, false).unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "[tasks.other]\n");
+}
+
+#[test]
+fn get_cached_file_git_branch_revision() {
+    let directory = create_directory("git_branch_revision");
+    let repository = directory.join("repository");
+    fs::create_dir_all(&repository).unwrap();
+    git(&repository, &["init", "--quiet"]);
+    commit(&repository, "Makefile.toml", "[tasks.default_branch]\n");
+    git(&repository, &["checkout", "--quiet", "-b", "feature"]);
+    commit(&repository, "Makefile.toml", "[tasks.feature]\n");
+    git(&repository, &["checkout", "--quiet", "-"]);
+
+    let bare_repository = directory.join("repository.git");
+    git(
+        &directory,
+        &[
+            "clone",
+            "--quiet",
+            "--bare",
+            repository.to_str().unwrap(),
+            bare_repository.to_str().unwrap(),
+        ],
+    );
+    let cache_directory = directory.join("cache");
+
+    let mut options = create_options(Some(bare_repository.to_string_lossy().to_string()), None);
+    options.rev = Some("feature".to_string());
+    let file = get_cached_file(&cache_directory, &options, false).unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "[tasks.feature]\n");
+
+    options.rev = None;
+    let file = get_cached_file(&cache_directory, &options, false).unwrap();
+    assert_eq!(
+        fs::read_to_string(&file).unwrap(),
+        "[tasks.default_branch]\n"
+    );
+}
+
+#[test]
+fn get_cached_file_git_invalid_revision() {
+    let directory = create_directory("git_invalid_revision");
+    let repository = create_bare_repository(&directory);
+    let cache_directory = directory.join("cache");
+    let mut options = create_options(Some(repository), None);
+    options.rev = Some("missing".to_string());
+
+    let output = get_cached_file(&cache_directory, &options, false);
+
+    assert!(output.is_err());
+    assert!(!cache_directory
+        .join("extend/git")
+        .read_dir()
+        .unwrap()
+        .any(|_| true));
+}
+
+#[test]
+fn get_cached_file_git_offline() {
+    let directory = create_directory("git_offline");
+    let repository = create_bare_repository(&directory);
+    let cache_directory = directory.join("cache");
+    let options = create_options(Some(repository.clone()), None);
+
+    let output = get_cached_file(&cache_directory, &options, true);
+    assert!(output.is_err());
+
+    get_cached_file(&cache_directory, &options, false).unwrap();
+
+    // remote is no longer needed once cached
+    fs::remove_dir_all(&repository).unwrap();
+    let file = get_cached_file(&cache_directory, &options, true).unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "[tasks.v2]\n");
+}
+
+#[test]
+fn get_cached_file_url() {
+    let directory = create_directory("url");
+    let source_file = directory.join("source.toml");
+    fsio::file::write_text_file(&source_file, "[tasks.url]\n").unwrap();
+    let cache_directory = directory.join("cache");
+    let options = create_options(None, Some(get_file_url(&source_file)));
+
+    let file = get_cached_file(&cache_directory, &options, false).unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "[tasks.url]\n");
+
+    // fetched only once
+    fsio::file::write_text_file(&source_file, "[tasks.updated]\n").unwrap();
+    let file = get_cached_file(&cache_directory, &options, false).unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "[tasks.url]\n");
+}
+
+#[test]
+fn get_cached_file_url_not_found() {
+    let directory = create_directory("url_not_found");
+    let cache_directory = directory.join("cache");
+    let options = create_options(None, Some(get_file_url(&directory.join("missing.toml"))));
+
+    let output = get_cached_file(&cache_directory, &options, false);
+
+    assert!(output.is_err());
+}
+
+#[test]
+fn get_cached_file_url_offline() {
+    let directory = create_directory("url_offline");
+    let source_file = directory.join("source.toml");
+    fsio::file::write_text_file(&source_file, "[tasks.url]\n").unwrap();
+    let cache_directory = directory.join("cache");
+    let options = create_options(None, Some(get_file_url(&source_file)));
+
+    let output = get_cached_file(&cache_directory, &options, true);
+
+    assert!(output.is_err());
+}
+
+#[test]
+fn get_cached_file_checksum_valid() {
+    let directory = create_directory("checksum_valid");
+    let source_file = directory.join("source.toml");
+    fsio::file::write_text_file(&source_file, "[tasks.url]\n").unwrap();
+    let cache_directory = directory.join("cache");
+    let mut options = create_options(None, Some(get_file_url(&source_file)));
+    options.checksum = Some(get_checksum(b"[tasks.url]\n").to_uppercase());
+
+    let output = get_cached_file(&cache_directory, &options, false);
+
+    assert!(output.is_ok());
+}
+
+#[test]
+fn get_cached_file_checksum_mismatch() {
+    let directory = create_directory("checksum_mismatch");
+    let source_file = directory.join("source.toml");
+    fsio::file::write_text_file(&source_file, "[tasks.url]\n").unwrap();
+    let cache_directory = directory.join("cache");
+    let mut options = create_options(None, Some(get_file_url(&source_file)));
+    options.checksum = Some(get_checksum(b"other"));
+
+    let output = get_cached_file(&cache_directory, &options, false);
+
+    assert!(output.unwrap_err().contains("Checksum mismatch"));
+}
+
+#[test]
+fn get_cached_file_modified_cache() {
+    let directory = create_directory("modified_cache");
+    let source_file = directory.join("source.toml");
+    fsio::file::write_text_file(&source_file, "[tasks.url]\n").unwrap();
+    let cache_directory = directory.join("cache");
+    let options = create_options(None, Some(get_file_url(&source_file)));
+
+    let file = get_cached_file(&cache_directory, &options, false).unwrap();
+    fsio::file::write_text_file(&file, "[tasks.modified]\n").unwrap();
+
+    let output = get_cached_file(&cache_directory, &options, true);
+
+    assert!(output.unwrap_err().contains("was modified"));
+}
@@ -52,7 +52,7 @@ fn makefile_task_condition_test(name: &str, expect_enabled: bool, linux_only: bo
             config: task,
         };
 
-        let enabled = condition::validate_condition_for_step(&flow_info, &step);
+        let enabled = condition::explain_condition_for_step(&flow_info, &step).is_ok();
 
         let should_be_enabled = if expect_enabled {
             if ci_only {
@@ -142,6 +142,17 @@ fn makefile_do_on_members_test() {
     makefile_task_disabled_test("do-on-members", false);
 }
 
+#[test]
+fn makefile_release_build_env_exported_test() {
+    let config = load_descriptor();
+
+    for name in ["setup-release-build-env-vars", "setup-musl"] {
+        let task = get_task(name, &config);
+
+        assert_eq!(task.export_env, Some(true));
+    }
+}
+
 #[test]
 fn makefile_audit_test() {
     makefile_task_enabled_test("audit", false, false);
@@ -178,7 +189,7 @@ fn makefile_build_file_increment_no_file_test() {
         config: task,
     };
 
-    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 
     envmnt::remove("CARGO_MAKE_BUILD_NUMBER_FILE");
 
@@ -210,9 +221,9 @@ fn makefile_build_file_increment_file_exists_test() {
         config: task,
     };
 
-    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
-    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
-    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 
     envmnt::remove("CARGO_MAKE_BUILD_NUMBER_FILE");
 
@@ -246,5 +257,5 @@ fn makefile_build_file_increment_panic_invalid_data_test() {
         config: task,
     };
 
-    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
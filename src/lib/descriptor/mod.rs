@@ -12,8 +12,10 @@ mod mod_test;
 
 mod cargo_alias;
 pub(crate) mod descriptor_deserializer;
+pub(crate) mod extend_source;
 mod makefiles;
 
+use crate::error::CargoMakeError;
 use crate::io;
 use crate::plugin::descriptor::merge_plugins_config;
 use crate::scriptengine;
@@ -198,7 +200,7 @@ fn add_file_location_info(
     external_config
 }
 
-fn run_load_script(external_config: &ExternalConfig) -> bool {
+fn run_load_script(external_config: &ExternalConfig) -> Result<bool, CargoMakeError> {
     match external_config.config {
         Some(ref config) => {
             let load_script = config.get_load_script();
@@ -207,19 +209,19 @@ fn run_load_script(external_config: &ExternalConfig) -> bool {
                 Some(ref script) => {
                     debug!("Load script found.");
 
-                    scriptengine::invoke_script_pre_flow(script, None, None, None, true, &vec![]);
+                    scriptengine::invoke_script_pre_flow(script, None, None, None, true, &vec![])?;
 
-                    true
+                    Ok(true)
                 }
                 None => {
                     debug!("No load script defined.");
-                    false
+                    Ok(false)
                 }
             }
         }
         None => {
             debug!("No load script defined.");
-            false
+            Ok(false)
         }
     }
 }
@@ -297,12 +299,24 @@ fn merge_external_configs(config: ExternalConfig, parent_config: ExternalConfig)
 fn load_descriptor_extended_makefiles(
     parent_path: &str,
     extend_struct: &Extend,
-) -> Result<ExternalConfig, String> {
+) -> Result<ExternalConfig, CargoMakeError> {
     match extend_struct {
         Extend::Path(base_file) => load_external_descriptor(parent_path, &base_file, true, false),
         Extend::Options(extend_options) => {
             let force = !extend_options.optional.unwrap_or(false);
-            load_external_descriptor(parent_path, &extend_options.path, force, false)
+
+            match extend_source::get_file(extend_options) {
+                Ok(file) => load_external_descriptor(parent_path, &file, force, false),
+                Err(message) => {
+                    if force {
+                        Err(CargoMakeError::DescriptorParseError(message))
+                    } else {
+                        warn!("{}", &message);
+
+                        Ok(ExternalConfig::new())
+                    }
+                }
+            }
         }
         Extend::List(extend_list) => {
             let mut ordered_list_config = ExternalConfig::new();
@@ -352,7 +366,7 @@ fn load_external_descriptor(
     file_name: &str,
     force: bool,
     set_env: bool,
-) -> Result<ExternalConfig, String> {
+) -> Result<ExternalConfig, CargoMakeError> {
     debug!(
         "Loading tasks from file: {} base directory: {}",
         &file_name, &base_path
@@ -368,17 +382,17 @@ fn load_external_descriptor(
             envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &absolute_file_path);
         }
 
-        let external_descriptor = io::read_text_file(&file_path);
+        let external_descriptor = io::read_text_file(&file_path)?;
 
-        check_makefile_min_version(&external_descriptor)?;
+        check_makefile_min_version(&external_descriptor).map_err(CargoMakeError::VersionTooOld)?;
 
         let mut file_config =
-            descriptor_deserializer::load_external_config(&external_descriptor, &file_path_string);
+            descriptor_deserializer::load_external_config(&external_descriptor, &file_path_string)?;
         debug!("Loaded external config: {:#?}", &file_config);
 
         file_config = add_file_location_info(file_config, &absolute_file_path);
 
-        run_load_script(&file_config);
+        run_load_script(&file_config)?;
 
         match file_config.extend {
             Some(ref extend_struct) => {
@@ -401,8 +415,10 @@ fn load_external_descriptor(
             None => Ok(file_config),
         }
     } else if force {
-        error!("Descriptor file: {:#?} not found.", &file_path);
-        panic!("Descriptor file: {:#?} not found.", &file_path);
+        Err(CargoMakeError::DescriptorParseError(format!(
+            "Descriptor file: {:#?} not found.",
+            &file_path
+        )))
     } else {
         debug!("External file not found or is not a file, skipping.");
 
@@ -550,8 +566,8 @@ fn split_once(value: &str, delimiter: char) -> Option<(&str, &str)> {
 /// It will first load the default descriptor which is defined in cargo-make internally and
 /// afterwards tries to find the external descriptor and load it as well.<br>
 /// If an extenal descriptor exists, it will be loaded and extend the default descriptor.
-/// If one of the descriptor requires a newer version of cargo-make, returns an error with the
-/// minimum version required by the descriptor.
+/// If one of the descriptor requires a newer version of cargo-make or can not be loaded,
+/// returns an error.
 fn load_descriptors(
     file_name: &str,
     force: bool,
@@ -559,7 +575,7 @@ fn load_descriptors(
     stable: bool,
     experimental: bool,
     modify_core_tasks: Option<ModifyConfig>,
-) -> Result<Config, String> {
+) -> Result<Config, CargoMakeError> {
     let default_config = load_internal_descriptors(stable, experimental, modify_core_tasks);
 
     let mut external_config = load_external_descriptor(".", file_name, force, true)?;
@@ -601,10 +617,10 @@ fn load_descriptors(
     Ok(config)
 }
 
-fn load_cargo_aliases(config: &mut Config) {
+fn load_cargo_aliases(config: &mut Config) -> Result<(), CargoMakeError> {
     if let Some(load_cargo_aliases) = config.config.load_cargo_aliases {
         if load_cargo_aliases {
-            let alias_tasks = cargo_alias::load();
+            let alias_tasks = cargo_alias::load()?;
             for (name, task) in alias_tasks {
                 match config.tasks.get(&name) {
                     None => {
@@ -616,20 +632,22 @@ fn load_cargo_aliases(config: &mut Config) {
             }
         }
     }
+
+    Ok(())
 }
 
 /// Loads the tasks descriptor.<br>
 /// It will first load the default descriptor which is defined in cargo-make internally and
 /// afterwards tries to find the external descriptor and load it as well.<br>
 /// If an extenal descriptor exists, it will be loaded and extend the default descriptor. <br>
-/// If one of the descriptor requires a newer version of cargo-make, returns an error with the
-/// minimum version required by the descriptor.
+/// If one of the descriptor requires a newer version of cargo-make or can not be loaded,
+/// returns an error.
 pub(crate) fn load(
     file_name: &str,
     force: bool,
     env_map: Option<Vec<String>>,
     experimental: bool,
-) -> Result<Config, String> {
+) -> Result<Config, CargoMakeError> {
     // load extended descriptor only
     let mut config = load_descriptors(&file_name, force, env_map.clone(), false, false, None)?;
 
@@ -673,7 +691,7 @@ pub(crate) fn load(
         };
     }
 
-    load_cargo_aliases(&mut config);
+    load_cargo_aliases(&mut config)?;
 
     Ok(config)
 }
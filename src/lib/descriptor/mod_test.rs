@@ -411,7 +411,7 @@ fn load_env_and_plugins_extended_override() {
 
     envmnt::remove_all(&vec!["IF_UNDEFINED", "COMPOSITE_OF_MAPPED"]);
     let config = load(toml_file, true, None, false).unwrap();
-    environment::set_env_for_config(config.env, None, false);
+    environment::set_env_for_config(config.env, None, false).unwrap();
 
     assert!(envmnt::is_equal("IF_UNDEFINED", "EXTENDED"));
     assert!(envmnt::is_equal("COMPOSITE_OF_MAPPED", "VALUE: EXTENDED"));
@@ -459,7 +459,7 @@ fn load_env_override_and_plugins() {
             .unwrap()
             .clone(),
     );
-    environment::set_env_for_config(env, None, false);
+    environment::set_env_for_config(env, None, false).unwrap();
 
     assert!(envmnt::is_equal("IF_UNDEFINED", "defined_in_makefile"));
     assert!(envmnt::is_equal(
@@ -487,7 +487,7 @@ fn load_env_override_and_plugins() {
             .unwrap()
             .clone(),
     );
-    environment::set_env_for_config(env, None, false);
+    environment::set_env_for_config(env, None, false).unwrap();
 
     assert!(envmnt::is_equal("IF_UNDEFINED", "test"));
     assert!(envmnt::is_equal("COMPOSITE_OF_MAPPED", "VALUE: test"));
@@ -794,7 +794,7 @@ fn load_external_descriptor_min_version_broken_makefile_nopanic() {
             false
         )
         .err(),
-        Some("999.999.999".into())
+        Some(CargoMakeError::VersionTooOld("999.999.999".to_string()))
     );
 }
 
@@ -817,7 +817,7 @@ fn load_external_descriptor_broken_makefile_panic() {
 fn run_load_script_no_config_section() {
     let external_config = ExternalConfig::new();
 
-    let invoked = run_load_script(&external_config);
+    let invoked = run_load_script(&external_config).unwrap();
     assert!(!invoked);
 }
 
@@ -826,7 +826,7 @@ fn run_load_script_no_load_script() {
     let mut external_config = ExternalConfig::new();
     external_config.config = Some(ConfigSection::new());
 
-    let invoked = run_load_script(&external_config);
+    let invoked = run_load_script(&external_config).unwrap();
     assert!(!invoked);
 }
 
@@ -838,7 +838,7 @@ fn run_load_script_valid_load_script() {
     let mut external_config = ExternalConfig::new();
     external_config.config = Some(config);
 
-    let invoked = run_load_script(&external_config);
+    let invoked = run_load_script(&external_config).unwrap();
     assert!(invoked);
 }
 
@@ -851,7 +851,7 @@ fn run_load_script_invalid_load_script() {
     let mut external_config = ExternalConfig::new();
     external_config.config = Some(config);
 
-    run_load_script(&external_config);
+    run_load_script(&external_config).unwrap();
 }
 
 #[test]
@@ -870,7 +870,7 @@ fn run_load_script_valid_load_script_duckscript() {
     let mut external_config = ExternalConfig::new();
     external_config.config = Some(config);
 
-    let invoked = run_load_script(&external_config);
+    let invoked = run_load_script(&external_config).unwrap();
     assert!(invoked);
 
     assert!(envmnt::exists(
@@ -915,8 +915,12 @@ fn load_descriptor_extended_makefiles_options_exists() {
     let descriptor = load_descriptor_extended_makefiles(
         &parent_path,
         &Extend::Options(ExtendOptions {
-            path: "src/lib/test/makefiles/test1.toml".to_string(),
+            path: Some("src/lib/test/makefiles/test1.toml".to_string()),
             optional: None,
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         }),
     )
     .unwrap();
@@ -933,8 +937,12 @@ fn load_descriptor_extended_makefiles_options_not_exists() {
     load_descriptor_extended_makefiles(
         &parent_path,
         &Extend::Options(ExtendOptions {
-            path: "src/lib/test/makefiles/bad.toml".to_string(),
+            path: Some("src/lib/test/makefiles/bad.toml".to_string()),
             optional: None,
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         }),
     )
     .unwrap();
@@ -947,8 +955,12 @@ fn load_descriptor_extended_makefiles_options_exists_optional() {
     let descriptor = load_descriptor_extended_makefiles(
         &parent_path,
         &Extend::Options(ExtendOptions {
-            path: "src/lib/test/makefiles/test1.toml".to_string(),
+            path: Some("src/lib/test/makefiles/test1.toml".to_string()),
             optional: Some(true),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         }),
     )
     .unwrap();
@@ -964,8 +976,12 @@ fn load_descriptor_extended_makefiles_options_exists_not_optional() {
     let descriptor = load_descriptor_extended_makefiles(
         &parent_path,
         &Extend::Options(ExtendOptions {
-            path: "src/lib/test/makefiles/test1.toml".to_string(),
+            path: Some("src/lib/test/makefiles/test1.toml".to_string()),
             optional: Some(false),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         }),
     )
     .unwrap();
@@ -982,8 +998,12 @@ fn load_descriptor_extended_makefiles_options_not_exists_optional() {
     let descriptor = load_descriptor_extended_makefiles(
         &parent_path,
         &Extend::Options(ExtendOptions {
-            path: "src/lib/test/makefiles/bad.toml".to_string(),
+            path: Some("src/lib/test/makefiles/bad.toml".to_string()),
             optional: Some(true),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         }),
     )
     .unwrap();
@@ -1000,8 +1020,12 @@ fn load_descriptor_extended_makefiles_options_not_exists_not_optional() {
     load_descriptor_extended_makefiles(
         &parent_path,
         &Extend::Options(ExtendOptions {
-            path: "src/lib/test/makefiles/bad.toml".to_string(),
+            path: Some("src/lib/test/makefiles/bad.toml".to_string()),
             optional: Some(false),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         }),
     )
     .unwrap();
@@ -1013,12 +1037,20 @@ fn load_descriptor_extended_makefiles_list_exists() {
     let parent_path = envmnt::get_or_panic("CARGO_MAKE_WORKING_DIRECTORY");
     let list = vec![
         ExtendOptions {
-            path: "src/lib/test/makefiles/test1.toml".to_string(),
+            path: Some("src/lib/test/makefiles/test1.toml".to_string()),
             optional: Some(false),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         },
         ExtendOptions {
-            path: "src/lib/test/makefiles/test2.toml".to_string(),
+            path: Some("src/lib/test/makefiles/test2.toml".to_string()),
             optional: Some(false),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         },
     ];
     let descriptor = load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -1035,12 +1067,20 @@ fn load_descriptor_extended_makefiles_list_not_exists() {
     let parent_path = envmnt::get_or_panic("CARGO_MAKE_WORKING_DIRECTORY");
     let list = vec![
         ExtendOptions {
-            path: "src/lib/test/makefiles/test1.toml".to_string(),
+            path: Some("src/lib/test/makefiles/test1.toml".to_string()),
             optional: Some(false),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         },
         ExtendOptions {
-            path: "src/lib/test/makefiles/bad.toml".to_string(),
+            path: Some("src/lib/test/makefiles/bad.toml".to_string()),
             optional: Some(false),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         },
     ];
     load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -1052,12 +1092,20 @@ fn load_descriptor_extended_makefiles_list_exists_optional() {
     let parent_path = envmnt::get_or_panic("CARGO_MAKE_WORKING_DIRECTORY");
     let list = vec![
         ExtendOptions {
-            path: "src/lib/test/makefiles/test1.toml".to_string(),
+            path: Some("src/lib/test/makefiles/test1.toml".to_string()),
             optional: Some(false),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         },
         ExtendOptions {
-            path: "src/lib/test/makefiles/bad.toml".to_string(),
+            path: Some("src/lib/test/makefiles/bad.toml".to_string()),
             optional: Some(true),
+            git: None,
+            rev: None,
+            url: None,
+            checksum: None,
         },
     ];
     let descriptor = load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -1144,7 +1192,7 @@ fn load_cargo_aliases_no_file() {
     let mut config = load_internal_descriptors(false, false, None);
     let count = config.tasks.len();
 
-    load_cargo_aliases(&mut config);
+    load_cargo_aliases(&mut config).unwrap();
 
     assert_eq!(count, config.tasks.len());
 }
@@ -1155,9 +1203,9 @@ fn load_cargo_aliases_found() {
     let mut config = load_internal_descriptors(false, false, None);
     let count = config.tasks.len();
 
-    setup_cwd(Some("src/lib/test/workspace1/member1"));
-    load_cargo_aliases(&mut config);
-    setup_cwd(Some("../../../../.."));
+    setup_cwd(Some("src/lib/test/workspace1/member1")).unwrap();
+    load_cargo_aliases(&mut config).unwrap();
+    setup_cwd(Some("../../../../..")).unwrap();
 
     assert_eq!(count, config.tasks.len());
 }
@@ -7,6 +7,7 @@
 #[path = "crateinfo_test.rs"]
 mod crateinfo_test;
 
+use crate::error::CargoMakeError;
 use crate::types::{CrateDependency, CrateInfo};
 use cargo_metadata::camino::Utf8PathBuf;
 use cargo_metadata::MetadataCommand;
@@ -176,30 +177,40 @@ fn load_workspace_members(crate_info: &mut CrateInfo) {
 }
 
 /// Loads the crate info based on the Cargo.toml found in the current working directory.
-pub(crate) fn load() -> CrateInfo {
+pub(crate) fn load() -> Result<CrateInfo, CargoMakeError> {
     load_from(Path::new("Cargo.toml").to_path_buf())
 }
 
-pub(crate) fn load_from(file_path: PathBuf) -> CrateInfo {
+pub(crate) fn load_from(file_path: PathBuf) -> Result<CrateInfo, CargoMakeError> {
     if file_path.exists() {
         debug!("Reading file: {:#?}", &file_path);
         let crate_info_string = match fsio::file::read_text_file(&file_path) {
             Ok(content) => content,
-            Err(error) => panic!("Unable to open Cargo.toml, error: {}", error),
+            Err(error) => {
+                return Err(CargoMakeError::Other(format!(
+                    "Unable to open Cargo.toml, error: {}",
+                    error
+                )))
+            }
         };
 
         let mut crate_info: CrateInfo = match toml::from_str(&crate_info_string) {
             Ok(value) => value,
-            Err(error) => panic!("Unable to parse Cargo.toml, {}", error),
+            Err(error) => {
+                return Err(CargoMakeError::Other(format!(
+                    "Unable to parse Cargo.toml, {}",
+                    error
+                )))
+            }
         };
 
         load_workspace_members(&mut crate_info);
 
         debug!("Loaded Cargo.toml: {:#?}", &crate_info);
 
-        crate_info
+        Ok(crate_info)
     } else {
-        CrateInfo::new()
+        Ok(CrateInfo::new())
     }
 }
 
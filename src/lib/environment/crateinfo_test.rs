@@ -5,7 +5,7 @@ use indexmap::IndexMap;
 
 #[test]
 fn crate_info_load() {
-    let crate_info = load();
+    let crate_info = load().unwrap();
 
     assert!(crate_info.package.is_some());
     assert!(crate_info.workspace.is_none());
@@ -152,7 +152,10 @@ fn get_members_from_dependencies_no_paths() {
     );
     dependencies.insert(
         "test3".to_string(),
-        CrateDependency::Info(CrateDependencyInfo { path: None }),
+        CrateDependency::Info(CrateDependencyInfo {
+            path: None,
+            workspace: None,
+        }),
     );
 
     let mut crate_info = CrateInfo::new();
@@ -178,7 +181,10 @@ fn get_members_from_dependencies_no_workspace_paths() {
     );
     dependencies.insert(
         "test3".to_string(),
-        CrateDependency::Info(CrateDependencyInfo { path: None }),
+        CrateDependency::Info(CrateDependencyInfo {
+            path: None,
+            workspace: None,
+        }),
     );
 
     let mut crate_info = CrateInfo::new();
@@ -203,18 +209,21 @@ fn get_members_from_dependencies_workspace_paths() {
         "test3".to_string(),
         CrateDependency::Info(CrateDependencyInfo {
             path: Some("somepath".to_string()),
+            workspace: None,
         }),
     );
     dependencies.insert(
         "valid1".to_string(),
         CrateDependency::Info(CrateDependencyInfo {
             path: Some("./member1".to_string()),
+            workspace: None,
         }),
     );
     dependencies.insert(
         "valid2".to_string(),
         CrateDependency::Info(CrateDependencyInfo {
             path: Some("./member2".to_string()),
+            workspace: None,
         }),
     );
 
@@ -485,24 +494,28 @@ fn load_workspace_members_mixed() {
         "test3".to_string(),
         CrateDependency::Info(CrateDependencyInfo {
             path: Some("somepath".to_string()),
+            workspace: None,
         }),
     );
     dependencies.insert(
         "valid1".to_string(),
         CrateDependency::Info(CrateDependencyInfo {
             path: Some("./path1".to_string()),
+            workspace: None,
         }),
     );
     dependencies.insert(
         "valid2".to_string(),
         CrateDependency::Info(CrateDependencyInfo {
             path: Some("./path2".to_string()),
+            workspace: None,
         }),
     );
     dependencies.insert(
         "valid3".to_string(),
         CrateDependency::Info(CrateDependencyInfo {
             path: Some("./member1".to_string()),
+            workspace: None,
         }),
     );
     crate_info.dependencies = Some(dependencies);
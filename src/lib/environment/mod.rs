@@ -4,6 +4,7 @@
 //!
 
 pub(crate) mod crateinfo;
+pub(crate) mod task_env;
 
 #[cfg(test)]
 #[path = "mod_test.rs"]
@@ -11,7 +12,9 @@ mod mod_test;
 
 use crate::command;
 use crate::condition;
+use crate::error::CargoMakeError;
 use crate::io;
+use crate::parameters;
 use crate::profile;
 use crate::scriptengine;
 use crate::types::{
@@ -22,7 +25,6 @@ use ci_info::types::CiInfo;
 use duckscript;
 use duckscriptsdk;
 use envmnt;
-use envmnt::{ExpandOptions, ExpansionType};
 use fsio::path::from_path::FromPath;
 use git_info;
 use git_info::types::GitInfo;
@@ -32,7 +34,7 @@ use rust_info::types::{RustChannel, RustInfo};
 use std::env;
 use std::path::{Path, PathBuf};
 
-fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
+fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> Result<String, CargoMakeError> {
     match command::run_script_get_output(&env_value.script, None, &vec![], true, Some(false)) {
         Ok(output) => {
             let exit_code = output.0;
@@ -40,7 +42,7 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
             let stderr = output.2;
 
             if exit_code != 0 {
-                error!(
+                return Err(CargoMakeError::Other(format!(
                     concat!(
                         "Error while evaluating script for env: {}, exit code: {}\n",
                         "Script:\n{:#?}\n",
@@ -48,7 +50,7 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
                         "Stderr:\n{}\n"
                     ),
                     key, exit_code, env_value.script, &stdout, &stderr
-                );
+                )));
             }
 
             debug!("Env script stdout:\n{}", &stdout);
@@ -59,7 +61,7 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
             };
 
             if multi_line {
-                stdout.to_string()
+                Ok(stdout.to_string())
             } else {
                 let mut lines: Vec<&str> = stdout.split("\n").collect();
                 lines.retain(|&line| line.len() > 0);
@@ -69,34 +71,90 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
 
                     let line_str = str::replace(&line, "\r", "");
 
-                    line_str.to_string()
+                    Ok(line_str.to_string())
                 } else {
-                    "".to_string()
+                    Ok("".to_string())
                 }
             }
         }
-        _ => "".to_string(),
+        _ => Ok("".to_string()),
     }
 }
 
+/// Expands the ${KEY} env var references in the value based on the env of the task currently
+/// running in this thread (see task_env::get_var).<br>
+/// References to undefined env vars are left as is.
 pub(crate) fn expand_value(value: &str) -> String {
-    let mut options = ExpandOptions::new();
-    options.expansion_type = Some(ExpansionType::UnixBrackets);
-    options.default_to_empty = false;
+    let mut value_string = String::new();
+
+    let mut found_dollar = false;
+    let mut reading_key = false;
+    let mut env_key = String::new();
+    for next_char in value.chars() {
+        if reading_key {
+            if next_char == '}' {
+                match task_env::get_var(&env_key) {
+                    Some(env_value) => value_string.push_str(&env_value),
+                    None => {
+                        value_string.push_str("${");
+                        value_string.push_str(&env_key);
+                        value_string.push('}');
+                    }
+                };
+
+                env_key.clear();
+                reading_key = false;
+            } else if next_char == ' '
+                || next_char == '='
+                || next_char == '\n'
+                || next_char == '\t'
+                || next_char == '\r'
+            {
+                value_string.push_str("${");
+                value_string.push_str(&env_key);
+                value_string.push(next_char);
+
+                env_key.clear();
+                reading_key = false;
+            } else {
+                env_key.push(next_char);
+            }
+        } else if found_dollar {
+            found_dollar = false;
+
+            if next_char == '{' {
+                reading_key = true;
+            } else {
+                value_string.push('$');
+                value_string.push(next_char);
+            }
+        } else if next_char == '$' {
+            found_dollar = true;
+        } else {
+            value_string.push(next_char);
+        }
+    }
+
+    if found_dollar {
+        value_string.push('$');
+    } else if reading_key {
+        value_string.push_str("${");
+        value_string.push_str(&env_key);
+    }
 
-    envmnt::expand(&value, Some(options))
+    value_string
 }
 
 fn evaluate_and_set_env(key: &str, value: &str) {
     let env_value = expand_value(&value);
 
     debug!("Setting Env: {} Value: {}", &key, &env_value);
-    envmnt::set(&key, &env_value);
+    task_env::set_var(&key, &env_value);
 }
 
 fn set_env_for_bool(key: &str, value: bool) {
     debug!("Setting Env: {} Value: {}", &key, &value);
-    envmnt::set_bool(&key, value);
+    task_env::set_var(&key, if value { "true" } else { "false" });
 }
 
 fn set_env_for_list(key: &str, list: &Vec<String>) {
@@ -107,13 +165,15 @@ fn set_env_for_list(key: &str, list: &Vec<String>) {
         expanded_list.push(env_value);
     }
 
-    envmnt::set_list(&key, &expanded_list);
+    task_env::set_var(&key, &expanded_list.join(";"));
 }
 
-fn set_env_for_script(key: &str, env_value: &EnvValueScript) {
-    let value = evaluate_env_value(&key, &env_value);
+fn set_env_for_script(key: &str, env_value: &EnvValueScript) -> Result<(), CargoMakeError> {
+    let value = evaluate_env_value(&key, &env_value)?;
 
     evaluate_and_set_env(&key, &value);
+
+    Ok(())
 }
 
 fn set_env_for_decode_info(key: &str, decode_info: &EnvValueDecode) {
@@ -143,22 +203,24 @@ fn set_env_for_conditional_value(key: &str, conditional_value: &EnvValueConditio
     }
 }
 
-fn set_env_for_path_glob(key: &str, path_glob: &EnvValuePathGlob) {
+fn set_env_for_path_glob(key: &str, path_glob: &EnvValuePathGlob) -> Result<(), CargoMakeError> {
     let path_list = io::get_path_list(
         &path_glob.glob,
         path_glob.include_files.unwrap_or(true),
         path_glob.include_dirs.unwrap_or(true),
         path_glob.ignore_type.clone(),
-    );
+    )?;
 
     set_env_for_list(key, &path_list);
+
+    Ok(())
 }
 
 fn set_env_for_profile(
     profile_name: &str,
     sub_env: &IndexMap<String, EnvValue>,
     additional_profiles: Option<&Vec<String>>,
-) {
+) -> Result<(), CargoMakeError> {
     let current_profile_name = profile::get();
     let profile_name_string = profile_name.to_string();
 
@@ -170,17 +232,19 @@ fn set_env_for_profile(
     if current_profile_name == profile_name_string || found {
         debug!("Setting Up Profile: {} Env.", &profile_name);
 
-        set_env_for_config(sub_env.clone(), None, false);
+        set_env_for_config(sub_env.clone(), None, false)?;
     }
+
+    Ok(())
 }
 
 /// Updates the env based on the provided data
-pub(crate) fn set_env(env: IndexMap<String, EnvValue>) {
+pub(crate) fn set_env(env: IndexMap<String, EnvValue>) -> Result<(), CargoMakeError> {
     set_env_for_config(env, None, true)
 }
 
 fn unset_env(key: &str) {
-    envmnt::remove(key);
+    task_env::remove_var(key);
 }
 
 /// Updates the env based on the provided data
@@ -188,7 +252,7 @@ pub(crate) fn set_env_for_config(
     env: IndexMap<String, EnvValue>,
     additional_profiles: Option<&Vec<String>>,
     allow_sub_env: bool,
-) {
+) -> Result<(), CargoMakeError> {
     debug!("Setting Up Env.");
 
     for (key, env_value) in &env {
@@ -199,15 +263,15 @@ pub(crate) fn set_env_for_config(
             EnvValue::Boolean(value) => set_env_for_bool(&key, value),
             EnvValue::Number(value) => evaluate_and_set_env(&key, &value.to_string()),
             EnvValue::List(ref value) => set_env_for_list(&key, value),
-            EnvValue::Script(ref script_info) => set_env_for_script(&key, script_info),
+            EnvValue::Script(ref script_info) => set_env_for_script(&key, script_info)?,
             EnvValue::Decode(ref decode_info) => set_env_for_decode_info(&key, decode_info),
             EnvValue::Conditional(ref conditioned_value) => {
                 set_env_for_conditional_value(&key, conditioned_value)
             }
-            EnvValue::PathGlob(ref path_glob_info) => set_env_for_path_glob(&key, path_glob_info),
+            EnvValue::PathGlob(ref path_glob_info) => set_env_for_path_glob(&key, path_glob_info)?,
             EnvValue::Profile(ref sub_env) => {
                 if allow_sub_env {
-                    set_env_for_profile(&key, sub_env, additional_profiles)
+                    set_env_for_profile(&key, sub_env, additional_profiles)?
                 }
             }
             EnvValue::Unset(ref value) => {
@@ -226,7 +290,7 @@ pub(crate) fn set_env_for_config(
                 Some(ref env_value) => {
                     match *env_value {
                         EnvValue::Profile(ref sub_env) => {
-                            set_env_for_profile(&profile_name, sub_env, None)
+                            set_env_for_profile(&profile_name, sub_env, None)?
                         }
                         _ => (),
                     };
@@ -235,20 +299,24 @@ pub(crate) fn set_env_for_config(
             };
         }
     }
+
+    Ok(())
 }
 
-pub(crate) fn set_env_files(env_files: Vec<EnvFile>) {
-    set_env_files_for_config(env_files, None);
+pub(crate) fn set_env_files(env_files: Vec<EnvFile>) -> Result<(), CargoMakeError> {
+    set_env_files_for_config(env_files, None)?;
+
+    Ok(())
 }
 
 fn set_env_files_for_config(
     env_files: Vec<EnvFile>,
     additional_profiles: Option<&Vec<String>>,
-) -> bool {
+) -> Result<bool, CargoMakeError> {
     let mut all_loaded = true;
     for env_file in env_files {
         let loaded = match env_file {
-            EnvFile::Path(file) => load_env_file(Some(file)),
+            EnvFile::Path(file) => load_env_file(Some(file))?,
             EnvFile::Info(info) => {
                 let is_valid_profile = match info.profile {
                     Some(profile_name) => {
@@ -265,7 +333,7 @@ fn set_env_files_for_config(
                 };
 
                 if is_valid_profile {
-                    load_env_file_with_base_directory(Some(info.path), info.base_path)
+                    load_env_file_with_base_directory(Some(info.path), info.base_path)?
                 } else {
                     false
                 }
@@ -275,10 +343,13 @@ fn set_env_files_for_config(
         all_loaded = all_loaded && loaded;
     }
 
-    all_loaded
+    Ok(all_loaded)
 }
 
-fn set_env_scripts(env_scripts: Vec<String>, cli_arguments: &Vec<String>) {
+fn set_env_scripts(
+    env_scripts: Vec<String>,
+    cli_arguments: &Vec<String>,
+) -> Result<(), CargoMakeError> {
     for env_script in env_scripts {
         if !env_script.is_empty() {
             scriptengine::invoke_script_pre_flow(
@@ -288,9 +359,11 @@ fn set_env_scripts(env_scripts: Vec<String>, cli_arguments: &Vec<String>) {
                 None,
                 true,
                 cli_arguments,
-            );
+            )?;
         }
     }
+
+    Ok(())
 }
 
 pub(crate) fn set_current_task_meta_info_env(env: IndexMap<String, EnvValue>) {
@@ -309,7 +382,7 @@ pub(crate) fn set_current_task_meta_info_env(env: IndexMap<String, EnvValue>) {
 }
 
 /// Updates the env for the current execution based on the descriptor.
-fn initialize_env(config: &Config, cli_args: &Vec<String>) {
+fn initialize_env(config: &Config, cli_args: &Vec<String>) -> Result<(), CargoMakeError> {
     debug!("Initializing Env.");
 
     let additional_profiles = match config.config.additional_profiles {
@@ -317,11 +390,11 @@ fn initialize_env(config: &Config, cli_args: &Vec<String>) {
         None => None,
     };
 
-    set_env_files_for_config(config.env_files.clone(), additional_profiles);
+    set_env_files_for_config(config.env_files.clone(), additional_profiles)?;
 
-    set_env_for_config(config.env.clone(), additional_profiles, true);
+    set_env_for_config(config.env.clone(), additional_profiles, true)?;
 
-    set_env_scripts(config.env_scripts.clone(), cli_args);
+    set_env_scripts(config.env_scripts.clone(), cli_args)
 }
 
 fn setup_env_for_duckscript() {
@@ -332,8 +405,8 @@ fn setup_env_for_duckscript() {
     envmnt::set("CARGO_MAKE_DUCKSCRIPT_SDK_VERSION", version);
 }
 
-fn setup_env_for_crate() -> CrateInfo {
-    let crate_info = crateinfo::load();
+fn setup_env_for_crate() -> Result<CrateInfo, CargoMakeError> {
+    let crate_info = crateinfo::load()?;
     let crate_info_clone = crate_info.clone();
 
     let package_info = crate_info.package.unwrap_or(PackageInfo::new());
@@ -387,7 +460,7 @@ fn setup_env_for_crate() -> CrateInfo {
     let lock_file_exists = lock_file.exists();
     envmnt::set_bool("CARGO_MAKE_CRATE_LOCK_FILE_EXISTS", lock_file_exists);
 
-    crate_info_clone
+    Ok(crate_info_clone)
 }
 
 fn setup_env_for_git_repo() -> GitInfo {
@@ -484,7 +557,7 @@ fn get_base_directory_name() -> Option<String> {
     }
 }
 
-fn setup_env_for_project(config: &Config, crate_info: &CrateInfo) {
+fn setup_env_for_project(config: &Config, crate_info: &CrateInfo) -> Result<(), CargoMakeError> {
     let project_name = match crate_info.package {
         Some(ref package) => match package.name {
             Some(ref name) => Some(name.to_string()),
@@ -512,7 +585,7 @@ fn setup_env_for_project(config: &Config, crate_info: &CrateInfo) {
                     let mut path = PathBuf::new();
                     path.push(member);
                     path.push("Cargo.toml");
-                    let member_crate_info = crateinfo::load_from(path);
+                    let member_crate_info = crateinfo::load_from(path)?;
 
                     match member_crate_info.package {
                         Some(package) => package.version,
@@ -529,6 +602,8 @@ fn setup_env_for_project(config: &Config, crate_info: &CrateInfo) {
     };
 
     envmnt::set_or_remove("CARGO_MAKE_PROJECT_VERSION", &project_version);
+
+    Ok(())
 }
 
 /// Sets up the env before the tasks execution.
@@ -537,7 +612,7 @@ pub(crate) fn setup_env(
     config: &Config,
     task: &str,
     home: Option<PathBuf>,
-) -> EnvInfo {
+) -> Result<EnvInfo, CargoMakeError> {
     envmnt::set_bool("CARGO_MAKE", true);
     envmnt::set("CARGO_MAKE_TASK", &task);
 
@@ -549,6 +624,8 @@ pub(crate) fn setup_env(
     };
     envmnt::set_list("CARGO_MAKE_TASK_ARGS", &task_arguments);
 
+    parameters::setup_env(&config, &cli_args.command, &task, &task_arguments)?;
+
     // load duckscript_info
     setup_env_for_duckscript();
 
@@ -556,7 +633,7 @@ pub(crate) fn setup_env(
     let crate_info = if config.config.skip_crate_env_info.unwrap_or(false) {
         CrateInfo::new()
     } else {
-        setup_env_for_crate()
+        setup_env_for_crate()?
     };
 
     // load git info
@@ -577,17 +654,22 @@ pub(crate) fn setup_env(
     let ci_info_struct = setup_env_for_ci();
 
     // setup project info
-    setup_env_for_project(config, &crate_info);
+    setup_env_for_project(config, &crate_info)?;
 
     // load env vars
-    initialize_env(config, &cli_args.arguments.clone().unwrap_or(vec![]));
+    initialize_env(config, &cli_args.arguments.clone().unwrap_or(vec![]))?;
+
+    // cli value overrides the makefile env value
+    if let Some(ref git_ref) = cli_args.affected_since {
+        envmnt::set("CARGO_MAKE_WORKSPACE_AFFECTED_SINCE", git_ref);
+    }
 
-    EnvInfo {
+    Ok(EnvInfo {
         rust_info: rustinfo,
         crate_info,
         git_info: gitinfo,
         ci_info: ci_info_struct,
-    }
+    })
 }
 
 fn set_workspace_cwd(directory_path: &Path, force: bool) {
@@ -612,7 +694,7 @@ pub(crate) fn search_and_set_workspace_cwd() {
     }
 }
 
-fn get_directory_path(path_option: Option<&str>) -> PathBuf {
+pub(crate) fn get_directory_path(path_option: Option<&str>) -> PathBuf {
     let cwd_str = path_option.unwrap_or(".");
     let directory = expand_value(cwd_str);
 
@@ -620,7 +702,7 @@ fn get_directory_path(path_option: Option<&str>) -> PathBuf {
     PathBuf::from(directory_path_string)
 }
 
-pub(crate) fn setup_cwd(cwd: Option<&str>) -> Option<PathBuf> {
+pub(crate) fn setup_cwd(cwd: Option<&str>) -> Result<Option<PathBuf>, CargoMakeError> {
     let directory_path_buf = get_directory_path(cwd);
     let directory_path = directory_path_buf.as_path();
 
@@ -630,14 +712,11 @@ pub(crate) fn setup_cwd(cwd: Option<&str>) -> Option<PathBuf> {
     );
 
     match env::set_current_dir(&directory_path) {
-        Err(error) => {
-            error!(
-                "Unable to set current working directory to: {} {:#?}",
-                directory_path.display(),
-                error
-            );
-            None
-        }
+        Err(error) => Err(CargoMakeError::Other(format!(
+            "Unable to set current working directory to: {} {:#?}",
+            directory_path.display(),
+            error
+        ))),
         _ => {
             envmnt::set("CARGO_MAKE_WORKING_DIRECTORY", &directory_path);
 
@@ -648,29 +727,31 @@ pub(crate) fn setup_cwd(cwd: Option<&str>) -> Option<PathBuf> {
             let home = home::cargo_home_with_cwd(directory_path).ok();
 
             envmnt::set_optional("CARGO_MAKE_CARGO_HOME", &home);
-            home
+            Ok(home)
         }
     }
 }
 
-pub(crate) fn load_env_file(env_file: Option<String>) -> bool {
+pub(crate) fn load_env_file(env_file: Option<String>) -> Result<bool, CargoMakeError> {
     load_env_file_with_base_directory(env_file, None)
 }
 
 pub(crate) fn load_env_file_with_base_directory(
     env_file: Option<String>,
     base_directory: Option<String>,
-) -> bool {
+) -> Result<bool, CargoMakeError> {
     match env_file {
         Some(file_name) => {
             let file_path = if file_name.starts_with(".") {
+                let working_directory =
+                    task_env::get_var("CARGO_MAKE_WORKING_DIRECTORY").unwrap_or(".".to_string());
                 let (base_path, check_relative_path) = match base_directory {
                     Some(file) => (file, true),
-                    None => (envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "."), false),
+                    None => (working_directory.clone(), false),
                 };
 
                 if check_relative_path && base_path.starts_with(".") {
-                    Path::new(&envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "."))
+                    Path::new(&working_directory)
                         .join(&base_path)
                         .join(file_name)
                 } else {
@@ -681,27 +762,24 @@ pub(crate) fn load_env_file_with_base_directory(
             };
 
             match file_path.to_str() {
-                Some(file_path_str) => {
-                    let evaluate_env_var = |value: String| expand_value(&value);
-
-                    match envmnt::evaluate_and_load_file(file_path_str, evaluate_env_var) {
-                        Err(error) => {
-                            error!(
-                                "Unable to load env file: {} Error: {:#?}",
-                                &file_path_str, error
-                            );
-                            false
-                        }
-                        _ => {
-                            debug!("Loaded env file: {}", &file_path_str);
-                            true
+                Some(file_path_str) => match envmnt::parse_file(file_path_str) {
+                    Err(error) => Err(CargoMakeError::Other(format!(
+                        "Unable to load env file: {} Error: {:#?}",
+                        &file_path_str, error
+                    ))),
+                    Ok(env) => {
+                        for (key, value) in &env {
+                            evaluate_and_set_env(key, value);
                         }
+
+                        debug!("Loaded env file: {}", &file_path_str);
+                        Ok(true)
                     }
-                }
-                None => false,
+                },
+                None => Ok(false),
             }
         }
-        None => false,
+        None => Ok(false),
     }
 }
 
@@ -10,16 +10,16 @@ use std::{thread, time};
 #[test]
 #[ignore]
 fn load_env_file_none() {
-    let output = load_env_file(None);
+    let output = load_env_file(None).unwrap();
 
     assert!(!output);
 }
 
 #[test]
-#[ignore]
-#[should_panic]
 fn load_env_file_no_exists() {
-    load_env_file(Some("./bad.env".to_string()));
+    let result = load_env_file(Some("./bad.env".to_string()));
+
+    assert!(result.is_err());
 }
 
 #[test]
@@ -29,7 +29,7 @@ fn load_env_file_exists() {
     envmnt::remove("ENV2_TEST");
     envmnt::remove("ENV3_TEST");
 
-    let output = load_env_file(Some("./examples/test.env".to_string()));
+    let output = load_env_file(Some("./examples/test.env".to_string())).unwrap();
 
     assert!(output);
 
@@ -38,6 +38,29 @@ fn load_env_file_exists() {
     assert!(envmnt::is_equal("ENV3_TEST", "VALUE OF ENV2 IS: TEST2"));
 }
 
+#[test]
+fn expand_value_from_task_env() {
+    let mut current_env = task_env::TaskEnv::default();
+    current_env
+        .vars
+        .insert("EXPAND_VALUE_TASK_ENV".to_string(), "task".to_string());
+    let _guard = task_env::set_current(Some(current_env));
+
+    let output = expand_value("value: ${EXPAND_VALUE_TASK_ENV} $HOME $${EXPAND_VALUE_TASK_ENV}");
+
+    assert_eq!(output, "value: task $HOME $${EXPAND_VALUE_TASK_ENV}");
+}
+
+#[test]
+fn expand_value_undefined() {
+    let output = expand_value("${EXPAND_VALUE_UNDEFINED} ${EXPAND_VALUE_UNDEFINED ${");
+
+    assert_eq!(
+        output,
+        "${EXPAND_VALUE_UNDEFINED} ${EXPAND_VALUE_UNDEFINED ${"
+    );
+}
+
 #[test]
 #[ignore]
 fn evaluate_and_set_env_simple() {
@@ -181,7 +204,7 @@ fn set_env_multi_types() {
     );
     env.insert(current_profile_name, EnvValue::Profile(profile_env));
 
-    set_env(env);
+    set_env(env).unwrap();
 
     assert!(envmnt::is_equal("value", "test val"));
     assert!(!envmnt::is_or("bool", true));
@@ -230,7 +253,7 @@ fn set_env_multi_line_script() {
         }),
     );
 
-    set_env(env);
+    set_env(env).unwrap();
 
     assert!(envmnt::is_equal("script", "script1\nscript2\n"));
     envmnt::remove("SET_ENV_MULTI_LINE_SCRIPT");
@@ -346,6 +369,17 @@ fn set_env_for_conditional_value_condition_true() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let info = EnvValueConditioned {
@@ -361,6 +395,27 @@ fn set_env_for_conditional_value_condition_true() {
     ));
 }
 
+#[test]
+#[ignore]
+fn set_env_for_conditional_value_not_condition() {
+    envmnt::remove("ENV_CONDITIONAL_NOT_CONDITION");
+
+    let condition: TaskCondition =
+        toml::from_str(r#"not = { env_set = ["ENV_CONDITIONAL_NOT_CONDITION"] }"#).unwrap();
+
+    let info = EnvValueConditioned {
+        value: "test value".to_string(),
+        condition: Some(condition),
+    };
+
+    set_env_for_conditional_value("ENV_CONDITIONAL_NOT_CONDITION", &info);
+
+    assert!(envmnt::is_equal(
+        "ENV_CONDITIONAL_NOT_CONDITION",
+        "test value"
+    ));
+}
+
 #[test]
 #[ignore]
 fn set_env_for_conditional_value_condition_false() {
@@ -380,6 +435,17 @@ fn set_env_for_conditional_value_condition_false() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     };
 
     let info = EnvValueConditioned {
@@ -404,7 +470,7 @@ fn set_env_for_path_glob_found() {
         ignore_type: Some("git".to_string()),
     };
 
-    set_env_for_path_glob("ENV_PATH_GLOB_FOUND", &info);
+    set_env_for_path_glob("ENV_PATH_GLOB_FOUND", &info).unwrap();
 
     assert!(envmnt::is_equal(
         "ENV_PATH_GLOB_FOUND",
@@ -421,7 +487,7 @@ fn set_env_for_profile_none_not_found() {
         EnvValue::Boolean(true),
     );
 
-    set_env_for_profile("test_profile", &env, None);
+    set_env_for_profile("test_profile", &env, None).unwrap();
 
     assert!(!envmnt::exists("TEST_PROFILE_NONE_NOT_FOUND"));
 }
@@ -439,7 +505,8 @@ fn set_env_for_profile_some_not_found() {
         "test_profile",
         &env,
         Some(&vec!["other_profile".to_string()]),
-    );
+    )
+    .unwrap();
 
     assert!(!envmnt::exists("TEST_PROFILE_SOME_NOT_FOUND"));
 }
@@ -454,7 +521,8 @@ fn set_env_for_profile_some_found() {
         "test_profile",
         &env,
         Some(&vec!["test_profile".to_string()]),
-    );
+    )
+    .unwrap();
 
     assert!(envmnt::exists("TEST_PROFILE_FOUND"));
     assert!(envmnt::is("TEST_PROFILE_FOUND"));
@@ -474,7 +542,7 @@ fn set_env_for_config_list() {
         ]),
     );
 
-    set_env_for_config(env, None, true);
+    set_env_for_config(env, None, true).unwrap();
 
     assert_eq!(
         envmnt::get_or_panic("SET_ENV_FOR_CONFIG_LIST_MATCH_TEST"),
@@ -496,7 +564,7 @@ fn set_env_for_config_unset() {
         EnvValue::Unset(unset),
     );
 
-    set_env_for_config(env, None, true);
+    set_env_for_config(env, None, true).unwrap();
 
     assert!(!envmnt::exists("set_env_for_config_unset"));
 }
@@ -523,6 +591,17 @@ fn set_env_for_config_conditional() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
     };
 
@@ -532,7 +611,7 @@ fn set_env_for_config_conditional() {
         EnvValue::Conditional(conditional),
     );
 
-    set_env_for_config(env, None, true);
+    set_env_for_config(env, None, true).unwrap();
 
     assert!(envmnt::is_equal(
         "set_env_for_config_conditional",
@@ -557,7 +636,7 @@ fn set_env_for_config_path_glob() {
         }),
     );
 
-    set_env_for_config(env, None, true);
+    set_env_for_config(env, None, true).unwrap();
 
     assert!(envmnt::is_equal(
         "set_env_for_config_path_glob",
@@ -586,7 +665,7 @@ fn set_env_for_config_profile_override() {
     env.insert(profile_name.clone(), EnvValue::Profile(profile_env));
     env.insert("additional".to_string(), EnvValue::Profile(additional_env));
 
-    set_env_for_config(env, Some(&vec!["additional".to_string()]), true);
+    set_env_for_config(env, Some(&vec!["additional".to_string()]), true).unwrap();
 
     assert!(envmnt::is_equal(
         "set_env_for_config_profile_override",
@@ -612,7 +691,8 @@ fn set_env_files_for_config_files() {
             EnvFile::Path("./src/lib/test/test_files/profile.env".to_string()),
         ],
         None,
-    );
+    )
+    .unwrap();
 
     assert!(loaded);
     assert!(envmnt::exists("CARGO_MAKE_ENV_FILE_TEST1"));
@@ -645,7 +725,8 @@ fn set_env_files_for_config_base_directory() {
             EnvFile::Path("./src/lib/test/test_files/profile.env".to_string()),
         ],
         None,
-    );
+    )
+    .unwrap();
 
     assert!(loaded);
     assert!(envmnt::exists("CARGO_MAKE_ENV_FILE_TEST1"));
@@ -684,7 +765,8 @@ fn set_env_files_for_config_profile() {
             }),
         ],
         None,
-    );
+    )
+    .unwrap();
 
     assert!(!loaded);
     assert!(!envmnt::exists("CARGO_MAKE_ENV_FILE_TEST1"));
@@ -723,7 +805,8 @@ fn set_env_files_for_config_profile_inverse() {
             }),
         ],
         None,
-    );
+    )
+    .unwrap();
 
     assert!(!loaded);
     assert!(!envmnt::exists("CARGO_MAKE_ENV_FILE_TEST1"));
@@ -762,7 +845,8 @@ fn set_env_files_for_config_additional_profiles() {
             }),
         ],
         Some(&vec!["env_test2".to_string()]),
-    );
+    )
+    .unwrap();
 
     assert!(loaded);
     assert!(envmnt::exists("CARGO_MAKE_ENV_FILE_TEST1"));
@@ -818,7 +902,7 @@ fn initialize_env_all() {
         plugins: None,
     };
 
-    initialize_env(&config, &vec![]);
+    initialize_env(&config, &vec![]).unwrap();
 
     assert!(envmnt::exists("initialize_env_all_test"));
     assert!(envmnt::exists("CARGO_MAKE_ENV_FILE_TEST1"));
@@ -834,7 +918,7 @@ fn initialize_env_all() {
 fn setup_cwd_empty() {
     envmnt::set("CARGO_MAKE_WORKING_DIRECTORY", "EMPTY");
 
-    setup_cwd(None);
+    setup_cwd(None).unwrap();
 
     assert!(envmnt::get_or_panic("CARGO_MAKE_WORKING_DIRECTORY") != "EMPTY");
 }
@@ -853,12 +937,12 @@ fn setup_env_empty() {
         plugins: None,
     };
 
-    setup_env(&cli_args, &config, "setup_env_empty1", None);
+    setup_env(&cli_args, &config, "setup_env_empty1", None).unwrap();
 
     let mut value = envmnt::get_or_panic("CARGO_MAKE_TASK");
     assert_eq!(value, "setup_env_empty1");
 
-    setup_env(&cli_args, &config, "setup_env_empty2", None);
+    setup_env(&cli_args, &config, "setup_env_empty2", None).unwrap();
 
     let delay = time::Duration::from_millis(10);
     thread::sleep(delay);
@@ -884,7 +968,7 @@ fn setup_env_skip_git() {
         plugins: None,
     };
 
-    let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None);
+    let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None).unwrap();
     assert!(env_info.git_info.user_name.is_none());
 }
 
@@ -905,7 +989,7 @@ fn setup_env_skip_rust() {
         plugins: None,
     };
 
-    let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None);
+    let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None).unwrap();
     assert!(env_info.rust_info.channel.is_none());
 }
 
@@ -926,14 +1010,14 @@ fn setup_env_skip_crate() {
         plugins: None,
     };
 
-    let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None);
+    let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None).unwrap();
     assert!(env_info.crate_info.dependencies.is_none());
 }
 
 #[test]
 #[ignore]
 fn setup_cargo_home() {
-    setup_cwd(None);
+    setup_cwd(None).unwrap();
 
     assert_eq!(
         envmnt::get_or_panic("CARGO_MAKE_CARGO_HOME"),
@@ -947,7 +1031,7 @@ fn setup_cargo_home_overwrite() {
     let path = Path::new("path");
     envmnt::set("CARGO_HOME", path);
 
-    setup_cwd(None);
+    setup_cwd(None).unwrap();
 
     let mut cargo_home = env::current_dir().unwrap();
     cargo_home.push(path);
@@ -976,7 +1060,7 @@ fn setup_env_cli_arguments() {
 
     envmnt::set("CARGO_MAKE_TASK_ARGS", "EMPTY");
 
-    setup_env(&cli_args, &config, "setup_env_empty1", None);
+    setup_env(&cli_args, &config, "setup_env_empty1", None).unwrap();
 
     let value = envmnt::get_or_panic("CARGO_MAKE_TASK_ARGS");
     assert_eq!(value, "arg1;arg2");
@@ -1007,7 +1091,7 @@ fn setup_env_values() {
     assert_eq!(envmnt::get_or("MY_ENV_KEY", "NONE"), "NONE".to_string());
     assert_eq!(envmnt::get_or("MY_ENV_KEY2", "NONE"), "NONE".to_string());
 
-    setup_env(&cli_args, &config, "set_env_values", None);
+    setup_env(&cli_args, &config, "set_env_values", None).unwrap();
 
     assert_eq!(envmnt::get_or_panic("MY_ENV_KEY"), "MY_ENV_VALUE");
     assert_eq!(envmnt::get_or_panic("MY_ENV_KEY2"), "MY_ENV_VALUE2");
@@ -1047,7 +1131,7 @@ fn setup_env_script() {
         "NONE".to_string()
     );
 
-    setup_env(&cli_args, &config, "set_env_values", None);
+    setup_env(&cli_args, &config, "set_env_values", None).unwrap();
 
     assert_eq!(envmnt::get_or_panic("MY_ENV_SCRIPT_KEY"), "MY_ENV_VALUE");
     assert_eq!(envmnt::get_or_panic("MY_ENV_SCRIPT_KEY2"), "script1");
@@ -1061,7 +1145,8 @@ fn evaluate_env_value_valid() {
             script: vec!["echo script1".to_string()],
             multi_line: None,
         },
-    );
+    )
+    .unwrap();
 
     assert_eq!(output, "script1".to_string());
 }
@@ -1075,21 +1160,23 @@ fn evaluate_env_value_empty() {
             script: vec!["".to_string()],
             multi_line: None,
         },
-    );
+    )
+    .unwrap();
 
     assert_eq!(output, "".to_string());
 }
 
 #[test]
-#[should_panic]
 fn evaluate_env_error() {
-    evaluate_env_value(
+    let result = evaluate_env_value(
         "MY_ENV_SCRIPT_KEY",
         &EnvValueScript {
             script: vec!["exit 1".to_string()],
             multi_line: None,
         },
     );
+
+    assert!(result.is_err());
 }
 
 #[test]
@@ -1100,7 +1187,8 @@ fn evaluate_env_value_single_line() {
             script: vec!["echo test".to_string()],
             multi_line: Some(false),
         },
-    );
+    )
+    .unwrap();
 
     assert!(output.contains("test"));
 }
@@ -1113,7 +1201,8 @@ fn evaluate_env_value_multi_line() {
             script: vec!["echo 1\necho 2".to_string()],
             multi_line: Some(true),
         },
-    );
+    )
+    .unwrap();
 
     assert!(output.contains("1"));
     assert!(output.contains("2"));
@@ -1128,7 +1217,8 @@ fn evaluate_env_value_multi_line_linux() {
             script: vec!["echo 1\necho 2".to_string()],
             multi_line: Some(true),
         },
-    );
+    )
+    .unwrap();
 
     assert!(output.contains("1"));
     assert!(output.contains("2"));
@@ -1150,7 +1240,7 @@ fn setup_env_for_crate_load_toml_found() {
     envmnt::set("CARGO_MAKE_CRATE_HAS_DEPENDENCIES", "EMPTY");
     envmnt::set("CARGO_MAKE_CRATE_WORKSPACE_MEMBERS", "EMPTY");
 
-    setup_env_for_crate();
+    setup_env_for_crate().unwrap();
 
     assert_eq!(envmnt::get_or_panic("CARGO_MAKE_CRATE_NAME"), "cargo-make");
     assert_eq!(
@@ -1213,9 +1303,9 @@ fn setup_env_for_crate_load_toml_not_found_and_cwd() {
     envmnt::set("CARGO_MAKE_WORKING_DIRECTORY", "EMPTY");
     assert!(envmnt::get_or_panic("CARGO_MAKE_WORKING_DIRECTORY") == "EMPTY");
 
-    setup_cwd(Some("examples"));
-    setup_env_for_crate();
-    setup_cwd(Some(".."));
+    setup_cwd(Some("examples")).unwrap();
+    setup_env_for_crate().unwrap();
+    setup_cwd(Some("..")).unwrap();
 
     assert!(envmnt::get_or_panic("CARGO_MAKE_WORKING_DIRECTORY") != "EMPTY");
 
@@ -1246,7 +1336,7 @@ fn setup_env_for_crate_load_toml_not_found_and_cwd() {
         ""
     );
 
-    setup_env_for_crate();
+    setup_env_for_crate().unwrap();
 
     assert_eq!(envmnt::get_or_panic("CARGO_MAKE_CRATE_NAME"), "cargo-make");
     assert_eq!(
@@ -1306,9 +1396,9 @@ fn setup_env_for_crate_workspace() {
     envmnt::set("CARGO_MAKE_CRATE_IS_WORKSPACE", "EMPTY");
     envmnt::set("CARGO_MAKE_CRATE_WORKSPACE_MEMBERS", "EMPTY");
 
-    setup_cwd(Some("examples/workspace"));
-    setup_env_for_crate();
-    setup_cwd(Some("../.."));
+    setup_cwd(Some("examples/workspace")).unwrap();
+    setup_env_for_crate().unwrap();
+    setup_cwd(Some("../..")).unwrap();
 
     assert_eq!(envmnt::get_or_panic("CARGO_MAKE_CRATE_NAME"), "EMPTY");
     assert_eq!(envmnt::get_or_panic("CARGO_MAKE_CRATE_FS_NAME"), "EMPTY");
@@ -1674,12 +1764,12 @@ fn setup_env_for_project_crate() {
         plugins: None,
     };
 
-    let crate_info = crateinfo::load();
+    let crate_info = crateinfo::load().unwrap();
 
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
     envmnt::remove("CARGO_MAKE_PROJECT_VERSION");
 
-    setup_env_for_project(&config, &crate_info);
+    setup_env_for_project(&config, &crate_info).unwrap();
 
     assert!(envmnt::is_equal("CARGO_MAKE_PROJECT_NAME", "cargo-make"));
     assert!(envmnt::is_equal(
@@ -1706,10 +1796,10 @@ fn setup_env_for_project_workspace_with_main_crate() {
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
     envmnt::remove("CARGO_MAKE_PROJECT_VERSION");
 
-    setup_cwd(Some("src/lib/test/workspace1"));
-    let crate_info = crateinfo::load();
-    setup_env_for_project(&config, &crate_info);
-    setup_cwd(Some("../../../.."));
+    setup_cwd(Some("src/lib/test/workspace1")).unwrap();
+    let crate_info = crateinfo::load().unwrap();
+    setup_env_for_project(&config, &crate_info).unwrap();
+    setup_cwd(Some("../../../..")).unwrap();
 
     assert!(envmnt::is_equal("CARGO_MAKE_PROJECT_NAME", "workspace1"));
     assert!(envmnt::is_equal("CARGO_MAKE_PROJECT_VERSION", "5.4.3"));
@@ -1730,10 +1820,10 @@ fn setup_env_for_project_workspace_no_main_crate() {
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
     envmnt::remove("CARGO_MAKE_PROJECT_VERSION");
 
-    setup_cwd(Some("src/lib/test/workspace1"));
-    let crate_info = crateinfo::load();
-    setup_env_for_project(&config, &crate_info);
-    setup_cwd(Some("../../../.."));
+    setup_cwd(Some("src/lib/test/workspace1")).unwrap();
+    let crate_info = crateinfo::load().unwrap();
+    setup_env_for_project(&config, &crate_info).unwrap();
+    setup_cwd(Some("../../../..")).unwrap();
 
     assert!(envmnt::is_equal("CARGO_MAKE_PROJECT_NAME", "workspace1"));
     assert!(!envmnt::exists("CARGO_MAKE_PROJECT_VERSION"));
@@ -0,0 +1,722 @@
+//! # task_env
+//!
+//! Creates the isolated env of a task (the global env with the task env files and env vars on top)
+//! without modifying the process env, and holds the env and working directory of the task
+//! currently running in this thread, so any process started by the task gets them.
+//!
+
+#[cfg(test)]
+#[path = "task_env_test.rs"]
+mod task_env_test;
+
+use crate::environment;
+use crate::error::CargoMakeError;
+use crate::profile;
+use crate::types::Step;
+use indexmap::IndexMap;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::{Mutex, MutexGuard};
+
+/// Serializes all temporary modifications of the process env
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+/// Serializes the actions which run within the cargo-make process with the task env applied to
+/// the process (see [apply_current])
+static IN_PROCESS_LOCK: Mutex<()> = Mutex::new(());
+/// Holds the original values of the process env vars and working directory while they are
+/// modified by an in process action, only accessed while holding the env lock
+static PROCESS_OVERRIDES: Mutex<ProcessOverrides> = Mutex::new(ProcessOverrides {
+    vars: Vec::new(),
+    cwd: None,
+});
+
+thread_local! {
+    static CURRENT_TASK_ENV: RefCell<Option<TaskEnv>> = RefCell::new(None);
+    static CURRENT_TASK_CWD: RefCell<Option<String>> = RefCell::new(None);
+    /// The amount of nested env locks held by this thread
+    static ENV_LOCK_DEPTH: Cell<usize> = Cell::new(0);
+    /// The amount of nested in process actions invoked by this thread
+    static IN_PROCESS_LOCK_DEPTH: Cell<usize> = Cell::new(0);
+    /// True while a task env is created in this thread, in which case env updates are written to
+    /// the task env instead of the process env
+    static CREATING_TASK_ENV: Cell<bool> = Cell::new(false);
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Holds the task env vars which differ from the process env
+pub(crate) struct TaskEnv {
+    /// The env vars which are added or modified by the task
+    pub(crate) vars: IndexMap<String, String>,
+    /// The env vars which are removed by the task
+    pub(crate) removed: Vec<String>,
+}
+
+impl TaskEnv {
+    /// Returns true if the task does not modify the env
+    pub(crate) fn is_empty(&self) -> bool {
+        self.vars.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Restores the previous task env of this thread once dropped
+pub(crate) struct CurrentTaskEnvGuard {
+    previous: Option<TaskEnv>,
+}
+
+impl Drop for CurrentTaskEnvGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_TASK_ENV.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Restores the previous task working directory of this thread once dropped
+pub(crate) struct CurrentTaskCwdGuard {
+    previous: Option<String>,
+}
+
+impl Drop for CurrentTaskCwdGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_TASK_CWD.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Holds the task env and working directory of a thread, used to pass them to the threads
+/// started by the task (for example parallel sub flows)
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TaskContext {
+    /// The task env
+    pub(crate) env: Option<TaskEnv>,
+    /// The task working directory
+    pub(crate) cwd: Option<String>,
+}
+
+/// Restores the previous task env and working directory of this thread once dropped
+pub(crate) struct CurrentTaskContextGuard {
+    _env_guard: CurrentTaskEnvGuard,
+    _cwd_guard: CurrentTaskCwdGuard,
+}
+
+#[derive(Debug, Default)]
+/// Holds the original process env vars and working directory modified by in process actions
+struct ProcessOverrides {
+    /// The modified env vars and their original values
+    vars: Vec<(OsString, Option<OsString>)>,
+    /// The original working directory
+    cwd: Option<PathBuf>,
+}
+
+/// Holds the process env vars and working directory modified by an in process action, and which
+/// of them were registered as process overrides by it
+struct AppliedOverrides {
+    /// The modified env vars and their previous values
+    vars: Vec<(OsString, Option<OsString>)>,
+    /// The previous working directory
+    cwd: Option<PathBuf>,
+    /// The env vars registered as process overrides
+    registered_vars: Vec<OsString>,
+    /// True if the working directory was registered as process override
+    registered_cwd: bool,
+}
+
+/// Releases the env lock once the outermost lock of this thread is dropped
+struct EnvLockGuard {
+    _guard: Option<MutexGuard<'static, ()>>,
+}
+
+impl Drop for EnvLockGuard {
+    fn drop(&mut self) {
+        ENV_LOCK_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Locks the process env, the lock is reentrant so actions invoked while this thread holds the
+/// lock (for example tasks invoked from duckscript) can lock it again
+fn lock_env() -> EnvLockGuard {
+    let locked = ENV_LOCK_DEPTH.with(|depth| {
+        let locked = depth.get() > 0;
+        depth.set(depth.get() + 1);
+        locked
+    });
+
+    if locked {
+        EnvLockGuard { _guard: None }
+    } else {
+        // a panic while holding the lock does not leave the process env in an invalid state
+        let guard = ENV_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+
+        EnvLockGuard {
+            _guard: Some(guard),
+        }
+    }
+}
+
+/// Releases the in process lock once the outermost lock of this thread is dropped
+struct InProcessLockGuard {
+    _guard: Option<MutexGuard<'static, ()>>,
+}
+
+impl Drop for InProcessLockGuard {
+    fn drop(&mut self) {
+        IN_PROCESS_LOCK_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Locks the in process actions, the lock is reentrant so in process actions can invoke tasks
+/// which run other in process actions
+fn lock_in_process() -> InProcessLockGuard {
+    let locked = IN_PROCESS_LOCK_DEPTH.with(|depth| {
+        let locked = depth.get() > 0;
+        depth.set(depth.get() + 1);
+        locked
+    });
+
+    if locked {
+        InProcessLockGuard { _guard: None }
+    } else {
+        let guard = IN_PROCESS_LOCK
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        InProcessLockGuard {
+            _guard: Some(guard),
+        }
+    }
+}
+
+/// Returns true if this thread holds the process env lock or runs an in process action, in which
+/// case other threads are not able to create task envs or run in process actions until it is
+/// released
+pub(crate) fn is_env_locked() -> bool {
+    ENV_LOCK_DEPTH.with(|depth| depth.get() > 0)
+        || IN_PROCESS_LOCK_DEPTH.with(|depth| depth.get() > 0)
+}
+
+/// Returns the original process env vars and working directory modified by an in process action
+/// of another thread (must be called while holding the env lock).<br>
+/// The process overrides of an in process action of this thread are part of its task env and
+/// are therefore not returned.
+fn get_foreign_overrides() -> Option<(Vec<(OsString, Option<OsString>)>, Option<PathBuf>)> {
+    if IN_PROCESS_LOCK_DEPTH.with(|depth| depth.get() > 0) {
+        return None;
+    }
+
+    let overrides = PROCESS_OVERRIDES
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    if overrides.vars.is_empty() && overrides.cwd.is_none() {
+        None
+    } else {
+        Some((overrides.vars.clone(), overrides.cwd.clone()))
+    }
+}
+
+/// Applies the task env on top of the process env
+fn set_vars(task_env: &TaskEnv) {
+    for key in &task_env.removed {
+        env::remove_var(key);
+    }
+    for (key, value) in &task_env.vars {
+        env::set_var(key, value);
+    }
+}
+
+/// Stops writing env updates to the task env of this thread once dropped
+struct CreateTaskEnvGuard {
+    previous: bool,
+}
+
+impl Drop for CreateTaskEnvGuard {
+    fn drop(&mut self) {
+        CREATING_TASK_ENV.with(|creating| creating.set(self.previous));
+    }
+}
+
+fn is_creating() -> bool {
+    CREATING_TASK_ENV.with(|creating| creating.get())
+}
+
+/// Invokes the action while all env updates (for example env files and env vars) are written
+/// to the provided task env instead of the process env, and returns the updated task env.
+fn update<T, F>(task_env: TaskEnv, action: F) -> (TaskEnv, T)
+where
+    F: FnOnce() -> T,
+{
+    let _env_guard = set_current(Some(task_env));
+    let _create_guard = CreateTaskEnvGuard {
+        previous: CREATING_TASK_ENV.with(|creating| creating.replace(true)),
+    };
+
+    let output = action();
+
+    (get_current().unwrap_or_default(), output)
+}
+
+/// Sets the env var in the task env which is currently created in this thread, or in the process
+/// env if no task env is created.
+pub(crate) fn set_var(key: &str, value: &str) {
+    if is_creating() {
+        CURRENT_TASK_ENV.with(|current| {
+            let mut current = current.borrow_mut();
+            let task_env = current.get_or_insert_with(TaskEnv::default);
+            task_env.removed.retain(|removed_key| removed_key != key);
+            task_env.vars.insert(key.to_string(), value.to_string());
+        });
+    } else {
+        let _lock = lock_env();
+        env::set_var(key, value);
+    }
+}
+
+/// Removes the env var from the task env which is currently created in this thread, or from the
+/// process env if no task env is created.
+pub(crate) fn remove_var(key: &str) {
+    if is_creating() {
+        CURRENT_TASK_ENV.with(|current| {
+            let mut current = current.borrow_mut();
+            let task_env = current.get_or_insert_with(TaskEnv::default);
+            task_env.vars.shift_remove(key);
+            if !task_env
+                .removed
+                .iter()
+                .any(|removed_key| removed_key == key)
+            {
+                task_env.removed.push(key.to_string());
+            }
+        });
+    } else {
+        let _lock = lock_env();
+        env::remove_var(key);
+    }
+}
+
+/// Creates the task env by applying the task env files and env vars on top of the task env of
+/// the current thread (for example the env of the parent task of a sub flow).<br>
+/// The task env is evaluated against itself and the process env, which is never modified.<br>
+/// The provided action is invoked while the task env is set, so it can use it (for example to
+/// expand the task attributes).
+pub(crate) fn create<T, F>(step: &Step, action: F) -> Result<(TaskEnv, T), CargoMakeError>
+where
+    F: FnOnce() -> T,
+{
+    let (mut task_env, result) = update(get_meta_info(step), || set_step_env(step));
+    result?;
+
+    //make sure profile env is not overwritten
+    task_env.vars.shift_remove(profile::PROFILE_ENV_KEY);
+    task_env
+        .removed
+        .retain(|key| key != profile::PROFILE_ENV_KEY);
+
+    task_env.vars.sort_keys();
+    task_env.removed.sort();
+
+    let output = {
+        let _env_guard = set_current(Some(task_env.clone()));
+        action()
+    };
+
+    Ok((task_env, output))
+}
+
+fn set_step_env(step: &Step) -> Result<(), CargoMakeError> {
+    if let Some(ref env_files) = step.config.env_files {
+        environment::set_env_files(env_files.clone())?;
+    }
+    if let Some(ref env) = step.config.env {
+        environment::set_env(env.clone())?;
+    }
+
+    Ok(())
+}
+
+/// Returns the task env of the current thread with the task meta info env vars
+/// (CARGO_MAKE_CURRENT_TASK_*) of the provided step
+fn get_meta_info(step: &Step) -> TaskEnv {
+    let task_env = get_current().unwrap_or_default();
+
+    let (task_env, _) = update(task_env, || {
+        set_var("CARGO_MAKE_CURRENT_TASK_NAME", &step.name);
+
+        if let Some(ref env) = step.config.env {
+            environment::set_current_task_meta_info_env(env.clone());
+        }
+    });
+
+    task_env
+}
+
+/// Sets the task meta info env vars (CARGO_MAKE_CURRENT_TASK_*) of the provided step on top of
+/// the task env of the current thread (for example to evaluate the task condition).<br>
+/// The previous task env is restored once the returned guard is dropped.
+pub(crate) fn set_current_meta_info(step: &Step) -> CurrentTaskEnvGuard {
+    set_current(Some(get_meta_info(step)))
+}
+
+/// Sets the task env of the task currently running in this thread.<br>
+/// The previous task env is restored once the returned guard is dropped.
+pub(crate) fn set_current(task_env: Option<TaskEnv>) -> CurrentTaskEnvGuard {
+    let previous = CURRENT_TASK_ENV.with(|current| current.replace(task_env));
+
+    CurrentTaskEnvGuard { previous }
+}
+
+/// Returns the task env of the task currently running in this thread
+pub(crate) fn get_current() -> Option<TaskEnv> {
+    CURRENT_TASK_ENV.with(|current| current.borrow().clone())
+}
+
+/// Returns the task env and working directory of this thread
+pub(crate) fn get_current_context() -> TaskContext {
+    TaskContext {
+        env: get_current(),
+        cwd: get_current_cwd(),
+    }
+}
+
+/// Sets the task env and working directory of this thread (for example in a thread started by
+/// a task).<br>
+/// The previous values are restored once the returned guard is dropped.
+pub(crate) fn set_current_context(context: TaskContext) -> CurrentTaskContextGuard {
+    CurrentTaskContextGuard {
+        _env_guard: set_current(context.env),
+        _cwd_guard: set_current_cwd(context.cwd),
+    }
+}
+
+/// Sets the working directory of the task currently running in this thread.<br>
+/// The previous working directory is restored once the returned guard is dropped.
+pub(crate) fn set_current_cwd(cwd: Option<String>) -> CurrentTaskCwdGuard {
+    let previous = CURRENT_TASK_CWD.with(|current| current.replace(cwd));
+
+    CurrentTaskCwdGuard { previous }
+}
+
+/// Returns the working directory of the task currently running in this thread
+pub(crate) fn get_current_cwd() -> Option<String> {
+    CURRENT_TASK_CWD.with(|current| current.borrow().clone())
+}
+
+/// Returns the working directory of the task currently running in this thread, or the process
+/// working directory (read while holding the env lock, as in process actions may temporarily
+/// change it) if the task does not define one
+pub(crate) fn get_working_directory() -> Option<String> {
+    match get_current_cwd() {
+        Some(cwd) => Some(cwd),
+        None => with_env_lock(|| env::current_dir())
+            .ok()
+            .map(|directory| directory.to_string_lossy().into_owned()),
+    }
+}
+
+/// Resolves relative paths from the working directory of the task currently running in this
+/// thread (or the process working directory if the task does not define one)
+pub(crate) fn resolve_path(path: &str) -> PathBuf {
+    match get_current_cwd() {
+        Some(ref cwd) if Path::new(path).is_relative() => Path::new(cwd).join(path),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Returns the env var value as seen by the task currently running in this thread
+pub(crate) fn get_var(key: &str) -> Option<String> {
+    match get_current() {
+        Some(task_env) => match task_env.vars.get(key) {
+            Some(value) => Some(value.to_string()),
+            None if task_env
+                .removed
+                .iter()
+                .any(|removed_key| removed_key == key) =>
+            {
+                None
+            }
+            None => env::var(key).ok(),
+        },
+        None => env::var(key).ok(),
+    }
+}
+
+/// Applies the task env and working directory of the task currently running in this thread to
+/// the given command.<br>
+/// Only the env vars which differ from the process env are set on the command, the rest is
+/// inherited from the process env once the command is spawned (see [spawn] and [output]).
+pub(crate) fn apply_to_command(command: &mut Command) {
+    if let Some(task_env) = get_current() {
+        for key in &task_env.removed {
+            command.env_remove(key);
+        }
+        command.envs(&task_env.vars);
+    }
+
+    if let Some(cwd) = get_current_cwd() {
+        command.current_dir(&cwd);
+        command.env("CARGO_MAKE_WORKING_DIRECTORY", &cwd);
+    }
+}
+
+/// Spawns the command while holding the env lock, so it does not inherit env vars and working
+/// directory temporarily set by tasks running in other threads.
+pub(crate) fn spawn(command: &mut Command) -> io::Result<Child> {
+    with_env_lock(|| {
+        if let Some((vars, cwd)) = get_foreign_overrides() {
+            for (key, value) in vars {
+                if command
+                    .get_envs()
+                    .any(|(command_key, _)| command_key == key)
+                {
+                    continue;
+                }
+
+                match value {
+                    Some(value) => command.env(key, value),
+                    None => command.env_remove(key),
+                };
+            }
+
+            if let (None, Some(cwd)) = (command.get_current_dir(), cwd) {
+                command.current_dir(cwd);
+            }
+        }
+
+        command.spawn()
+    })
+}
+
+/// Spawns the command (see [spawn]) with piped stdout and stderr and waits for its output.
+pub(crate) fn output(command: &mut Command) -> io::Result<Output> {
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    spawn(command).and_then(|child| child.wait_with_output())
+}
+
+/// Returns the env vars which should be added to script processes started by the task currently
+/// running in this thread.<br>
+/// Env vars removed by the task are not part of it, see [spawn_script].
+pub(crate) fn get_script_env_vars() -> Option<HashMap<String, String>> {
+    let mut env_vars: HashMap<String, String> = HashMap::new();
+
+    if let Some(task_env) = get_current() {
+        env_vars.extend(task_env.vars.into_iter());
+    }
+
+    if let Some(cwd) = get_current_cwd() {
+        env_vars.insert("CARGO_MAKE_WORKING_DIRECTORY".to_string(), cwd);
+    }
+
+    if env_vars.is_empty() {
+        None
+    } else {
+        Some(env_vars)
+    }
+}
+
+/// Spawns a script process, which inherits the process env, while holding the env lock.<br>
+/// The spawn action gets the env vars to add to the script process (see [get_script_env_vars])
+/// and its working directory.<br>
+/// Script runners only support adding env vars, so the env vars removed by the task are removed
+/// from the process env while the script process is spawned.
+pub(crate) fn spawn_script<T, F>(spawn: F) -> T
+where
+    F: FnOnce(Option<HashMap<String, String>>, Option<PathBuf>) -> T,
+{
+    with_env_lock(|| {
+        let mut env_vars = get_script_env_vars().unwrap_or_default();
+        let mut removed: Vec<OsString> = vec![];
+        let mut cwd = get_current_cwd().map(PathBuf::from);
+
+        if let Some((vars, original_cwd)) = get_foreign_overrides() {
+            for (key, value) in vars {
+                let key_string = key.to_string_lossy().into_owned();
+                if env_vars.contains_key(&key_string) {
+                    continue;
+                }
+
+                match value {
+                    Some(value) => {
+                        env_vars.insert(key_string, value.to_string_lossy().into_owned());
+                    }
+                    None => removed.push(key),
+                };
+            }
+
+            if cwd.is_none() {
+                cwd = original_cwd;
+            }
+        }
+
+        if let Some(task_env) = get_current() {
+            removed.extend(task_env.removed.into_iter().map(OsString::from));
+        }
+
+        let previous: Vec<(OsString, OsString)> = removed
+            .iter()
+            .filter_map(|key| env::var_os(key).map(|value| (key.clone(), value)))
+            .collect();
+        for (key, _) in &previous {
+            env::remove_var(key);
+        }
+
+        let env_vars = if env_vars.is_empty() {
+            None
+        } else {
+            Some(env_vars)
+        };
+        let output = spawn(env_vars, cwd);
+
+        for (key, value) in previous {
+            env::set_var(key, value);
+        }
+
+        output
+    })
+}
+
+/// Invokes the action while holding the env lock, for example to spawn a process which inherits
+/// the process env.
+pub(crate) fn with_env_lock<T, F>(action: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let _lock = lock_env();
+
+    action()
+}
+
+/// Applies the task env to the process env.
+pub(crate) fn export(task_env: &TaskEnv) {
+    let _lock = lock_env();
+
+    set_vars(task_env);
+}
+
+/// Invokes the action while the task env and working directory of the task currently running in
+/// this thread are applied to the process.<br>
+/// This is used for actions which run within the cargo-make process and read the process env
+/// and working directory (for example duckscript scripts and plugins).<br>
+/// In process actions are serialized, while the process env lock is only held while the task env
+/// is applied and reverted, so other threads can keep creating task envs and spawning processes.
+/// The original values are registered so processes spawned by other threads do not inherit the
+/// applied values.<br>
+/// Once done, the process working directory and only the env vars modified by applying the task
+/// env are restored.
+pub(crate) fn apply_current<T, F>(action: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let task_env = get_current().unwrap_or_default();
+    let cwd = get_current_cwd();
+    if task_env.is_empty() && cwd.is_none() {
+        return action();
+    }
+
+    let _in_process_lock = lock_in_process();
+
+    let applied = {
+        let _lock = lock_env();
+        apply_to_process(&task_env, &cwd)
+    };
+
+    let output = action();
+
+    {
+        let _lock = lock_env();
+        revert_from_process(applied);
+    }
+
+    output
+}
+
+/// Applies the task env and working directory to the process and registers the original values
+/// as process overrides (must be called while holding the env lock)
+fn apply_to_process(task_env: &TaskEnv, cwd: &Option<String>) -> AppliedOverrides {
+    let original_env: HashMap<OsString, OsString> = env::vars_os().collect();
+
+    set_vars(task_env);
+
+    let previous_cwd = match cwd {
+        Some(ref directory) => {
+            let current_directory = env::current_dir().ok();
+            if let Err(error) = environment::setup_cwd(Some(directory)) {
+                warn!("{}", error);
+            }
+            current_directory
+        }
+        None => None,
+    };
+
+    // the working directory setup also modifies env vars, so all modified env vars are collected
+    let mut vars = vec![];
+    for (key, value) in env::vars_os() {
+        if original_env.get(&key) != Some(&value) {
+            vars.push((key.clone(), original_env.get(&key).cloned()));
+        }
+    }
+    for (key, value) in original_env {
+        if env::var_os(&key).is_none() {
+            vars.push((key, Some(value)));
+        }
+    }
+
+    let mut overrides = PROCESS_OVERRIDES
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let mut registered_vars = vec![];
+    for (key, value) in &vars {
+        if !overrides
+            .vars
+            .iter()
+            .any(|(override_key, _)| override_key == key)
+        {
+            overrides.vars.push((key.clone(), value.clone()));
+            registered_vars.push(key.clone());
+        }
+    }
+    let registered_cwd = previous_cwd.is_some() && overrides.cwd.is_none();
+    if registered_cwd {
+        overrides.cwd = previous_cwd.clone();
+    }
+
+    AppliedOverrides {
+        vars,
+        cwd: previous_cwd,
+        registered_vars,
+        registered_cwd,
+    }
+}
+
+/// Reverts the process env and working directory modified by [apply_to_process] and removes the
+/// process overrides it registered (must be called while holding the env lock)
+fn revert_from_process(applied: AppliedOverrides) {
+    if let Some(directory) = applied.cwd {
+        if let Err(error) = env::set_current_dir(&directory) {
+            warn!(
+                "Unable to restore current working directory to: {} {:#?}",
+                directory.display(),
+                error
+            );
+        }
+    }
+
+    for (key, value) in applied.vars {
+        match value {
+            Some(value) => env::set_var(&key, value),
+            None => env::remove_var(&key),
+        }
+    }
+
+    let mut overrides = PROCESS_OVERRIDES
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    overrides
+        .vars
+        .retain(|(key, _)| !applied.registered_vars.contains(key));
+    if applied.registered_cwd {
+        overrides.cwd = None;
+    }
+}
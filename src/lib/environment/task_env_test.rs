@@ -0,0 +1,453 @@
+use super::*;
+
+use crate::types::{EnvValue, EnvValueUnset, Task};
+use std::ffi::OsStr;
+
+fn create_step(env: IndexMap<String, EnvValue>) -> Step {
+    let mut task = Task::new();
+    task.env = Some(env);
+
+    Step {
+        name: "test".to_string(),
+        config: task,
+    }
+}
+
+#[test]
+fn update_set_var() {
+    envmnt::remove("TASK_ENV_TEST_UPDATE_SET");
+
+    let (task_env, value) = update(TaskEnv::default(), || {
+        set_var("TASK_ENV_TEST_UPDATE_SET", "value");
+        get_var("TASK_ENV_TEST_UPDATE_SET")
+    });
+
+    assert_eq!(value, Some("value".to_string()));
+    assert_eq!(
+        task_env.vars.get("TASK_ENV_TEST_UPDATE_SET").unwrap(),
+        "value"
+    );
+    assert!(task_env.removed.is_empty());
+    assert!(!envmnt::exists("TASK_ENV_TEST_UPDATE_SET"));
+    assert!(get_current().is_none());
+}
+
+#[test]
+fn update_remove_var() {
+    let mut current_env = TaskEnv::default();
+    current_env.vars.insert(
+        "TASK_ENV_TEST_UPDATE_REMOVE".to_string(),
+        "value".to_string(),
+    );
+
+    let (task_env, value) = update(current_env, || {
+        remove_var("TASK_ENV_TEST_UPDATE_REMOVE");
+        get_var("TASK_ENV_TEST_UPDATE_REMOVE")
+    });
+
+    assert!(value.is_none());
+    assert!(task_env.vars.is_empty());
+    assert_eq!(
+        task_env.removed,
+        vec!["TASK_ENV_TEST_UPDATE_REMOVE".to_string()]
+    );
+}
+
+#[test]
+fn create_meta_info() {
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_TASK_ENV_TEST".to_string(),
+        EnvValue::Value("meta".to_string()),
+    );
+    let step = create_step(env);
+
+    let (task_env, value) = create(&step, || get_var("CARGO_MAKE_CURRENT_TASK_NAME")).unwrap();
+
+    assert_eq!(value, Some("test".to_string()));
+    assert_eq!(
+        task_env
+            .vars
+            .get("CARGO_MAKE_CURRENT_TASK_TASK_ENV_TEST")
+            .unwrap(),
+        "meta"
+    );
+    assert!(!envmnt::exists("CARGO_MAKE_CURRENT_TASK_TASK_ENV_TEST"));
+}
+
+#[test]
+fn set_current_restore_on_drop() {
+    assert!(get_current().is_none());
+
+    let mut outer_env = TaskEnv::default();
+    outer_env
+        .vars
+        .insert("KEY".to_string(), "outer".to_string());
+
+    {
+        let _outer_guard = set_current(Some(outer_env.clone()));
+        assert_eq!(get_current(), Some(outer_env.clone()));
+
+        {
+            let _inner_guard = set_current(Some(TaskEnv::default()));
+            assert_eq!(get_current(), Some(TaskEnv::default()));
+        }
+
+        assert_eq!(get_current(), Some(outer_env));
+    }
+
+    assert!(get_current().is_none());
+}
+
+#[test]
+fn get_var_from_current() {
+    let mut task_env = TaskEnv::default();
+    task_env
+        .vars
+        .insert("TASK_ENV_TEST_GET_VAR".to_string(), "task".to_string());
+    task_env.removed.push("CARGO_MAKE".to_string());
+
+    assert!(get_var("TASK_ENV_TEST_GET_VAR").is_none());
+    assert!(get_var("CARGO_MAKE").is_some());
+
+    let _guard = set_current(Some(task_env));
+
+    assert_eq!(get_var("TASK_ENV_TEST_GET_VAR").unwrap(), "task");
+    assert!(get_var("CARGO_MAKE").is_none());
+}
+
+#[test]
+fn set_current_cwd_restore_on_drop() {
+    assert!(get_current_cwd().is_none());
+
+    {
+        let _guard = set_current_cwd(Some("/test".to_string()));
+        assert_eq!(get_current_cwd().unwrap(), "/test");
+    }
+
+    assert!(get_current_cwd().is_none());
+}
+
+#[test]
+fn set_current_context_restore_on_drop() {
+    let mut task_env = TaskEnv::default();
+    task_env.vars.insert("KEY".to_string(), "value".to_string());
+
+    {
+        let _guard = set_current_context(TaskContext {
+            env: Some(task_env.clone()),
+            cwd: Some("/test".to_string()),
+        });
+
+        let context = get_current_context();
+        assert_eq!(context.env, Some(task_env));
+        assert_eq!(context.cwd, Some("/test".to_string()));
+    }
+
+    let context = get_current_context();
+    assert!(context.env.is_none());
+    assert!(context.cwd.is_none());
+}
+
+#[test]
+fn lock_env_reentrant() {
+    assert!(!is_env_locked());
+
+    {
+        let _outer_lock = lock_env();
+        assert!(is_env_locked());
+
+        {
+            let _inner_lock = lock_env();
+            assert!(is_env_locked());
+        }
+
+        assert!(is_env_locked());
+    }
+
+    assert!(!is_env_locked());
+}
+
+#[test]
+fn apply_current_holds_env_lock() {
+    let mut task_env = TaskEnv::default();
+    task_env
+        .vars
+        .insert("TASK_ENV_TEST_LOCKED".to_string(), "locked".to_string());
+    let _guard = set_current(Some(task_env));
+
+    let locked = apply_current(|| is_env_locked());
+
+    assert!(locked);
+    assert!(!is_env_locked());
+}
+
+#[test]
+fn resolve_path_no_cwd() {
+    assert_eq!(resolve_path("src"), PathBuf::from("src"));
+}
+
+#[test]
+fn resolve_path_relative() {
+    let _guard = set_current_cwd(Some("/test".to_string()));
+
+    assert_eq!(resolve_path("src"), Path::new("/test").join("src"));
+}
+
+#[test]
+fn resolve_path_absolute() {
+    let cwd = env::current_dir().unwrap();
+    let _guard = set_current_cwd(Some("/test".to_string()));
+
+    assert_eq!(resolve_path(&cwd.to_string_lossy()), cwd);
+}
+
+#[test]
+fn apply_to_command_no_cwd() {
+    let mut command = Command::new("test");
+
+    apply_to_command(&mut command);
+
+    assert!(command.get_current_dir().is_none());
+    assert_eq!(command.get_envs().count(), 0);
+}
+
+#[test]
+fn apply_to_command_with_cwd() {
+    let mut task_env = TaskEnv::default();
+    task_env
+        .vars
+        .insert("TASK_ENV_TEST_COMMAND".to_string(), "value".to_string());
+    let _env_guard = set_current(Some(task_env));
+    let _cwd_guard = set_current_cwd(Some("/test".to_string()));
+    let mut command = Command::new("test");
+
+    apply_to_command(&mut command);
+
+    assert_eq!(command.get_current_dir().unwrap(), Path::new("/test"));
+    let envs: Vec<_> = command.get_envs().collect();
+    assert!(envs.contains(&(
+        OsStr::new("TASK_ENV_TEST_COMMAND"),
+        Some(OsStr::new("value"))
+    )));
+}
+
+#[test]
+fn get_script_env_vars_with_cwd() {
+    let _guard = set_current_cwd(Some("/test".to_string()));
+
+    let env_vars = get_script_env_vars().unwrap();
+
+    assert_eq!(env_vars.len(), 1);
+    assert_eq!(
+        env_vars.get("CARGO_MAKE_WORKING_DIRECTORY").unwrap(),
+        "/test"
+    );
+}
+
+#[test]
+fn get_script_env_vars_none() {
+    assert!(get_script_env_vars().is_none());
+
+    let _guard = set_current(Some(TaskEnv::default()));
+
+    assert!(get_script_env_vars().is_none());
+}
+
+#[test]
+fn get_script_env_vars_with_values() {
+    let mut task_env = TaskEnv::default();
+    task_env
+        .vars
+        .insert("KEY1".to_string(), "value1".to_string());
+    task_env.removed.push("KEY2".to_string());
+    let _guard = set_current(Some(task_env));
+
+    let env_vars = get_script_env_vars().unwrap();
+
+    assert_eq!(env_vars.len(), 1);
+    assert_eq!(env_vars.get("KEY1").unwrap(), "value1");
+    assert!(!env_vars.contains_key("KEY2"));
+}
+
+#[test]
+#[ignore]
+fn spawn_script_removes_task_removed_vars() {
+    envmnt::set("TASK_ENV_TEST_SCRIPT_REMOVED", "value");
+
+    let mut task_env = TaskEnv::default();
+    task_env
+        .vars
+        .insert("TASK_ENV_TEST_SCRIPT".to_string(), "script".to_string());
+    task_env
+        .removed
+        .push("TASK_ENV_TEST_SCRIPT_REMOVED".to_string());
+    let _guard = set_current(Some(task_env));
+
+    let (env_vars, removed) =
+        spawn_script(|env_vars, _| (env_vars, !envmnt::exists("TASK_ENV_TEST_SCRIPT_REMOVED")));
+
+    assert_eq!(
+        env_vars.unwrap().get("TASK_ENV_TEST_SCRIPT").unwrap(),
+        "script"
+    );
+    assert!(removed);
+    assert!(envmnt::is_equal("TASK_ENV_TEST_SCRIPT_REMOVED", "value"));
+}
+
+#[test]
+#[ignore]
+fn create_process_env_not_modified() {
+    envmnt::set("TASK_ENV_TEST_EXISTING", "existing");
+    envmnt::set("TASK_ENV_TEST_UNSET", "unset");
+    envmnt::remove("TASK_ENV_TEST_NEW");
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TASK_ENV_TEST_NEW".to_string(),
+        EnvValue::Value("new-${TASK_ENV_TEST_EXISTING}".to_string()),
+    );
+    env.insert(
+        "TASK_ENV_TEST_EXISTING".to_string(),
+        EnvValue::Value("modified".to_string()),
+    );
+    env.insert(
+        "TASK_ENV_TEST_UNSET".to_string(),
+        EnvValue::Unset(EnvValueUnset { unset: true }),
+    );
+    let step = create_step(env);
+
+    let (task_env, value) =
+        create(&step, || get_var("TASK_ENV_TEST_NEW").unwrap_or_default()).unwrap();
+
+    assert_eq!(value, "new-existing");
+    assert_eq!(
+        task_env.vars.get("TASK_ENV_TEST_NEW").unwrap(),
+        "new-existing"
+    );
+    assert_eq!(
+        task_env.vars.get("TASK_ENV_TEST_EXISTING").unwrap(),
+        "modified"
+    );
+    assert_eq!(task_env.removed, vec!["TASK_ENV_TEST_UNSET".to_string()]);
+
+    assert!(!envmnt::exists("TASK_ENV_TEST_NEW"));
+    assert!(envmnt::is_equal("TASK_ENV_TEST_EXISTING", "existing"));
+    assert!(envmnt::is_equal("TASK_ENV_TEST_UNSET", "unset"));
+}
+
+#[test]
+#[ignore]
+fn create_from_current_task_env() {
+    envmnt::remove("TASK_ENV_TEST_PARENT");
+    envmnt::remove("TASK_ENV_TEST_CHILD");
+
+    let mut parent_env = TaskEnv::default();
+    parent_env
+        .vars
+        .insert("TASK_ENV_TEST_PARENT".to_string(), "parent".to_string());
+    let _guard = set_current(Some(parent_env));
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TASK_ENV_TEST_CHILD".to_string(),
+        EnvValue::Value("child-${TASK_ENV_TEST_PARENT}".to_string()),
+    );
+    let step = create_step(env);
+
+    let (task_env, _) = create(&step, || ()).unwrap();
+
+    assert_eq!(task_env.vars.get("TASK_ENV_TEST_PARENT").unwrap(), "parent");
+    assert_eq!(
+        task_env.vars.get("TASK_ENV_TEST_CHILD").unwrap(),
+        "child-parent"
+    );
+
+    assert!(!envmnt::exists("TASK_ENV_TEST_PARENT"));
+    assert!(!envmnt::exists("TASK_ENV_TEST_CHILD"));
+}
+
+#[test]
+#[ignore]
+fn export_modifies_process_env() {
+    envmnt::remove("TASK_ENV_TEST_EXPORT");
+    envmnt::set("TASK_ENV_TEST_EXPORT_REMOVED", "value");
+
+    let mut task_env = TaskEnv::default();
+    task_env
+        .vars
+        .insert("TASK_ENV_TEST_EXPORT".to_string(), "exported".to_string());
+    task_env
+        .removed
+        .push("TASK_ENV_TEST_EXPORT_REMOVED".to_string());
+
+    export(&task_env);
+
+    assert!(envmnt::is_equal("TASK_ENV_TEST_EXPORT", "exported"));
+    assert!(!envmnt::exists("TASK_ENV_TEST_EXPORT_REMOVED"));
+}
+
+#[test]
+#[ignore]
+fn apply_current_restores_process_env() {
+    envmnt::remove("TASK_ENV_TEST_APPLY");
+    envmnt::set("TASK_ENV_TEST_APPLY_REMOVED", "value");
+
+    let mut task_env = TaskEnv::default();
+    task_env
+        .vars
+        .insert("TASK_ENV_TEST_APPLY".to_string(), "applied".to_string());
+    task_env
+        .removed
+        .push("TASK_ENV_TEST_APPLY_REMOVED".to_string());
+    let _guard = set_current(Some(task_env));
+
+    let (value, removed) = apply_current(|| {
+        (
+            envmnt::get_or("TASK_ENV_TEST_APPLY", ""),
+            !envmnt::exists("TASK_ENV_TEST_APPLY_REMOVED"),
+        )
+    });
+
+    assert_eq!(value, "applied");
+    assert!(removed);
+    assert!(!envmnt::exists("TASK_ENV_TEST_APPLY"));
+    assert!(envmnt::is_equal("TASK_ENV_TEST_APPLY_REMOVED", "value"));
+}
+
+#[test]
+#[ignore]
+fn apply_current_other_threads_get_original_values() {
+    envmnt::set("TASK_ENV_TEST_APPLY_ORIGINAL", "original");
+    envmnt::remove("TASK_ENV_TEST_APPLY_NEW");
+
+    let mut task_env = TaskEnv::default();
+    task_env.vars.insert(
+        "TASK_ENV_TEST_APPLY_ORIGINAL".to_string(),
+        "applied".to_string(),
+    );
+    task_env
+        .vars
+        .insert("TASK_ENV_TEST_APPLY_NEW".to_string(), "new".to_string());
+    let _guard = set_current(Some(task_env));
+
+    // the other thread would wait for the env lock if it was held during the whole action
+    let (env_vars, new_removed) = apply_current(|| {
+        std::thread::spawn(|| {
+            spawn_script(|env_vars, _| (env_vars, !envmnt::exists("TASK_ENV_TEST_APPLY_NEW")))
+        })
+        .join()
+        .unwrap()
+    });
+
+    assert_eq!(
+        env_vars
+            .unwrap()
+            .get("TASK_ENV_TEST_APPLY_ORIGINAL")
+            .unwrap(),
+        "original"
+    );
+    assert!(new_removed);
+    assert!(envmnt::is_equal("TASK_ENV_TEST_APPLY_ORIGINAL", "original"));
+    assert!(!envmnt::exists("TASK_ENV_TEST_APPLY_NEW"));
+}
@@ -0,0 +1,96 @@
+//! # error
+//!
+//! The cargo-make error type.<br>
+//! Each error kind is mapped to a documented process exit code.
+//!
+
+#[cfg(test)]
+#[path = "error_test.rs"]
+mod error_test;
+
+use std::error::Error;
+use std::fmt;
+
+/// Exit code for all errors which do not have a dedicated exit code
+pub const EXIT_CODE_GENERAL_ERROR: i32 = 1;
+/// Exit code for makefile errors (parse errors, unsupported min_version, invalid tasks)
+pub const EXIT_CODE_MAKEFILE_ERROR: i32 = 3;
+/// Exit code in case the requested task (or one of its dependencies) is not defined
+pub const EXIT_CODE_TASK_NOT_FOUND: i32 = 4;
+/// Exit code in case a task command or script failed
+pub const EXIT_CODE_TASK_FAILED: i32 = 5;
+/// Exit code in case a task dependency (crate, rustup component, ...) could not be installed
+pub const EXIT_CODE_INSTALLER_FAILED: i32 = 6;
+
+static VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds the cargo-make errors
+pub enum CargoMakeError {
+    /// Unable to find or parse a makefile
+    DescriptorParseError(String),
+    /// The makefile requires a newer cargo-make version (holds the minimum required version)
+    VersionTooOld(String),
+    /// Invalid task definition (for example alias or dependency cycles)
+    InvalidTask(String),
+    /// The requested task is not defined
+    TaskNotFound(String),
+    /// A task command or script failed
+    TaskFailed(String),
+    /// Unable to install a task dependency
+    InstallerFailed(String),
+    /// Any other error
+    Other(String),
+}
+
+impl CargoMakeError {
+    /// Returns the process exit code of the error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CargoMakeError::DescriptorParseError(_)
+            | CargoMakeError::VersionTooOld(_)
+            | CargoMakeError::InvalidTask(_) => EXIT_CODE_MAKEFILE_ERROR,
+            CargoMakeError::TaskNotFound(_) => EXIT_CODE_TASK_NOT_FOUND,
+            CargoMakeError::TaskFailed(_) => EXIT_CODE_TASK_FAILED,
+            CargoMakeError::InstallerFailed(_) => EXIT_CODE_INSTALLER_FAILED,
+            CargoMakeError::Other(_) => EXIT_CODE_GENERAL_ERROR,
+        }
+    }
+
+    /// Creates the error matching the exit code of a cargo-make sub process
+    pub(crate) fn from_exit_code(exit_code: i32, message: &str) -> CargoMakeError {
+        let message = message.to_string();
+
+        if exit_code == EXIT_CODE_MAKEFILE_ERROR {
+            CargoMakeError::DescriptorParseError(message)
+        } else if exit_code == EXIT_CODE_TASK_NOT_FOUND {
+            CargoMakeError::TaskNotFound(message)
+        } else if exit_code == EXIT_CODE_INSTALLER_FAILED {
+            CargoMakeError::InstallerFailed(message)
+        } else if exit_code == EXIT_CODE_GENERAL_ERROR {
+            CargoMakeError::Other(message)
+        } else {
+            CargoMakeError::TaskFailed(message)
+        }
+    }
+}
+
+impl fmt::Display for CargoMakeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CargoMakeError::VersionTooOld(min_version) => write!(
+                formatter,
+                "cargo-make version: {} does not meet minimum required version: {}",
+                VERSION, min_version
+            ),
+            CargoMakeError::DescriptorParseError(message)
+            | CargoMakeError::InvalidTask(message)
+            | CargoMakeError::TaskNotFound(message)
+            | CargoMakeError::TaskFailed(message)
+            | CargoMakeError::InstallerFailed(message)
+            | CargoMakeError::Other(message) => write!(formatter, "{}", message),
+        }
+    }
+}
+
+impl Error for CargoMakeError {}
@@ -0,0 +1,60 @@
+use super::*;
+
+#[test]
+fn exit_code_per_kind() {
+    assert_eq!(
+        CargoMakeError::DescriptorParseError("".to_string()).exit_code(),
+        3
+    );
+    assert_eq!(CargoMakeError::VersionTooOld("".to_string()).exit_code(), 3);
+    assert_eq!(CargoMakeError::InvalidTask("".to_string()).exit_code(), 3);
+    assert_eq!(CargoMakeError::TaskNotFound("".to_string()).exit_code(), 4);
+    assert_eq!(CargoMakeError::TaskFailed("".to_string()).exit_code(), 5);
+    assert_eq!(
+        CargoMakeError::InstallerFailed("".to_string()).exit_code(),
+        6
+    );
+    assert_eq!(CargoMakeError::Other("".to_string()).exit_code(), 1);
+}
+
+#[test]
+fn from_exit_code_known() {
+    for error in &[
+        CargoMakeError::DescriptorParseError("test".to_string()),
+        CargoMakeError::TaskNotFound("test".to_string()),
+        CargoMakeError::TaskFailed("test".to_string()),
+        CargoMakeError::InstallerFailed("test".to_string()),
+        CargoMakeError::Other("test".to_string()),
+    ] {
+        let output = CargoMakeError::from_exit_code(error.exit_code(), "test");
+
+        assert_eq!(&output, error);
+    }
+}
+
+#[test]
+fn from_exit_code_unknown() {
+    let output = CargoMakeError::from_exit_code(101, "test");
+
+    assert_eq!(output, CargoMakeError::TaskFailed("test".to_string()));
+}
+
+#[test]
+fn display_message() {
+    let error = CargoMakeError::TaskNotFound("Task test not found".to_string());
+
+    assert_eq!(error.to_string(), "Task test not found");
+}
+
+#[test]
+fn display_version_too_old() {
+    let error = CargoMakeError::VersionTooOld("1000.0.0".to_string());
+
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "cargo-make version: {} does not meet minimum required version: 1000.0.0",
+            env!("CARGO_PKG_VERSION")
+        )
+    );
+}
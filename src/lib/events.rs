@@ -0,0 +1,358 @@
+//! # events
+//!
+//! Emits machine readable (JSON lines) events of the flow execution.<br>
+//! Events are written to the stdout (json-events output format) and/or appended to an events file.<br>
+//! The settings are passed via env vars so sub processes (forked tasks, workspace members) write to the same stream.
+//!
+
+#[cfg(test)]
+#[path = "events_test.rs"]
+mod events_test;
+
+use crate::error::CargoMakeError;
+use crate::recursion_level;
+use crate::types::CliArgs;
+use envmnt;
+use serde_json;
+use std::cell::RefCell;
+use std::env;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static JSON_EVENTS_ENV_VAR_NAME: &str = "CARGO_MAKE_JSON_EVENTS";
+static EVENTS_FILE_ENV_VAR_NAME: &str = "CARGO_MAKE_EVENTS_FILE";
+
+/// Holds the start time of the currently running tasks (used for failed tasks duration)
+static TASKS_START_TIME: Mutex<Vec<(String, SystemTime)>> = Mutex::new(Vec::new());
+/// Holds the events emitted by this process while collecting events (used by the library API)
+static COLLECTED_EVENTS: Mutex<Option<Vec<Event>>> = Mutex::new(None);
+
+thread_local! {
+    /// Holds the exit code of the last failed command invoked by this thread
+    static FAILED_EXIT_CODE: RefCell<Option<i32>> = RefCell::new(None);
+    /// Holds the stderr tail of the last failed command invoked by this thread (if captured)
+    static STDERR_TAIL: RefCell<Option<String>> = RefCell::new(None);
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+/// The event type
+pub(crate) enum EventType {
+    /// Task invocation started
+    TaskStarted,
+    /// Task skipped due to unmet condition
+    TaskSkipped,
+    /// Task invocation done
+    TaskFinished,
+    /// The entire flow is done
+    FlowFinished,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Holds a single flow event
+pub(crate) struct Event {
+    /// The event type
+    pub(crate) event: EventType,
+    /// The task name
+    pub(crate) task: String,
+    /// The event time (milliseconds since epoch)
+    pub(crate) timestamp: u128,
+    /// The cargo-make recursion level (0 for top level)
+    pub(crate) level: u32,
+    /// The workspace member name (for workspace member flows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) member: Option<String>,
+    /// The condition fail message (for skipped tasks) or the error message (for failed tasks)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) fail_message: Option<String>,
+    /// The exit code (for finished tasks)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) exit_code: Option<i32>,
+    /// The last lines of the failed command stderr (for failed tasks)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) stderr: Option<String>,
+    /// The duration in milliseconds (for finished tasks/flows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) duration: Option<u128>,
+    /// True if the flow was successful (for finished flows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) success: Option<bool>,
+}
+
+fn get_workspace_member() -> Option<String> {
+    match env::var("CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER") {
+        Ok(member) if !member.is_empty() => Some(member),
+        _ => None,
+    }
+}
+
+impl Event {
+    fn new(event: EventType, task: &str) -> Event {
+        let timestamp = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(value) => value.as_millis(),
+            Err(_) => 0,
+        };
+
+        Event {
+            event,
+            task: task.to_string(),
+            timestamp,
+            level: recursion_level::get(),
+            member: get_workspace_member(),
+            fail_message: None,
+            exit_code: None,
+            stderr: None,
+            duration: None,
+            success: None,
+        }
+    }
+}
+
+fn is_collecting() -> bool {
+    match COLLECTED_EVENTS.lock() {
+        Ok(collected_events) => collected_events.is_some(),
+        Err(_) => false,
+    }
+}
+
+/// Returns true if events are written to the stdout (json-events output format)
+pub(crate) fn is_json_events() -> bool {
+    envmnt::is(JSON_EVENTS_ENV_VAR_NAME)
+}
+
+/// Returns true if the given output line is a serialized event
+pub(crate) fn is_event_line(line: &[u8]) -> bool {
+    serde_json::from_slice::<Event>(line).is_ok()
+}
+
+/// Returns true if events are written to the stdout or to an events file or are collected
+pub(crate) fn is_enabled() -> bool {
+    envmnt::is(JSON_EVENTS_ENV_VAR_NAME)
+        || envmnt::exists(EVENTS_FILE_ENV_VAR_NAME)
+        || is_collecting()
+}
+
+/// Starts collecting all events emitted by this process
+pub(crate) fn start_collecting() {
+    if let Ok(mut collected_events) = COLLECTED_EVENTS.lock() {
+        *collected_events = Some(vec![]);
+    }
+}
+
+/// Stops collecting events and returns all events collected so far
+pub(crate) fn stop_collecting() -> Vec<Event> {
+    match COLLECTED_EVENTS.lock() {
+        Ok(mut collected_events) => collected_events.take().unwrap_or_default(),
+        Err(_) => vec![],
+    }
+}
+
+fn get_duration(start_time: SystemTime) -> u128 {
+    match start_time.elapsed() {
+        Ok(elapsed) => elapsed.as_millis(),
+        Err(_) => 0,
+    }
+}
+
+fn write_to_file(file: &str, line: &str) {
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file)
+        .and_then(|mut events_file| writeln!(events_file, "{}", line));
+
+    if let Err(error) = result {
+        debug!(
+            "Unable to write to events file: {} error: {:#?}",
+            file, error
+        );
+    }
+}
+
+fn emit(event: &Event) {
+    if let Ok(mut collected_events) = COLLECTED_EVENTS.lock() {
+        if let Some(ref mut events) = *collected_events {
+            events.push(event.clone());
+        }
+    }
+
+    match serde_json::to_string(event) {
+        Ok(line) => {
+            if is_json_events() {
+                println!("{}", &line);
+            }
+
+            if let Some(file) = get_events_file() {
+                write_to_file(&file, &line);
+            }
+        }
+        Err(error) => debug!("Unable to serialize event: {:#?}", error),
+    }
+}
+
+fn remove_start_time(task: &str) -> Option<SystemTime> {
+    match TASKS_START_TIME.lock() {
+        Ok(mut tasks_start_time) => {
+            match tasks_start_time.iter().rposition(|entry| entry.0 == task) {
+                Some(index) => Some(tasks_start_time.remove(index).1),
+                None => None,
+            }
+        }
+        Err(_) => None,
+    }
+}
+
+fn take_stderr_tail() -> Option<String> {
+    STDERR_TAIL.with(|stderr_tail| stderr_tail.borrow_mut().take())
+}
+
+/// Sets the stderr tail of the last invoked command, to be attached to the next failed task event
+pub(crate) fn set_stderr_tail(tail: String) {
+    STDERR_TAIL.with(|stderr_tail| *stderr_tail.borrow_mut() = Some(tail));
+}
+
+fn take_failed_exit_code() -> Option<i32> {
+    FAILED_EXIT_CODE.with(|failed_exit_code| failed_exit_code.borrow_mut().take())
+}
+
+/// Sets the exit code of the last failed command, to be attached to the next failed task event
+pub(crate) fn set_failed_exit_code(exit_code: i32) {
+    FAILED_EXIT_CODE.with(|failed_exit_code| *failed_exit_code.borrow_mut() = Some(exit_code));
+}
+
+/// Reads all events from the given events file, starting at the given byte offset
+pub(crate) fn read_events_file(file: &str, offset: u64) -> Vec<Event> {
+    let content = match fs::read(file) {
+        Ok(content) => content,
+        Err(error) => {
+            debug!("Unable to read events file: {} error: {:#?}", file, error);
+            return vec![];
+        }
+    };
+
+    let start = if (offset as usize) <= content.len() {
+        offset as usize
+    } else {
+        0
+    };
+
+    String::from_utf8_lossy(&content[start..])
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(event) => Some(event),
+            Err(error) => {
+                debug!("Unable to parse event: {} error: {:#?}", line, error);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the events file path (if defined)
+pub(crate) fn get_events_file() -> Option<String> {
+    match env::var(EVENTS_FILE_ENV_VAR_NAME) {
+        Ok(file) if !file.is_empty() => Some(file),
+        _ => None,
+    }
+}
+
+/// Sets the events file path which is shared with all sub processes
+pub(crate) fn set_events_file(file: &str) {
+    envmnt::set(EVENTS_FILE_ENV_VAR_NAME, file);
+}
+
+/// Emits the task started event
+pub(crate) fn task_started(task: &str) {
+    if is_enabled() {
+        take_failed_exit_code();
+        take_stderr_tail();
+
+        if let Ok(mut tasks_start_time) = TASKS_START_TIME.lock() {
+            tasks_start_time.push((task.to_string(), SystemTime::now()));
+        }
+
+        emit(&Event::new(EventType::TaskStarted, task));
+    }
+}
+
+/// Emits the task skipped event
+pub(crate) fn task_skipped(task: &str, fail_message: &str) {
+    if is_enabled() {
+        let mut event = Event::new(EventType::TaskSkipped, task);
+        event.fail_message = Some(fail_message.to_string());
+
+        emit(&event);
+    }
+}
+
+/// Emits the task finished event
+pub(crate) fn task_finished(task: &str, exit_code: i32, start_time: SystemTime) {
+    if is_enabled() {
+        remove_start_time(task);
+        take_failed_exit_code();
+        take_stderr_tail();
+
+        let mut event = Event::new(EventType::TaskFinished, task);
+        event.exit_code = Some(exit_code);
+        event.duration = Some(get_duration(start_time));
+
+        emit(&event);
+    }
+}
+
+/// Emits the task finished event for a started task which failed with the given error.<br>
+/// The exit code is taken from the last failed command (if any), otherwise from the error.<br>
+/// Nothing is emitted if the task was not started or already finished.
+pub(crate) fn task_failed(task: &str, error: &CargoMakeError) {
+    if is_enabled() {
+        let exit_code = take_failed_exit_code();
+        let stderr = take_stderr_tail();
+
+        if let Some(start_time) = remove_start_time(task) {
+            let mut event = Event::new(EventType::TaskFinished, task);
+            event.fail_message = Some(error.to_string());
+            event.exit_code = Some(exit_code.unwrap_or_else(|| error.exit_code()));
+            event.stderr = stderr;
+            event.duration = Some(get_duration(start_time));
+
+            emit(&event);
+        }
+    }
+}
+
+/// Emits the flow finished event
+pub(crate) fn flow_finished(task: &str, success: bool, start_time: Option<SystemTime>) {
+    if is_enabled() {
+        let mut event = Event::new(EventType::FlowFinished, task);
+        event.success = Some(success);
+        event.duration = start_time.map(get_duration);
+
+        emit(&event);
+    }
+}
+
+pub(crate) fn init(cli_args: &CliArgs) {
+    if cli_args.output_format == "json-events" {
+        envmnt::set_bool(JSON_EVENTS_ENV_VAR_NAME, true);
+    }
+
+    if let Some(ref file) = cli_args.events_file {
+        // sub processes might run in other directories
+        let file_path = Path::new(file);
+        let absolute_path = if file_path.is_absolute() {
+            file_path.to_path_buf()
+        } else {
+            match env::current_dir() {
+                Ok(directory) => directory.join(file_path),
+                Err(_) => file_path.to_path_buf(),
+            }
+        };
+
+        set_events_file(&absolute_path.to_string_lossy());
+    }
+}
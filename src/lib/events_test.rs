@@ -0,0 +1,249 @@
+use super::*;
+use crate::error::EXIT_CODE_INSTALLER_FAILED;
+use crate::test;
+use fsio::file::read_text_file;
+
+#[test]
+fn event_new() {
+    let event = Event::new(EventType::TaskStarted, "test");
+
+    assert_eq!(event.event, EventType::TaskStarted);
+    assert_eq!(event.task, "test");
+    assert!(event.timestamp > 0);
+    assert!(event.fail_message.is_none());
+    assert!(event.exit_code.is_none());
+    assert!(event.duration.is_none());
+    assert!(event.success.is_none());
+}
+
+#[test]
+fn event_serialize_task_started() {
+    let mut event = Event::new(EventType::TaskStarted, "test");
+    event.timestamp = 100;
+    event.level = 0;
+
+    let output = serde_json::to_string(&event).unwrap();
+
+    assert_eq!(
+        output,
+        r#"{"event":"task_started","task":"test","timestamp":100,"level":0}"#
+    );
+}
+
+#[test]
+fn event_serialize_task_finished() {
+    let mut event = Event::new(EventType::TaskFinished, "test");
+    event.timestamp = 100;
+    event.level = 1;
+    event.exit_code = Some(2);
+    event.duration = Some(50);
+
+    let output = serde_json::to_string(&event).unwrap();
+
+    assert_eq!(
+        output,
+        r#"{"event":"task_finished","task":"test","timestamp":100,"level":1,"exit_code":2,"duration":50}"#
+    );
+}
+
+#[test]
+fn event_serialize_task_skipped() {
+    let mut event = Event::new(EventType::TaskSkipped, "test");
+    event.timestamp = 100;
+    event.level = 0;
+    event.fail_message = Some("message".to_string());
+
+    let output = serde_json::to_string(&event).unwrap();
+
+    assert_eq!(
+        output,
+        r#"{"event":"task_skipped","task":"test","timestamp":100,"level":0,"fail_message":"message"}"#
+    );
+}
+
+#[test]
+fn event_serialize_flow_finished() {
+    let mut event = Event::new(EventType::FlowFinished, "test");
+    event.timestamp = 100;
+    event.level = 0;
+    event.success = Some(true);
+    event.duration = Some(50);
+
+    let output = serde_json::to_string(&event).unwrap();
+
+    assert_eq!(
+        output,
+        r#"{"event":"flow_finished","task":"test","timestamp":100,"level":0,"duration":50,"success":true}"#
+    );
+}
+
+#[test]
+fn remove_start_time_not_found() {
+    let start_time = remove_start_time("events_test_not_found");
+
+    assert!(start_time.is_none());
+}
+
+#[test]
+fn remove_start_time_found() {
+    TASKS_START_TIME
+        .lock()
+        .unwrap()
+        .push(("events_test_found".to_string(), SystemTime::now()));
+
+    let start_time = remove_start_time("events_test_found");
+    assert!(start_time.is_some());
+
+    let start_time = remove_start_time("events_test_found");
+    assert!(start_time.is_none());
+}
+
+#[test]
+#[ignore]
+fn init_and_emit_to_file() {
+    let directory = test::get_temp_test_directory();
+    let file = directory.join("events.json");
+
+    let mut cli_args = CliArgs::new();
+    cli_args.events_file = Some(file.to_string_lossy().into_owned());
+    init(&cli_args);
+
+    assert!(is_enabled());
+
+    task_started("test");
+    task_skipped("skipped", "condition not met");
+    task_finished("test", 0, SystemTime::now());
+    flow_finished("test", true, None);
+
+    envmnt::remove(EVENTS_FILE_ENV_VAR_NAME);
+
+    let text = read_text_file(&file).unwrap();
+    let lines: Vec<&str> = text.trim().split('\n').collect();
+
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].contains(r#""event":"task_started""#));
+    assert!(lines[1].contains(r#""fail_message":"condition not met""#));
+    assert!(lines[2].contains(r#""exit_code":0"#));
+    assert!(lines[3].contains(r#""success":true"#));
+}
+
+#[test]
+#[ignore]
+fn collect_events() {
+    start_collecting();
+
+    assert!(is_enabled());
+
+    task_started("events_test_collect");
+    task_finished("events_test_collect", 0, SystemTime::now());
+
+    let events: Vec<Event> = stop_collecting()
+        .into_iter()
+        .filter(|event| event.task == "events_test_collect")
+        .collect();
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].event, EventType::TaskStarted);
+    assert_eq!(events[1].event, EventType::TaskFinished);
+    assert_eq!(events[1].exit_code, Some(0));
+
+    assert!(stop_collecting().is_empty());
+}
+
+#[test]
+#[ignore]
+fn collect_task_failed_in_parallel_threads() {
+    start_collecting();
+
+    let threads: Vec<_> = vec!["events_test_failed1", "events_test_failed2"]
+        .into_iter()
+        .map(|task| {
+            std::thread::spawn(move || {
+                task_started(task);
+                set_failed_exit_code(1);
+                set_stderr_tail(format!("{} error", task));
+                task_failed(task, &CargoMakeError::TaskFailed("failed".to_string()));
+            })
+        })
+        .collect();
+    for task_thread in threads {
+        task_thread.join().unwrap();
+    }
+
+    let events: Vec<Event> = stop_collecting()
+        .into_iter()
+        .filter(|event| event.event == EventType::TaskFinished)
+        .collect();
+
+    assert_eq!(events.len(), 2);
+    for event in events {
+        assert_eq!(event.exit_code, Some(1));
+        assert_eq!(event.stderr, Some(format!("{} error", &event.task)));
+    }
+}
+
+#[test]
+#[ignore]
+fn collect_task_failed_without_command() {
+    start_collecting();
+
+    task_started("events_test_error");
+    task_failed(
+        "events_test_error",
+        &CargoMakeError::InstallerFailed("install error".to_string()),
+    );
+    task_failed(
+        "events_test_error",
+        &CargoMakeError::TaskFailed("parent error".to_string()),
+    );
+    task_failed(
+        "events_test_not_started",
+        &CargoMakeError::TaskFailed("not started".to_string()),
+    );
+
+    let events: Vec<Event> = stop_collecting()
+        .into_iter()
+        .filter(|event| {
+            event.event == EventType::TaskFinished
+                && (event.task == "events_test_error" || event.task == "events_test_not_started")
+        })
+        .collect();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].task, "events_test_error");
+    assert_eq!(events[0].exit_code, Some(EXIT_CODE_INSTALLER_FAILED));
+    assert_eq!(events[0].fail_message, Some("install error".to_string()));
+    assert!(events[0].duration.is_some());
+}
+
+#[test]
+fn read_events_file_with_offset() {
+    let file = "./target/_cargo_make_temp/events/read.jsonl";
+    let first_line = r#"{"event":"task_started","task":"old","timestamp":100,"level":0}"#;
+    fsio::file::write_text_file(
+        file,
+        &format!(
+            "{}\n{}\n\n{}\n",
+            first_line,
+            r#"{"event":"task_finished","task":"test","timestamp":100,"level":1,"member":"member","exit_code":1,"stderr":"error","duration":5}"#,
+            "bad"
+        ),
+    )
+    .unwrap();
+
+    let flow_events = read_events_file(file, first_line.len() as u64 + 1);
+
+    assert_eq!(flow_events.len(), 1);
+    assert_eq!(flow_events[0].event, EventType::TaskFinished);
+    assert_eq!(flow_events[0].task, "test");
+    assert_eq!(flow_events[0].member.clone().unwrap(), "member");
+    assert_eq!(flow_events[0].exit_code.unwrap(), 1);
+    assert_eq!(flow_events[0].stderr.clone().unwrap(), "error");
+}
+
+#[test]
+fn read_events_file_missing() {
+    let flow_events = read_events_file("./target/_cargo_make_temp/events/missing.jsonl", 0);
+
+    assert!(flow_events.is_empty());
+}
@@ -8,24 +8,31 @@
 mod execution_plan_test;
 
 use crate::environment;
+use crate::error::CargoMakeError;
 use crate::logger;
+use crate::matrix;
 use crate::profile;
 use crate::proxy_task::create_proxy_task;
 use crate::types::{
     Config, CrateInfo, EnvValue, ExecutionPlan, ScriptValue, Step, Task, TaskIdentifier, Workspace,
 };
+use crate::workspace;
 use envmnt;
 use fsio::path::{get_basename, get_parent_directory};
 use glob::Pattern;
 use indexmap::IndexMap;
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::path::Path;
 use std::vec::Vec;
 
 /// Resolve aliases to different tasks, checking for cycles
-fn get_task_name_recursive(config: &Config, name: &str, seen: &mut Vec<String>) -> Option<String> {
+fn get_task_name_recursive(
+    config: &Config,
+    name: &str,
+    seen: &mut Vec<String>,
+) -> Result<Option<String>, CargoMakeError> {
     seen.push(name.to_string());
 
     match config.tasks.get(name) {
@@ -35,37 +42,47 @@ fn get_task_name_recursive(config: &Config, name: &str, seen: &mut Vec<String>)
             match alias {
                 Some(ref alias) if seen.contains(alias) => {
                     let chain = seen.join(" -> ");
-                    error!("Detected cycle while resolving alias {}: {}", &name, chain);
-                    panic!("Detected cycle while resolving alias {}: {}", &name, chain);
+                    Err(CargoMakeError::InvalidTask(format!(
+                        "Detected cycle while resolving alias {}: {}",
+                        &name, chain
+                    )))
                 }
                 Some(ref alias) => get_task_name_recursive(config, alias, seen),
-                _ => Some(name.to_string()),
+                _ => Ok(Some(name.to_string())),
             }
         }
-        None => None,
+        None => Ok(None),
     }
 }
 
 /// Returns the actual task name to invoke as tasks may have aliases
-fn get_task_name(config: &Config, name: &str) -> Option<String> {
+fn get_task_name(config: &Config, name: &str) -> Result<Option<String>, CargoMakeError> {
     let mut seen = Vec::new();
 
     get_task_name_recursive(config, name, &mut seen)
 }
 
-pub(crate) fn get_normalized_task(config: &Config, name: &str, support_alias: bool) -> Task {
-    match get_optional_normalized_task(config, name, support_alias) {
-        Some(task) => task,
-        None => {
-            error!("Task {} not found", &name);
-            panic!("Task {} not found", &name);
-        }
+pub(crate) fn get_normalized_task(
+    config: &Config,
+    name: &str,
+    support_alias: bool,
+) -> Result<Task, CargoMakeError> {
+    match get_optional_normalized_task(config, name, support_alias)? {
+        Some(task) => Ok(task),
+        None => Err(CargoMakeError::TaskNotFound(format!(
+            "Task {} not found",
+            &name
+        ))),
     }
 }
 
-fn get_optional_normalized_task(config: &Config, name: &str, support_alias: bool) -> Option<Task> {
+fn get_optional_normalized_task(
+    config: &Config,
+    name: &str,
+    support_alias: bool,
+) -> Result<Option<Task>, CargoMakeError> {
     let actual_task_name_option = if support_alias {
-        get_task_name(config, name)
+        get_task_name(config, name)?
     } else {
         Some(name.to_string())
     };
@@ -79,7 +96,7 @@ fn get_optional_normalized_task(config: &Config, name: &str, support_alias: bool
                 normalized_task = match normalized_task.extend {
                     Some(ref extended_task_name) => {
                         let mut extended_task =
-                            get_normalized_task(config, extended_task_name, support_alias);
+                            get_normalized_task(config, extended_task_name, support_alias)?;
 
                         if let Some(ref env) = normalized_task.env {
                             if env.len() == 2
@@ -98,11 +115,11 @@ fn get_optional_normalized_task(config: &Config, name: &str, support_alias: bool
                     None => normalized_task,
                 };
 
-                Some(normalized_task)
+                Ok(Some(normalized_task))
             }
-            None => None,
+            None => Ok(None),
         },
-        None => None,
+        None => Ok(None),
     }
 }
 
@@ -163,12 +180,40 @@ fn filter_workspace_members(members: &Vec<String>) -> Vec<String> {
     let include_members_config = envmnt::get_or("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS", "");
     let include_members = get_workspace_members_config(include_members_config);
 
+    let affected_since = envmnt::get_or("CARGO_MAKE_WORKSPACE_AFFECTED_SINCE", "");
+    let affected_members = if affected_since.is_empty() {
+        None
+    } else {
+        match workspace::get_affected_members(members, &affected_since) {
+            Ok(affected_members) => Some(affected_members),
+            Err(error) => {
+                warn!(
+                    "Unable to detect members affected since: {}, all members will be used. {}",
+                    &affected_since, error
+                );
+                None
+            }
+        }
+    };
+
     let mut filtered_members = vec![];
     for member in members {
         if !should_skip_workspace_member(&member, &skip_members)
             && should_include_workspace_member(&member, &include_members)
         {
-            filtered_members.push(member.to_string());
+            match affected_members {
+                Some(ref affected_members) => match affected_members.get(member) {
+                    Some(reason) => {
+                        info!("Selecting Member: {}, reason: {}", &member, reason);
+                        filtered_members.push(member.to_string());
+                    }
+                    None => info!(
+                        "Skipping Member: {}, reason: not affected since: {}",
+                        &member, &affected_since
+                    ),
+                },
+                None => filtered_members.push(member.to_string()),
+            }
         } else {
             debug!("Skipping Member: {}.", &member);
         }
@@ -177,7 +222,7 @@ fn filter_workspace_members(members: &Vec<String>) -> Vec<String> {
     filtered_members
 }
 
-fn create_workspace_task(crate_info: CrateInfo, task: &str) -> Task {
+fn create_workspace_task(crate_info: CrateInfo, task: &str, jobs: usize) -> Task {
     let set_workspace_emulation = crate_info.workspace.is_none()
         && envmnt::is("CARGO_MAKE_WORKSPACE_EMULATION")
         && !envmnt::exists("CARGO_MAKE_WORKSPACE_EMULATION_ROOT_DIRECTORY");
@@ -209,45 +254,31 @@ fn create_workspace_task(crate_info: CrateInfo, task: &str) -> Task {
     let mut script_lines = vec![];
 
     if !filtered_members.is_empty() {
-        let cargo_make_command = "cargo make";
+        let mut run_line = "cm_run_workspace_members --jobs ".to_string();
+        run_line.push_str(&jobs.to_string());
+        run_line.push_str(" --loglevel ");
+        run_line.push_str(&log_level);
+        run_line.push_str(" --profile ");
+        run_line.push_str(&profile_name);
 
-        script_lines.push("workspace_directory = pwd".to_string());
         for member in &filtered_members {
-            let mut cd_line = "cd ./".to_string();
-            cd_line.push_str(&member.replace("\\", "/"));
-            script_lines.push(cd_line);
-
-            //get member name
-            let member_name = match Path::new(&member).file_name() {
-                Some(name) => String::from(name.to_string_lossy()),
-                None => member.clone(),
-            };
+            debug!("Adding Member: {}", &member);
 
-            debug!("Adding Member: {} Path: {}", &member_name, &member);
-
-            let mut make_line = "exec --fail-on-error ".to_string();
-            make_line.push_str(&cargo_make_command);
-            make_line
-                .push_str(" --disable-check-for-updates --allow-private --no-on-error --loglevel=");
-            make_line.push_str(&log_level);
-            make_line.push_str(" --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=");
-            make_line.push_str(&member_name);
-            make_line.push_str(" --profile ");
-            make_line.push_str(&profile_name);
-            make_line.push_str(" -- ");
-            make_line.push_str(&task);
-
-            if let Some(args) = envmnt::get_list("CARGO_MAKE_TASK_ARGS") {
-                for arg in args {
-                    make_line.push_str(" ");
-                    make_line.push_str(&arg);
-                }
-            }
+            run_line.push_str(" --member ");
+            run_line.push_str(&member.replace("\\", "/"));
+        }
 
-            script_lines.push(make_line);
+        run_line.push_str(" -- ");
+        run_line.push_str(&task);
 
-            script_lines.push("cd ${workspace_directory}".to_string());
+        if let Some(args) = envmnt::get_list("CARGO_MAKE_TASK_ARGS") {
+            for arg in args {
+                run_line.push_str(" ");
+                run_line.push_str(&arg);
+            }
         }
+
+        script_lines.push(run_line);
     }
 
     //only if environment variable is set
@@ -288,11 +319,11 @@ fn is_workspace_flow(
     // determine if workspace flow is explicitly set and enabled in the requested task
     let (task_set_workspace, task_enable_workspace) =
         match get_optional_normalized_task(config, task, true) {
-            Some(normalized_task) => match normalized_task.workspace {
+            Ok(Some(normalized_task)) => match normalized_task.workspace {
                 Some(enable_workspace) => (true, enable_workspace),
                 None => (false, false),
             },
-            None => (false, false),
+            _ => (false, false),
         };
 
     // if project is not a workspace or if workspace is disabled via cli, return no workspace flow
@@ -315,20 +346,64 @@ fn is_workspace_flow(
     }
 }
 
-/// Creates an execution plan for the given step based on existing execution plan data
+/// Adds the steps of the task to the execution plan, a step for each of the task matrix
+/// combinations (or a single step if the task does not define a matrix).<br>
+/// Returns the indexes of the added steps.
+fn add_task_steps(
+    task: &TaskIdentifier,
+    task_config: Task,
+    execution_plan: &mut ExecutionPlan,
+    dependencies: Vec<usize>,
+) -> Vec<usize> {
+    match task_config.matrix {
+        Some(ref task_matrix) => {
+            let combinations = matrix::get_combinations(task_matrix);
+            if combinations.is_empty() {
+                warn!("Task: {} matrix does not contain any combination.", &task);
+            }
+
+            combinations
+                .iter()
+                .map(|combination| {
+                    execution_plan.add_step(
+                        Step {
+                            name: matrix::get_step_name(&task.to_string(), combination),
+                            config: matrix::create_task(&task_config, combination),
+                        },
+                        dependencies.clone(),
+                    )
+                })
+                .collect()
+        }
+        None => {
+            let index = execution_plan.add_step(
+                Step {
+                    name: task.to_string(),
+                    config: task_config,
+                },
+                dependencies,
+            );
+
+            vec![index]
+        }
+    }
+}
+
+/// Creates an execution plan for the given step based on existing execution plan data.<br>
+/// Returns the indexes of the task steps in the execution plan (empty if not added).
 fn create_for_step(
     config: &Config,
     task: &TaskIdentifier,
-    steps: &mut Vec<Step>,
-    task_names: &mut HashSet<String>,
+    execution_plan: &mut ExecutionPlan,
+    task_names: &mut HashMap<String, Vec<usize>>,
     root: bool,
     allow_private: bool,
     skip_tasks_pattern: &Option<Regex>,
-) {
+) -> Result<Vec<usize>, CargoMakeError> {
     if let Some(skip_tasks_pattern_regex) = skip_tasks_pattern {
         if skip_tasks_pattern_regex.is_match(&task.name) {
             debug!("Skipping task: {} due to skip pattern.", &task.name);
-            return;
+            return Ok(vec![]);
         }
     }
 
@@ -356,12 +431,12 @@ fn create_for_step(
 
         debug!("Created external depedency step: {:#?}", &step);
 
-        steps.push(step);
-        task_names.insert(task.to_string());
-        return;
+        let index = execution_plan.add_step(step, vec![]);
+        task_names.insert(task.to_string(), vec![index]);
+        return Ok(vec![index]);
     }
 
-    let task_config = get_normalized_task(config, &task.name, true);
+    let task_config = get_normalized_task(config, &task.name, true)?;
 
     debug!("Normalized Task: {} config: {:#?}", &task, &task_config);
 
@@ -374,48 +449,82 @@ fn create_for_step(
         let add = !task_config.disabled.unwrap_or(false);
 
         if add {
+            let mut dependencies_indexes = vec![];
+
             match task_config.dependencies {
                 Some(ref dependencies) => {
                     for dependency in dependencies {
-                        create_for_step(
+                        let dependency_indexes = create_for_step(
                             &config,
                             &dependency.to_owned().into(),
-                            steps,
+                            execution_plan,
                             task_names,
                             false,
                             true,
                             skip_tasks_pattern,
-                        );
+                        )?;
+
+                        for index in dependency_indexes {
+                            if !dependencies_indexes.contains(&index) {
+                                dependencies_indexes.push(index);
+                            }
+                        }
                     }
                 }
                 _ => debug!("No dependencies found for task: {}", &task),
             };
 
-            if !task_names.contains(&task.name) {
-                steps.push(Step {
-                    name: task.to_string(),
-                    config: task_config,
-                });
-                task_names.insert(task.to_string());
-            } else if root {
-                error!("Circular reference found for task: {}", &task);
+            match task_names.get(&task.to_string()) {
+                Some(indexes) => {
+                    if root {
+                        return Err(CargoMakeError::InvalidTask(format!(
+                            "Circular reference found for task: {}",
+                            &task
+                        )));
+                    }
+
+                    Ok(indexes.clone())
+                }
+                None => {
+                    let indexes =
+                        add_task_steps(task, task_config, execution_plan, dependencies_indexes);
+                    task_names.insert(task.to_string(), indexes.clone());
+
+                    Ok(indexes)
+                }
             }
+        } else {
+            Ok(vec![])
         }
     } else {
-        error!("Task {} is private", &task);
-        panic!("Task {} is private", &task);
+        Err(CargoMakeError::InvalidTask(format!(
+            "Task {} is private",
+            &task
+        )))
     }
 }
 
-fn add_predefined_step(config: &Config, task: &str, steps: &mut Vec<Step>) {
-    let task_config = get_normalized_task(config, task, false);
+fn add_predefined_step(
+    config: &Config,
+    task: &str,
+    execution_plan: &mut ExecutionPlan,
+    dependencies: Vec<usize>,
+) -> Result<Option<usize>, CargoMakeError> {
+    let task_config = get_normalized_task(config, task, false)?;
     let add = !task_config.disabled.unwrap_or(false);
 
     if add {
-        steps.push(Step {
-            name: task.to_string(),
-            config: task_config,
-        });
+        let index = execution_plan.add_step(
+            Step {
+                name: task.to_string(),
+                config: task_config,
+            },
+            dependencies,
+        );
+
+        Ok(Some(index))
+    } else {
+        Ok(None)
     }
 }
 
@@ -427,20 +536,36 @@ pub(crate) fn create(
     allow_private: bool,
     sub_flow: bool,
     skip_tasks_pattern: &Option<Regex>,
-) -> ExecutionPlan {
-    let mut task_names = HashSet::new();
-    let mut steps = Vec::new();
+) -> Result<ExecutionPlan, CargoMakeError> {
+    let mut task_names = HashMap::new();
+    let mut execution_plan = ExecutionPlan::new();
 
+    // all flow steps depend on the legacy migration and init tasks
+    let mut predefined_steps = vec![];
     if !sub_flow {
         match config.config.legacy_migration_task {
-            Some(ref task) => add_predefined_step(config, task, &mut steps),
+            Some(ref task) => predefined_steps.extend(add_predefined_step(
+                config,
+                task,
+                &mut execution_plan,
+                vec![],
+            )?),
             None => debug!("Legacy migration task not defined."),
         };
         match config.config.init_task {
-            Some(ref task) => add_predefined_step(config, task, &mut steps),
+            Some(ref task) => {
+                let dependencies = predefined_steps.clone();
+                predefined_steps.extend(add_predefined_step(
+                    config,
+                    task,
+                    &mut execution_plan,
+                    dependencies,
+                )?)
+            }
             None => debug!("Init task not defined."),
         };
     }
+    let flow_start_index = execution_plan.steps.len();
 
     let skip = match skip_tasks_pattern {
         Some(ref pattern) => pattern.is_match(task),
@@ -449,40 +574,57 @@ pub(crate) fn create(
 
     if !skip {
         // load crate info and look for workspace info
-        let crate_info = environment::crateinfo::load();
+        let crate_info = environment::crateinfo::load()?;
 
         let workspace_flow =
             is_workspace_flow(&config, &task, disable_workspace, &crate_info, sub_flow);
 
         if workspace_flow {
-            let workspace_task = create_workspace_task(crate_info, task);
-
-            steps.push(Step {
-                name: "workspace".to_string(),
-                config: workspace_task,
-            });
+            let jobs = match config.config.parallelism {
+                Some(value) if value > 1 => value,
+                _ => 1,
+            };
+            let workspace_task = create_workspace_task(crate_info, task, jobs);
+
+            execution_plan.add_step(
+                Step {
+                    name: "workspace".to_string(),
+                    config: workspace_task,
+                },
+                vec![],
+            );
         } else {
             create_for_step(
                 &config,
                 &TaskIdentifier::from_name(task),
-                &mut steps,
+                &mut execution_plan,
                 &mut task_names,
                 true,
                 allow_private,
                 &skip_tasks_pattern,
-            );
+            )?;
         }
     } else {
         debug!("Skipping task: {} due to skip pattern.", &task);
     }
 
+    for index in flow_start_index..execution_plan.steps.len() {
+        let dependencies = &mut execution_plan.steps_dependencies[index];
+        for predefined_index in predefined_steps.iter().rev() {
+            dependencies.insert(0, *predefined_index);
+        }
+    }
+
     if !sub_flow {
         // always add end task even if already executed due to some depedency
         match config.config.end_task {
-            Some(ref task) => add_predefined_step(config, task, &mut steps),
+            Some(ref task) => {
+                let dependencies = (0..execution_plan.steps.len()).collect();
+                add_predefined_step(config, task, &mut execution_plan, dependencies)?;
+            }
             None => debug!("Ent task not defined."),
         };
     }
 
-    ExecutionPlan { steps }
+    Ok(execution_plan)
 }
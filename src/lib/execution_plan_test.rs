@@ -18,7 +18,7 @@ fn get_task_name_not_found() {
         plugins: None,
     };
 
-    let name = get_task_name(&config, "test");
+    let name = get_task_name(&config, "test").unwrap();
 
     assert!(name.is_none());
 }
@@ -36,7 +36,7 @@ fn get_task_name_no_alias() {
 
     config.tasks.insert("test".to_string(), Task::new());
 
-    let name = get_task_name(&config, "test");
+    let name = get_task_name(&config, "test").unwrap();
 
     assert_eq!(name.unwrap(), "test");
 }
@@ -58,13 +58,12 @@ fn get_task_name_alias() {
 
     config.tasks.insert("test2".to_string(), Task::new());
 
-    let name = get_task_name(&config, "test");
+    let name = get_task_name(&config, "test").unwrap();
 
     assert_eq!(name.unwrap(), "test2");
 }
 
 #[test]
-#[should_panic]
 fn get_task_name_alias_self_referential() {
     let mut config = Config {
         config: ConfigSection::new(),
@@ -79,11 +78,15 @@ fn get_task_name_alias_self_referential() {
     task.alias = Some("rec".to_string());
     config.tasks.insert("rec".to_string(), task);
 
-    get_task_name(&config, "rec");
+    let output = get_task_name(&config, "rec");
+
+    assert_eq!(
+        output.unwrap_err(),
+        CargoMakeError::InvalidTask("Detected cycle while resolving alias rec: rec".to_string())
+    );
 }
 
 #[test]
-#[should_panic]
 fn get_task_name_alias_circular() {
     let mut config = Config {
         config: ConfigSection::new(),
@@ -103,7 +106,9 @@ fn get_task_name_alias_circular() {
     config.tasks.insert("rec-mut-a".to_string(), task_a);
     config.tasks.insert("rec-mut-b".to_string(), task_b);
 
-    get_task_name(&config, "rec-mut-a");
+    let output = get_task_name(&config, "rec-mut-a");
+
+    assert!(matches!(output, Err(CargoMakeError::InvalidTask(_))));
 }
 
 #[test]
@@ -130,7 +135,7 @@ fn get_task_name_platform_alias() {
 
     config.tasks.insert("test2".to_string(), Task::new());
 
-    let name = get_task_name(&config, "test");
+    let name = get_task_name(&config, "test").unwrap();
 
     assert_eq!(name.unwrap(), "test2");
 }
@@ -167,9 +172,10 @@ fn create_workspace_task_no_members() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
-    let task = create_workspace_task(crate_info, "some_task");
+    let task = create_workspace_task(crate_info, "some_task", 1);
 
     assert!(task.script.is_some());
     let script = match task.script.unwrap() {
@@ -192,22 +198,14 @@ fn create_workspace_task_with_members() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     envmnt::remove("CARGO_MAKE_USE_WORKSPACE_PROFILE");
 
-    let task = create_workspace_task(crate_info, "some_task");
-
-    let mut expected_script = r#"workspace_directory = pwd
-cd ./member1
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1 --profile PROFILE_NAME -- some_task
-cd ${workspace_directory}
-cd ./member2
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member2 --profile PROFILE_NAME -- some_task
-cd ${workspace_directory}
-cd ./dir1/member3
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member3 --profile PROFILE_NAME -- some_task
-cd ${workspace_directory}"#
+    let task = create_workspace_task(crate_info, "some_task", 1);
+
+    let mut expected_script = r#"cm_run_workspace_members --jobs 1 --loglevel LEVEL_NAME --profile PROFILE_NAME --member member1 --member member2 --member dir1/member3 -- some_task"#
         .to_string();
 
     let log_level = logger::get_log_level();
@@ -225,6 +223,38 @@ cd ${workspace_directory}"#
     assert!(task.env.is_none());
 }
 
+#[test]
+#[ignore]
+fn create_workspace_task_with_members_and_jobs() {
+    let mut crate_info = CrateInfo::new();
+    let members = vec!["member1".to_string(), "member2".to_string()];
+    crate_info.workspace = Some(Workspace {
+        members: Some(members),
+        exclude: None,
+        dependencies: None,
+    });
+
+    envmnt::remove("CARGO_MAKE_USE_WORKSPACE_PROFILE");
+
+    let task = create_workspace_task(crate_info, "some_task", 4);
+
+    let mut expected_script = r#"cm_run_workspace_members --jobs 4 --loglevel LEVEL_NAME --profile PROFILE_NAME --member member1 --member member2 -- some_task"#
+        .to_string();
+
+    let log_level = logger::get_log_level();
+    expected_script = str::replace(&expected_script, "LEVEL_NAME", &log_level);
+
+    let profile_name = profile::get();
+    expected_script = str::replace(&expected_script, "PROFILE_NAME", &profile_name);
+
+    assert!(task.script.is_some());
+    let script = match task.script.unwrap() {
+        ScriptValue::Text(value) => value.join("\n"),
+        _ => panic!("Invalid script value type."),
+    };
+    assert_eq!(script, expected_script);
+}
+
 #[test]
 #[ignore]
 fn create_workspace_task_with_members_no_workspace_profile() {
@@ -237,22 +267,14 @@ fn create_workspace_task_with_members_no_workspace_profile() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     envmnt::set_bool("CARGO_MAKE_USE_WORKSPACE_PROFILE", false);
 
-    let task = create_workspace_task(crate_info, "some_task");
-
-    let mut expected_script = r#"workspace_directory = pwd
-cd ./member1
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1 --profile development -- some_task
-cd ${workspace_directory}
-cd ./member2
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member2 --profile development -- some_task
-cd ${workspace_directory}
-cd ./dir1/member3
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member3 --profile development -- some_task
-cd ${workspace_directory}"#
+    let task = create_workspace_task(crate_info, "some_task", 1);
+
+    let mut expected_script = r#"cm_run_workspace_members --jobs 1 --loglevel LEVEL_NAME --profile development --member member1 --member member2 --member dir1/member3 -- some_task"#
         .to_string();
 
     let log_level = logger::get_log_level();
@@ -279,6 +301,7 @@ fn create_workspace_task_with_members_and_arguments() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     envmnt::remove("CARGO_MAKE_USE_WORKSPACE_PROFILE");
@@ -288,20 +311,11 @@ fn create_workspace_task_with_members_and_arguments() {
         &vec!["arg1".to_string(), "arg2".to_string()],
     );
 
-    let task = create_workspace_task(crate_info, "some_task");
+    let task = create_workspace_task(crate_info, "some_task", 1);
 
     envmnt::remove("CARGO_MAKE_TASK_ARGS");
 
-    let mut expected_script = r#"workspace_directory = pwd
-cd ./member1
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1 --profile PROFILE_NAME -- some_task arg1 arg2
-cd ${workspace_directory}
-cd ./member2
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member2 --profile PROFILE_NAME -- some_task arg1 arg2
-cd ${workspace_directory}
-cd ./dir1/member3
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member3 --profile PROFILE_NAME -- some_task arg1 arg2
-cd ${workspace_directory}"#
+    let mut expected_script = r#"cm_run_workspace_members --jobs 1 --loglevel LEVEL_NAME --profile PROFILE_NAME --member member1 --member member2 --member dir1/member3 -- some_task arg1 arg2"#
         .to_string();
 
     let log_level = logger::get_log_level();
@@ -332,6 +346,7 @@ fn create_workspace_task_with_included_members() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     envmnt::set_list(
@@ -345,20 +360,11 @@ fn create_workspace_task_with_included_members() {
 
     profile::set(profile::DEFAULT_PROFILE);
 
-    let task = create_workspace_task(crate_info, "some_task");
+    let task = create_workspace_task(crate_info, "some_task", 1);
 
     envmnt::remove("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS");
 
-    let mut expected_script = r#"workspace_directory = pwd
-cd ./member1
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1 --profile development -- some_task
-cd ${workspace_directory}
-cd ./member2
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member2 --profile development -- some_task
-cd ${workspace_directory}
-cd ./dir1/member3
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member3 --profile development -- some_task
-cd ${workspace_directory}"#.to_string();
+    let mut expected_script = r#"cm_run_workspace_members --jobs 1 --loglevel LEVEL_NAME --profile development --member member1 --member member2 --member dir1/member3 -- some_task"#.to_string();
 
     let log_level = logger::get_log_level();
     expected_script = str::replace(&expected_script, "LEVEL_NAME", &log_level);
@@ -384,6 +390,7 @@ fn create_workspace_task_with_included_and_skipped_members() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     envmnt::set_list(
@@ -398,15 +405,12 @@ fn create_workspace_task_with_included_and_skipped_members() {
 
     profile::set(profile::DEFAULT_PROFILE);
 
-    let task = create_workspace_task(crate_info, "some_task");
+    let task = create_workspace_task(crate_info, "some_task", 1);
 
     envmnt::remove("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS");
     envmnt::remove("CARGO_MAKE_WORKSPACE_SKIP_MEMBERS");
 
-    let mut expected_script = r#"workspace_directory = pwd
-cd ./member1
-exec --fail-on-error cargo make --disable-check-for-updates --allow-private --no-on-error --loglevel=LEVEL_NAME --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1 --profile development -- some_task
-cd ${workspace_directory}"#
+    let mut expected_script = r#"cm_run_workspace_members --jobs 1 --loglevel LEVEL_NAME --profile development --member member1 -- some_task"#
         .to_string();
 
     let log_level = logger::get_log_level();
@@ -421,6 +425,33 @@ cd ${workspace_directory}"#
     assert!(task.env.is_none());
 }
 
+#[test]
+#[ignore]
+fn filter_workspace_members_affected_since_invalid_ref() {
+    envmnt::set(
+        "CARGO_MAKE_WORKSPACE_AFFECTED_SINCE",
+        "filter-workspace-members-bad-ref",
+    );
+
+    let members = filter_workspace_members(&vec!["member1".to_string(), "member2".to_string()]);
+
+    envmnt::remove("CARGO_MAKE_WORKSPACE_AFFECTED_SINCE");
+
+    assert_eq!(members, vec!["member1".to_string(), "member2".to_string()]);
+}
+
+#[test]
+#[ignore]
+fn filter_workspace_members_affected_since_no_changes() {
+    envmnt::set("CARGO_MAKE_WORKSPACE_AFFECTED_SINCE", "HEAD");
+
+    let members = filter_workspace_members(&vec!["examples/workspace/member1".to_string()]);
+
+    envmnt::remove("CARGO_MAKE_WORKSPACE_AFFECTED_SINCE");
+
+    assert!(members.is_empty());
+}
+
 #[test]
 #[ignore]
 fn create_workspace_task_extend_workspace_makefile() {
@@ -429,10 +460,11 @@ fn create_workspace_task_extend_workspace_makefile() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     envmnt::set("CARGO_MAKE_EXTEND_WORKSPACE_MAKEFILE", "true");
-    let task = create_workspace_task(crate_info, "some_task");
+    let task = create_workspace_task(crate_info, "some_task", 1);
     envmnt::set("CARGO_MAKE_EXTEND_WORKSPACE_MAKEFILE", "false");
 
     assert!(task.script.is_some());
@@ -456,6 +488,7 @@ fn is_workspace_flow_true_default() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let task = Task::new();
@@ -482,6 +515,7 @@ fn is_workspace_flow_false_in_config() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let task = Task::new();
@@ -511,6 +545,7 @@ fn is_workspace_flow_true_in_config() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let task = Task::new();
@@ -540,6 +575,7 @@ fn is_workspace_flow_true_in_task() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let mut task = Task::new();
@@ -567,6 +603,7 @@ fn is_workspace_flow_default_false_in_task_and_sub_flow() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let task = Task::new();
@@ -593,6 +630,7 @@ fn is_workspace_flow_true_in_task_and_sub_flow() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let mut task = Task::new();
@@ -620,6 +658,7 @@ fn is_workspace_flow_false_in_task_and_sub_flow() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let mut task = Task::new();
@@ -647,6 +686,7 @@ fn is_workspace_flow_task_not_defined() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let config = Config {
@@ -692,6 +732,7 @@ fn is_workspace_flow_disabled_via_cli() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let mut task = Task::new();
@@ -719,6 +760,7 @@ fn is_workspace_flow_disabled_via_task() {
     crate_info.workspace = Some(Workspace {
         members: Some(members),
         exclude: None,
+        dependencies: None,
     });
 
     let mut task = Task::new();
@@ -760,7 +802,7 @@ fn create_single() {
 
     config.tasks.insert("test".to_string(), task);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
     assert_eq!(execution_plan.steps.len(), 3);
     assert_eq!(execution_plan.steps[0].name, "init");
     assert_eq!(execution_plan.steps[1].name, "test");
@@ -789,14 +831,13 @@ fn create_single_disabled() {
 
     config.tasks.insert("test".to_string(), task);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
     assert_eq!(execution_plan.steps.len(), 2);
     assert_eq!(execution_plan.steps[0].name, "init");
     assert_eq!(execution_plan.steps[1].name, "end");
 }
 
 #[test]
-#[should_panic]
 fn create_single_private() {
     let mut config_section = ConfigSection::new();
     config_section.init_task = Some("init".to_string());
@@ -818,7 +859,12 @@ fn create_single_private() {
 
     config.tasks.insert("test-private".to_string(), task);
 
-    create(&config, "test-private", false, false, false, &None);
+    let output = create(&config, "test-private", false, false, false, &None);
+
+    assert_eq!(
+        output.unwrap_err(),
+        CargoMakeError::InvalidTask("Task test-private is private".to_string())
+    );
 }
 
 #[test]
@@ -843,7 +889,7 @@ fn create_single_allow_private() {
 
     config.tasks.insert("test-private".to_string(), task);
 
-    let execution_plan = create(&config, "test-private", false, true, false, &None);
+    let execution_plan = create(&config, "test-private", false, true, false, &None).unwrap();
     assert_eq!(execution_plan.steps.len(), 3);
     assert_eq!(execution_plan.steps[0].name, "init");
     assert_eq!(execution_plan.steps[1].name, "test-private");
@@ -877,7 +923,7 @@ fn create_with_dependencies() {
         .tasks
         .insert("task_dependency".to_string(), task_dependency);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
     assert_eq!(execution_plan.steps.len(), 4);
     assert_eq!(execution_plan.steps[0].name, "init");
     assert_eq!(execution_plan.steps[1].name, "task_dependency");
@@ -915,7 +961,7 @@ fn create_with_foreign_dependencies_directory() {
         .tasks
         .insert("task_dependency".to_string(), task_dependency);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
 
     assert_eq!(execution_plan.steps.len(), 4);
     assert_eq!(execution_plan.steps[0].name, "init");
@@ -958,7 +1004,7 @@ fn create_with_foreign_dependencies_filename() {
         .tasks
         .insert("task_dependency".to_string(), task_dependency);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
 
     assert_eq!(execution_plan.steps.len(), 4);
     assert_eq!(execution_plan.steps[0].name, "init");
@@ -1001,7 +1047,7 @@ fn create_with_foreign_dependencies_file_and_directory() {
         .tasks
         .insert("task_dependency".to_string(), task_dependency);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
 
     assert_eq!(execution_plan.steps.len(), 4);
     assert_eq!(execution_plan.steps[0].name, "init");
@@ -1041,7 +1087,7 @@ fn create_with_dependencies_sub_flow() {
         .tasks
         .insert("task_dependency".to_string(), task_dependency);
 
-    let execution_plan = create(&config, "test", false, true, true, &None);
+    let execution_plan = create(&config, "test", false, true, true, &None).unwrap();
     assert_eq!(execution_plan.steps.len(), 2);
     assert_eq!(execution_plan.steps[0].name, "task_dependency");
     assert_eq!(execution_plan.steps[1].name, "test");
@@ -1075,7 +1121,7 @@ fn create_disabled_task_with_dependencies() {
         .tasks
         .insert("task_dependency".to_string(), task_dependency);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
     assert_eq!(execution_plan.steps.len(), 2);
     assert_eq!(execution_plan.steps[0].name, "init");
     assert_eq!(execution_plan.steps[1].name, "end");
@@ -1109,7 +1155,7 @@ fn create_with_dependencies_disabled() {
         .tasks
         .insert("task_dependency".to_string(), task_dependency);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
     assert_eq!(execution_plan.steps.len(), 3);
     assert_eq!(execution_plan.steps[0].name, "init");
     assert_eq!(execution_plan.steps[1].name, "test");
@@ -1155,6 +1201,7 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        timeout: None,
     });
     task.windows = Some(PlatformOverrideTask {
         clear: Some(true),
@@ -1183,6 +1230,7 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        timeout: None,
     });
     task.mac = Some(PlatformOverrideTask {
         clear: Some(true),
@@ -1211,11 +1259,12 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        timeout: None,
     });
 
     config.tasks.insert("test".to_string(), task);
 
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
     assert_eq!(execution_plan.steps.len(), 0);
 }
 
@@ -1249,7 +1298,7 @@ fn create_with_dependencies_and_skip_filter() {
 
     let skip_filter = Regex::new("filtered.*").unwrap();
 
-    let execution_plan = create(&config, "test", false, true, false, &Some(skip_filter));
+    let execution_plan = create(&config, "test", false, true, false, &Some(skip_filter)).unwrap();
     assert_eq!(execution_plan.steps.len(), 4);
     assert_eq!(execution_plan.steps[0].name, "init");
     assert_eq!(execution_plan.steps[1].name, "task_dependency");
@@ -1257,6 +1306,148 @@ fn create_with_dependencies_and_skip_filter() {
     assert_eq!(execution_plan.steps[3].name, "end");
 }
 
+#[test]
+fn create_with_dependencies_graph() {
+    let mut config_section = ConfigSection::new();
+    config_section.init_task = Some("init".to_string());
+    config_section.end_task = Some("end".to_string());
+    let mut config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+
+    config.tasks.insert("init".to_string(), Task::new());
+    config.tasks.insert("end".to_string(), Task::new());
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["dependency1".into(), "dependency2".into()]);
+
+    let mut dependency1 = Task::new();
+    dependency1.dependencies = Some(vec!["dependency2".into()]);
+
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("dependency1".to_string(), dependency1);
+    config.tasks.insert("dependency2".to_string(), Task::new());
+
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
+    assert_eq!(execution_plan.steps.len(), 5);
+    assert_eq!(execution_plan.steps_dependencies.len(), 5);
+    assert_eq!(execution_plan.steps[0].name, "init");
+    assert_eq!(execution_plan.steps[1].name, "dependency2");
+    assert_eq!(execution_plan.steps[2].name, "dependency1");
+    assert_eq!(execution_plan.steps[3].name, "test");
+    assert_eq!(execution_plan.steps[4].name, "end");
+    assert!(execution_plan.steps_dependencies[0].is_empty());
+    assert_eq!(execution_plan.steps_dependencies[1], vec![0]);
+    assert_eq!(execution_plan.steps_dependencies[2], vec![0, 1]);
+    assert_eq!(execution_plan.steps_dependencies[3], vec![0, 2, 1]);
+    assert_eq!(execution_plan.steps_dependencies[4], vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn create_with_matrix_dependency() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["matrix".into(), "dependency".into()]);
+
+    let mut matrix_task: Task = toml::from_str(
+        r#"
+        dependencies = ["dependency"]
+        command = "echo"
+        args = ["${CARGO_MAKE_MATRIX_FEATURES}"]
+        matrix = { features = ["a", "b"], target = ["x86", "wasm32"], exclude = [{ features = "b", target = "wasm32" }] }
+        "#,
+    )
+    .unwrap();
+    matrix_task.description = Some("matrix".to_string());
+
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("matrix".to_string(), matrix_task);
+    config.tasks.insert("dependency".to_string(), Task::new());
+
+    let execution_plan = create(&config, "test", false, true, true, &None).unwrap();
+    let names: Vec<String> = execution_plan
+        .steps
+        .iter()
+        .map(|step| step.name.clone())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "dependency",
+            "matrix[features=a,target=x86]",
+            "matrix[features=a,target=wasm32]",
+            "matrix[features=b,target=x86]",
+            "test",
+        ]
+    );
+    assert!(execution_plan.steps_dependencies[0].is_empty());
+    assert_eq!(execution_plan.steps_dependencies[1], vec![0]);
+    assert_eq!(execution_plan.steps_dependencies[2], vec![0]);
+    assert_eq!(execution_plan.steps_dependencies[3], vec![0]);
+    assert_eq!(execution_plan.steps_dependencies[4], vec![1, 2, 3, 0]);
+
+    let step = &execution_plan.steps[2];
+    assert!(step.config.matrix.is_none());
+    assert_eq!(step.config.description.clone().unwrap(), "matrix");
+    let env: Vec<String> = step
+        .config
+        .env
+        .clone()
+        .unwrap()
+        .iter()
+        .map(|(key, value)| match value {
+            EnvValue::Value(value) => format!("{}={}", key, value),
+            _ => panic!("invalid env value type"),
+        })
+        .collect();
+    assert_eq!(
+        env,
+        vec![
+            "CARGO_MAKE_MATRIX_FEATURES=a",
+            "CARGO_MAKE_MATRIX_TARGET=wasm32"
+        ]
+    );
+}
+
+#[test]
+fn create_with_matrix_root_task() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+
+    let task: Task = toml::from_str(
+        r#"
+        command = "echo"
+        matrix = { include = [{ features = "a" }] }
+        "#,
+    )
+    .unwrap();
+    config.tasks.insert("test".to_string(), task);
+
+    let execution_plan = create(&config, "test", false, true, true, &None).unwrap();
+
+    assert_eq!(execution_plan.steps.len(), 1);
+    assert_eq!(execution_plan.steps[0].name, "test[features=a]");
+}
+
 #[test]
 #[ignore]
 fn create_workspace() {
@@ -1274,7 +1465,7 @@ fn create_workspace() {
     config.tasks.insert("test".to_string(), task);
 
     env::set_current_dir("./examples/workspace").unwrap();
-    let execution_plan = create(&config, "test", false, true, false, &None);
+    let execution_plan = create(&config, "test", false, true, false, &None).unwrap();
     env::set_current_dir("../../").unwrap();
     assert_eq!(execution_plan.steps.len(), 1);
     assert_eq!(execution_plan.steps[0].name, "workspace");
@@ -1297,7 +1488,7 @@ fn create_noworkspace() {
     config.tasks.insert("test".to_string(), task);
 
     env::set_current_dir("./examples/workspace").unwrap();
-    let execution_plan = create(&config, "test", true, true, false, &None);
+    let execution_plan = create(&config, "test", true, true, false, &None).unwrap();
     env::set_current_dir("../../").unwrap();
     assert_eq!(execution_plan.steps.len(), 1);
     assert_eq!(execution_plan.steps[0].name, "test");
@@ -1314,7 +1505,7 @@ fn create_task_extends_empty_env_bug_verification() {
     )
     .unwrap();
 
-    let execution_plan = create(&config, "task2", true, false, false, &None);
+    let execution_plan = create(&config, "task2", true, false, false, &None).unwrap();
 
     assert_eq!(execution_plan.steps.len(), 3);
 
@@ -1423,6 +1614,7 @@ fn get_normalized_task_multi_extend() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        timeout: None,
     };
 
     let mut task2 = Task::new();
@@ -1449,7 +1641,7 @@ fn get_normalized_task_multi_extend() {
     config.tasks.insert("2".to_string(), task2);
     config.tasks.insert("3".to_string(), task3);
 
-    let task = get_normalized_task(&config, "3", true);
+    let task = get_normalized_task(&config, "3", true).unwrap();
 
     assert_eq!(task.category.unwrap(), "2");
     assert_eq!(task.description.unwrap(), "1");
@@ -1477,7 +1669,7 @@ fn get_normalized_task_simple() {
     };
     config.tasks.insert("1".to_string(), task1);
 
-    let task = get_normalized_task(&config, "1", true);
+    let task = get_normalized_task(&config, "1", true).unwrap();
 
     assert_eq!(task.category.unwrap(), "1");
     assert_eq!(task.description.unwrap(), "1");
@@ -0,0 +1,239 @@
+//! # fingerprint
+//!
+//! Enables to skip tasks which did not change since their last successful invocation.<br>
+//! The fingerprint of a task is based on the content of its inputs files and its env, command, args and script.
+//!
+
+#[cfg(test)]
+#[path = "fingerprint_test.rs"]
+mod fingerprint_test;
+
+use crate::environment;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::storage;
+use crate::types::{CliArgs, FlowInfo, Step};
+use dirs_next;
+use envmnt;
+use fsio;
+use fsio::file::{read_text_file, write_text_file};
+use glob::Pattern;
+use serde_json;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+static FINGERPRINTS_DIRECTORY: &'static str = "fingerprints";
+
+fn get_fingerprints_directory() -> Option<PathBuf> {
+    let os_directory = dirs_next::cache_dir();
+    match storage::get_storage_directory(os_directory, FINGERPRINTS_DIRECTORY, false) {
+        Some(directory) => Some(directory.join(FINGERPRINTS_DIRECTORY)),
+        None => None,
+    }
+}
+
+fn has_fingerprint_info(step: &Step) -> bool {
+    step.config.inputs.is_some() || step.config.outputs.is_some()
+}
+
+fn get_path_list(
+    globs: &Vec<String>,
+    include_dirs: bool,
+) -> Result<Vec<Vec<String>>, CargoMakeError> {
+    globs
+        .iter()
+        .map(|glob| {
+            let expanded_glob = environment::expand_value(glob);
+            let task_glob = match task_env::get_current_cwd() {
+                Some(ref cwd) if Path::new(&expanded_glob).is_relative() => {
+                    format!("{}/{}", Pattern::escape(cwd), &expanded_glob)
+                }
+                _ => expanded_glob,
+            };
+            io::get_path_list(&task_glob, true, include_dirs, None)
+        })
+        .collect()
+}
+
+fn outputs_exist(step: &Step) -> Result<bool, CargoMakeError> {
+    let exist = match step.config.outputs {
+        Some(ref outputs) => get_path_list(outputs, true)?
+            .iter()
+            .all(|path_list| !path_list.is_empty()),
+        None => true,
+    };
+
+    Ok(exist)
+}
+
+/// Adds the value to the hasher, prefixed with its length so values do not run into each other
+fn update_hasher(hasher: &mut Sha256, value: &[u8]) {
+    hasher.update((value.len() as u64).to_le_bytes());
+    hasher.update(value);
+}
+
+fn get_fingerprint_file_name(step: &Step) -> String {
+    // fingerprints are kept per task and per working directory
+    let working_directory = task_env::get_working_directory().unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    update_hasher(&mut hasher, working_directory.as_bytes());
+    update_hasher(&mut hasher, step.name.as_bytes());
+
+    format!("{:x}.txt", hasher.finalize())
+}
+
+/// Returns the canonical serialization of the task definition.<br>
+/// The task is converted to a JSON value first, which orders all map keys, so the output does
+/// not depend on the order of hash map entries.
+fn serialize_task(step: &Step) -> Result<Vec<u8>, CargoMakeError> {
+    serde_json::to_value(&step.config)
+        .and_then(|value| serde_json::to_vec(&value))
+        .map_err(|error| {
+            CargoMakeError::Other(format!(
+                "Unable to serialize task: {} for fingerprint, error: {}",
+                &step.name, error
+            ))
+        })
+}
+
+fn calculate(step: &Step, cli_arguments: &Option<Vec<String>>) -> Result<String, CargoMakeError> {
+    let mut hasher = Sha256::new();
+
+    // task definition (command, args, script, env, ...)
+    update_hasher(&mut hasher, &serialize_task(step)?);
+    match cli_arguments {
+        Some(ref cli_arguments) => {
+            update_hasher(&mut hasher, &[1]);
+            for argument in cli_arguments {
+                update_hasher(&mut hasher, argument.as_bytes());
+            }
+        }
+        None => update_hasher(&mut hasher, &[0]),
+    };
+
+    // current values of the task env
+    if let Some(ref task_env) = step.config.env {
+        for key in task_env.keys() {
+            update_hasher(&mut hasher, key.as_bytes());
+            update_hasher(
+                &mut hasher,
+                task_env::get_var(key).unwrap_or_default().as_bytes(),
+            );
+        }
+    }
+
+    if let Some(ref inputs) = step.config.inputs {
+        let mut path_list: Vec<String> = get_path_list(inputs, false)?.concat();
+        path_list.sort();
+        path_list.dedup();
+
+        for path in path_list {
+            update_hasher(&mut hasher, path.as_bytes());
+
+            match fs::read(&path) {
+                Ok(content) => update_hasher(&mut hasher, &content),
+                Err(error) => debug!("Unable to read input file: {} error: {}", &path, error),
+            };
+        }
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn is_up_to_date_in_directory(
+    directory: &PathBuf,
+    step: &Step,
+    cli_arguments: &Option<Vec<String>>,
+) -> Result<bool, CargoMakeError> {
+    if !outputs_exist(step)? {
+        debug!("Task: {} outputs are missing.", &step.name);
+        return Ok(false);
+    }
+
+    let file_path = directory.join(get_fingerprint_file_name(step));
+    if !file_path.exists() {
+        return Ok(false);
+    }
+
+    match read_text_file(&file_path) {
+        Ok(fingerprint) => Ok(fingerprint == calculate(step, cli_arguments)?),
+        Err(error) => {
+            debug!(
+                "Unable to read fingerprint file: {:?} error: {}",
+                &file_path,
+                error.to_string()
+            );
+            Ok(false)
+        }
+    }
+}
+
+fn store_in_directory(
+    directory: &PathBuf,
+    step: &Step,
+    cli_arguments: &Option<Vec<String>>,
+) -> Result<(), CargoMakeError> {
+    let exists = if directory.exists() {
+        true
+    } else {
+        match fsio::directory::create(directory) {
+            Ok(_) => true,
+            _ => false,
+        }
+    };
+
+    if exists {
+        let file_path = directory.join(get_fingerprint_file_name(step));
+        let fingerprint = calculate(step, cli_arguments)?;
+
+        match write_text_file(&file_path, &fingerprint) {
+            Err(error) => info!(
+                "Error while writing to fingerprint file: {:#?}, error: {:#?}",
+                &file_path, error
+            ),
+            _ => (),
+        };
+    }
+
+    Ok(())
+}
+
+/// Returns true if all tasks should run even if they are up to date
+pub(crate) fn is_force_rerun() -> bool {
+    envmnt::is("CARGO_MAKE_FORCE_RERUN")
+}
+
+/// Returns true if the task defines inputs/outputs, its fingerprint did not change since
+/// the last successful invocation and all its outputs exist.
+pub(crate) fn is_up_to_date(flow_info: &FlowInfo, step: &Step) -> Result<bool, CargoMakeError> {
+    if !has_fingerprint_info(step) || is_force_rerun() {
+        return Ok(false);
+    }
+
+    match get_fingerprints_directory() {
+        Some(directory) => is_up_to_date_in_directory(&directory, step, &flow_info.cli_arguments),
+        None => Ok(false),
+    }
+}
+
+/// Stores the task fingerprint after a successful invocation
+pub(crate) fn store(flow_info: &FlowInfo, step: &Step) -> Result<(), CargoMakeError> {
+    // we can't tell if the task actually succeeded so no fingerprint is stored
+    if !has_fingerprint_info(step) || step.config.should_ignore_errors() {
+        return Ok(());
+    }
+
+    match get_fingerprints_directory() {
+        Some(directory) => store_in_directory(&directory, step, &flow_info.cli_arguments),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn init(cli_args: &CliArgs) {
+    if cli_args.force_rerun {
+        envmnt::set_bool("CARGO_MAKE_FORCE_RERUN", true);
+    }
+}
@@ -0,0 +1,230 @@
+use super::*;
+use crate::environment::task_env::TaskEnv;
+use crate::types::{EnvValue, EnvValueDecode, Task};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::env;
+
+fn create_step(inputs: Option<Vec<String>>, outputs: Option<Vec<String>>) -> Step {
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.args = Some(vec!["test".to_string()]);
+    task.inputs = inputs;
+    task.outputs = outputs;
+
+    Step {
+        name: "test".to_string(),
+        config: task,
+    }
+}
+
+fn get_test_directory(name: &str) -> PathBuf {
+    let directory = env::current_dir()
+        .unwrap()
+        .join("target/_cargo_make_temp/fingerprint")
+        .join(name);
+
+    if directory.exists() {
+        fsio::directory::delete(&directory).unwrap();
+    }
+
+    directory
+}
+
+#[test]
+fn has_fingerprint_info_none() {
+    let step = create_step(None, None);
+
+    assert!(!has_fingerprint_info(&step));
+}
+
+#[test]
+fn has_fingerprint_info_inputs() {
+    let step = create_step(Some(vec!["src/**/*.rs".to_string()]), None);
+
+    assert!(has_fingerprint_info(&step));
+}
+
+#[test]
+fn has_fingerprint_info_outputs() {
+    let step = create_step(None, Some(vec!["Cargo.toml".to_string()]));
+
+    assert!(has_fingerprint_info(&step));
+}
+
+#[test]
+fn outputs_exist_none() {
+    let step = create_step(None, None);
+
+    assert!(outputs_exist(&step).unwrap());
+}
+
+#[test]
+fn outputs_exist_found() {
+    let step = create_step(
+        None,
+        Some(vec!["Cargo.toml".to_string(), "src/lib/*.rs".to_string()]),
+    );
+
+    assert!(outputs_exist(&step).unwrap());
+}
+
+#[test]
+fn outputs_exist_missing() {
+    let step = create_step(
+        None,
+        Some(vec!["Cargo.toml".to_string(), "bad/*.rs".to_string()]),
+    );
+
+    assert!(!outputs_exist(&step).unwrap());
+}
+
+#[test]
+fn calculate_same_step() {
+    let step = create_step(Some(vec!["src/lib/*.rs".to_string()]), None);
+
+    let fingerprint1 = calculate(&step, &None).unwrap();
+    let fingerprint2 = calculate(&step, &None).unwrap();
+
+    assert_eq!(fingerprint1, fingerprint2);
+}
+
+#[test]
+fn calculate_different_args() {
+    let step1 = create_step(Some(vec!["src/lib/*.rs".to_string()]), None);
+    let mut step2 = step1.clone();
+    step2.config.args = Some(vec!["test2".to_string()]);
+
+    let fingerprint1 = calculate(&step1, &None).unwrap();
+    let fingerprint2 = calculate(&step2, &None).unwrap();
+
+    assert_ne!(fingerprint1, fingerprint2);
+}
+
+#[test]
+fn calculate_different_cli_arguments() {
+    let step = create_step(Some(vec!["src/lib/*.rs".to_string()]), None);
+
+    let fingerprint1 = calculate(&step, &None).unwrap();
+    let fingerprint2 = calculate(&step, &Some(vec!["arg".to_string()])).unwrap();
+
+    assert_ne!(fingerprint1, fingerprint2);
+}
+
+#[test]
+fn calculate_decode_mapping_order() {
+    let create_decode_step = |keys: Vec<usize>| {
+        let mut mapping = HashMap::new();
+        for key in keys {
+            mapping.insert(format!("key{}", key), format!("value{}", key));
+        }
+
+        let mut env = IndexMap::new();
+        env.insert(
+            "FINGERPRINT_TEST_DECODE".to_string(),
+            EnvValue::Decode(EnvValueDecode {
+                source: "source".to_string(),
+                default_value: None,
+                mapping,
+            }),
+        );
+        let mut step = create_step(None, None);
+        step.config.env = Some(env);
+
+        step
+    };
+
+    let step1 = create_decode_step((0..20).collect());
+    let step2 = create_decode_step((0..20).rev().collect());
+
+    let fingerprint1 = calculate(&step1, &None).unwrap();
+    let fingerprint2 = calculate(&step2, &None).unwrap();
+
+    assert_eq!(fingerprint1, fingerprint2);
+    assert_eq!(fingerprint1.len(), 64);
+}
+
+#[test]
+fn get_fingerprint_file_name_sha256() {
+    let step = create_step(None, None);
+
+    let file_name = get_fingerprint_file_name(&step);
+
+    assert_eq!(file_name.len(), 68);
+    assert!(file_name.ends_with(".txt"));
+    assert_eq!(file_name, get_fingerprint_file_name(&step));
+}
+
+#[test]
+fn calculate_different_task_env() {
+    let mut step = create_step(None, None);
+    let mut env = IndexMap::new();
+    env.insert(
+        "FINGERPRINT_TEST_TASK_ENV".to_string(),
+        EnvValue::Value("${FINGERPRINT_TEST_TASK_ENV_SOURCE}".to_string()),
+    );
+    step.config.env = Some(env);
+
+    let mut task_env = TaskEnv::default();
+    task_env
+        .vars
+        .insert("FINGERPRINT_TEST_TASK_ENV".to_string(), "1".to_string());
+    let guard = task_env::set_current(Some(task_env.clone()));
+    let fingerprint1 = calculate(&step, &None).unwrap();
+    drop(guard);
+
+    task_env
+        .vars
+        .insert("FINGERPRINT_TEST_TASK_ENV".to_string(), "2".to_string());
+    let _guard = task_env::set_current(Some(task_env));
+    let fingerprint2 = calculate(&step, &None).unwrap();
+
+    assert_ne!(fingerprint1, fingerprint2);
+}
+
+#[test]
+fn is_up_to_date_in_directory_not_stored() {
+    let directory = get_test_directory("not_stored");
+    let step = create_step(Some(vec!["src/lib/*.rs".to_string()]), None);
+
+    assert!(!is_up_to_date_in_directory(&directory, &step, &None).unwrap());
+}
+
+#[test]
+fn is_up_to_date_in_directory_stored() {
+    let directory = get_test_directory("stored");
+    let step = create_step(
+        Some(vec!["src/lib/*.rs".to_string()]),
+        Some(vec!["Cargo.toml".to_string()]),
+    );
+
+    store_in_directory(&directory, &step, &None).unwrap();
+
+    assert!(is_up_to_date_in_directory(&directory, &step, &None).unwrap());
+}
+
+#[test]
+fn is_up_to_date_in_directory_changed() {
+    let directory = get_test_directory("changed");
+    let step = create_step(Some(vec!["src/lib/*.rs".to_string()]), None);
+
+    store_in_directory(&directory, &step, &None).unwrap();
+
+    let mut changed_step = step.clone();
+    changed_step.config.args = Some(vec!["test2".to_string()]);
+
+    assert!(!is_up_to_date_in_directory(&directory, &changed_step, &None).unwrap());
+}
+
+#[test]
+fn is_up_to_date_in_directory_missing_outputs() {
+    let directory = get_test_directory("missing_outputs");
+    let step = create_step(
+        Some(vec!["src/lib/*.rs".to_string()]),
+        Some(vec!["bad/output.txt".to_string()]),
+    );
+
+    store_in_directory(&directory, &step, &None).unwrap();
+
+    assert!(!is_up_to_date_in_directory(&directory, &step, &None).unwrap());
+}
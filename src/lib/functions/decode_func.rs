@@ -10,15 +10,15 @@
 mod decode_func_test;
 
 use crate::environment;
-use envmnt;
+use crate::environment::task_env;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
+pub(crate) fn invoke(function_args: &Vec<String>) -> Result<Vec<String>, String> {
     if function_args.len() == 0 {
-        error!("decode expects at least one argument.");
+        return Err("decode expects at least one argument.".to_string());
     }
 
     let env_key = function_args[0].clone();
-    let env_value = envmnt::get_or(&env_key, "");
+    let env_value = task_env::get_var(&env_key).unwrap_or_default();
 
     let mut mapped_value = None;
     let mut found = false;
@@ -51,8 +51,8 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
     output_value = environment::expand_value(&output_value);
 
     if output_value.len() > 0 {
-        vec![output_value]
+        Ok(vec![output_value])
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
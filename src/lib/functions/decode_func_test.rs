@@ -3,16 +3,17 @@ use super::*;
 use envmnt;
 
 #[test]
-#[should_panic]
 fn decode_invoke_empty() {
-    invoke(&vec![]);
+    let result = invoke(&vec![]);
+
+    assert!(result.is_err());
 }
 
 #[test]
 fn decode_invoke_only_source_not_found() {
     envmnt::remove("TEST_DECODE_ONLY_SOURCE_NOT_DEFINED");
 
-    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_NOT_DEFINED".to_string()]);
+    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_NOT_DEFINED".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -21,7 +22,7 @@ fn decode_invoke_only_source_not_found() {
 fn decode_invoke_only_source_found_empty() {
     envmnt::set("TEST_DECODE_ONLY_SOURCE_DEFINED_EMPTY", "");
 
-    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_DEFINED_EMPTY".to_string()]);
+    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_DEFINED_EMPTY".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -30,7 +31,7 @@ fn decode_invoke_only_source_found_empty() {
 fn decode_invoke_only_source_found_value() {
     envmnt::set("TEST_DECODE_ONLY_SOURCE_DEFINED_VALUE", "test");
 
-    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_DEFINED_VALUE".to_string()]);
+    let output = invoke(&vec!["TEST_DECODE_ONLY_SOURCE_DEFINED_VALUE".to_string()]).unwrap();
 
     assert_eq!(output, vec!["test"]);
 }
@@ -42,7 +43,8 @@ fn decode_invoke_only_default_empty() {
     let output = invoke(&vec![
         "TEST_DECODE_ONLY_DEFAULT_EMPTY".to_string(),
         "".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -54,7 +56,8 @@ fn decode_invoke_only_default_value() {
     let output = invoke(&vec![
         "TEST_DECODE_ONLY_DEFAULT_VALUE".to_string(),
         "default".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["default"]);
 }
@@ -67,7 +70,8 @@ fn decode_invoke_only_default_eval_value() {
     let output = invoke(&vec![
         "TEST_DECODE_ONLY_DEFAULT_EVAL_VALUE".to_string(),
         "${TEST_DECODE_ONLY_DEFAULT_EVAL_VALUE_RESULT}-test".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["result-test"]);
 }
@@ -82,7 +86,8 @@ fn decode_invoke_mappings_not_found_use_source() {
         "value1".to_string(),
         "key2".to_string(),
         "value2".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["source"]);
 }
@@ -98,7 +103,8 @@ fn decode_invoke_mappings_not_found_use_default() {
         "key2".to_string(),
         "value2".to_string(),
         "default".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["default"]);
 }
@@ -113,7 +119,8 @@ fn decode_invoke_mappings_found_no_default() {
         "value1".to_string(),
         "key2".to_string(),
         "value2".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["value2"]);
 }
@@ -129,7 +136,8 @@ fn decode_invoke_mappings_found_with_default() {
         "key2".to_string(),
         "value2".to_string(),
         "default".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["value2"]);
 }
@@ -146,7 +154,8 @@ fn decode_invoke_mappings_found_eval_output() {
         "key2".to_string(),
         "${TEST_DECODE_MAPPINGS_FOUND_EVAL_OUTPUT_VALUE}-output".to_string(),
         "default".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["value2-output"]);
 }
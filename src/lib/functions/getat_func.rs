@@ -7,12 +7,13 @@
 #[path = "getat_func_test.rs"]
 mod getat_func_test;
 
-use envmnt;
+use crate::environment::task_env;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
+pub(crate) fn invoke(function_args: &Vec<String>) -> Result<Vec<String>, String> {
     if function_args.len() != 3 {
-        error!(
-            "split expects only 3 arguments (environment variable name, split by character, index)"
+        return Err(
+            "getat expects only 3 arguments (environment variable name, split by character, index)"
+                .to_string(),
         );
     }
 
@@ -20,19 +21,16 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
     let split_by = function_args[1].clone();
     let index: usize = match function_args[2].parse() {
         Ok(value) => value,
-        Err(error) => {
-            error!("Invalid index value: {}", &error);
-            return vec![]; // should not get here
-        }
+        Err(error) => return Err(format!("Invalid index value: {}", &error)),
     };
 
     if split_by.len() != 1 {
-        error!("split expects a single character separator");
+        return Err("getat expects a single character separator".to_string());
     }
 
     let split_by_char = split_by.chars().next().unwrap();
 
-    let value = envmnt::get_or(&env_key, "");
+    let value = task_env::get_var(&env_key).unwrap_or_default();
 
     if value.len() > index {
         let splitted = value.split(split_by_char);
@@ -40,8 +38,8 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
         let splitted_vec: Vec<String> = splitted.map(|str_value| str_value.to_string()).collect();
         let value = splitted_vec[index].clone();
 
-        vec![value]
+        Ok(vec![value])
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
@@ -4,34 +4,38 @@ use crate::test;
 use envmnt;
 
 #[test]
-#[should_panic]
 fn getat_invoke_empty() {
-    invoke(&vec![]);
+    let result = invoke(&vec![]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn getat_invoke_invalid_too_many_args() {
     test::on_test_startup();
-    invoke(&vec![
+    let result = invoke(&vec![
         "TEST".to_string(),
         "1".to_string(),
         "2".to_string(),
         "3".to_string(),
     ]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn getat_invoke_invalid_getat_by_big() {
     test::on_test_startup();
-    invoke(&vec!["TEST".to_string(), "ab".to_string(), "0".to_string()]);
+    let result = invoke(&vec!["TEST".to_string(), "ab".to_string(), "0".to_string()]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn getat_invoke_invalid_getat_by_empty() {
-    invoke(&vec!["TEST".to_string(), "".to_string(), "0".to_string()]);
+    let result = invoke(&vec!["TEST".to_string(), "".to_string(), "0".to_string()]);
+
+    assert!(result.is_err());
 }
 
 #[test]
@@ -42,7 +46,8 @@ fn getat_invoke_exists_splitted_comma() {
         "TEST_GETAT_VALUE_COMMA".to_string(),
         ",".to_string(),
         "0".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["1"]);
 }
@@ -55,7 +60,8 @@ fn getat_invoke_exists_splitted_space() {
         "TEST_GETAT_VALUE_SPACE".to_string(),
         " ".to_string(),
         "0".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["1"]);
 }
@@ -68,7 +74,8 @@ fn getat_invoke_exists_not_splitted() {
         "TEST_GETAT_VALUE_NOT_GETATTED".to_string(),
         "|".to_string(),
         "0".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["1,2,3,4"]);
 }
@@ -79,7 +86,8 @@ fn getat_invoke_not_exists() {
         "TEST_GETAT_VALUE_NOT_EXISTS".to_string(),
         ",".to_string(),
         "0".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     let expected: Vec<String> = vec![];
     assert_eq!(output, expected);
@@ -93,7 +101,8 @@ fn getat_invoke_exists_splitted_middle() {
         "TEST_GETAT_VALUE_MIDDLE".to_string(),
         ",".to_string(),
         "2".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["3"]);
 }
@@ -106,7 +115,8 @@ fn getat_invoke_exists_splitted_out_of_bounds() {
         "TEST_GETAT_VALUE_OUT_OF_BOUNDS".to_string(),
         ",".to_string(),
         "20".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     let expected: Vec<String> = vec![];
     assert_eq!(output, expected);
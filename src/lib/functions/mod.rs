@@ -13,9 +13,12 @@ mod remove_empty_func;
 mod split_func;
 mod trim_func;
 
+use crate::error::CargoMakeError;
 use crate::types::{Step, Task};
 
-fn run_function(function_name: &str, function_args: &Vec<String>) -> Vec<String> {
+static FUNCTION_NAMES: [&str; 5] = ["split", "remove-empty", "trim", "getat", "decode"];
+
+fn run_function(function_name: &str, function_args: &Vec<String>) -> Result<Vec<String>, String> {
     debug!(
         "Running function: {} arguments: {:#?}",
         &function_name, &function_args
@@ -27,10 +30,7 @@ fn run_function(function_name: &str, function_args: &Vec<String>) -> Vec<String>
         "trim" => trim_func::invoke(function_args),
         "getat" => getat_func::invoke(function_args),
         "decode" => decode_func::invoke(function_args),
-        _ => {
-            error!("Unknown function: {}", &function_name);
-            panic!("Unknown function: {}", &function_name);
-        }
+        _ => Err(format!("Unknown function: {}", &function_name)),
     }
 }
 
@@ -70,7 +70,7 @@ fn get_function_arguments(function_string: &str) -> Option<Vec<String>> {
     }
 }
 
-fn evaluate_and_run(value: &str) -> Vec<String> {
+fn evaluate_and_run(value: &str) -> Result<Vec<String>, String> {
     let value_string = value.to_string();
 
     if value_string.starts_with("@@") {
@@ -84,23 +84,37 @@ fn evaluate_and_run(value: &str) -> Vec<String> {
 
                 match func_args_option {
                     Some(function_args) => run_function(&function_name, &function_args),
-                    None => vec![value_string],
+                    None => Ok(vec![value_string]),
                 }
             }
-            None => vec![value_string],
+            None => Ok(vec![value_string]),
         }
     } else {
-        vec![value_string]
+        Ok(vec![value_string])
+    }
+}
+
+/// Returns true if a function with the provided name exists
+pub(crate) fn is_function_defined(function_name: &str) -> bool {
+    FUNCTION_NAMES.contains(&function_name)
+}
+
+/// Returns the function name if the provided value is a function invocation (@@name(args))
+pub(crate) fn get_invoked_function_name(value: &str) -> Option<String> {
+    if value.starts_with("@@") {
+        get_function_name(&value[2..])
+    } else {
+        None
     }
 }
 
-fn modify_arguments(task: &mut Task) {
+fn modify_arguments(task: &mut Task) -> Result<(), String> {
     task.args = match task.args {
         Some(ref args) => {
             let mut new_args = vec![];
 
             for index in 0..args.len() {
-                let result_args = evaluate_and_run(&args[index]);
+                let result_args = evaluate_and_run(&args[index])?;
 
                 for result_index in 0..result_args.len() {
                     new_args.push(result_args[result_index].clone());
@@ -111,17 +125,26 @@ fn modify_arguments(task: &mut Task) {
         }
         None => None,
     };
+
+    Ok(())
 }
 
-pub(crate) fn run(step: &Step) -> Step {
+/// Returns the step with all function invocations in the task arguments replaced by the
+/// function output
+pub(crate) fn run(step: &Step) -> Result<Step, CargoMakeError> {
     //clone data before modify
     let mut config = step.config.clone();
 
     //update args by running any needed function
-    modify_arguments(&mut config);
+    if let Err(error) = modify_arguments(&mut config) {
+        return Err(CargoMakeError::InvalidTask(format!(
+            "Task: {} invalid function invocation: {}",
+            &step.name, error
+        )));
+    }
 
-    Step {
+    Ok(Step {
         name: step.name.clone(),
         config,
-    }
+    })
 }
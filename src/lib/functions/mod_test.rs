@@ -3,15 +3,17 @@ use super::*;
 use envmnt;
 
 #[test]
-#[should_panic]
 fn run_function_empty() {
-    run_function("", &vec![]);
+    let result = run_function("", &vec![]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn run_function_not_exists() {
-    run_function("bad", &vec![]);
+    let result = run_function("bad", &vec![]);
+
+    assert!(result.is_err());
 }
 
 #[test]
@@ -21,7 +23,8 @@ fn run_function_split() {
     let output = run_function(
         "split",
         &vec!["TEST_MOD_SPLIT_FUNC_MOD".to_string(), ",".to_string()],
-    );
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["1", "2", "3", "4"]);
 }
@@ -37,7 +40,8 @@ fn run_function_getat() {
             ",".to_string(),
             "2".to_string(),
         ],
-    );
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["3"]);
 }
@@ -49,7 +53,8 @@ fn run_function_remove_empty() {
     let output = run_function(
         "remove-empty",
         &vec!["TEST_MOD_REMOVE_EMPTY_FUNC_MOD".to_string()],
-    );
+    )
+    .unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -58,7 +63,7 @@ fn run_function_remove_empty() {
 fn run_function_trim() {
     envmnt::set("TEST_MOD_TRIM_FUNC_MOD", "    ");
 
-    let output = run_function("trim", &vec!["TEST_MOD_TRIM_FUNC_MOD".to_string()]);
+    let output = run_function("trim", &vec!["TEST_MOD_TRIM_FUNC_MOD".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -76,7 +81,8 @@ fn run_function_decode() {
             "ci".to_string(),
             "test".to_string(),
         ],
-    );
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["test"]);
 }
@@ -170,20 +176,21 @@ fn get_function_arguments_multiple_with_spaces() {
 fn evaluate_and_run_valid() {
     envmnt::set("TEST_MOD_RUN_FUNC_VALUE", "1 2 3 4");
 
-    let output = evaluate_and_run("@@split(TEST_MOD_RUN_FUNC_VALUE, )");
+    let output = evaluate_and_run("@@split(TEST_MOD_RUN_FUNC_VALUE, )").unwrap();
 
     assert_eq!(output, vec!["1", "2", "3", "4"]);
 }
 
 #[test]
-#[should_panic]
 fn evaluate_and_run_unknown_function() {
-    evaluate_and_run("@@bad()");
+    let result = evaluate_and_run("@@bad()");
+
+    assert!(result.is_err());
 }
 
 #[test]
 fn evaluate_and_run_no_function() {
-    let output = evaluate_and_run("value");
+    let output = evaluate_and_run("value").unwrap();
 
     assert_eq!(output, vec!["value"]);
 }
@@ -199,7 +206,7 @@ fn modify_arguments_with_functions() {
         "end".to_string(),
     ]);
 
-    modify_arguments(&mut task);
+    modify_arguments(&mut task).unwrap();
 
     assert_eq!(task.args.unwrap(), vec!["start", "1", "2", "3", "4", "end"]);
 }
@@ -219,10 +226,50 @@ fn run_with_functions() {
         config: task,
     };
 
-    step = run(&step);
+    step = run(&step).unwrap();
 
     assert_eq!(
         step.config.args.unwrap(),
         vec!["start", "1", "2", "3", "4", "end"]
     );
 }
+
+#[test]
+fn run_with_unknown_function() {
+    let mut task = Task::new();
+    task.args = Some(vec!["@@bad()".to_string()]);
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let result = run(&step);
+
+    assert_eq!(
+        result.unwrap_err(),
+        CargoMakeError::InvalidTask(
+            "Task: test invalid function invocation: Unknown function: bad".to_string()
+        )
+    );
+}
+
+#[test]
+fn is_function_defined_valid() {
+    assert!(is_function_defined("split"));
+    assert!(is_function_defined("remove-empty"));
+    assert!(!is_function_defined("unknown"));
+}
+
+#[test]
+fn get_invoked_function_name_function() {
+    let output = get_invoked_function_name("@@split(a,b)");
+
+    assert_eq!(output.unwrap(), "split");
+}
+
+#[test]
+fn get_invoked_function_name_not_function() {
+    let output = get_invoked_function_name("split(a,b)");
+
+    assert!(output.is_none());
+}
@@ -7,20 +7,20 @@
 #[path = "remove_empty_func_test.rs"]
 mod remove_empty_func_test;
 
-use envmnt;
+use crate::environment::task_env;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
+pub(crate) fn invoke(function_args: &Vec<String>) -> Result<Vec<String>, String> {
     if function_args.len() != 1 {
-        error!("remove_empty expects only 1 argument (environment variable name)");
+        return Err("remove_empty expects only 1 argument (environment variable name)".to_string());
     }
 
     let env_key = function_args[0].clone();
 
-    let value = envmnt::get_or(&env_key, "");
+    let value = task_env::get_var(&env_key).unwrap_or_default();
 
     if value.len() > 0 {
-        vec![value]
+        Ok(vec![value])
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
@@ -3,22 +3,24 @@ use super::*;
 use envmnt;
 
 #[test]
-#[should_panic]
 fn remove_empty_invoke_empty() {
-    invoke(&vec![]);
+    let result = invoke(&vec![]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn remove_empty_invoke_invalid_too_many_args() {
-    invoke(&vec!["TEST".to_string(), "1".to_string()]);
+    let result = invoke(&vec!["TEST".to_string(), "1".to_string()]);
+
+    assert!(result.is_err());
 }
 
 #[test]
 fn remove_empty_invoke_exists_with_value() {
     envmnt::set("TEST_REMOVE_EMPTY_VALID", "abc");
 
-    let output = invoke(&vec!["TEST_REMOVE_EMPTY_VALID".to_string()]);
+    let output = invoke(&vec!["TEST_REMOVE_EMPTY_VALID".to_string()]).unwrap();
 
     assert_eq!(output, vec!["abc"]);
 }
@@ -27,14 +29,14 @@ fn remove_empty_invoke_exists_with_value() {
 fn remove_empty_invoke_exists_empty() {
     envmnt::set("TEST_REMOVE_EMPTY_EMPTY", "");
 
-    let output = invoke(&vec!["TEST_REMOVE_EMPTY_EMPTY".to_string()]);
+    let output = invoke(&vec!["TEST_REMOVE_EMPTY_EMPTY".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
 
 #[test]
 fn remove_empty_invoke_not_exists() {
-    let output = invoke(&vec!["TEST_REMOVE_EMPTY_NOT_EXISTS".to_string()]);
+    let output = invoke(&vec!["TEST_REMOVE_EMPTY_NOT_EXISTS".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
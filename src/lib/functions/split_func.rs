@@ -7,29 +7,32 @@
 #[path = "split_func_test.rs"]
 mod split_func_test;
 
-use envmnt;
+use crate::environment::task_env;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
+pub(crate) fn invoke(function_args: &Vec<String>) -> Result<Vec<String>, String> {
     if function_args.len() != 2 {
-        error!("split expects only 2 arguments (environment variable name, split by character)");
+        return Err(
+            "split expects only 2 arguments (environment variable name, split by character)"
+                .to_string(),
+        );
     }
 
     let env_key = function_args[0].clone();
     let split_by = function_args[1].clone();
 
     if split_by.len() != 1 {
-        error!("split expects a single character separator");
+        return Err("split expects a single character separator".to_string());
     }
 
     let split_by_char = split_by.chars().next().unwrap();
 
-    let value = envmnt::get_or(&env_key, "");
+    let value = task_env::get_var(&env_key).unwrap_or_default();
 
     if value.len() > 0 {
         let splitted = value.split(split_by_char);
 
-        splitted.map(|str_value| str_value.to_string()).collect()
+        Ok(splitted.map(|str_value| str_value.to_string()).collect())
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
@@ -4,36 +4,40 @@ use crate::test;
 use envmnt;
 
 #[test]
-#[should_panic]
 fn split_invoke_empty() {
-    invoke(&vec![]);
+    let result = invoke(&vec![]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn split_invoke_invalid_too_many_args() {
     test::on_test_startup();
-    invoke(&vec!["TEST".to_string(), "1".to_string(), "2".to_string()]);
+    let result = invoke(&vec!["TEST".to_string(), "1".to_string(), "2".to_string()]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn split_invoke_invalid_split_by_big() {
     test::on_test_startup();
-    invoke(&vec!["TEST".to_string(), "ab".to_string()]);
+    let result = invoke(&vec!["TEST".to_string(), "ab".to_string()]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn split_invoke_invalid_split_by_empty() {
-    invoke(&vec!["TEST".to_string(), "".to_string()]);
+    let result = invoke(&vec!["TEST".to_string(), "".to_string()]);
+
+    assert!(result.is_err());
 }
 
 #[test]
 fn split_invoke_exists_splitted_comma() {
     envmnt::set("TEST_SPLIT_VALUE_COMMA", "1,2,3,4");
 
-    let output = invoke(&vec!["TEST_SPLIT_VALUE_COMMA".to_string(), ",".to_string()]);
+    let output = invoke(&vec!["TEST_SPLIT_VALUE_COMMA".to_string(), ",".to_string()]).unwrap();
 
     assert_eq!(output, vec!["1", "2", "3", "4"]);
 }
@@ -42,7 +46,7 @@ fn split_invoke_exists_splitted_comma() {
 fn split_invoke_exists_splitted_space() {
     envmnt::set("TEST_SPLIT_VALUE_SPACE", "1 2 3 4");
 
-    let output = invoke(&vec!["TEST_SPLIT_VALUE_SPACE".to_string(), " ".to_string()]);
+    let output = invoke(&vec!["TEST_SPLIT_VALUE_SPACE".to_string(), " ".to_string()]).unwrap();
 
     assert_eq!(output, vec!["1", "2", "3", "4"]);
 }
@@ -54,7 +58,8 @@ fn split_invoke_exists_not_splitted() {
     let output = invoke(&vec![
         "TEST_SPLIT_VALUE_NOT_SPLITTED".to_string(),
         "|".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     assert_eq!(output, vec!["1,2,3,4"]);
 }
@@ -64,7 +69,8 @@ fn split_invoke_not_exists() {
     let output = invoke(&vec![
         "TEST_SPLIT_VALUE_NOT_EXISTS".to_string(),
         ",".to_string(),
-    ]);
+    ])
+    .unwrap();
 
     let expected: Vec<String> = vec![];
     assert_eq!(output, expected);
@@ -8,16 +8,16 @@
 #[path = "trim_func_test.rs"]
 mod trim_func_test;
 
-use envmnt;
+use crate::environment::task_env;
 
-pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
-    if function_args.len() > 2 {
-        error!("trim expects up to 2 arguments (environment variable name and optionally start/end trim flag)");
+pub(crate) fn invoke(function_args: &Vec<String>) -> Result<Vec<String>, String> {
+    if function_args.len() == 0 || function_args.len() > 2 {
+        return Err("trim expects up to 2 arguments (environment variable name and optionally start/end trim flag)".to_string());
     }
 
     let env_key = function_args[0].clone();
 
-    let value = envmnt::get_or(&env_key, "");
+    let value = task_env::get_var(&env_key).unwrap_or_default();
 
     let trimmed_value = if function_args.len() == 1 {
         value.trim().to_string()
@@ -28,15 +28,16 @@ pub(crate) fn invoke(function_args: &Vec<String>) -> Vec<String> {
             "start" => value.trim_start().to_string(),
             "end" => value.trim_end().to_string(),
             _ => {
-                error!("Invalid trim type provided, only start or end are supported.");
-                panic!("Invalid trim type provided, only start or end are supported.");
+                return Err(
+                    "Invalid trim type provided, only start or end are supported.".to_string(),
+                )
             }
         }
     };
 
     if trimmed_value.len() > 0 {
-        vec![trimmed_value]
+        Ok(vec![trimmed_value])
     } else {
-        vec![]
+        Ok(vec![])
     }
 }
@@ -3,28 +3,31 @@ use super::*;
 use envmnt;
 
 #[test]
-#[should_panic]
 fn trim_invoke_empty() {
-    invoke(&vec![]);
+    let result = invoke(&vec![]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn trim_invoke_invalid_too_many_args() {
-    invoke(&vec!["TEST".to_string(), "1".to_string(), "2".to_string()]);
+    let result = invoke(&vec!["TEST".to_string(), "1".to_string(), "2".to_string()]);
+
+    assert!(result.is_err());
 }
 
 #[test]
-#[should_panic]
 fn trim_invoke_invalid_trim_type() {
-    invoke(&vec!["TEST".to_string(), "bad".to_string()]);
+    let result = invoke(&vec!["TEST".to_string(), "bad".to_string()]);
+
+    assert!(result.is_err());
 }
 
 #[test]
 fn trim_invoke_exists_with_value() {
     envmnt::set("TEST_TRIM_VALID", "abc");
 
-    let output = invoke(&vec!["TEST_TRIM_VALID".to_string()]);
+    let output = invoke(&vec!["TEST_TRIM_VALID".to_string()]).unwrap();
 
     assert_eq!(output, vec!["abc"]);
 }
@@ -33,14 +36,14 @@ fn trim_invoke_exists_with_value() {
 fn trim_invoke_exists_empty() {
     envmnt::set("TEST_TRIM_EMPTY", "");
 
-    let output = invoke(&vec!["TEST_TRIM_EMPTY".to_string()]);
+    let output = invoke(&vec!["TEST_TRIM_EMPTY".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
 
 #[test]
 fn trim_invoke_not_exists() {
-    let output = invoke(&vec!["TEST_TRIM_NOT_EXISTS".to_string()]);
+    let output = invoke(&vec!["TEST_TRIM_NOT_EXISTS".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -49,7 +52,7 @@ fn trim_invoke_not_exists() {
 fn trim_invoke_all_spaces() {
     envmnt::set("TEST_TRIM_ALL_SPACES", "");
 
-    let output = invoke(&vec!["TEST_TRIM_ALL_SPACES".to_string()]);
+    let output = invoke(&vec!["TEST_TRIM_ALL_SPACES".to_string()]).unwrap();
 
     assert_eq!(output.len(), 0);
 }
@@ -58,7 +61,7 @@ fn trim_invoke_all_spaces() {
 fn trim_invoke_partial_spaces() {
     envmnt::set("TEST_TRIM_PARTIAL_SPACES", "   123   123   ");
 
-    let output = invoke(&vec!["TEST_TRIM_PARTIAL_SPACES".to_string()]);
+    let output = invoke(&vec!["TEST_TRIM_PARTIAL_SPACES".to_string()]).unwrap();
 
     assert_eq!(output, vec!["123   123"]);
 }
@@ -67,7 +70,7 @@ fn trim_invoke_partial_spaces() {
 fn trim_invoke_trim_start() {
     envmnt::set("TEST_TRIM_START", "   123   ");
 
-    let output = invoke(&vec!["TEST_TRIM_START".to_string(), "start".to_string()]);
+    let output = invoke(&vec!["TEST_TRIM_START".to_string(), "start".to_string()]).unwrap();
 
     assert_eq!(output, vec!["123   "]);
 }
@@ -76,7 +79,7 @@ fn trim_invoke_trim_start() {
 fn trim_invoke_trim_end() {
     envmnt::set("TEST_TRIM_END", "   123   ");
 
-    let output = invoke(&vec!["TEST_TRIM_END".to_string(), "end".to_string()]);
+    let output = invoke(&vec!["TEST_TRIM_END".to_string(), "end".to_string()]).unwrap();
 
     assert_eq!(output, vec!["   123"]);
 }
@@ -0,0 +1,69 @@
+//! # git
+//!
+//! Runs git commands in order to query the repository state (changed files, tags and so on).
+//!
+
+#[cfg(test)]
+#[path = "git_test.rs"]
+mod git_test;
+
+use crate::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Runs git with the given arguments in the given directory and returns its output
+pub(crate) fn run_git(directory: &Path, args: &[&str]) -> Result<String, String> {
+    match Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .output()
+    {
+        Ok(output) => {
+            if output.status.success() {
+                Ok(String::from_utf8_lossy(&output.stdout).to_string())
+            } else {
+                Err(format!(
+                    "git {} failed: {}",
+                    args.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ))
+            }
+        }
+        Err(error) => Err(format!("Unable to run git, error: {}", error)),
+    }
+}
+
+/// Returns the files (as absolute paths) changed since the merge base of the given git ref and
+/// the current HEAD, including uncommitted and untracked files
+pub(crate) fn get_changed_files(directory: &Path, git_ref: &str) -> Result<Vec<PathBuf>, String> {
+    let root_directory = run_git(directory, &["rev-parse", "--show-toplevel"])?;
+    let root_directory = PathBuf::from(io::canonicalize_to_string(root_directory.trim()));
+
+    let merge_base = run_git(&root_directory, &["merge-base", git_ref, "HEAD"])?;
+    let changed = run_git(
+        &root_directory,
+        &["diff", "--name-only", "--no-renames", merge_base.trim()],
+    )?;
+    let untracked = run_git(
+        &root_directory,
+        &["ls-files", "--others", "--exclude-standard"],
+    )?;
+
+    Ok(changed
+        .lines()
+        .chain(untracked.lines())
+        .filter(|file| !file.is_empty())
+        .map(|file| root_directory.join(file))
+        .collect())
+}
+
+/// Returns the tags which point at the current HEAD
+pub(crate) fn get_head_tags(directory: &Path) -> Result<Vec<String>, String> {
+    let tags = run_git(directory, &["tag", "--points-at", "HEAD"])?;
+
+    Ok(tags
+        .lines()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect())
+}
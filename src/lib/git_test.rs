@@ -0,0 +1,64 @@
+use super::*;
+use crate::test;
+
+#[test]
+fn run_git_valid() {
+    let directory = test::create_git_repo("run_git_valid");
+
+    let output = run_git(&directory, &["ls-files"]).unwrap();
+
+    assert_eq!(output.trim(), "README.md");
+}
+
+#[test]
+fn run_git_error() {
+    let directory = test::create_git_repo("run_git_error");
+
+    let output = run_git(&directory, &["rev-parse", "--verify", "-q", "no-such-ref"]);
+
+    assert!(output.is_err());
+    assert!(output.unwrap_err().starts_with("git rev-parse"));
+}
+
+#[test]
+fn get_head_tags_none() {
+    let directory = test::create_git_repo("get_head_tags_none");
+
+    let tags = get_head_tags(&directory).unwrap();
+
+    assert!(tags.is_empty());
+}
+
+#[test]
+fn get_head_tags_multiple() {
+    let directory = test::create_git_repo("get_head_tags_multiple");
+    test::run_git_commands(
+        &directory,
+        vec![
+            vec!["tag", "v1.0.0"],
+            vec!["tag", "-a", "latest", "-m", "latest"],
+        ],
+    );
+
+    let tags = get_head_tags(&directory).unwrap();
+
+    assert_eq!(tags, vec!["latest", "v1.0.0"]);
+}
+
+#[test]
+fn get_head_tags_previous_commit() {
+    let directory = test::create_git_repo("get_head_tags_previous_commit");
+    fsio::file::write_text_file(&directory.join("file.txt"), "test").unwrap();
+    test::run_git_commands(
+        &directory,
+        vec![
+            vec!["tag", "v1.0.0"],
+            vec!["add", "-A"],
+            vec!["commit", "-q", "-m", "second"],
+        ],
+    );
+
+    let tags = get_head_tags(&directory).unwrap();
+
+    assert!(tags.is_empty());
+}
@@ -8,18 +8,25 @@
 mod cargo_plugin_installer_test;
 
 use crate::command;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
 use crate::installer::crate_version_check;
+use crate::installer::to_installer_error;
 use crate::toolchain::wrap_command;
 use crate::types::ToolchainSpecifier;
 use envmnt;
 use std::process::Command;
 
-fn is_crate_installed(toolchain: &Option<ToolchainSpecifier>, crate_name: &str) -> bool {
+pub(crate) fn is_crate_installed(
+    toolchain: &Option<ToolchainSpecifier>,
+    crate_name: &str,
+) -> Result<bool, CargoMakeError> {
     debug!("Getting list of installed cargo commands.");
 
     let mut command_struct = match toolchain {
         Some(ref toolchain_string) => {
-            let command_spec = wrap_command(toolchain_string, "cargo", &None);
+            let command_spec =
+                wrap_command(toolchain_string, "cargo", &None).map_err(to_installer_error)?;
             let mut cmd = Command::new(command_spec.command);
             cmd.args(command_spec.args.unwrap());
 
@@ -27,15 +34,18 @@ fn is_crate_installed(toolchain: &Option<ToolchainSpecifier>, crate_name: &str)
         }
         None => Command::new("cargo"),
     };
+    task_env::apply_to_command(&mut command_struct);
 
-    let result = command_struct.arg("--list").output();
+    command_struct.arg("--list");
+    let result = task_env::output(&mut command_struct);
 
     match result {
         Ok(output) => {
             let mut found = false;
 
-            let exit_code = command::get_exit_code(Ok(output.status), false);
-            command::validate_exit_code(exit_code);
+            let exit_code =
+                command::get_exit_code(Ok(output.status), false).map_err(to_installer_error)?;
+            command::validate_exit_code(exit_code).map_err(to_installer_error)?;
 
             let stdout = String::from_utf8_lossy(&output.stdout);
             let lines: Vec<&str> = stdout.split(' ').collect();
@@ -52,15 +62,12 @@ fn is_crate_installed(toolchain: &Option<ToolchainSpecifier>, crate_name: &str)
                 }
             }
 
-            found
-        }
-        Err(error) => {
-            error!(
-                "Unable to check if crate is installed: {} {:#?}",
-                crate_name, &error
-            );
-            false
+            Ok(found)
         }
+        Err(error) => Err(CargoMakeError::InstallerFailed(format!(
+            "Unable to check if crate is installed: {} {:#?}",
+            crate_name, &error
+        ))),
     }
 }
 
@@ -122,8 +129,8 @@ pub(crate) fn install_crate(
     validate: bool,
     min_version: &Option<String>,
     install_command: &Option<String>,
-) {
-    let installed = is_crate_installed(&toolchain, cargo_command);
+) -> Result<(), CargoMakeError> {
+    let installed = is_crate_installed(&toolchain, cargo_command)?;
     let mut force = false;
     let run_installation = if !installed {
         true
@@ -149,10 +156,13 @@ pub(crate) fn install_crate(
 
         match toolchain {
             Some(ref toolchain_string) => {
-                let command_spec = wrap_command(&toolchain_string, "cargo", &Some(install_args));
+                let command_spec = wrap_command(&toolchain_string, "cargo", &Some(install_args))?;
                 command::run_command(&command_spec.command, &command_spec.args, validate)
             }
             None => command::run_command("cargo", &Some(install_args), validate),
-        };
+        }
+        .map_err(to_installer_error)?;
     }
+
+    Ok(())
 }
@@ -3,13 +3,13 @@ use crate::test;
 
 #[test]
 fn is_crate_installed_true() {
-    let output = is_crate_installed(&None, "test");
+    let output = is_crate_installed(&None, "test").unwrap();
     assert!(output);
 }
 
 #[test]
 fn is_crate_installed_false() {
-    let output = is_crate_installed(&None, "badbadbad");
+    let output = is_crate_installed(&None, "badbadbad").unwrap();
     assert!(!output);
 }
 
@@ -18,7 +18,7 @@ fn is_crate_installed_with_toolchain_true() {
     if test::is_not_rust_stable() {
         let toolchain = test::get_toolchain();
 
-        let output = is_crate_installed(&Some(toolchain), "test");
+        let output = is_crate_installed(&Some(toolchain), "test").unwrap();
         assert!(output);
     }
 }
@@ -28,7 +28,7 @@ fn is_crate_installed_with_toolchain_false() {
     if test::is_not_rust_stable() {
         let toolchain = test::get_toolchain();
 
-        let output = is_crate_installed(&Some(toolchain), "badbadbad");
+        let output = is_crate_installed(&Some(toolchain), "badbadbad").unwrap();
         assert!(!output);
     }
 }
@@ -229,12 +229,12 @@ fn should_skip_crate_name_git() {
 
 #[test]
 fn install_crate_already_installed_test() {
-    install_crate(&None, "test", "bad", &None, true, &None, &None);
+    install_crate(&None, "test", "bad", &None, true, &None, &None).unwrap();
 }
 
 #[test]
 fn install_crate_already_installed_cargo_make() {
-    install_crate(&None, "make", "cargo-make", &None, true, &None, &None);
+    install_crate(&None, "make", "cargo-make", &None, true, &None, &None).unwrap();
 }
 
 #[test]
@@ -256,7 +256,8 @@ fn install_crate_already_installed_min_version_equal() {
         true,
         &Some(version_string),
         &None,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -286,5 +287,6 @@ fn install_crate_already_installed_min_version_smaller() {
         true,
         &Some(version_string),
         &None,
-    );
+    )
+    .unwrap();
 }
@@ -8,10 +8,12 @@
 mod crate_installer_test;
 
 use crate::command;
+use crate::error::CargoMakeError;
 use crate::installer::crate_version_check;
+use crate::installer::to_installer_error;
 use crate::installer::{cargo_plugin_installer, rustup_component_installer};
 use crate::toolchain::wrap_command;
-use crate::types::{CommandSpec, InstallCrateInfo, InstallRustupComponentInfo, ToolchainSpecifier};
+use crate::types::{InstallCrateInfo, InstallRustupComponentInfo, ToolchainSpecifier};
 
 fn invoke_rustup_install(toolchain: &Option<ToolchainSpecifier>, info: &InstallCrateInfo) -> bool {
     match info.rustup_component_name {
@@ -32,7 +34,7 @@ fn invoke_cargo_install(
     info: &InstallCrateInfo,
     args: &Option<Vec<String>>,
     validate: bool,
-) {
+) -> Result<(), CargoMakeError> {
     let (automatic_lock_version, version_option) = if info.min_version.is_some() {
         (false, &info.min_version)
     } else {
@@ -55,19 +57,19 @@ fn invoke_cargo_install(
         &info.install_command,
     );
 
-    let command_spec = match toolchain {
-        Some(ref toolchain_string) => wrap_command(toolchain_string, "cargo", &Some(install_args)),
-        None => CommandSpec {
-            command: "cargo".to_string(),
-            args: Some(install_args),
-        },
+    let result = match toolchain {
+        Some(ref toolchain_string) => wrap_command(toolchain_string, "cargo", &Some(install_args))
+            .and_then(|command_spec| {
+                command::run_command(&command_spec.command, &command_spec.args, validate)
+            }),
+        None => command::run_command("cargo", &Some(install_args), validate),
     };
 
-    command::run_command(&command_spec.command, &command_spec.args, validate);
-
     if remove_lock {
         envmnt::remove("CARGO_MAKE_CRATE_INSTALLATION_LOCKED");
     }
+
+    result.map(|_| ()).map_err(to_installer_error)
 }
 
 fn is_crate_only_info(info: &InstallCrateInfo) -> bool {
@@ -82,7 +84,7 @@ pub(crate) fn install(
     info: &InstallCrateInfo,
     args: &Option<Vec<String>>,
     validate: bool,
-) {
+) -> Result<(), CargoMakeError> {
     let installed =
         rustup_component_installer::is_installed(&toolchain, &info.binary, &info.test_arg);
     let crate_only_info = is_crate_only_info(&info);
@@ -112,7 +114,9 @@ pub(crate) fn install(
         debug!("Crate: {} not installed.", &info.crate_name);
 
         if !invoke_rustup_install(&toolchain, &info) {
-            invoke_cargo_install(&toolchain, &info, &args, validate);
+            invoke_cargo_install(&toolchain, &info, &args, validate)?;
         }
     }
+
+    Ok(())
 }
@@ -92,7 +92,7 @@ fn invoke_cargo_install_test() {
         install_command: None,
     };
 
-    invoke_cargo_install(&None, &info, &None, false);
+    invoke_cargo_install(&None, &info, &None, false).unwrap();
 }
 
 #[test]
@@ -111,7 +111,7 @@ fn invoke_cargo_install_with_toolchain_test() {
         install_command: None,
     };
 
-    invoke_cargo_install(&Some(toolchain), &info, &None, false);
+    invoke_cargo_install(&Some(toolchain), &info, &None, false).unwrap();
 }
 
 #[test]
@@ -128,7 +128,7 @@ fn install_test_test() {
         install_command: None,
     };
 
-    install(&None, &info, &None, false);
+    install(&None, &info, &None, false).unwrap();
 }
 
 #[test]
@@ -147,7 +147,7 @@ fn install_test_with_toolchain_test() {
         install_command: None,
     };
 
-    install(&Some(toolchain), &info, &None, false);
+    install(&Some(toolchain), &info, &None, false).unwrap();
 }
 
 #[test]
@@ -164,7 +164,7 @@ fn install_already_installed_crate_only() {
         install_command: None,
     };
 
-    install(&None, &info, &None, false);
+    install(&None, &info, &None, false).unwrap();
 }
 
 #[test]
@@ -189,7 +189,7 @@ fn install_already_installed_crate_only_min_version_equal() {
         install_command: None,
     };
 
-    install(&None, &info, &None, false);
+    install(&None, &info, &None, false).unwrap();
 }
 
 #[test]
@@ -222,7 +222,7 @@ fn install_already_installed_crate_only_min_version_smaller() {
         install_command: None,
     };
 
-    install(&None, &info, &None, false);
+    install(&None, &info, &None, false).unwrap();
 }
 
 #[test]
@@ -247,7 +247,7 @@ fn install_already_installed_crate_only_version_equal() {
         install_command: None,
     };
 
-    install(&None, &info, &None, false);
+    install(&None, &info, &None, false).unwrap();
 }
 
 #[test]
@@ -15,11 +15,22 @@ pub(crate) mod rustup_component_installer;
 #[path = "mod_test.rs"]
 mod mod_test;
 
+use crate::error::CargoMakeError;
 use crate::scriptengine;
 use crate::types::{FlowInfo, FlowState, InstallCrate, Task};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Converts task failures of the installation commands/scripts to installer failures
+pub(crate) fn to_installer_error(error: CargoMakeError) -> CargoMakeError {
+    match error {
+        CargoMakeError::TaskFailed(message) | CargoMakeError::Other(message) => {
+            CargoMakeError::InstallerFailed(message)
+        }
+        _ => error,
+    }
+}
+
 fn get_cargo_plugin_info_from_command(task_config: &Task) -> Option<(String, String)> {
     match task_config.command {
         Some(ref command) => {
@@ -63,10 +74,10 @@ pub(crate) fn install(
     task_config: &Task,
     flow_info: &FlowInfo,
     flow_state: Rc<RefCell<FlowState>>,
-) {
+) -> Result<(), CargoMakeError> {
     let validate = !task_config.should_ignore_errors();
 
-    let toolchain = task_config.toolchain.clone();
+    let toolchain = task_config.get_toolchain();
 
     let mut install_crate = task_config.install_crate.clone();
     if let Some(ref install_crate_value) = install_crate {
@@ -80,14 +91,15 @@ pub(crate) fn install(
 
     match install_crate {
         Some(ref install_crate_info) => match install_crate_info {
-            InstallCrate::Enabled(_) => (),
+            InstallCrate::Enabled(_) => Ok(()),
             InstallCrate::Value(ref crate_name) => {
                 let first_arg = get_first_command_arg(task_config);
                 let cargo_command = match first_arg {
                     Some(ref arg) => arg,
                     None => {
-                        error!("Missing cargo command to invoke.");
-                        panic!("Missing cargo command to invoke.");
+                        return Err(CargoMakeError::InvalidTask(
+                            "Missing cargo command to invoke.".to_string(),
+                        ));
                     }
                 };
 
@@ -99,7 +111,7 @@ pub(crate) fn install(
                     validate,
                     &None,
                     &None,
-                );
+                )
             }
             InstallCrate::CargoPluginInfo(ref install_info) => {
                 let (cargo_command, crate_name) =
@@ -109,8 +121,9 @@ pub(crate) fn install(
                             Some(arg) => match install_info.crate_name {
                                 Some(ref crate_name) => (arg, crate_name.to_string()),
                                 None => {
-                                    error!("Missing crate name to invoke.");
-                                    panic!("Missing crate name to invoke.");
+                                    return Err(CargoMakeError::InvalidTask(
+                                        "Missing crate name to invoke.".to_string(),
+                                    ));
                                 }
                             },
                             None => match install_info.crate_name {
@@ -118,8 +131,9 @@ pub(crate) fn install(
                                     (crate_name.to_string(), crate_name.to_string())
                                 }
                                 None => {
-                                    error!("Missing cargo command to invoke.");
-                                    panic!("Missing crate command to invoke.");
+                                    return Err(CargoMakeError::InvalidTask(
+                                        "Missing crate command to invoke.".to_string(),
+                                    ));
                                 }
                             },
                         },
@@ -133,7 +147,7 @@ pub(crate) fn install(
                     validate,
                     &install_info.min_version,
                     &install_info.install_command,
-                );
+                )
             }
             InstallCrate::CrateInfo(ref install_info) => crate_installer::install(
                 &toolchain,
@@ -142,7 +156,9 @@ pub(crate) fn install(
                 validate,
             ),
             InstallCrate::RustupComponentInfo(ref install_info) => {
-                rustup_component_installer::install(&toolchain, install_info, validate);
+                rustup_component_installer::install(&toolchain, install_info, validate)?;
+
+                Ok(())
             }
         },
         None => match task_config.install_script {
@@ -155,23 +171,117 @@ pub(crate) fn install(
                     validate,
                     Some(flow_info),
                     Some(flow_state),
+                )
+                .map_err(to_installer_error)?;
+
+                Ok(())
+            }
+            None => match get_cargo_plugin_info_from_command(&task_config) {
+                Some((cargo_command, crate_name)) => cargo_plugin_installer::install_crate(
+                    &toolchain,
+                    &cargo_command,
+                    &crate_name,
+                    &task_config.install_crate_args,
+                    validate,
+                    &None,
+                    &None,
+                ),
+                None => {
+                    debug!("No installation script defined.");
+
+                    Ok(())
+                }
+            },
+        },
+    }
+}
+
+/// Returns a description of the installation step which will be invoked before the task action
+/// (or None if nothing is installed)
+pub(crate) fn describe(task_config: &Task) -> Result<Option<String>, CargoMakeError> {
+    let plugin_description = |crate_name: &str, cargo_command: &str| {
+        format!(
+            "cargo plugin: {} (cargo {}), if not installed",
+            crate_name, cargo_command
+        )
+    };
+
+    let mut install_crate = task_config.install_crate.clone();
+    if let Some(InstallCrate::Enabled(true)) = install_crate {
+        // enabled true is the same as no install_crate defined
+        install_crate = None;
+    }
+
+    let description = match install_crate {
+        Some(ref install_crate_info) => match install_crate_info {
+            InstallCrate::Enabled(_) => None,
+            InstallCrate::Value(ref crate_name) => {
+                let cargo_command = get_first_command_arg(task_config).unwrap_or_default();
+                Some(plugin_description(crate_name, &cargo_command))
+            }
+            InstallCrate::CargoPluginInfo(ref install_info) => {
+                let (cargo_command, crate_name) =
+                    match get_cargo_plugin_info_from_command(&task_config) {
+                        Some(cargo_plugin_info) => cargo_plugin_info,
+                        None => {
+                            let crate_name = install_info.crate_name.clone().unwrap_or_default();
+                            let cargo_command =
+                                get_first_command_arg(task_config).unwrap_or(crate_name.clone());
+                            (cargo_command, crate_name)
+                        }
+                    };
+
+                let mut description = plugin_description(&crate_name, &cargo_command);
+                if let Some(ref min_version) = install_info.min_version {
+                    description.push_str(&format!(" or older than: {}", min_version));
+                }
+
+                Some(description)
+            }
+            InstallCrate::CrateInfo(ref install_info) => {
+                let mut description = format!(
+                    "crate: {} (binary: {}), if not installed",
+                    &install_info.crate_name, &install_info.binary
                 );
-                ()
+                if let Some(ref version) = install_info.version {
+                    description.push_str(&format!(" or not version: {}", version));
+                } else if let Some(ref min_version) = install_info.min_version {
+                    description.push_str(&format!(" or older than: {}", min_version));
+                }
+
+                Some(description)
+            }
+            InstallCrate::RustupComponentInfo(ref install_info) => Some(format!(
+                "rustup component: {}, if not installed",
+                &install_info.rustup_component_name
+            )),
+        },
+        None => match task_config.install_script {
+            Some(ref script) => {
+                let engine_type = scriptengine::get_engine_type(
+                    &script,
+                    &task_config.script_runner,
+                    &task_config.script_extension,
+                );
+
+                Some(format!(
+                    "script (engine: {:?}):\n{}",
+                    engine_type,
+                    scriptengine::get_script_text(&script)?.join("\n")
+                ))
             }
             None => match get_cargo_plugin_info_from_command(&task_config) {
+                // built in cargo commands are never installed
                 Some((cargo_command, crate_name)) => {
-                    cargo_plugin_installer::install_crate(
-                        &toolchain,
-                        &cargo_command,
-                        &crate_name,
-                        &task_config.install_crate_args,
-                        validate,
-                        &None,
-                        &None,
-                    );
+                    match cargo_plugin_installer::is_crate_installed(&None, &cargo_command) {
+                        Ok(true) => None,
+                        _ => Some(plugin_description(&crate_name, &cargo_command)),
+                    }
                 }
-                None => debug!("No installation script defined."),
+                None => None,
             },
         },
-    }
+    };
+
+    Ok(description)
 }
@@ -3,6 +3,20 @@ use crate::test;
 use crate::types::{InstallCrateInfo, InstallRustupComponentInfo, ScriptValue, TestArg};
 use envmnt;
 
+#[test]
+fn to_installer_error_task_failed() {
+    let error = to_installer_error(CargoMakeError::TaskFailed("test".to_string()));
+
+    assert_eq!(error, CargoMakeError::InstallerFailed("test".to_string()));
+}
+
+#[test]
+fn to_installer_error_other_kind() {
+    let error = to_installer_error(CargoMakeError::InvalidTask("test".to_string()));
+
+    assert_eq!(error, CargoMakeError::InvalidTask("test".to_string()));
+}
+
 #[test]
 fn get_cargo_plugin_info_from_command_no_command() {
     let task = Task::new();
@@ -67,7 +81,8 @@ fn install_empty() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -81,7 +96,8 @@ fn install_disabled_bad_crate() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -96,7 +112,8 @@ fn install_empty_args() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -110,7 +127,8 @@ fn install_enabled_crate_already_installed() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -124,7 +142,8 @@ fn install_crate_already_installed() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -138,7 +157,8 @@ fn install_crate_missing_cargo_command() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -151,7 +171,8 @@ fn install_crate_auto_detect_already_installed() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -165,7 +186,8 @@ fn install_crate_auto_detect_unable_to_install() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -190,7 +212,8 @@ fn install_rustup_via_crate_info() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -211,7 +234,8 @@ fn install_rustup_via_rustup_info() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -223,7 +247,8 @@ fn install_script_ok() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -236,7 +261,8 @@ fn install_script_error() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -256,7 +282,8 @@ fn install_script_duckscript() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
     assert!(envmnt::exists("install_script_duckscript"));
     assert!(envmnt::is_or("install_script_duckscript", false));
@@ -272,5 +299,106 @@ fn install_script_error_ignore_errors() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
+    )
+    .unwrap();
+}
+
+#[test]
+fn describe_empty() {
+    let task = Task::new();
+
+    let description = describe(&task).unwrap();
+
+    assert!(description.is_none());
+}
+
+#[test]
+fn describe_install_crate_disabled() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["test".to_string()]);
+    task.install_crate = Some(InstallCrate::Enabled(false));
+
+    let description = describe(&task).unwrap();
+
+    assert!(description.is_none());
+}
+
+#[test]
+fn describe_cargo_plugin_from_command() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["dry-run-missing".to_string()]);
+    task.install_crate = Some(InstallCrate::Enabled(true));
+
+    let description = describe(&task).unwrap();
+
+    assert_eq!(
+        description.unwrap(),
+        "cargo plugin: cargo-dry-run-missing (cargo dry-run-missing), if not installed"
     );
 }
+
+#[test]
+fn describe_cargo_plugin_from_command_installed() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["build".to_string()]);
+
+    let description = describe(&task).unwrap();
+
+    assert!(description.is_none());
+}
+
+#[test]
+fn describe_crate_info() {
+    let mut task = Task::new();
+    task.install_crate = Some(InstallCrate::CrateInfo(InstallCrateInfo {
+        crate_name: "bad_crate_name".to_string(),
+        binary: "test".to_string(),
+        test_arg: TestArg {
+            inner: vec!["--help".to_string()],
+        },
+        rustup_component_name: None,
+        min_version: Some("1.0.0".to_string()),
+        version: None,
+        install_command: None,
+    }));
+
+    let description = describe(&task).unwrap();
+
+    assert_eq!(
+        description.unwrap(),
+        "crate: bad_crate_name (binary: test), if not installed or older than: 1.0.0"
+    );
+}
+
+#[test]
+fn describe_rustup_component() {
+    let mut task = Task::new();
+    task.install_crate = Some(InstallCrate::RustupComponentInfo(
+        InstallRustupComponentInfo {
+            rustup_component_name: "rustfmt".to_string(),
+            binary: None,
+            test_arg: None,
+        },
+    ));
+
+    let description = describe(&task).unwrap();
+
+    assert_eq!(
+        description.unwrap(),
+        "rustup component: rustfmt, if not installed"
+    );
+}
+
+#[test]
+fn describe_install_script() {
+    let mut task = Task::new();
+    task.install_script = Some(ScriptValue::Text(vec!["exit 0".to_string()]));
+    task.script_runner = Some("@duckscript".to_string());
+
+    let description = describe(&task).unwrap();
+
+    assert_eq!(description.unwrap(), "script (engine: Duckscript):\nexit 0");
+}
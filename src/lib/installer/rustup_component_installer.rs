@@ -8,6 +8,8 @@
 mod rustup_component_installer_test;
 
 use crate::command;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
 use crate::toolchain::wrap_command;
 use crate::types::{InstallRustupComponentInfo, ToolchainSpecifier};
 use std::process::Command;
@@ -19,7 +21,16 @@ pub(crate) fn is_installed(
 ) -> bool {
     let mut command_struct = match toolchain {
         Some(ref toolchain_string) => {
-            let command_spec = wrap_command(toolchain_string, binary, &None);
+            let command_spec = match wrap_command(toolchain_string, binary, &None) {
+                Ok(value) => value,
+                Err(error) => {
+                    debug!(
+                        "Unable to check if crate is installed: {} {}",
+                        binary, &error
+                    );
+                    return false;
+                }
+            };
             let mut cmd = Command::new(command_spec.command);
             cmd.args(command_spec.args.unwrap());
 
@@ -27,16 +38,18 @@ pub(crate) fn is_installed(
         }
         None => Command::new(binary),
     };
+    task_env::apply_to_command(&mut command_struct);
 
     debug!(
         "Validating installation using command: {} args: {:#?}",
         binary, &test_args
     );
-    let result = command_struct.args(test_args).output();
+    command_struct.args(test_args);
+    let result = task_env::output(&mut command_struct);
 
     match result {
         Ok(output) => {
-            let exit_code = command::get_exit_code(Ok(output.status), false);
+            let exit_code = command::get_exit_code(Ok(output.status), false).unwrap_or(-1);
             debug!("Installation validation test exit code: {}", exit_code);
 
             if exit_code != 0 {
@@ -55,11 +68,57 @@ pub(crate) fn is_installed(
     }
 }
 
+/// Returns the names of the rustup components installed for the active toolchain
+pub(crate) fn get_installed_components() -> Result<Vec<String>, String> {
+    let mut command_spec = Command::new("rustup");
+    task_env::apply_to_command(&mut command_spec);
+
+    command_spec.args(&["component", "list", "--installed"]);
+
+    match task_env::output(&mut command_spec) {
+        Ok(output) => {
+            if output.status.success() {
+                Ok(String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .map(|component| component.trim().to_string())
+                    .filter(|component| !component.is_empty())
+                    .collect())
+            } else {
+                Err(format!(
+                    "rustup component list failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ))
+            }
+        }
+        Err(error) => Err(format!("Unable to run rustup, error: {}", error)),
+    }
+}
+
+/// Returns true if the component is in the installed components list.<br>
+/// Installed components are listed with the target triple suffix (for example
+/// clippy-x86_64-unknown-linux-gnu) so both forms are accepted.
+pub(crate) fn is_component_in_list(
+    installed_components: &Vec<String>,
+    component: &str,
+    target_triple: &Option<String>,
+) -> bool {
+    installed_components.iter().any(|installed_component| {
+        installed_component == component
+            || match target_triple {
+                Some(ref target_triple) => {
+                    *installed_component == format!("{}-{}", component, target_triple)
+                }
+                None => false,
+            }
+    })
+}
+
 pub(crate) fn invoke_rustup_install(
     toolchain: &Option<ToolchainSpecifier>,
     info: &InstallRustupComponentInfo,
 ) -> bool {
     let mut command_spec = Command::new("rustup");
+    task_env::apply_to_command(&mut command_spec);
     command_spec.arg("component");
     command_spec.arg("add");
 
@@ -71,11 +130,12 @@ pub(crate) fn invoke_rustup_install(
         None => {}
     };
 
-    let result = command_spec.arg(&info.rustup_component_name).output();
+    command_spec.arg(&info.rustup_component_name);
+    let result = task_env::output(&mut command_spec);
 
     match result {
         Ok(output) => {
-            let exit_code = command::get_exit_code(Ok(output.status), false);
+            let exit_code = command::get_exit_code(Ok(output.status), false).unwrap_or(-1);
 
             if exit_code != 0 {
                 debug!(
@@ -107,7 +167,7 @@ pub(crate) fn install(
     toolchain: &Option<ToolchainSpecifier>,
     info: &InstallRustupComponentInfo,
     validate: bool,
-) -> bool {
+) -> Result<bool, CargoMakeError> {
     let mut installed = match info.binary {
         Some(ref binary) => match info.test_arg {
             Some(ref test_arg) => is_installed(&toolchain, binary, test_arg),
@@ -125,14 +185,14 @@ pub(crate) fn install(
         installed = invoke_rustup_install(&toolchain, &info);
 
         if validate && !installed {
-            error!(
+            Err(CargoMakeError::InstallerFailed(format!(
                 "Failed to add rustup component: {}",
                 &info.rustup_component_name
-            );
+            )))
+        } else {
+            Ok(installed)
         }
-
-        installed
     } else {
-        true
+        Ok(true)
     }
 }
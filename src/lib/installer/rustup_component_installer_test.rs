@@ -88,7 +88,7 @@ fn install_test() {
         }),
     };
 
-    let output = install(&None, &info, false);
+    let output = install(&None, &info, false).unwrap();
     assert!(!output);
 }
 
@@ -104,6 +104,52 @@ fn install_with_toolchain_test() {
         }),
     };
 
-    let output = install(&Some(toolchain), &info, false);
+    let output = install(&Some(toolchain), &info, false).unwrap();
     assert!(!output);
 }
+
+#[test]
+fn is_component_in_list_with_target_triple_suffix() {
+    let installed_components = vec![
+        "clippy-x86_64-unknown-linux-gnu".to_string(),
+        "rust-src".to_string(),
+    ];
+    let target_triple = Some("x86_64-unknown-linux-gnu".to_string());
+
+    assert!(is_component_in_list(
+        &installed_components,
+        "clippy",
+        &target_triple
+    ));
+    assert!(is_component_in_list(
+        &installed_components,
+        "clippy-x86_64-unknown-linux-gnu",
+        &target_triple
+    ));
+    assert!(is_component_in_list(
+        &installed_components,
+        "rust-src",
+        &target_triple
+    ));
+    assert!(!is_component_in_list(
+        &installed_components,
+        "rustfmt",
+        &target_triple
+    ));
+    assert!(!is_component_in_list(
+        &installed_components,
+        "clippy-x86",
+        &target_triple
+    ));
+}
+
+#[test]
+fn is_component_in_list_without_target_triple() {
+    let installed_components = vec!["clippy-x86_64-unknown-linux-gnu".to_string()];
+
+    assert!(!is_component_in_list(
+        &installed_components,
+        "clippy",
+        &None
+    ));
+}
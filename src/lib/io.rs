@@ -7,40 +7,43 @@
 #[path = "io_test.rs"]
 mod io_test;
 
+use crate::error::CargoMakeError;
 use fsio::file::modify_file;
 use fsio::path as fsio_path;
 use fsio::path::from_path::FromPath;
 use glob::glob;
 use ignore::WalkBuilder;
 use std::collections::HashSet;
+use std::env;
+use std::fs;
 use std::fs::File;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-pub(crate) fn create_text_file(text: &str, extension: &str) -> String {
+pub(crate) fn create_text_file(text: &str, extension: &str) -> Result<String, CargoMakeError> {
     let file_path = fsio_path::get_temporary_file_path(extension);
 
     match fsio::file::write_text_file(&file_path, text) {
-        Ok(_) => file_path,
-        Err(error) => {
-            error!("Unable to create file: {} {:#?}", &file_path, &error);
-            panic!("Unable to create file, error: {}", error);
-        }
+        Ok(_) => Ok(file_path),
+        Err(error) => Err(CargoMakeError::Other(format!(
+            "Unable to create file: {} {:#?}",
+            &file_path, &error
+        ))),
     }
 }
 
 pub(crate) fn create_file(
     write_content: &dyn Fn(&mut File) -> io::Result<()>,
     extension: &str,
-) -> String {
+) -> Result<String, CargoMakeError> {
     let file_path = fsio_path::get_temporary_file_path(extension);
 
     match modify_file(&file_path, write_content, false) {
-        Ok(_) => file_path,
-        Err(error) => {
-            error!("Unable to write to file: {} {:#?}", &file_path, &error);
-            panic!("Unable to write to file, error: {}", error);
-        }
+        Ok(_) => Ok(file_path),
+        Err(error) => Err(CargoMakeError::Other(format!(
+            "Unable to write to file: {} {:#?}",
+            &file_path, &error
+        ))),
     }
 }
 
@@ -58,12 +61,15 @@ pub(crate) fn write_text_file(file_path: &str, text: &str) -> bool {
     }
 }
 
-pub(crate) fn read_text_file(file_path: &PathBuf) -> String {
+pub(crate) fn read_text_file(file_path: &PathBuf) -> Result<String, CargoMakeError> {
     debug!("Opening file: {:#?}", &file_path);
 
     match fsio::file::read_text_file(file_path) {
-        Ok(content) => content,
-        Err(error) => panic!("Unable to read file: {:?} error: {:#?}", file_path, error),
+        Ok(content) => Ok(content),
+        Err(error) => Err(CargoMakeError::Other(format!(
+            "Unable to read file: {:?} error: {:#?}",
+            file_path, error
+        ))),
     }
 }
 
@@ -72,7 +78,7 @@ pub(crate) fn get_path_list(
     include_files: bool,
     include_dirs: bool,
     ignore_type: Option<String>,
-) -> Vec<String> {
+) -> Result<Vec<String>, CargoMakeError> {
     let mut path_list = vec![];
     match glob(glob_pattern) {
         Ok(paths) => {
@@ -90,21 +96,19 @@ pub(crate) fn get_path_list(
                         }
                     }
                     Err(error) => {
-                        error!(
+                        return Err(CargoMakeError::Other(format!(
                             "Error while iterating over path entries of glob: {}, error: {:#?}",
                             glob_pattern, error
-                        );
-                        return vec![];
+                        )));
                     }
                 }
             }
         }
         Err(error) => {
-            error!(
+            return Err(CargoMakeError::Other(format!(
                 "Error while running glob: {}, error: {:#?}",
                 glob_pattern, error
-            );
-            return vec![];
+            )));
         }
     }
 
@@ -127,14 +131,21 @@ pub(crate) fn get_path_list(
                                 value_string = value_string.replace("\\", "/");
                                 included_paths.insert(value_string);
                             }
-                            Err(error) => error!(
-                                "Error while running git ignore path checks, error: {:#?}",
-                                error
-                            ),
+                            Err(error) => {
+                                return Err(CargoMakeError::Other(format!(
+                                    "Error while running git ignore path checks, error: {:#?}",
+                                    error
+                                )))
+                            }
                         }
                     }
                 }
-                _ => error!("Unsupported ignore type: {}", &ignore_type_value),
+                _ => {
+                    return Err(CargoMakeError::Other(format!(
+                        "Unsupported ignore type: {}",
+                        &ignore_type_value
+                    )))
+                }
             };
 
             if included_paths.is_empty() {
@@ -151,9 +162,70 @@ pub(crate) fn get_path_list(
         }
     }
 
-    path_list
+    Ok(path_list)
 }
 
 pub(crate) fn canonicalize_to_string(path_string: &str) -> String {
     fsio_path::canonicalize_or(path_string, path_string)
 }
+
+#[cfg(unix)]
+fn has_execute_permission(metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn has_execute_permission(_metadata: &fs::Metadata) -> bool {
+    true
+}
+
+fn is_executable_file(path: &Path) -> bool {
+    match fs::metadata(path) {
+        Ok(metadata) => metadata.is_file() && has_execute_permission(&metadata),
+        Err(_) => false,
+    }
+}
+
+/// Returns the possible file names of the command (on windows, including the PATHEXT extensions)
+fn get_command_file_names(command: &str) -> Vec<String> {
+    let mut file_names = vec![command.to_string()];
+
+    if cfg!(windows) && Path::new(command).extension().is_none() {
+        let extensions = env::var("PATHEXT").unwrap_or(".COM;.EXE;.BAT;.CMD".to_string());
+        for extension in extensions
+            .split(';')
+            .filter(|extension| !extension.is_empty())
+        {
+            file_names.push(format!("{}{}", command, extension));
+        }
+    }
+
+    file_names
+}
+
+/// Returns the path of the command executable.<br>
+/// Commands which are paths are checked directly, otherwise the command is searched in the
+/// directories of the provided PATH value.
+pub(crate) fn find_command(command: &str, path_value: &Option<String>) -> Option<PathBuf> {
+    let file_names = get_command_file_names(command);
+
+    if Path::new(command).components().count() > 1 {
+        return file_names
+            .iter()
+            .map(PathBuf::from)
+            .find(|path| is_executable_file(path));
+    }
+
+    match path_value {
+        Some(ref value) => env::split_paths(value)
+            .flat_map(|directory| {
+                file_names
+                    .iter()
+                    .map(move |file_name| directory.join(file_name))
+            })
+            .find(|path| is_executable_file(path)),
+        None => None,
+    }
+}
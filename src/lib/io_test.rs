@@ -3,14 +3,14 @@ use std::iter::FromIterator;
 
 #[test]
 fn create_text_file_read_and_delete() {
-    let file = create_text_file("test\nend", ".testfile");
+    let file = create_text_file("test\nend", ".testfile").unwrap();
     assert!(file.ends_with(".testfile"));
 
     let text = fsio::file::read_text_file(&file).unwrap();
 
     let mut file_path = PathBuf::new();
     file_path.push(&file);
-    let read_text = read_text_file(&file_path);
+    let read_text = read_text_file(&file_path).unwrap();
 
     delete_file(&file);
 
@@ -28,7 +28,7 @@ fn write_text_file_read_and_delete() {
 
     let mut file_path = PathBuf::new();
     file_path.push(&file);
-    let read_text = read_text_file(&file_path);
+    let read_text = read_text_file(&file_path).unwrap();
 
     delete_file(&file);
 
@@ -38,14 +38,14 @@ fn write_text_file_read_and_delete() {
 
 #[test]
 fn get_path_list_not_exists() {
-    let output = get_path_list("./target2", true, true, None);
+    let output = get_path_list("./target2", true, true, None).unwrap();
 
     assert!(output.is_empty());
 }
 
 #[test]
 fn get_path_list_files() {
-    let output = get_path_list("./src/*_test.rs", true, true, None);
+    let output = get_path_list("./src/*_test.rs", true, true, None).unwrap();
 
     let set: HashSet<String> = HashSet::from_iter(output.iter().cloned());
     assert_eq!(
@@ -59,14 +59,14 @@ fn get_path_list_files() {
 
 #[test]
 fn get_path_list_files_exclude_files() {
-    let output = get_path_list("./src/*_test.rs", false, true, None);
+    let output = get_path_list("./src/*_test.rs", false, true, None).unwrap();
 
     assert!(output.is_empty());
 }
 
 #[test]
 fn get_path_list_dirs() {
-    let output = get_path_list("./src/l*", true, true, None);
+    let output = get_path_list("./src/l*", true, true, None).unwrap();
 
     let set: HashSet<String> = HashSet::from_iter(output.iter().cloned());
     assert_eq!(set, HashSet::from_iter(vec!["./src/lib".to_string(),]));
@@ -74,14 +74,14 @@ fn get_path_list_dirs() {
 
 #[test]
 fn get_path_list_dirs_exclude_dirs() {
-    let output = get_path_list("./src/l*", true, false, None);
+    let output = get_path_list("./src/l*", true, false, None).unwrap();
 
     assert!(output.is_empty());
 }
 
 #[test]
 fn get_path_list_files_and_dirs() {
-    let output = get_path_list("./src/*i*", true, true, None);
+    let output = get_path_list("./src/*i*", true, true, None).unwrap();
 
     let set: HashSet<String> = HashSet::from_iter(output.iter().cloned());
     assert_eq!(
@@ -96,7 +96,7 @@ fn get_path_list_files_and_dirs() {
 
 #[test]
 fn get_path_list_dirs_without_gitignore() {
-    let output = get_path_list("./target", true, true, None);
+    let output = get_path_list("./target", true, true, None).unwrap();
 
     let set: HashSet<String> = HashSet::from_iter(output.iter().cloned());
     assert_eq!(set, HashSet::from_iter(vec!["./target".to_string(),]));
@@ -104,13 +104,66 @@ fn get_path_list_dirs_without_gitignore() {
 
 #[test]
 fn get_path_list_dirs_with_gitignore() {
-    let output = get_path_list("./target", true, true, Some("git".to_string()));
+    let output = get_path_list("./target", true, true, Some("git".to_string())).unwrap();
 
     assert!(output.is_empty());
 }
 
 #[test]
-#[should_panic]
 fn get_path_list_dirs_with_wrong_include_file_type() {
-    get_path_list("./target", true, true, Some("bad".to_string()));
+    let result = get_path_list("./target", true, true, Some("bad".to_string()));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn find_command_in_path() {
+    let path = find_command("cargo", &env::var("PATH").ok());
+
+    assert!(path.is_some());
+}
+
+#[test]
+fn find_command_not_in_path() {
+    let path = find_command("cargo_bad", &env::var("PATH").ok());
+
+    assert!(path.is_none());
+}
+
+#[test]
+fn find_command_no_path() {
+    let path = find_command("cargo", &None);
+
+    assert!(path.is_none());
+}
+
+#[test]
+fn find_command_path_value() {
+    let executable = env::current_exe().unwrap();
+    let directory = executable.parent().unwrap().to_string_lossy().into_owned();
+    let file_name = executable
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned();
+
+    let path = find_command(&file_name, &Some(directory));
+
+    assert_eq!(path.unwrap(), executable);
+}
+
+#[test]
+fn find_command_full_path() {
+    let executable = env::current_exe().unwrap();
+
+    let path = find_command(&executable.to_string_lossy(), &None);
+
+    assert_eq!(path.unwrap(), executable);
+}
+
+#[test]
+fn find_command_directory() {
+    let path = find_command("./src", &None);
+
+    assert!(path.is_none());
 }
@@ -7,13 +7,16 @@
 #[path = "logger_test.rs"]
 mod logger_test;
 
+use crate::error::CargoMakeError;
+use crate::events;
 use crate::recursion_level;
+use crate::report;
 use crate::types::FlowInfo;
 use colored::{ColoredString, Colorize};
 use envmnt;
 use fern;
 use log::{Level, LevelFilter};
-use std::io::stdout;
+use std::io::{stderr, stdout};
 use std::process::exit;
 
 #[derive(Debug, PartialEq)]
@@ -30,6 +33,9 @@ pub(crate) struct LoggerOptions {
     pub(crate) level: String,
     /// True to printout colorful output
     pub(crate) color: bool,
+    /// True to write the log to the stderr instead of the stdout (for example when the stdout
+    /// holds the json events)
+    pub(crate) to_stderr: bool,
 }
 
 pub(crate) fn get_level(level_name: &str) -> LogLevel {
@@ -139,7 +145,7 @@ pub(crate) fn init(options: &LoggerOptions) {
         format!("[{}]", recursion_lvl)
     };
 
-    let result = fern::Dispatch::new()
+    let dispatch = fern::Dispatch::new()
         .format(move |out, message, record| {
             let name = env!("CARGO_PKG_NAME");
 
@@ -159,18 +165,34 @@ pub(crate) fn init(options: &LoggerOptions) {
                 "[{}]{} {} - {}",
                 &name_fmt, &recursion_level_log, &record_level_fmt, &message
             ));
-
-            if record_level == Level::Error {
-                warn!("Build Failed.");
-
-                exit(1);
-            }
         })
-        .level(log_level)
-        .chain(stdout())
-        .apply();
+        .level(log_level);
+    let result = if options.to_stderr {
+        dispatch.chain(stderr()).apply()
+    } else {
+        dispatch.chain(stdout()).apply()
+    };
 
     if result.is_err() {
         println!("Unable to setup logger.");
     }
 }
+
+/// Logs the error, finishes the flow events and report and exits the process with the exit code
+/// matching the error.
+pub(crate) fn exit_with_error(error: &CargoMakeError) -> ! {
+    if log::max_level() == LevelFilter::Off {
+        // the logger is not initialized
+        eprintln!("{}", error);
+    } else {
+        error!("{}", error);
+    }
+
+    let task = envmnt::get_or("CARGO_MAKE_TASK", "");
+    events::flow_finished(&task, false, None);
+    report::write(&task);
+
+    warn!("Build Failed.");
+
+    exit(error.exit_code());
+}
@@ -160,6 +160,7 @@ fn create_error() {
     init(&LoggerOptions {
         level: "error".to_string(),
         color: false,
+        to_stderr: false,
     });
 
     error!("test");
@@ -173,6 +174,7 @@ fn update_disable_color_env_var() {
     init(&LoggerOptions {
         level: "info".to_string(),
         color: false,
+        to_stderr: false,
     });
 
     assert!(envmnt::is("CARGO_MAKE_DISABLE_COLOR"));
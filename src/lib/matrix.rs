@@ -0,0 +1,126 @@
+//! # matrix
+//!
+//! Expands the task matrix into the task value combinations.<br>
+//! Similar to CI matrices, the combinations are created from all the matrix values, after which
+//! the exclude entries are removed and the include entries are added.
+//!
+
+#[cfg(test)]
+#[path = "matrix_test.rs"]
+mod matrix_test;
+
+use crate::types::{EnvValue, Task, TaskMatrix};
+use indexmap::IndexMap;
+
+/// Returns true if all the entry values are equal to the combination values
+fn is_matching(combination: &IndexMap<String, String>, entry: &IndexMap<String, String>) -> bool {
+    entry
+        .iter()
+        .all(|(key, value)| combination.get(key) == Some(value))
+}
+
+/// Returns all the matrix value combinations, after removing the excluded combinations and adding
+/// the included combinations
+pub(crate) fn get_combinations(matrix: &TaskMatrix) -> Vec<IndexMap<String, String>> {
+    let mut combinations: Vec<IndexMap<String, String>> = if matrix.values.is_empty() {
+        vec![]
+    } else {
+        vec![IndexMap::new()]
+    };
+
+    for (key, values) in &matrix.values {
+        let mut updated_combinations = vec![];
+
+        for combination in &combinations {
+            for value in values {
+                let mut updated_combination = combination.clone();
+                updated_combination.insert(key.to_string(), value.to_string());
+                updated_combinations.push(updated_combination);
+            }
+        }
+
+        combinations = updated_combinations;
+    }
+
+    if let Some(ref exclude) = matrix.exclude {
+        combinations
+            .retain(|combination| !exclude.iter().any(|entry| is_matching(combination, entry)));
+    }
+
+    if let Some(ref include) = matrix.include {
+        for entry in include {
+            let mut matched = false;
+
+            // an include entry extends all the combinations which match its original matrix values,
+            // as long as it does not modify any value already defined in the combination
+            for combination in combinations.iter_mut() {
+                let extends = entry.iter().all(|(key, value)| match combination.get(key) {
+                    Some(current_value) => current_value == value,
+                    None => !matrix.values.contains_key(key),
+                });
+
+                if extends {
+                    for (key, value) in entry {
+                        if !combination.contains_key(key) {
+                            combination.insert(key.to_string(), value.to_string());
+                        }
+                    }
+
+                    matched = true;
+                }
+            }
+
+            if !matched {
+                combinations.push(entry.clone());
+            }
+        }
+    }
+
+    combinations
+}
+
+/// Returns the step name of the combination, for example: test[features=a,target=wasm32]
+pub(crate) fn get_step_name(task_name: &str, combination: &IndexMap<String, String>) -> String {
+    let values: Vec<String> = combination
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+
+    format!("{}[{}]", task_name, values.join(","))
+}
+
+/// Returns the env var name of the matrix value, for example: CARGO_MAKE_MATRIX_FEATURES
+pub(crate) fn get_env_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() {
+                character.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("CARGO_MAKE_MATRIX_{}", name)
+}
+
+/// Creates the task of the combination, which defines the combination values as env vars
+/// (before the task env so the task env can reference them)
+pub(crate) fn create_task(task: &Task, combination: &IndexMap<String, String>) -> Task {
+    let mut env = IndexMap::new();
+    for (key, value) in combination {
+        env.insert(get_env_name(key), EnvValue::Value(value.to_string()));
+    }
+    if let Some(ref task_env) = task.env {
+        for (key, value) in task_env {
+            env.insert(key.to_string(), value.clone());
+        }
+    }
+
+    let mut matrix_task = task.clone();
+    matrix_task.matrix = None;
+    matrix_task.env = Some(env);
+
+    matrix_task
+}
@@ -0,0 +1,200 @@
+use super::*;
+
+fn create_matrix(value: &str) -> TaskMatrix {
+    toml::from_str(value).unwrap()
+}
+
+fn to_strings(combinations: Vec<IndexMap<String, String>>) -> Vec<String> {
+    combinations
+        .iter()
+        .map(|combination| get_step_name("test", combination))
+        .collect()
+}
+
+#[test]
+fn get_combinations_empty() {
+    let matrix = create_matrix("");
+
+    let combinations = get_combinations(&matrix);
+
+    assert!(combinations.is_empty());
+}
+
+#[test]
+fn get_combinations_single_value() {
+    let matrix = create_matrix(r#"features = ["a", "b"]"#);
+
+    let combinations = get_combinations(&matrix);
+
+    assert_eq!(
+        to_strings(combinations),
+        vec!["test[features=a]", "test[features=b]"]
+    );
+}
+
+#[test]
+fn get_combinations_multiple_values() {
+    let matrix = create_matrix(
+        r#"
+        features = ["a", "b"]
+        target = ["x86_64", "wasm32"]
+        "#,
+    );
+
+    let combinations = get_combinations(&matrix);
+
+    assert_eq!(
+        to_strings(combinations),
+        vec![
+            "test[features=a,target=x86_64]",
+            "test[features=a,target=wasm32]",
+            "test[features=b,target=x86_64]",
+            "test[features=b,target=wasm32]",
+        ]
+    );
+}
+
+#[test]
+fn get_combinations_with_exclude() {
+    let matrix = create_matrix(
+        r#"
+        features = ["a", "b"]
+        target = ["x86_64", "wasm32"]
+        exclude = [
+            { features = "b", target = "wasm32" },
+            { features = "a", target = "other" },
+        ]
+        "#,
+    );
+
+    let combinations = get_combinations(&matrix);
+
+    assert_eq!(
+        to_strings(combinations),
+        vec![
+            "test[features=a,target=x86_64]",
+            "test[features=a,target=wasm32]",
+            "test[features=b,target=x86_64]",
+        ]
+    );
+}
+
+#[test]
+fn get_combinations_with_partial_exclude() {
+    let matrix = create_matrix(
+        r#"
+        features = ["a", "b"]
+        target = ["x86_64", "wasm32"]
+        exclude = [{ target = "wasm32" }]
+        "#,
+    );
+
+    let combinations = get_combinations(&matrix);
+
+    assert_eq!(
+        to_strings(combinations),
+        vec![
+            "test[features=a,target=x86_64]",
+            "test[features=b,target=x86_64]"
+        ]
+    );
+}
+
+#[test]
+fn get_combinations_with_include() {
+    let matrix = create_matrix(
+        r#"
+        features = ["a", "b"]
+        target = ["x86_64"]
+        include = [
+            { features = "a", experimental = "true" },
+            { features = "c", target = "wasm32" },
+            { features = "b", target = "x86_64" },
+        ]
+        "#,
+    );
+
+    let combinations = get_combinations(&matrix);
+
+    assert_eq!(
+        to_strings(combinations),
+        vec![
+            "test[features=a,target=x86_64,experimental=true]",
+            "test[features=b,target=x86_64]",
+            "test[features=c,target=wasm32]",
+        ]
+    );
+}
+
+#[test]
+fn get_combinations_include_without_overriding_values() {
+    let matrix = create_matrix(
+        r#"
+        features = ["a", "b"]
+        include = [
+            { toolchain = "stable" },
+            { features = "a", toolchain = "nightly" },
+        ]
+        "#,
+    );
+
+    let combinations = get_combinations(&matrix);
+
+    assert_eq!(
+        to_strings(combinations),
+        vec![
+            "test[features=a,toolchain=stable]",
+            "test[features=b,toolchain=stable]",
+            "test[features=a,toolchain=nightly]",
+        ]
+    );
+}
+
+#[test]
+fn get_combinations_only_include() {
+    let matrix = create_matrix(r#"include = [{ features = "a" }, { features = "b" }]"#);
+
+    let combinations = get_combinations(&matrix);
+
+    assert_eq!(
+        to_strings(combinations),
+        vec!["test[features=a]", "test[features=b]"]
+    );
+}
+
+#[test]
+fn get_env_name_valid() {
+    assert_eq!(get_env_name("features"), "CARGO_MAKE_MATRIX_FEATURES");
+    assert_eq!(
+        get_env_name("rust-version"),
+        "CARGO_MAKE_MATRIX_RUST_VERSION"
+    );
+}
+
+#[test]
+fn create_task_with_env() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.matrix = Some(create_matrix(r#"features = ["a"]"#));
+    let mut env = IndexMap::new();
+    env.insert(
+        "FEATURES_ARG".to_string(),
+        EnvValue::Value("--features=${CARGO_MAKE_MATRIX_FEATURES}".to_string()),
+    );
+    task.env = Some(env);
+
+    let mut combination = IndexMap::new();
+    combination.insert("features".to_string(), "a".to_string());
+
+    let matrix_task = create_task(&task, &combination);
+
+    assert!(matrix_task.matrix.is_none());
+    assert_eq!(matrix_task.command.unwrap(), "cargo");
+    let env = matrix_task.env.unwrap();
+    let keys: Vec<&String> = env.keys().collect();
+    assert_eq!(keys, vec!["CARGO_MAKE_MATRIX_FEATURES", "FEATURES_ARG"]);
+    match env["CARGO_MAKE_MATRIX_FEATURES"] {
+        EnvValue::Value(ref value) => assert_eq!(value, "a"),
+        _ => panic!("invalid env value type"),
+    }
+}
@@ -191,6 +191,7 @@ mod test;
 // make types public for docs
 pub mod types;
 
+mod api;
 mod cache;
 mod cli;
 mod cli_commands;
@@ -199,22 +200,42 @@ mod condition;
 mod config;
 mod descriptor;
 mod environment;
+mod error;
+mod events;
 mod execution_plan;
+mod fingerprint;
 mod functions;
+mod git;
 mod installer;
 mod io;
 mod legacy;
 mod logger;
+mod matrix;
+mod output;
+mod parameters;
 mod plugin;
 mod profile;
 mod proxy_task;
 mod recursion_level;
+mod report;
+mod retry;
 mod runner;
+mod scheduler;
 mod scriptengine;
 mod storage;
 mod time_summary;
+mod timeout;
 mod toolchain;
 mod version;
+mod watch;
+mod workspace;
+
+pub use api::{LoadOptions, Makefile, RunOptions, RunResult, StepResult, StepStatus};
+pub use error::{
+    CargoMakeError, EXIT_CODE_GENERAL_ERROR, EXIT_CODE_INSTALLER_FAILED, EXIT_CODE_MAKEFILE_ERROR,
+    EXIT_CODE_TASK_FAILED, EXIT_CODE_TASK_NOT_FOUND,
+};
+pub use output::OutputSink;
 
 /// Handles the command line arguments and executes the runner.
 pub fn run_cli(command_name: String, sub_command: bool) {
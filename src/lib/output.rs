@@ -0,0 +1,67 @@
+//! # output
+//!
+//! Forwards the output of the task commands and scripts to the registered output sink.<br>
+//! In case no sink is registered, the output is written to the process stdout/stderr.
+//!
+
+#[cfg(test)]
+#[path = "output_test.rs"]
+mod output_test;
+
+use std::io;
+use std::io::Write;
+use std::sync::{Arc, RwLock};
+
+/// Receives the output of the task commands and scripts
+pub trait OutputSink: Send + Sync {
+    /// Called with the next chunk of the stdout output
+    fn write_stdout(&self, data: &[u8]);
+    /// Called with the next chunk of the stderr output
+    fn write_stderr(&self, data: &[u8]);
+}
+
+/// Holds the currently registered output sink
+static OUTPUT_SINK: RwLock<Option<Arc<dyn OutputSink>>> = RwLock::new(None);
+
+/// Registers (or clears) the output sink used by all task commands and scripts
+pub(crate) fn set_sink(sink: Option<Arc<dyn OutputSink>>) {
+    if let Ok(mut output_sink) = OUTPUT_SINK.write() {
+        *output_sink = sink;
+    }
+}
+
+fn get_sink() -> Option<Arc<dyn OutputSink>> {
+    match OUTPUT_SINK.read() {
+        Ok(output_sink) => output_sink.clone(),
+        Err(_) => None,
+    }
+}
+
+/// Returns true if an output sink is registered and the output must be piped to it
+pub(crate) fn is_redirected() -> bool {
+    get_sink().is_some()
+}
+
+/// Writes the data to the output sink or to the stdout/stderr if no sink is registered
+pub(crate) fn write(data: &[u8], to_stderr: bool) {
+    match get_sink() {
+        Some(sink) => {
+            if to_stderr {
+                sink.write_stderr(data);
+            } else {
+                sink.write_stdout(data);
+            }
+        }
+        None => {
+            if to_stderr {
+                let mut stderr = io::stderr();
+                stderr.write_all(data).unwrap_or(());
+                stderr.flush().unwrap_or(());
+            } else {
+                let mut stdout = io::stdout();
+                stdout.write_all(data).unwrap_or(());
+                stdout.flush().unwrap_or(());
+            }
+        }
+    }
+}
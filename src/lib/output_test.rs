@@ -0,0 +1,43 @@
+use super::*;
+use std::sync::Mutex;
+
+struct TestSink {
+    stdout: Mutex<Vec<u8>>,
+    stderr: Mutex<Vec<u8>>,
+}
+
+impl OutputSink for TestSink {
+    fn write_stdout(&self, data: &[u8]) {
+        self.stdout.lock().unwrap().extend_from_slice(data);
+    }
+
+    fn write_stderr(&self, data: &[u8]) {
+        self.stderr.lock().unwrap().extend_from_slice(data);
+    }
+}
+
+#[test]
+fn write_to_sink() {
+    let sink = Arc::new(TestSink {
+        stdout: Mutex::new(vec![]),
+        stderr: Mutex::new(vec![]),
+    });
+
+    set_sink(Some(sink.clone()));
+    assert!(is_redirected());
+
+    write(b"out\n", false);
+    write(b"err\n", true);
+
+    set_sink(None);
+    assert!(!is_redirected());
+
+    write(b"not captured\n", false);
+
+    // other tests might write to the sink while it is registered
+    let stdout = String::from_utf8_lossy(&sink.stdout.lock().unwrap()).into_owned();
+    let stderr = String::from_utf8_lossy(&sink.stderr.lock().unwrap()).into_owned();
+    assert!(stdout.contains("out\n"));
+    assert!(!stdout.contains("not captured"));
+    assert!(stderr.contains("err\n"));
+}
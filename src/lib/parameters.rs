@@ -0,0 +1,299 @@
+//! # parameters
+//!
+//! Parses the task command line arguments based on the task parameters definition.<br>
+//! Each parameter is provided as --name=value (or --name value), boolean parameters can also be
+//! provided as a flag (--name), and the parsed values are exported as CARGO_MAKE_PARAM_* env vars.
+//!
+
+#[cfg(test)]
+#[path = "parameters_test.rs"]
+mod parameters_test;
+
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::types::{Config, TaskParameter};
+use indexmap::IndexMap;
+use std::env;
+use std::path::Path;
+
+static SUPPORTED_TYPES: &[&str] = &["string", "int", "bool", "enum", "path"];
+
+/// Returns the env var name which holds the parameter value
+pub(crate) fn get_env_name(name: &str) -> String {
+    format!(
+        "CARGO_MAKE_PARAM_{}",
+        name.to_uppercase()
+            .replace(|c: char| !c.is_alphanumeric(), "_")
+    )
+}
+
+fn get_type(parameter: &TaskParameter) -> &str {
+    match parameter.parameter_type {
+        Some(ref parameter_type) => parameter_type,
+        None => "string",
+    }
+}
+
+/// Returns the parameter value placeholder, for example: <int> or <dev|prod>
+fn get_value_placeholder(parameter: &TaskParameter) -> String {
+    match get_type(parameter) {
+        "enum" => format!(
+            "<{}>",
+            parameter.values.clone().unwrap_or_default().join("|")
+        ),
+        parameter_type => format!("<{}>", parameter_type),
+    }
+}
+
+/// Validates the parameter definition
+pub(crate) fn validate(name: &str, parameter: &TaskParameter) -> Result<(), String> {
+    let parameter_type = get_type(parameter);
+    if !SUPPORTED_TYPES.contains(&parameter_type) {
+        return Err(format!(
+            "Invalid type: {} for parameter: {} (supported values: {})",
+            parameter_type,
+            name,
+            SUPPORTED_TYPES.join(", ")
+        ));
+    }
+
+    if parameter_type == "enum" {
+        let has_values = match parameter.values {
+            Some(ref values) => !values.is_empty(),
+            None => false,
+        };
+        if !has_values {
+            return Err(format!(
+                "Enum parameter: {} does not define any values",
+                name
+            ));
+        }
+    }
+
+    match parameter.default {
+        Some(ref default_value) => {
+            parse_value(name, parameter, &default_value.to_string())?;
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Validates the value matches the parameter type and returns the value to export
+fn parse_value(name: &str, parameter: &TaskParameter, value: &str) -> Result<String, String> {
+    let expected = match get_type(parameter) {
+        "int" => match value.parse::<i64>() {
+            Ok(_) => return Ok(value.to_string()),
+            Err(_) => "an integer".to_string(),
+        },
+        "bool" => match value {
+            "true" | "false" => return Ok(value.to_string()),
+            _ => "true or false".to_string(),
+        },
+        "enum" => {
+            let values = parameter.values.clone().unwrap_or_default();
+            if values.iter().any(|enum_value| enum_value == value) {
+                return Ok(value.to_string());
+            }
+
+            format!("one of: {}", values.join(", "))
+        }
+        "path" => {
+            if value.is_empty() {
+                "a path".to_string()
+            } else {
+                let path = Path::new(value);
+                if path.is_absolute() {
+                    return Ok(value.to_string());
+                }
+
+                return match env::current_dir() {
+                    Ok(directory) => Ok(directory.join(path).to_string_lossy().into_owned()),
+                    Err(_) => Ok(value.to_string()),
+                };
+            }
+        }
+        _ => return Ok(value.to_string()),
+    };
+
+    Err(format!(
+        "Invalid value: {} for parameter: --{} (expected {})",
+        value, name, expected
+    ))
+}
+
+/// Parses the command line arguments and returns the values of all the parameters which were
+/// provided or have a default value.<br>
+/// Boolean parameters which were not provided and have no default value are set to false.
+pub(crate) fn parse(
+    parameters: &IndexMap<String, TaskParameter>,
+    arguments: &Vec<String>,
+) -> Result<IndexMap<String, String>, String> {
+    let mut provided: IndexMap<String, String> = IndexMap::new();
+
+    let mut index = 0;
+    while index < arguments.len() {
+        let argument = &arguments[index];
+        index = index + 1;
+
+        if !argument.starts_with("--") || argument.len() == 2 {
+            return Err(format!("Unexpected argument: {}", argument));
+        }
+
+        let (name, value) = match argument[2..].split_once('=') {
+            Some((name, value)) => (name.to_string(), Some(value.to_string())),
+            None => (argument[2..].to_string(), None),
+        };
+
+        let parameter = match parameters.get(&name) {
+            Some(parameter) => parameter,
+            None => return Err(format!("Unknown parameter: --{}", name)),
+        };
+
+        if provided.contains_key(&name) {
+            return Err(format!("Parameter: --{} provided multiple times", name));
+        }
+
+        let value = match value {
+            Some(value) => value,
+            None => {
+                if get_type(parameter) == "bool" {
+                    "true".to_string()
+                } else {
+                    match arguments.get(index) {
+                        Some(next_argument) if !next_argument.starts_with("--") => {
+                            index = index + 1;
+                            next_argument.to_string()
+                        }
+                        _ => return Err(format!("Missing value for parameter: --{}", name)),
+                    }
+                }
+            }
+        };
+
+        provided.insert(name, value);
+    }
+
+    let mut values = IndexMap::new();
+    for (name, parameter) in parameters {
+        let value = match provided.get(name) {
+            Some(value) => value.to_string(),
+            None => match parameter.default {
+                Some(ref default_value) => default_value.to_string(),
+                None => {
+                    if parameter.required.unwrap_or(false) {
+                        return Err(format!("Missing required parameter: --{}", name));
+                    } else if get_type(parameter) == "bool" {
+                        "false".to_string()
+                    } else {
+                        continue;
+                    }
+                }
+            },
+        };
+
+        values.insert(name.to_string(), parse_value(name, parameter, &value)?);
+    }
+
+    Ok(values)
+}
+
+/// Returns the task usage message, listing all the task parameters
+pub(crate) fn get_usage(
+    command: &str,
+    task: &str,
+    parameters: &IndexMap<String, TaskParameter>,
+) -> String {
+    let mut usage_parameters = vec![];
+    let mut lines = vec![];
+    for (name, parameter) in parameters {
+        let parameter_text = if get_type(parameter) == "bool" {
+            format!("--{}", name)
+        } else {
+            format!("--{}={}", name, get_value_placeholder(parameter))
+        };
+
+        let required = parameter.required.unwrap_or(false) && parameter.default.is_none();
+        if required {
+            usage_parameters.push(parameter_text.clone());
+        } else {
+            usage_parameters.push(format!("[{}]", &parameter_text));
+        }
+
+        let mut details = vec![];
+        if let Some(ref description) = parameter.description {
+            details.push(description.to_string());
+        }
+        if required {
+            details.push("(required)".to_string());
+        }
+        if let Some(ref default_value) = parameter.default {
+            details.push(format!("(default: {})", default_value));
+        }
+
+        lines.push((parameter_text, details.join(" ")));
+    }
+
+    let width = lines
+        .iter()
+        .map(|(parameter_text, _)| parameter_text.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut usage = format!("Usage: {} {}", command, task);
+    for usage_parameter in usage_parameters {
+        usage.push(' ');
+        usage.push_str(&usage_parameter);
+    }
+    usage.push_str("\n\nParameters:");
+    for (parameter_text, details) in lines {
+        let line = format!("  {:width$}  {}", parameter_text, details, width = width);
+        usage.push('\n');
+        usage.push_str(line.trim_end());
+    }
+
+    usage
+}
+
+/// Parses the task command line arguments based on the parameters defined by the task and
+/// exports the parameter values as env vars.<br>
+/// In case of invalid arguments, an error holding the task usage message is returned.
+pub(crate) fn setup_env(
+    config: &Config,
+    command: &str,
+    task: &str,
+    arguments: &Vec<String>,
+) -> Result<(), CargoMakeError> {
+    // missing tasks are reported once the execution plan is created
+    let parameters = match execution_plan::get_normalized_task(config, task, true) {
+        Ok(task_config) => match task_config.parameters {
+            Some(parameters) => parameters,
+            None => return Ok(()),
+        },
+        Err(_) => return Ok(()),
+    };
+
+    for (name, parameter) in &parameters {
+        if let Err(error) = validate(name, parameter) {
+            return Err(CargoMakeError::InvalidTask(format!(
+                "Task: {} has invalid parameters: {}",
+                task, error
+            )));
+        }
+    }
+
+    match parse(&parameters, arguments) {
+        Ok(values) => {
+            for (name, value) in values {
+                envmnt::set(get_env_name(&name), value);
+            }
+
+            Ok(())
+        }
+        Err(error) => Err(CargoMakeError::Other(format!(
+            "{}\n\n{}",
+            error,
+            get_usage(command, task, &parameters)
+        ))),
+    }
+}
@@ -0,0 +1,347 @@
+use super::*;
+use crate::test::create_empty_flow_info;
+use crate::types::{Task, TaskParameterValue};
+
+fn create_parameters(value: &str) -> IndexMap<String, TaskParameter> {
+    toml::from_str(value).unwrap()
+}
+
+fn create_deploy_parameters() -> IndexMap<String, TaskParameter> {
+    create_parameters(
+        r#"
+        env = { type = "enum", values = ["dev", "prod"], required = true, description = "The target env" }
+        dry-run = { type = "bool", description = "Only print the plan" }
+        count = { type = "int", default = 1 }
+        name = { description = "The deployment name" }
+        "#,
+    )
+}
+
+fn to_arguments(arguments: Vec<&str>) -> Vec<String> {
+    arguments
+        .iter()
+        .map(|argument| argument.to_string())
+        .collect()
+}
+
+#[test]
+fn get_env_name_simple() {
+    assert_eq!(get_env_name("env"), "CARGO_MAKE_PARAM_ENV");
+}
+
+#[test]
+fn get_env_name_with_dash() {
+    assert_eq!(get_env_name("dry-run"), "CARGO_MAKE_PARAM_DRY_RUN");
+}
+
+#[test]
+fn validate_default_type() {
+    let result = validate("name", &TaskParameter::new());
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn validate_all_types() {
+    let parameters = create_parameters(
+        r#"
+        a = { type = "string", default = "test" }
+        b = { type = "int", default = 10 }
+        c = { type = "bool", default = true }
+        d = { type = "enum", values = ["x", "y"], default = "y" }
+        e = { type = "path", default = "./src" }
+        "#,
+    );
+
+    for (name, parameter) in &parameters {
+        assert!(validate(name, parameter).is_ok());
+    }
+}
+
+#[test]
+fn validate_unsupported_type() {
+    let mut parameter = TaskParameter::new();
+    parameter.parameter_type = Some("float".to_string());
+
+    let result = validate("value", &parameter);
+
+    assert_eq!(
+        result.unwrap_err(),
+        "Invalid type: float for parameter: value (supported values: string, int, bool, enum, path)"
+    );
+}
+
+#[test]
+fn validate_enum_without_values() {
+    let mut parameter = TaskParameter::new();
+    parameter.parameter_type = Some("enum".to_string());
+    parameter.values = Some(vec![]);
+
+    let result = validate("env", &parameter);
+
+    assert_eq!(
+        result.unwrap_err(),
+        "Enum parameter: env does not define any values"
+    );
+}
+
+#[test]
+fn validate_invalid_default() {
+    let mut parameter = TaskParameter::new();
+    parameter.parameter_type = Some("bool".to_string());
+    parameter.default = Some(TaskParameterValue::Number(1));
+
+    let result = validate("flag", &parameter);
+
+    assert_eq!(
+        result.unwrap_err(),
+        "Invalid value: 1 for parameter: --flag (expected true or false)"
+    );
+}
+
+#[test]
+fn parse_with_equals() {
+    let values = parse(
+        &create_deploy_parameters(),
+        &to_arguments(vec!["--env=prod", "--count=3", "--name=a=b"]),
+    )
+    .unwrap();
+
+    assert_eq!(values.len(), 4);
+    assert_eq!(values.get("env").unwrap(), "prod");
+    assert_eq!(values.get("dry-run").unwrap(), "false");
+    assert_eq!(values.get("count").unwrap(), "3");
+    assert_eq!(values.get("name").unwrap(), "a=b");
+}
+
+#[test]
+fn parse_with_separate_values() {
+    let values = parse(
+        &create_deploy_parameters(),
+        &to_arguments(vec!["--env", "dev", "--dry-run", "--count", "-2"]),
+    )
+    .unwrap();
+
+    assert_eq!(values.len(), 3);
+    assert_eq!(values.get("env").unwrap(), "dev");
+    assert_eq!(values.get("dry-run").unwrap(), "true");
+    assert_eq!(values.get("count").unwrap(), "-2");
+}
+
+#[test]
+fn parse_defaults() {
+    let values = parse(
+        &create_deploy_parameters(),
+        &to_arguments(vec!["--env=dev", "--dry-run=false"]),
+    )
+    .unwrap();
+
+    assert_eq!(values.len(), 3);
+    assert_eq!(values.get("env").unwrap(), "dev");
+    assert_eq!(values.get("dry-run").unwrap(), "false");
+    assert_eq!(values.get("count").unwrap(), "1");
+    assert!(values.get("name").is_none());
+}
+
+#[test]
+fn parse_relative_path() {
+    let parameters = create_parameters(r#"out = { type = "path" }"#);
+
+    let values = parse(&parameters, &to_arguments(vec!["--out=target/out"])).unwrap();
+
+    assert_eq!(
+        values.get("out").unwrap(),
+        &env::current_dir()
+            .unwrap()
+            .join("target/out")
+            .to_string_lossy()
+            .into_owned()
+    );
+}
+
+#[test]
+fn parse_missing_required() {
+    let result = parse(&create_deploy_parameters(), &vec![]);
+
+    assert_eq!(result.unwrap_err(), "Missing required parameter: --env");
+}
+
+#[test]
+fn parse_unknown_parameter() {
+    let result = parse(
+        &create_deploy_parameters(),
+        &to_arguments(vec!["--env=dev", "--force"]),
+    );
+
+    assert_eq!(result.unwrap_err(), "Unknown parameter: --force");
+}
+
+#[test]
+fn parse_unexpected_argument() {
+    let result = parse(
+        &create_deploy_parameters(),
+        &to_arguments(vec!["--env=dev", "test"]),
+    );
+
+    assert_eq!(result.unwrap_err(), "Unexpected argument: test");
+}
+
+#[test]
+fn parse_multiple_times() {
+    let result = parse(
+        &create_deploy_parameters(),
+        &to_arguments(vec!["--env=dev", "--env=prod"]),
+    );
+
+    assert_eq!(
+        result.unwrap_err(),
+        "Parameter: --env provided multiple times"
+    );
+}
+
+#[test]
+fn parse_missing_value() {
+    let result = parse(
+        &create_deploy_parameters(),
+        &to_arguments(vec!["--env", "--dry-run"]),
+    );
+
+    assert_eq!(result.unwrap_err(), "Missing value for parameter: --env");
+}
+
+#[test]
+fn parse_invalid_int() {
+    let result = parse(
+        &create_deploy_parameters(),
+        &to_arguments(vec!["--env=dev", "--count=many"]),
+    );
+
+    assert_eq!(
+        result.unwrap_err(),
+        "Invalid value: many for parameter: --count (expected an integer)"
+    );
+}
+
+#[test]
+fn parse_invalid_enum() {
+    let result = parse(&create_deploy_parameters(), &to_arguments(vec!["--env=qa"]));
+
+    assert_eq!(
+        result.unwrap_err(),
+        "Invalid value: qa for parameter: --env (expected one of: dev, prod)"
+    );
+}
+
+#[test]
+fn get_usage_all_parameters() {
+    let usage = get_usage("cargo make", "deploy", &create_deploy_parameters());
+
+    assert_eq!(
+        usage,
+        r#"Usage: cargo make deploy --env=<dev|prod> [--dry-run] [--count=<int>] [--name=<string>]
+
+Parameters:
+  --env=<dev|prod>  The target env (required)
+  --dry-run         Only print the plan
+  --count=<int>     (default: 1)
+  --name=<string>   The deployment name"#
+    );
+}
+
+#[test]
+fn setup_env_task_not_found() {
+    let flow_info = create_empty_flow_info();
+
+    let result = setup_env(
+        &flow_info.config,
+        "cargo make",
+        "missing",
+        &to_arguments(vec!["--unknown"]),
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn setup_env_no_parameters() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info
+        .config
+        .tasks
+        .insert("test".to_string(), Task::new());
+
+    let result = setup_env(
+        &flow_info.config,
+        "cargo make",
+        "test",
+        &to_arguments(vec!["--unknown"]),
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn setup_env_invalid_arguments() {
+    let mut flow_info = create_empty_flow_info();
+    let mut task = Task::new();
+    task.parameters = Some(create_deploy_parameters());
+    flow_info.config.tasks.insert("deploy".to_string(), task);
+
+    let result = setup_env(&flow_info.config, "makers", "deploy", &vec![]);
+
+    match result {
+        Err(CargoMakeError::Other(message)) => {
+            assert!(message.starts_with(
+                "Missing required parameter: --env\n\nUsage: makers deploy --env=<dev|prod>"
+            ));
+        }
+        _ => panic!("expected an invalid arguments error"),
+    };
+}
+
+#[test]
+fn setup_env_invalid_definition() {
+    let mut flow_info = create_empty_flow_info();
+    let mut task = Task::new();
+    task.parameters = Some(create_parameters(r#"env = { type = "enum" }"#));
+    flow_info.config.tasks.insert("deploy".to_string(), task);
+
+    let result = setup_env(
+        &flow_info.config,
+        "makers",
+        "deploy",
+        &to_arguments(vec!["--env=dev"]),
+    );
+
+    assert_eq!(
+        result.unwrap_err(),
+        CargoMakeError::InvalidTask(
+            "Task: deploy has invalid parameters: Enum parameter: env does not define any values"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+#[ignore]
+fn setup_env_valid() {
+    let mut flow_info = create_empty_flow_info();
+    let mut task = Task::new();
+    task.parameters = Some(create_deploy_parameters());
+    flow_info.config.tasks.insert("deploy".to_string(), task);
+
+    envmnt::remove("CARGO_MAKE_PARAM_NAME");
+
+    setup_env(
+        &flow_info.config,
+        "cargo make",
+        "deploy",
+        &to_arguments(vec!["--env=prod", "--dry-run"]),
+    )
+    .unwrap();
+
+    assert_eq!(envmnt::get_or_panic("CARGO_MAKE_PARAM_ENV"), "prod");
+    assert_eq!(envmnt::get_or_panic("CARGO_MAKE_PARAM_DRY_RUN"), "true");
+    assert_eq!(envmnt::get_or_panic("CARGO_MAKE_PARAM_COUNT"), "1");
+    assert!(!envmnt::exists("CARGO_MAKE_PARAM_NAME"));
+}
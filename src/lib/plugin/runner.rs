@@ -8,6 +8,8 @@
 mod runner_test;
 
 use crate::environment;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
 use crate::plugin::sdk;
 use crate::plugin::types::Plugin;
 use crate::scriptengine::duck_script;
@@ -27,36 +29,42 @@ fn get_plugin_name_recursive(
     aliases: &IndexMap<String, String>,
     name: &str,
     seen: &mut Vec<String>,
-) -> String {
+) -> Result<String, CargoMakeError> {
     let name_string = name.to_string();
     if seen.contains(&name_string) {
-        error!("Detected cycle while resolving plugin alias: {}", name);
+        return Err(CargoMakeError::InvalidTask(format!(
+            "Detected cycle while resolving plugin alias: {}",
+            name
+        )));
     }
     seen.push(name_string);
 
     match aliases.get(name) {
         Some(target_name) => get_plugin_name_recursive(aliases, target_name, seen),
-        None => name.to_string(),
+        None => Ok(name.to_string()),
     }
 }
 
-fn get_plugin(config: &Config, plugin_name: &str) -> Option<(String, Plugin)> {
+fn get_plugin(
+    config: &Config,
+    plugin_name: &str,
+) -> Result<Option<(String, Plugin)>, CargoMakeError> {
     match &config.plugins {
         Some(plugins_config) => {
             let normalized_plugin_name = match plugins_config.aliases {
                 Some(ref aliases) => {
                     let mut seen = vec![];
-                    get_plugin_name_recursive(aliases, plugin_name, &mut seen)
+                    get_plugin_name_recursive(aliases, plugin_name, &mut seen)?
                 }
                 None => plugin_name.to_string(),
             };
 
             match plugins_config.plugins.get(&normalized_plugin_name) {
-                Some(plugin) => Some((normalized_plugin_name, plugin.clone())),
-                None => None,
+                Some(plugin) => Ok(Some((normalized_plugin_name, plugin.clone()))),
+                None => Ok(None),
             }
         }
-        None => None,
+        None => Ok(None),
     }
 }
 
@@ -66,7 +74,7 @@ fn run_plugin(
     step: &Step,
     plugin: Plugin,
     impl_plugin_name: String,
-) {
+) -> Result<(), CargoMakeError> {
     debug!(
         "Running Task: {} via plugin: {} script:\n{}",
         &step.name, &impl_plugin_name, &plugin.script
@@ -91,21 +99,40 @@ fn run_plugin(
 
     match load_sdk(flow_info, flow_state, step, &mut context.commands) {
         Ok(_) => {
-            let directory = env::current_dir();
+            // the plugin runs within the cargo-make process and reads the process env
+            let result = task_env::apply_current(|| {
+                let directory = env::current_dir();
 
-            match run_script(&script_text, context) {
-                Ok(_) => (),
-                Err(error) => error!("Error while running plugin: {}", error),
-            };
+                let result = match run_script(&script_text, context) {
+                    Ok(_) => Ok(()),
+                    Err(error) => Err(CargoMakeError::TaskFailed(format!(
+                        "Error while running plugin: {}",
+                        error
+                    ))),
+                };
+
+                // revert to originl working directory
+                if let Ok(directory_path) = directory {
+                    let path = directory_path.to_string_lossy().into_owned();
+                    let revert_result = environment::setup_cwd(Some(&path));
+                    result.and(revert_result.map(|_| ()))
+                } else {
+                    result
+                }
+            });
 
-            // revert to originl working directory
-            if let Ok(directory_path) = directory {
-                let path = directory_path.to_string_lossy().into_owned();
-                environment::setup_cwd(Some(&path));
+            // async tasks wait for the env lock, so they can only be awaited once it is released
+            if !task_env::is_env_locked() {
+                duck_script::wait_for_async_tasks()?;
             }
+
+            result
         }
-        Err(error) => error!("Unable to load duckscript SDK: {}", error),
-    };
+        Err(error) => Err(CargoMakeError::TaskFailed(format!(
+            "Unable to load duckscript SDK: {}",
+            error
+        ))),
+    }
 }
 
 fn setup_script_globals(
@@ -274,9 +301,9 @@ pub(crate) fn run_task(
     flow_state: Rc<RefCell<FlowState>>,
     step: &Step,
     options: &RunTaskOptions,
-) -> bool {
+) -> Result<bool, CargoMakeError> {
     if !options.plugins_enabled {
-        false
+        Ok(false)
     } else {
         let plugin_name_option = match flow_state.borrow().forced_plugin {
             Some(ref value) => Some(value.clone()),
@@ -287,26 +314,23 @@ pub(crate) fn run_task(
         };
 
         match plugin_name_option {
-            Some(ref plugin_name) => match get_plugin(&flow_info.config, plugin_name) {
+            Some(ref plugin_name) => match get_plugin(&flow_info.config, plugin_name)? {
                 Some((normalized_plugin_name, plugin)) => {
                     debug!(
                         "Running Task: {} via plugin: {}",
                         &step.name, &normalized_plugin_name
                     );
 
-                    run_plugin(flow_info, flow_state, step, plugin, normalized_plugin_name);
+                    run_plugin(flow_info, flow_state, step, plugin, normalized_plugin_name)?;
 
-                    true
-                }
-                None => {
-                    error!(
-                        "Invalid task: {}, unknown plugin: {}",
-                        &step.name, plugin_name
-                    );
-                    false
+                    Ok(true)
                 }
+                None => Err(CargoMakeError::InvalidTask(format!(
+                    "Invalid task: {}, unknown plugin: {}",
+                    &step.name, plugin_name
+                ))),
             },
-            None => false,
+            None => Ok(false),
         }
     }
 }
@@ -10,7 +10,7 @@ use crate::types::{
 fn get_plugin_name_recursive_empty() {
     let aliases = IndexMap::new();
 
-    let output = get_plugin_name_recursive(&aliases, "test", &mut vec![]);
+    let output = get_plugin_name_recursive(&aliases, "test", &mut vec![]).unwrap();
 
     assert_eq!(output, "test");
 }
@@ -20,7 +20,7 @@ fn get_plugin_name_recursive_not_found() {
     let mut aliases = IndexMap::new();
     aliases.insert("a".to_string(), "b".to_string());
 
-    let output = get_plugin_name_recursive(&aliases, "test", &mut vec![]);
+    let output = get_plugin_name_recursive(&aliases, "test", &mut vec![]).unwrap();
 
     assert_eq!(output, "test");
 }
@@ -32,13 +32,12 @@ fn get_plugin_name_recursive_found() {
     aliases.insert("test1".to_string(), "test2".to_string());
     aliases.insert("test2".to_string(), "test3".to_string());
 
-    let output = get_plugin_name_recursive(&aliases, "test", &mut vec![]);
+    let output = get_plugin_name_recursive(&aliases, "test", &mut vec![]).unwrap();
 
     assert_eq!(output, "test3");
 }
 
 #[test]
-#[should_panic]
 fn get_plugin_name_recursive_endless_loop() {
     let mut aliases = IndexMap::new();
     aliases.insert("test".to_string(), "test1".to_string());
@@ -47,7 +46,12 @@ fn get_plugin_name_recursive_endless_loop() {
 
     let output = get_plugin_name_recursive(&aliases, "test", &mut vec![]);
 
-    assert_eq!(output, "test3");
+    assert_eq!(
+        output.unwrap_err(),
+        CargoMakeError::InvalidTask(
+            "Detected cycle while resolving plugin alias: test1".to_string()
+        )
+    );
 }
 
 #[test]
@@ -62,7 +66,8 @@ fn get_plugin_no_plugins_config() {
             plugins: None,
         },
         "test",
-    );
+    )
+    .unwrap();
 
     assert!(output.is_none());
 }
@@ -82,7 +87,8 @@ fn get_plugin_not_found() {
             }),
         },
         "test",
-    );
+    )
+    .unwrap();
 
     assert!(output.is_none());
 }
@@ -110,7 +116,8 @@ fn get_plugin_found() {
             }),
         },
         "test",
-    );
+    )
+    .unwrap();
 
     assert!(output.is_some());
     let (name, plugin) = output.unwrap();
@@ -143,7 +150,8 @@ fn get_plugin_found_with_alias() {
             }),
         },
         "test",
-    );
+    )
+    .unwrap();
 
     assert!(output.is_some());
     let (name, plugin) = output.unwrap();
@@ -186,7 +194,8 @@ fn run_task_with_plugin_disabled() {
         &RunTaskOptions {
             plugins_enabled: false,
         },
-    );
+    )
+    .unwrap();
 
     assert!(!done);
 }
@@ -213,7 +222,8 @@ fn run_task_with_no_plugin_config() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 
     assert!(!done);
 }
@@ -252,7 +262,8 @@ fn run_task_no_plugin_value() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 
     assert!(!done);
 }
@@ -293,7 +304,8 @@ fn run_task_with_plugin_not_found() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -333,7 +345,8 @@ fn run_task_invoked_with_error() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -469,12 +482,24 @@ fn run_task_invoked_valid() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: Some(vec!["exit 0".to_string()]),
         ignore_errors: Some(true),
         force: Some(true),
         env_files: Some(vec![EnvFile::Path("extended".to_string())]),
         env: Some(env.clone()),
+        export_env: None,
         cwd: Some("cwd".to_string()),
         alias: Some("alias2".to_string()),
         linux_alias: Some("linux".to_string()),
@@ -493,9 +518,17 @@ fn run_task_invoked_valid() {
         run_task: Some(RunTaskInfo::Name("task2".to_string())),
         dependencies: Some(vec!["A".into()]),
         toolchain: Some("toolchain".into()),
+        timeout: None,
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: None,
         windows: None,
         mac: None,
+        unix: None,
+        arch: None,
     };
 
     let mut flow_info = create_empty_flow_info();
@@ -521,7 +554,8 @@ fn run_task_invoked_valid() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 
     assert!(done);
     assert!(envmnt::is_equal("PLUGIN_RUNNER_RUN_TASK_INVOKED", "done"));
@@ -547,7 +581,8 @@ fn run_task_invoked_valid() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 
     assert!(done);
     assert!(envmnt::is_equal("PLUGIN_RUNNER_RUN_TASK_INVOKED2", "done"));
@@ -594,7 +629,8 @@ fn run_task_invoked_with_forced_plugin() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 
     assert!(done);
     assert!(envmnt::is_equal(
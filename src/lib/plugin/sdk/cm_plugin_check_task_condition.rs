@@ -26,10 +26,23 @@ impl Command for CommandImpl {
         Box::new((*self).clone())
     }
 
-    fn run(&self, _arguments: Vec<String>) -> CommandResult {
-        let passed = runner::validate_condition(&self.flow_info, &self.step);
-
-        CommandResult::Continue(Some(passed.to_string()))
+    fn run(&self, arguments: Vec<String>) -> CommandResult {
+        let result = match runner::explain_condition(&self.flow_info, &self.step) {
+            Ok(result) => result,
+            Err(error) => return CommandResult::Error(error.to_string()),
+        };
+
+        if arguments.contains(&"--explain".to_string()) {
+            // the failed criterion explanation (empty if the condition is met)
+            let explanation = match result {
+                Ok(_) => "".to_string(),
+                Err(failure) => failure.to_string(),
+            };
+
+            CommandResult::Continue(Some(explanation))
+        } else {
+            CommandResult::Continue(Some(result.is_ok().to_string()))
+        }
     }
 }
 
@@ -18,10 +18,14 @@ fn run_valid() {
                 assert_eq ${value} ""
                 valid = cm_plugin_check_task_condition
                 assert_false "${valid}"
+                reason = cm_plugin_check_task_condition --explain
+                assert_eq "${reason}" "env_set: env var: cm_plugin_check_task_condition_test_valid_env is not defined"
 
                 set_env cm_plugin_check_task_condition_test_valid_env 1
                 valid = cm_plugin_check_task_condition
                 assert "${valid}"
+                reason = cm_plugin_check_task_condition --explain
+                assert_eq "${reason}" ""
 
                 set_env cm_plugin_check_task_condition_test_valid_plugin done
             "#
@@ -47,6 +51,17 @@ fn run_valid() {
         rust_version: None,
         files_exist: None,
         files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
     });
 
     let mut flow_info = create_empty_flow_info();
@@ -73,7 +88,8 @@ fn run_valid() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 
     assert!(done);
     assert!(envmnt::is_equal(
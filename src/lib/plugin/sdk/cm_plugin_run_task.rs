@@ -34,14 +34,15 @@ impl Command for CommandImpl {
             plugins_enabled: false,
         };
 
-        runner::run_task_with_options(
+        match runner::run_task_with_options(
             &self.flow_info,
             self.flow_state.clone(),
             &self.step,
             &options,
-        );
-
-        CommandResult::Continue(Some("true".to_string()))
+        ) {
+            Ok(_) => CommandResult::Continue(Some("true".to_string())),
+            Err(error) => CommandResult::Error(error.to_string()),
+        }
     }
 }
 
@@ -45,6 +45,7 @@ fn run_valid() {
         EnvValue::Value("1".to_string()),
     );
     task.env = Some(env);
+    task.export_env = Some(true);
 
     let mut flow_info = create_empty_flow_info();
     flow_info
@@ -70,7 +71,8 @@ fn run_valid() {
         &RunTaskOptions {
             plugins_enabled: true,
         },
-    );
+    )
+    .unwrap();
 
     assert!(done);
     assert!(envmnt::is_equal("cm_plugin_run_task_test_valid_env", "1"));
@@ -8,15 +8,16 @@
 mod types_test;
 
 use indexmap::IndexMap;
+use schemars::JsonSchema;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds a plugin implementation
 pub(crate) struct Plugin {
     /// The plugin script content
     pub(crate) script: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds the entire plugin config and implementation structure
 pub struct Plugins {
     /// The plugin name aliases
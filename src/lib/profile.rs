@@ -9,7 +9,7 @@ mod profile_test;
 
 use envmnt;
 
-static PROFILE_ENV_KEY: &str = "CARGO_MAKE_PROFILE";
+pub(crate) static PROFILE_ENV_KEY: &str = "CARGO_MAKE_PROFILE";
 static ADDITIONAL_PROFILES_ENV_KEY: &str = "CARGO_MAKE_ADDITIONAL_PROFILES";
 pub(crate) static DEFAULT_PROFILE: &str = "development";
 
@@ -1,11 +1,17 @@
 use std::env;
 
-use crate::{logger, profile, types::Task};
+use crate::{
+    condition, fingerprint, logger, profile,
+    types::{CliArgs, Task},
+};
 
 #[cfg(test)]
 #[path = "proxy_task_test.rs"]
 mod proxy_task_test;
 
+/// Holds the cli jobs value which is forwarded to the proxy sub processes
+static JOBS_ENV_VAR_NAME: &str = "CARGO_MAKE_CLI_JOBS";
+
 pub(crate) fn create_proxy_task(
     task: &str,
     allow_private: bool,
@@ -42,6 +48,21 @@ pub(crate) fn create_proxy_task(
         args.push("--skip-init-end-tasks".to_string());
     }
 
+    //forward the cli flags which change how the flow runs
+    if let Ok(jobs) = env::var(JOBS_ENV_VAR_NAME) {
+        if !jobs.is_empty() {
+            args.push(format!("--jobs={}", jobs));
+        }
+    }
+
+    if fingerprint::is_force_rerun() {
+        args.push("--force-rerun".to_string());
+    }
+
+    if condition::should_explain_skips() {
+        args.push("--explain-skips".to_string());
+    }
+
     //get makefile location
     let makefile_path_option = match makefile {
         Some(makefile_path) => Some(makefile_path),
@@ -71,3 +92,9 @@ pub(crate) fn create_proxy_task(
 
     proxy_task.get_normalized_task()
 }
+
+pub(crate) fn init(cli_args: &CliArgs) {
+    if let Some(jobs) = cli_args.jobs {
+        envmnt::set(JOBS_ENV_VAR_NAME, jobs.to_string());
+    }
+}
@@ -157,3 +157,29 @@ fn create_proxy_task_cli_args() {
     assert_eq!(args[6], "arg1");
     assert_eq!(args[7], "arg2");
 }
+
+#[test]
+#[ignore]
+#[cfg(target_os = "linux")]
+fn create_proxy_task_forward_cli_flags() {
+    let mut cli_args = CliArgs::new();
+    cli_args.jobs = Some(4);
+    init(&cli_args);
+    envmnt::set_bool("CARGO_MAKE_FORCE_RERUN", true);
+    envmnt::set_bool("CARGO_MAKE_EXPLAIN_SKIPS", true);
+
+    let task = create_proxy_task("some_task", false, false, None, None);
+
+    envmnt::remove(JOBS_ENV_VAR_NAME);
+    envmnt::remove("CARGO_MAKE_FORCE_RERUN");
+    envmnt::remove("CARGO_MAKE_EXPLAIN_SKIPS");
+
+    let args = task.args.unwrap();
+    assert!(args.contains(&"--jobs=4".to_string()));
+    assert!(args.contains(&"--force-rerun".to_string()));
+    assert!(args.contains(&"--explain-skips".to_string()));
+
+    let task_index = args.iter().position(|arg| arg == "some_task").unwrap();
+    let jobs_index = args.iter().position(|arg| arg == "--jobs=4").unwrap();
+    assert!(jobs_index < task_index);
+}
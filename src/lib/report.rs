@@ -0,0 +1,365 @@
+//! # report
+//!
+//! Writes a JUnit XML report of the flow execution.<br>
+//! The report is generated from the flow events which are shared with all sub processes, so workspace
+//! member flows are reported as separate test suites.
+//!
+
+#[cfg(test)]
+#[path = "report_test.rs"]
+mod report_test;
+
+use crate::error::CargoMakeError;
+use crate::events;
+use crate::events::{Event, EventType};
+use crate::recursion_level;
+use crate::types::CliArgs;
+use envmnt;
+use fsio;
+use fsio::file::write_text_file;
+use indexmap::IndexMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+static JUNIT_REPORT_ENV_VAR_NAME: &str = "CARGO_MAKE_JUNIT_REPORT";
+
+/// Holds the events file read offset and whether the events file is a temporary file
+static EVENTS_FILE_INFO: Mutex<Option<(u64, bool)>> = Mutex::new(None);
+
+#[derive(Debug, Clone, PartialEq)]
+/// The test case result
+enum TestCaseStatus {
+    /// Task started but did not finish
+    Running,
+    /// Task finished successfully
+    Passed,
+    /// Task failed
+    Failed(Option<i32>, Option<String>),
+    /// Task skipped
+    Skipped(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds a single test case (task invocation)
+struct TestCase {
+    /// The task name
+    name: String,
+    /// The cargo-make recursion level of the invocation
+    level: u32,
+    /// The duration in milliseconds
+    duration: u128,
+    /// The test case result
+    status: TestCaseStatus,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds a single test suite (flow or workspace member)
+struct TestSuite {
+    /// The suite name
+    name: String,
+    /// All test cases
+    test_cases: Vec<TestCase>,
+}
+
+/// Parses the report value (format=file) and returns the report file path
+fn parse_report_value(value: &str) -> Option<String> {
+    let mut parts = value.splitn(2, '=');
+    let format = parts.next().unwrap_or("").trim();
+    let file = parts.next().unwrap_or("").trim();
+
+    if format == "junit" && !file.is_empty() {
+        Some(file.to_string())
+    } else {
+        None
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .chars()
+        .filter(|character| {
+            // remove control characters which are not allowed in xml
+            !character.is_control() || *character == '\n' || *character == '\t'
+        })
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn format_seconds(duration: u128) -> String {
+    format!("{:.3}", duration as f64 / 1000.0)
+}
+
+fn find_running_test_case<'a>(
+    test_cases: &'a mut Vec<TestCase>,
+    event: &Event,
+) -> Option<&'a mut TestCase> {
+    test_cases.iter_mut().rev().find(|test_case| {
+        test_case.status == TestCaseStatus::Running
+            && test_case.name == event.task
+            && test_case.level == event.level
+    })
+}
+
+/// Groups the flow events into test suites (one for the main flow and one per workspace member)
+fn create_test_suites(flow_name: &str, flow_events: &Vec<Event>) -> Vec<TestSuite> {
+    let mut test_suites: IndexMap<String, Vec<TestCase>> = IndexMap::new();
+    test_suites.insert(flow_name.to_string(), vec![]);
+
+    for event in flow_events {
+        let suite_name = match event.member {
+            Some(ref member) => member.to_string(),
+            None => flow_name.to_string(),
+        };
+        let test_cases = test_suites.entry(suite_name).or_insert(vec![]);
+
+        match event.event {
+            EventType::TaskStarted => test_cases.push(TestCase {
+                name: event.task.clone(),
+                level: event.level,
+                duration: 0,
+                status: TestCaseStatus::Running,
+            }),
+            EventType::TaskFinished => {
+                let exit_code = event.exit_code.unwrap_or(0);
+                let status = if exit_code == 0 {
+                    TestCaseStatus::Passed
+                } else {
+                    TestCaseStatus::Failed(Some(exit_code), event.stderr.clone())
+                };
+                let duration = event.duration.unwrap_or(0);
+
+                match find_running_test_case(test_cases, event) {
+                    Some(test_case) => {
+                        test_case.duration = duration;
+                        test_case.status = status;
+                    }
+                    None => test_cases.push(TestCase {
+                        name: event.task.clone(),
+                        level: event.level,
+                        duration,
+                        status,
+                    }),
+                }
+            }
+            EventType::TaskSkipped => {
+                let status =
+                    TestCaseStatus::Skipped(event.fail_message.clone().unwrap_or_default());
+
+                match find_running_test_case(test_cases, event) {
+                    Some(test_case) => test_case.status = status,
+                    None => test_cases.push(TestCase {
+                        name: event.task.clone(),
+                        level: event.level,
+                        duration: 0,
+                        status,
+                    }),
+                }
+            }
+            EventType::FlowFinished => (),
+        }
+    }
+
+    test_suites
+        .into_iter()
+        .filter(|(_, test_cases)| !test_cases.is_empty())
+        .map(|(name, test_cases)| {
+            let test_cases = test_cases
+                .into_iter()
+                .map(|mut test_case| {
+                    // tasks which never finished were aborted by an error
+                    if test_case.status == TestCaseStatus::Running {
+                        test_case.status = TestCaseStatus::Failed(None, None);
+                    }
+
+                    test_case
+                })
+                .collect();
+
+            TestSuite { name, test_cases }
+        })
+        .collect()
+}
+
+fn create_junit_xml(flow_name: &str, test_suites: &Vec<TestSuite>) -> String {
+    let mut total_tests = 0;
+    let mut total_failures = 0;
+    let mut total_skipped = 0;
+    let mut total_duration = 0;
+    let mut suites_xml = String::new();
+
+    for test_suite in test_suites {
+        let mut failures = 0;
+        let mut skipped = 0;
+        let mut duration = 0;
+        let mut cases_xml = String::new();
+
+        for test_case in &test_suite.test_cases {
+            duration = duration + test_case.duration;
+
+            cases_xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{}\"",
+                escape_xml(&test_case.name),
+                escape_xml(&test_suite.name),
+                format_seconds(test_case.duration)
+            ));
+
+            match test_case.status {
+                TestCaseStatus::Failed(ref exit_code, ref stderr) => {
+                    failures = failures + 1;
+
+                    let message = match exit_code {
+                        Some(code) => format!("Task failed with exit code: {}", code),
+                        None => "Task did not complete.".to_string(),
+                    };
+                    cases_xml.push_str(&format!(
+                        ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                        escape_xml(&message),
+                        escape_xml(stderr.as_ref().map(|value| value.as_str()).unwrap_or(""))
+                    ));
+                }
+                TestCaseStatus::Skipped(ref message) => {
+                    skipped = skipped + 1;
+
+                    cases_xml.push_str(&format!(
+                        ">\n      <skipped message=\"{}\"/>\n    </testcase>\n",
+                        escape_xml(message)
+                    ));
+                }
+                _ => cases_xml.push_str("/>\n"),
+            }
+        }
+
+        suites_xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\" time=\"{}\">\n{}  </testsuite>\n",
+            escape_xml(&test_suite.name),
+            test_suite.test_cases.len(),
+            failures,
+            skipped,
+            format_seconds(duration),
+            cases_xml
+        ));
+
+        total_tests = total_tests + test_suite.test_cases.len();
+        total_failures = total_failures + failures;
+        total_skipped = total_skipped + skipped;
+        total_duration = total_duration + duration;
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\" time=\"{}\">\n{}</testsuites>\n",
+        escape_xml(flow_name),
+        total_tests,
+        total_failures,
+        total_skipped,
+        format_seconds(total_duration),
+        suites_xml
+    )
+}
+
+/// Returns true if a report was requested and the commands stderr should be captured
+pub(crate) fn should_capture_stderr() -> bool {
+    envmnt::exists(JUNIT_REPORT_ENV_VAR_NAME)
+}
+
+/// Writes the report of the flow (only invoked by the top level cargo-make process).
+pub(crate) fn write(flow_name: &str) {
+    if !recursion_level::is_top() {
+        return;
+    }
+
+    let report_file = match env::var(JUNIT_REPORT_ENV_VAR_NAME) {
+        Ok(file) => file,
+        Err(_) => return,
+    };
+    let events_file_info = match EVENTS_FILE_INFO.lock() {
+        Ok(mut events_file_info) => events_file_info.take(),
+        Err(_) => None,
+    };
+
+    match (events::get_events_file(), events_file_info) {
+        (Some(events_file), Some((offset, temporary))) => {
+            let flow_events = events::read_events_file(&events_file, offset);
+            let test_suites = create_test_suites(flow_name, &flow_events);
+            let xml = create_junit_xml(flow_name, &test_suites);
+
+            match write_text_file(&report_file, &xml) {
+                Ok(_) => info!("JUnit report written to: {}", &report_file),
+                Err(error) => warn!(
+                    "Unable to write JUnit report: {} error: {:#?}",
+                    &report_file, error
+                ),
+            };
+
+            if temporary {
+                fsio::file::delete_ignore_error(&events_file);
+            }
+        }
+        _ => (),
+    }
+}
+
+pub(crate) fn init(cli_args: &CliArgs) -> Result<(), CargoMakeError> {
+    let report_file = match cli_args.report {
+        Some(ref value) => match parse_report_value(value) {
+            Some(file) => file,
+            None => {
+                return Err(CargoMakeError::Other(format!(
+                    "Invalid report value: {}, expected format: junit=<file>",
+                    value
+                )));
+            }
+        },
+        None => return Ok(()),
+    };
+
+    // sub processes might run in other directories
+    let file_path = Path::new(&report_file);
+    let absolute_path = if file_path.is_absolute() {
+        file_path.to_path_buf()
+    } else {
+        match env::current_dir() {
+            Ok(directory) => directory.join(file_path),
+            Err(_) => file_path.to_path_buf(),
+        }
+    };
+    envmnt::set(
+        JUNIT_REPORT_ENV_VAR_NAME,
+        absolute_path.to_string_lossy().into_owned(),
+    );
+
+    // the report is generated from the flow events so they must be written to a file
+    let events_file_info = match events::get_events_file() {
+        Some(events_file) => {
+            let offset = match fs::metadata(&events_file) {
+                Ok(metadata) => metadata.len(),
+                Err(_) => 0,
+            };
+
+            (offset, false)
+        }
+        None => {
+            let events_file = fsio::path::get_temporary_file_path("jsonl");
+            if let Err(error) = write_text_file(&events_file, "") {
+                warn!(
+                    "Unable to create events file: {} error: {:#?}",
+                    &events_file, error
+                );
+            }
+            events::set_events_file(&events_file);
+
+            (0, true)
+        }
+    };
+
+    if let Ok(mut info) = EVENTS_FILE_INFO.lock() {
+        *info = Some(events_file_info);
+    }
+
+    Ok(())
+}
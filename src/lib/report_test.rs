@@ -0,0 +1,222 @@
+use super::*;
+
+fn create_event(event_type: EventType, task: &str, member: Option<&str>) -> Event {
+    Event {
+        event: event_type,
+        task: task.to_string(),
+        timestamp: 100,
+        level: 0,
+        member: member.map(|value| value.to_string()),
+        fail_message: None,
+        exit_code: None,
+        stderr: None,
+        duration: None,
+        success: None,
+    }
+}
+
+fn create_finished_event(task: &str, exit_code: i32, duration: u128) -> Event {
+    let mut event = create_event(EventType::TaskFinished, task, None);
+    event.exit_code = Some(exit_code);
+    event.duration = Some(duration);
+
+    event
+}
+
+#[test]
+fn parse_report_value_valid() {
+    let output = parse_report_value("junit=report.xml");
+
+    assert_eq!(output.unwrap(), "report.xml");
+}
+
+#[test]
+fn parse_report_value_unsupported_format() {
+    let output = parse_report_value("html=report.html");
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn parse_report_value_missing_file() {
+    let output = parse_report_value("junit=");
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn escape_xml_special_characters() {
+    let output = escape_xml("<a href=\"test\">'1' & 2</a>\u{1b}[0m");
+
+    assert_eq!(
+        output,
+        "&lt;a href=&quot;test&quot;&gt;&apos;1&apos; &amp; 2&lt;/a&gt;[0m"
+    );
+}
+
+#[test]
+fn create_test_suites_empty() {
+    let test_suites = create_test_suites("flow", &vec![]);
+
+    assert!(test_suites.is_empty());
+}
+
+#[test]
+fn create_test_suites_passed() {
+    let test_suites = create_test_suites(
+        "flow",
+        &vec![
+            create_event(EventType::TaskStarted, "test", None),
+            create_finished_event("test", 0, 1500),
+            create_event(EventType::FlowFinished, "flow", None),
+        ],
+    );
+
+    assert_eq!(test_suites.len(), 1);
+    assert_eq!(test_suites[0].name, "flow");
+    assert_eq!(test_suites[0].test_cases.len(), 1);
+    assert_eq!(test_suites[0].test_cases[0].name, "test");
+    assert_eq!(test_suites[0].test_cases[0].duration, 1500);
+    assert_eq!(test_suites[0].test_cases[0].status, TestCaseStatus::Passed);
+}
+
+#[test]
+fn create_test_suites_failed() {
+    let mut failed_event = create_finished_event("test", 2, 10);
+    failed_event.stderr = Some("error\n".to_string());
+
+    let test_suites = create_test_suites(
+        "flow",
+        &vec![
+            create_event(EventType::TaskStarted, "test", None),
+            failed_event,
+        ],
+    );
+
+    assert_eq!(test_suites[0].test_cases.len(), 1);
+    assert_eq!(
+        test_suites[0].test_cases[0].status,
+        TestCaseStatus::Failed(Some(2), Some("error\n".to_string()))
+    );
+}
+
+#[test]
+fn create_test_suites_not_finished() {
+    let test_suites = create_test_suites(
+        "flow",
+        &vec![create_event(EventType::TaskStarted, "test", None)],
+    );
+
+    assert_eq!(
+        test_suites[0].test_cases[0].status,
+        TestCaseStatus::Failed(None, None)
+    );
+}
+
+#[test]
+fn create_test_suites_skipped() {
+    let mut skipped_event = create_event(EventType::TaskSkipped, "test", None);
+    skipped_event.fail_message = Some("condition failed".to_string());
+
+    let test_suites = create_test_suites("flow", &vec![skipped_event]);
+
+    assert_eq!(
+        test_suites[0].test_cases[0].status,
+        TestCaseStatus::Skipped("condition failed".to_string())
+    );
+}
+
+#[test]
+fn create_test_suites_up_to_date() {
+    let mut skipped_event = create_event(EventType::TaskSkipped, "test", None);
+    skipped_event.fail_message = Some("Up to date".to_string());
+
+    let test_suites = create_test_suites(
+        "flow",
+        &vec![
+            create_event(EventType::TaskStarted, "test", None),
+            skipped_event,
+        ],
+    );
+
+    assert_eq!(test_suites[0].test_cases.len(), 1);
+    assert_eq!(
+        test_suites[0].test_cases[0].status,
+        TestCaseStatus::Skipped("Up to date".to_string())
+    );
+}
+
+#[test]
+fn create_test_suites_workspace_members() {
+    let mut member1_event = create_finished_event("build", 0, 10);
+    member1_event.member = Some("member1".to_string());
+    member1_event.level = 1;
+    let mut member2_event = create_finished_event("build", 0, 20);
+    member2_event.member = Some("member2".to_string());
+    member2_event.level = 1;
+
+    let test_suites = create_test_suites(
+        "flow",
+        &vec![
+            create_event(EventType::TaskStarted, "workspace", None),
+            member1_event,
+            member2_event,
+            create_finished_event("workspace", 0, 40),
+        ],
+    );
+
+    assert_eq!(test_suites.len(), 3);
+    assert_eq!(test_suites[0].name, "flow");
+    assert_eq!(test_suites[0].test_cases[0].name, "workspace");
+    assert_eq!(test_suites[0].test_cases[0].duration, 40);
+    assert_eq!(test_suites[1].name, "member1");
+    assert_eq!(test_suites[1].test_cases[0].duration, 10);
+    assert_eq!(test_suites[2].name, "member2");
+    assert_eq!(test_suites[2].test_cases[0].duration, 20);
+}
+
+#[test]
+fn create_junit_xml_all_statuses() {
+    let test_suites = vec![TestSuite {
+        name: "flow".to_string(),
+        test_cases: vec![
+            TestCase {
+                name: "passed".to_string(),
+                level: 0,
+                duration: 1500,
+                status: TestCaseStatus::Passed,
+            },
+            TestCase {
+                name: "skipped".to_string(),
+                level: 0,
+                duration: 0,
+                status: TestCaseStatus::Skipped("message".to_string()),
+            },
+            TestCase {
+                name: "failed".to_string(),
+                level: 0,
+                duration: 250,
+                status: TestCaseStatus::Failed(Some(1), Some("error <1>".to_string())),
+            },
+        ],
+    }];
+
+    let output = create_junit_xml("flow", &test_suites);
+
+    assert_eq!(
+        output,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="flow" tests="3" failures="1" errors="0" skipped="1" time="1.750">
+  <testsuite name="flow" tests="3" failures="1" errors="0" skipped="1" time="1.750">
+    <testcase name="passed" classname="flow" time="1.500"/>
+    <testcase name="skipped" classname="flow" time="0.000">
+      <skipped message="message"/>
+    </testcase>
+    <testcase name="failed" classname="flow" time="0.250">
+      <failure message="Task failed with exit code: 1">error &lt;1&gt;</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+    );
+}
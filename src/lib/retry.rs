@@ -0,0 +1,74 @@
+//! # retry
+//!
+//! Decides whether a failed task command/script should be retried and how long to wait before
+//! the next attempt.
+//!
+
+#[cfg(test)]
+#[path = "retry_test.rs"]
+mod retry_test;
+
+use crate::timeout;
+use crate::types::RetryOptions;
+use std::time::Duration;
+
+/// Returns the total amount of attempts (the first run and all retries)
+pub(crate) fn get_max_attempts(options: &Option<RetryOptions>) -> u32 {
+    match options {
+        Some(ref options) => options.count.saturating_add(1),
+        None => 1,
+    }
+}
+
+/// Returns true if the failed attempt should be retried based on its exit code
+pub(crate) fn should_retry(options: &RetryOptions, exit_code: i32, attempt: u32) -> bool {
+    if exit_code == 0 || attempt > options.count {
+        return false;
+    }
+
+    match options.on_exit_codes {
+        Some(ref exit_codes) => exit_codes.contains(&exit_code),
+        None => true,
+    }
+}
+
+/// Validates the retry options values
+pub(crate) fn validate(options: &RetryOptions) -> Result<(), String> {
+    if let Some(ref delay) = options.delay {
+        if timeout::parse(delay).is_none() {
+            return Err(format!("Invalid retries delay: {}", delay));
+        }
+    }
+
+    match options.backoff {
+        Some(ref backoff) => match backoff.as_str() {
+            "fixed" | "linear" | "exponential" => Ok(()),
+            _ => Err(format!(
+                "Invalid retries backoff: {} (supported values: fixed, linear, exponential)",
+                backoff
+            )),
+        },
+        None => Ok(()),
+    }
+}
+
+/// Returns the delay before the next attempt after the provided failed attempt (starting at 1)
+pub(crate) fn get_delay(options: &RetryOptions, attempt: u32) -> Result<Duration, String> {
+    validate(options)?;
+
+    let delay = match options.delay {
+        Some(ref delay) => timeout::parse(delay).unwrap_or_default(),
+        None => Duration::from_millis(0),
+    };
+
+    let attempt = attempt.max(1);
+    let factor = match options.backoff {
+        Some(ref backoff) if backoff == "linear" => attempt,
+        Some(ref backoff) if backoff == "exponential" => {
+            2u32.checked_pow(attempt - 1).unwrap_or(u32::MAX)
+        }
+        _ => 1,
+    };
+
+    Ok(delay.checked_mul(factor).unwrap_or(Duration::MAX))
+}
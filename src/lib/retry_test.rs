@@ -0,0 +1,118 @@
+use super::*;
+
+fn create_options(delay: Option<&str>, backoff: Option<&str>) -> RetryOptions {
+    RetryOptions {
+        count: 3,
+        delay: delay.map(|value| value.to_string()),
+        backoff: backoff.map(|value| value.to_string()),
+        on_exit_codes: None,
+    }
+}
+
+#[test]
+fn get_max_attempts_none() {
+    assert_eq!(get_max_attempts(&None), 1);
+}
+
+#[test]
+fn get_max_attempts_with_retries() {
+    let options = create_options(None, None);
+
+    assert_eq!(get_max_attempts(&Some(options)), 4);
+}
+
+#[test]
+fn should_retry_success() {
+    let options = create_options(None, None);
+
+    assert!(!should_retry(&options, 0, 1));
+}
+
+#[test]
+fn should_retry_any_exit_code() {
+    let options = create_options(None, None);
+
+    assert!(should_retry(&options, 1, 1));
+    assert!(should_retry(&options, -1, 3));
+}
+
+#[test]
+fn should_retry_count_exceeded() {
+    let options = create_options(None, None);
+
+    assert!(!should_retry(&options, 1, 4));
+}
+
+#[test]
+fn should_retry_on_exit_codes() {
+    let mut options = create_options(None, None);
+    options.on_exit_codes = Some(vec![2, 3]);
+
+    assert!(should_retry(&options, 2, 1));
+    assert!(should_retry(&options, 3, 1));
+    assert!(!should_retry(&options, 1, 1));
+}
+
+#[test]
+fn validate_valid() {
+    assert!(validate(&create_options(None, None)).is_ok());
+    assert!(validate(&create_options(Some("5s"), Some("fixed"))).is_ok());
+    assert!(validate(&create_options(Some("100ms"), Some("linear"))).is_ok());
+    assert!(validate(&create_options(Some("1m"), Some("exponential"))).is_ok());
+}
+
+#[test]
+fn validate_invalid_delay() {
+    let output = validate(&create_options(Some("5x"), None));
+
+    assert_eq!(output.unwrap_err(), "Invalid retries delay: 5x");
+}
+
+#[test]
+fn validate_invalid_backoff() {
+    let output = validate(&create_options(Some("5s"), Some("random")));
+
+    assert!(output
+        .unwrap_err()
+        .starts_with("Invalid retries backoff: random"));
+}
+
+#[test]
+fn get_delay_no_delay() {
+    let options = create_options(None, Some("exponential"));
+
+    assert_eq!(get_delay(&options, 3).unwrap(), Duration::from_millis(0));
+}
+
+#[test]
+fn get_delay_fixed() {
+    let options = create_options(Some("5s"), None);
+
+    assert_eq!(get_delay(&options, 1).unwrap(), Duration::from_secs(5));
+    assert_eq!(get_delay(&options, 3).unwrap(), Duration::from_secs(5));
+}
+
+#[test]
+fn get_delay_linear() {
+    let options = create_options(Some("5s"), Some("linear"));
+
+    assert_eq!(get_delay(&options, 1).unwrap(), Duration::from_secs(5));
+    assert_eq!(get_delay(&options, 2).unwrap(), Duration::from_secs(10));
+    assert_eq!(get_delay(&options, 3).unwrap(), Duration::from_secs(15));
+}
+
+#[test]
+fn get_delay_exponential() {
+    let options = create_options(Some("5s"), Some("exponential"));
+
+    assert_eq!(get_delay(&options, 1).unwrap(), Duration::from_secs(5));
+    assert_eq!(get_delay(&options, 2).unwrap(), Duration::from_secs(10));
+    assert_eq!(get_delay(&options, 3).unwrap(), Duration::from_secs(20));
+}
+
+#[test]
+fn get_delay_invalid() {
+    let options = create_options(Some("5s"), Some("random"));
+
+    assert!(get_delay(&options, 1).is_err());
+}
@@ -14,69 +14,148 @@ mod runner_test;
 
 use crate::command;
 use crate::condition;
+use crate::condition::{ConditionFailure, ConditionResult};
 use crate::environment;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
+use crate::events;
 use crate::execution_plan::create as create_execution_plan;
+use crate::fingerprint;
 use crate::functions;
 use crate::installer;
+use crate::io;
 use crate::logger;
 use crate::plugin::runner::run_task as run_task_plugin;
-use crate::profile;
+use crate::proxy_task;
 use crate::proxy_task::create_proxy_task;
+use crate::report;
+use crate::retry;
+use crate::scheduler;
+use crate::scheduler::SkipReason;
 use crate::scriptengine;
 use crate::time_summary;
+use crate::timeout;
+use crate::toolchain;
 use crate::types::{
-    CliArgs, Config, DeprecationInfo, EnvInfo, EnvValue, ExecutionPlan, FlowInfo, FlowState,
-    RunTaskInfo, RunTaskName, RunTaskOptions, RunTaskRoutingInfo, Step, Task, TaskWatchOptions,
+    CliArgs, Config, DeprecationInfo, EnvInfo, ExecutionPlan, FlowInfo, FlowState, RunTaskInfo,
+    RunTaskName, RunTaskOptions, RunTaskRoutingInfo, Step, Task, TaskWatchOptions,
+    ToolchainSpecifier,
 };
-use indexmap::IndexMap;
+use crate::watch;
 use regex::Regex;
 use std::cell::RefCell;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::thread;
 use std::time::SystemTime;
 
-fn do_in_task_working_directory<F>(step: &Step, mut action: F)
-where
-    F: FnMut(),
-{
-    let revert_directory = match step.config.cwd {
+/// Returns the task working directory path (relative values are resolved from the current
+/// working directory) without validating it exists
+fn resolve_task_working_directory(step: &Step) -> Option<PathBuf> {
+    match step.config.cwd {
         Some(ref cwd) => {
             let expanded_cwd = environment::expand_value(cwd);
 
             if expanded_cwd.len() > 0 {
-                let directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "");
-
-                environment::setup_cwd(Some(&expanded_cwd));
-
-                directory
+                Some(task_env::resolve_path(&expanded_cwd))
             } else {
-                "".to_string()
+                None
             }
         }
-        None => "".to_string(),
-    };
+        None => None,
+    }
+}
 
-    action();
+/// Returns the absolute task working directory (relative values are resolved from the current
+/// working directory)
+pub(crate) fn get_task_working_directory(step: &Step) -> Result<Option<String>, CargoMakeError> {
+    match resolve_task_working_directory(step) {
+        Some(directory) => {
+            if !directory.is_dir() {
+                return Err(CargoMakeError::InvalidTask(format!(
+                    "Unable to set current working directory to: {}",
+                    directory.display()
+                )));
+            }
 
-    // revert to original cwd
-    match step.config.cwd {
-        Some(_) => {
-            environment::setup_cwd(Some(&revert_directory));
+            let directory_string = directory.to_string_lossy().into_owned();
+            Ok(Some(io::canonicalize_to_string(&directory_string)))
         }
-        _ => (),
-    };
+        None => Ok(None),
+    }
 }
 
-pub(crate) fn validate_condition(flow_info: &FlowInfo, step: &Step) -> bool {
-    let mut valid = true;
+/// Invokes the action while the task working directory (or the working directory of the parent
+/// task if not defined) is set for this thread.<br>
+/// The process working directory is not modified, instead it is passed to all processes started
+/// by the action.
+fn do_in_task_working_directory<F>(step: &Step, mut action: F) -> Result<(), CargoMakeError>
+where
+    F: FnMut(),
+{
+    let cwd = get_task_working_directory(step)?.or_else(task_env::get_current_cwd);
+    let _cwd_guard = task_env::set_current_cwd(cwd);
+
+    action();
+
+    Ok(())
+}
+
+fn is_up_to_date(flow_info: &FlowInfo, step: &Step) -> Result<bool, CargoMakeError> {
+    let mut up_to_date = Ok(false);
+
+    do_in_task_working_directory(&step, || {
+        up_to_date = fingerprint::is_up_to_date(&flow_info, &step);
+    })?;
+
+    up_to_date
+}
+
+fn store_fingerprint(flow_info: &FlowInfo, step: &Step) -> Result<(), CargoMakeError> {
+    let mut result = Ok(());
+
+    do_in_task_working_directory(&step, || {
+        result = fingerprint::store(&flow_info, &step);
+    })?;
+
+    result
+}
+
+/// Evaluates the task condition in the task working directory and returns the first criterion
+/// which was not met
+pub(crate) fn explain_condition(
+    flow_info: &FlowInfo,
+    step: &Step,
+) -> Result<ConditionResult, CargoMakeError> {
+    let mut result = Ok(());
 
     let do_validate = || {
-        valid = condition::validate_condition_for_step(&flow_info, &step);
+        result = condition::explain_condition_for_step(&flow_info, &step);
     };
 
-    do_in_task_working_directory(&step, do_validate);
+    do_in_task_working_directory(&step, do_validate)?;
 
-    valid
+    Ok(result)
+}
+
+/// Returns the skipped task message, which is the condition fail message and in case skip
+/// explanations are enabled, also the criterion which was not met
+pub(crate) fn get_skip_message(step: &Step, failure: &ConditionFailure) -> String {
+    let fail_message = match step.config.condition {
+        Some(ref condition) => match condition.fail_message {
+            Some(ref value) => value.to_string(),
+            None => "".to_string(),
+        },
+        None => "".to_string(),
+    };
+
+    if condition::should_explain_skips() {
+        format!("{} (reason: {})", &fail_message, failure)
+            .trim_start()
+            .to_string()
+    } else {
+        fail_message
+    }
 }
 
 pub(crate) fn get_sub_task_info_for_routing_info(
@@ -127,7 +206,11 @@ fn create_fork_step(flow_info: &FlowInfo) -> Step {
     }
 }
 
-fn run_cleanup_task(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>, task: &str) {
+fn run_cleanup_task(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    task: &str,
+) -> Result<(), CargoMakeError> {
     match flow_info.config.tasks.get(task) {
         Some(cleanup_task_info) => run_task(
             &flow_info,
@@ -137,7 +220,10 @@ fn run_cleanup_task(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>, ta
                 config: cleanup_task_info.clone(),
             },
         ),
-        None => error!("Cleanup task: {} not found.", &task),
+        None => Err(CargoMakeError::TaskNotFound(format!(
+            "Cleanup task: {} not found.",
+            &task
+        ))),
     }
 }
 
@@ -145,7 +231,7 @@ fn run_forked_task(
     flow_info: &FlowInfo,
     flow_state: Rc<RefCell<FlowState>>,
     cleanup_task: &Option<String>,
-) {
+) -> Result<(), CargoMakeError> {
     // run task as a sub process
     let step = create_fork_step(&flow_info);
 
@@ -153,24 +239,26 @@ fn run_forked_task(
         Some(cleanup_task_name) => {
             // run the forked task (forked tasks only run a command + args)
             let exit_code =
-                command::run_command(&step.config.command.unwrap(), &step.config.args, false);
+                command::run_command(&step.config.command.unwrap(), &step.config.args, false)?;
 
             if exit_code != 0 {
-                run_cleanup_task(&flow_info, flow_state, &cleanup_task_name);
-                command::validate_exit_code(exit_code);
+                run_cleanup_task(&flow_info, flow_state, &cleanup_task_name)?;
+                command::validate_exit_code(exit_code)?;
             }
+
+            Ok(())
         }
         None => run_task(&flow_info, flow_state, &step),
     }
 }
 
-/// runs a sub task and returns true/false based if a sub task was actually invoked
-fn run_sub_task_and_report(
+/// Returns the sub task names, fork, parallel and cleanup task values of the run_task info.<br>
+/// For routing info, the first routing step with a valid condition is selected.
+pub(crate) fn get_sub_task_info(
     flow_info: &FlowInfo,
-    flow_state: Rc<RefCell<FlowState>>,
     sub_task: &RunTaskInfo,
-) -> bool {
-    let (task_names, fork, parallel, cleanup_task) = match sub_task {
+) -> (Option<Vec<String>>, bool, bool, Option<String>) {
+    match sub_task {
         RunTaskInfo::Name(ref name) => (Some(vec![name.to_string()]), false, false, None),
         RunTaskInfo::Details(ref details) => {
             let task_name_values = match details.name.clone() {
@@ -187,7 +275,16 @@ fn run_sub_task_and_report(
         RunTaskInfo::Routing(ref routing_info) => {
             get_sub_task_info_for_routing_info(&flow_info, routing_info)
         }
-    };
+    }
+}
+
+/// runs a sub task and returns true/false based if a sub task was actually invoked
+fn run_sub_task_and_report(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    sub_task: &RunTaskInfo,
+) -> Result<bool, CargoMakeError> {
+    let (task_names, fork, parallel, cleanup_task) = get_sub_task_info(&flow_info, &sub_task);
 
     if task_names.is_some() {
         let names = task_names.unwrap();
@@ -195,7 +292,16 @@ fn run_sub_task_and_report(
 
         // clean up task only supported for forked tasks
         if !fork && cleanup_task.is_some() {
-            error!("Invalid task, cannot use cleanup_task without fork.");
+            return Err(CargoMakeError::InvalidTask(
+                "Invalid task, cannot use cleanup_task without fork.".to_string(),
+            ));
+        }
+
+        // parallel sub flows would wait for the env lock held by this thread (for example when
+        // invoked from a duckscript), so they are invoked sequentially instead
+        let run_in_parallel = parallel && !task_env::is_env_locked();
+        if parallel && !run_in_parallel {
+            debug!("Env is locked, running parallel sub tasks sequentially.");
         }
 
         for name in names {
@@ -207,78 +313,79 @@ fn run_sub_task_and_report(
                 sub_flow_info.task = name;
 
                 if fork {
-                    run_forked_task(&sub_flow_info, flow_state, cleanup_task);
+                    run_forked_task(&sub_flow_info, flow_state, cleanup_task)
                 } else {
-                    run_flow(&sub_flow_info, flow_state, true);
+                    run_flow(&sub_flow_info, flow_state, true)
                 }
             };
 
-            if parallel {
+            if run_in_parallel {
                 let run_flow_info = flow_info.clone();
                 // we do not support merging changes back to parent
                 let cloned_flow_state = flow_state.borrow().clone();
                 let cloned_cleanup_task = cleanup_task.clone();
+                let task_context = task_env::get_current_context();
                 threads.push(thread::spawn(move || {
+                    let _task_context_guard = task_env::set_current_context(task_context);
+
                     task_run_fn(
                         &run_flow_info,
                         Rc::new(RefCell::new(cloned_flow_state)),
                         fork,
                         &cloned_cleanup_task,
-                    );
+                    )
                 }));
             } else {
-                task_run_fn(&flow_info, flow_state.clone(), fork, &cleanup_task);
+                task_run_fn(&flow_info, flow_state.clone(), fork, &cleanup_task)?;
             }
         }
 
         if threads.len() > 0 {
+            // wait for all parallel sub tasks before reporting the first failure
+            let mut result = Ok(());
             for task_thread in threads {
-                task_thread.join().unwrap();
+                let thread_result = task_thread.join().unwrap();
+
+                if result.is_ok() {
+                    result = thread_result;
+                }
             }
+
+            result?;
         }
 
         if let Some(cleanup_task_name) = cleanup_task {
-            run_cleanup_task(&flow_info, flow_state, &cleanup_task_name);
+            run_cleanup_task(&flow_info, flow_state, &cleanup_task_name)?;
         }
 
-        true
+        Ok(true)
     } else {
-        false
+        Ok(false)
     }
 }
 
-fn run_sub_task(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>, sub_task: &RunTaskInfo) {
-    run_sub_task_and_report(&flow_info, flow_state, &sub_task);
-}
-
-fn create_watch_task_name(task: &str) -> String {
-    let mut watch_task_name = "".to_string();
-    watch_task_name.push_str(&task);
-    watch_task_name.push_str("-watch");
-
-    watch_task_name
-}
-
-fn create_watch_step(task: &str, options: Option<TaskWatchOptions>, flow_info: &FlowInfo) -> Step {
-    let watch_task = create_watch_task(&task, options, flow_info);
-
-    let watch_task_name = create_watch_task_name(&task);
+fn run_sub_task(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    sub_task: &RunTaskInfo,
+) -> Result<(), CargoMakeError> {
+    run_sub_task_and_report(&flow_info, flow_state, &sub_task)?;
 
-    Step {
-        name: watch_task_name,
-        config: watch_task,
-    }
+    Ok(())
 }
 
 fn watch_task(
     flow_info: &FlowInfo,
-    flow_state: Rc<RefCell<FlowState>>,
-    task: &str,
+    step: &Step,
     options: Option<TaskWatchOptions>,
-) {
-    let step = create_watch_step(&task, options, flow_info);
-
-    run_task(&flow_info, flow_state, &step);
+) -> Result<(), CargoMakeError> {
+    // the watched paths are resolved from the task working directory
+    let mut result = Ok(());
+    do_in_task_working_directory(&step, || {
+        result = watch::watch(&step.name, options.clone(), flow_info);
+    })?;
+
+    result
 }
 
 fn is_watch_enabled() -> bool {
@@ -301,12 +408,255 @@ fn should_watch(task: &Task) -> bool {
     }
 }
 
-pub(crate) fn run_task(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>, step: &Step) {
+fn run_task_action_attempt(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+) -> Result<i32, CargoMakeError> {
+    let mut result = Ok(0);
+
+    do_in_task_working_directory(&step, || {
+        // run script
+        result = match scriptengine::invoke(&step.config, flow_info, flow_state.clone()) {
+            Ok(Some(value)) => Ok(value),
+            // run command
+            Ok(None) => command::run(&step),
+            Err(error) => Err(error),
+        };
+    })?;
+
+    result
+}
+
+/// Runs the task script/command, retrying failed attempts based on the task retries options.
+/// Each attempt is added separately to the time summary.
+fn run_task_action(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+    start_time: SystemTime,
+) -> Result<(), CargoMakeError> {
+    let timeout_value = timeout::get_value(&step.config, &flow_info.config.config);
+    let max_attempts = retry::get_max_attempts(&step.config.retries);
+
+    let mut attempt = 1;
+    let mut attempt_start_time = start_time;
+    loop {
+        let last_attempt = attempt >= max_attempts;
+
+        // failures are validated here and not by the script/command runner, to enable retries
+        let mut attempt_step = step.clone();
+        if !last_attempt {
+            attempt_step.config.ignore_errors = Some(true);
+        }
+
+        if let Err(error) = timeout::start(&step.name, &timeout_value) {
+            return Err(CargoMakeError::InvalidTask(error));
+        }
+
+        let exit_code = match run_task_action_attempt(flow_info, flow_state.clone(), &attempt_step)
+        {
+            Ok(value) => value,
+            Err(error) => {
+                timeout::end();
+                return Err(error);
+            }
+        };
+
+        let summary_name = if attempt > 1 {
+            format!("{} (attempt {})", &step.name, attempt)
+        } else {
+            step.name.clone()
+        };
+
+        if !last_attempt && exit_code != 0 {
+            let retry_options = step.config.retries.clone().unwrap();
+
+            if retry::should_retry(&retry_options, exit_code, attempt) {
+                if let Some(message) = timeout::end() {
+                    warn!("{}", message);
+                }
+
+                let delay = match retry::get_delay(&retry_options, attempt) {
+                    Ok(value) => value,
+                    Err(error) => {
+                        return Err(CargoMakeError::InvalidTask(format!(
+                            "Task: {} {}",
+                            &step.name, error
+                        )))
+                    }
+                };
+                warn!(
+                    "Task: {} failed with exit code: {} (attempt {}/{}), retrying in {:?}",
+                    &step.name, exit_code, attempt, max_attempts, delay
+                );
+
+                time_summary::add(
+                    &mut flow_state.borrow_mut().time_summary,
+                    &summary_name,
+                    attempt_start_time,
+                );
+
+                thread::sleep(delay);
+
+                attempt = attempt + 1;
+                attempt_start_time = SystemTime::now();
+                info!(
+                    "Running Task: {} (attempt {}/{})",
+                    &step.name, attempt, max_attempts
+                );
+
+                continue;
+            } else if !step.config.should_ignore_errors() {
+                timeout::end();
+                command::validate_exit_code(exit_code)?;
+            }
+        }
+
+        // timeout of tasks which ignore errors is only reported
+        if let Some(message) = timeout::end() {
+            warn!("{}", message);
+        }
+
+        time_summary::add(
+            &mut flow_state.borrow_mut().time_summary,
+            &summary_name,
+            attempt_start_time,
+        );
+
+        return Ok(());
+    }
+}
+
+/// Installs the task dependencies and runs the task script/command with the given toolchain,
+/// which is also set as the RUSTUP_TOOLCHAIN of the task env so it applies to all action types.
+fn run_task_action_for_toolchain(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+    toolchain: &ToolchainSpecifier,
+    start_time: SystemTime,
+) -> Result<(), CargoMakeError> {
+    let mut toolchain_env = task_env::get_current().unwrap_or_default();
+    toolchain_env.vars.insert(
+        "RUSTUP_TOOLCHAIN".to_string(),
+        toolchain.channel().to_string(),
+    );
+    toolchain_env
+        .removed
+        .retain(|key| key != "RUSTUP_TOOLCHAIN");
+    let _task_env_guard = task_env::set_current(Some(toolchain_env));
+
+    let mut install_result = Ok(());
+    do_in_task_working_directory(&step, || {
+        install_result = installer::install(&step.config, flow_info, flow_state.clone());
+    })?;
+    install_result?;
+
+    run_task_action(&flow_info, flow_state, &step, start_time)
+}
+
+/// Runs the task once for each of its toolchains.<br>
+/// In case multiple toolchains are defined, all of them are invoked even if some fail, after
+/// which a pass/fail summary is printed and the task fails if any of the toolchains failed.
+fn run_task_toolchains(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+    start_time: SystemTime,
+) -> Result<(), CargoMakeError> {
+    let toolchains = match step.config.toolchain {
+        Some(ref task_toolchain) => task_toolchain.get_toolchains(),
+        None => vec![],
+    };
+
+    if toolchains.len() == 1 {
+        let toolchain = &toolchains[0];
+        if step.config.command.is_none() {
+            if let Err(message) = toolchain::validate_toolchain(toolchain) {
+                return Err(CargoMakeError::TaskFailed(message));
+            }
+        }
+
+        return run_task_action_for_toolchain(flow_info, flow_state, step, toolchain, start_time);
+    }
+
+    let mut results = vec![];
+    let mut toolchain_start_time = start_time;
+    for toolchain in toolchains {
+        let mut toolchain_step = step.clone();
+        toolchain_step.name = format!("{} (toolchain: {})", &step.name, toolchain.channel());
+        toolchain_step.config.toolchain = Some(toolchain.clone().into());
+        // failures are validated once all toolchains are invoked
+        toolchain_step.config.ignore_errors = Some(false);
+        toolchain_step.config.force = None;
+
+        info!("Running Task: {}", &toolchain_step.name);
+
+        let result = match toolchain::validate_toolchain(&toolchain) {
+            Ok(_) => run_task_action_for_toolchain(
+                flow_info,
+                flow_state.clone(),
+                &toolchain_step,
+                &toolchain,
+                toolchain_start_time,
+            ),
+            Err(message) => Err(CargoMakeError::TaskFailed(message)),
+        };
+
+        if let Err(ref error) = result {
+            warn!("Task: {} failed: {}", &toolchain_step.name, error);
+        }
+
+        results.push((toolchain, result.is_ok()));
+        toolchain_start_time = SystemTime::now();
+    }
+
+    let summary: Vec<String> = results
+        .iter()
+        .map(|(toolchain, passed)| {
+            format!(
+                "{}: {}",
+                toolchain.channel(),
+                if *passed { "passed" } else { "failed" }
+            )
+        })
+        .collect();
+    info!("Task: {} toolchains: {}", &step.name, summary.join(", "));
+
+    let failed: Vec<&str> = results
+        .iter()
+        .filter(|(_, passed)| !passed)
+        .map(|(toolchain, _)| toolchain.channel())
+        .collect();
+    if failed.is_empty() {
+        Ok(())
+    } else if step.config.should_ignore_errors() {
+        warn!(
+            "Task: {} failed for toolchains: {}",
+            &step.name,
+            failed.join(", ")
+        );
+        Ok(())
+    } else {
+        Err(CargoMakeError::TaskFailed(format!(
+            "Task: {} failed for toolchains: {}",
+            &step.name,
+            failed.join(", ")
+        )))
+    }
+}
+
+pub(crate) fn run_task(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+) -> Result<(), CargoMakeError> {
     let options = RunTaskOptions {
         plugins_enabled: true,
     };
 
-    run_task_with_options(flow_info, flow_state, step, &options);
+    run_task_with_options(flow_info, flow_state, step, &options)
 }
 
 pub(crate) fn run_task_with_options(
@@ -314,37 +664,54 @@ pub(crate) fn run_task_with_options(
     flow_state: Rc<RefCell<FlowState>>,
     step: &Step,
     options: &RunTaskOptions,
-) {
+) -> Result<(), CargoMakeError> {
+    let result = run_task_step(flow_info, flow_state, step, options);
+
+    if let Err(ref error) = result {
+        events::task_failed(&step.name, error);
+    }
+
+    result
+}
+
+fn run_task_step(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+    options: &RunTaskOptions,
+) -> Result<(), CargoMakeError> {
     let start_time = SystemTime::now();
 
     // if a plugin is handling the task execution flow
-    if run_task_plugin(flow_info, flow_state.clone(), step, options) {
+    if run_task_plugin(flow_info, flow_state.clone(), step, options)? {
         time_summary::add(
             &mut flow_state.borrow_mut().time_summary,
             &step.name,
             start_time,
         );
-        return;
+        events::task_finished(&step.name, 0, start_time);
+        return Ok(());
     }
 
     if step.config.is_actionable() {
-        match step.config.env {
-            Some(ref env) => environment::set_current_task_meta_info_env(env.clone()),
-            None => (),
-        };
+        let _meta_info_guard = task_env::set_current_meta_info(&step);
 
-        if validate_condition(&flow_info, &step) {
+        let condition_result = explain_condition(&flow_info, &step)?;
+
+        if condition_result.is_ok() {
             if logger::should_reduce_output(&flow_info) && step.config.script.is_none() {
                 debug!("Running Task: {}", &step.name);
             } else {
                 info!("Running Task: {}", &step.name);
             }
 
+            events::task_started(&step.name);
+
             if !step.config.is_valid() {
-                error!(
+                return Err(CargoMakeError::InvalidTask(format!(
                     "Invalid task: {}, contains multiple actions.\n{:#?}",
                     &step.name, &step.config
-                );
+                )));
             }
 
             let deprecated_info = step.config.deprecated.clone();
@@ -366,40 +733,38 @@ pub(crate) fn run_task_with_options(
                 None => (),
             };
 
-            //get profile
-            let profile_name = profile::get();
-
-            match step.config.env_files {
-                Some(ref env_files) => environment::set_env_files(env_files.clone()),
-                None => (),
-            };
-            match step.config.env {
-                Some(ref env) => environment::set_env(env.clone()),
-                None => (),
-            };
-
-            envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", &step.name);
-
-            //make sure profile env is not overwritten
-            profile::set(&profile_name);
-
             // modify step using env and functions
-            let mut updated_step = functions::run(&step);
-            updated_step = environment::expand_env(&updated_step);
+            let (step_env, updated_step) = task_env::create(&step, || {
+                let updated_step = functions::run(&step)?;
+                Ok(environment::expand_env(&updated_step))
+            })?;
+            let updated_step = updated_step?;
+
+            if step.config.export_env.unwrap_or(false) {
+                task_env::export(&step_env);
+            }
+            let _task_env_guard = task_env::set_current(Some(step_env));
 
             let watch = should_watch(&step.config);
 
             if watch {
-                watch_task(
-                    &flow_info,
-                    flow_state,
-                    &step.name,
-                    step.config.watch.clone(),
-                );
+                watch_task(&flow_info, &updated_step, step.config.watch.clone())?;
+            } else if is_up_to_date(&flow_info, &updated_step)? {
+                info!("Up to date: {}", &step.name);
+                events::task_skipped(&step.name, "Up to date");
+            } else if step.config.run_task.is_none() && updated_step.config.toolchain.is_some() {
+                run_task_toolchains(&flow_info, flow_state.clone(), &updated_step, start_time)?;
+
+                store_fingerprint(&flow_info, &updated_step)?;
+
+                events::task_finished(&step.name, 0, start_time);
             } else {
-                do_in_task_working_directory(&step, || {
-                    installer::install(&updated_step.config, flow_info, flow_state.clone());
-                });
+                let mut install_result = Ok(());
+                do_in_task_working_directory(&updated_step, || {
+                    install_result =
+                        installer::install(&updated_step.config, flow_info, flow_state.clone());
+                })?;
+                install_result?;
 
                 match step.config.run_task {
                     Some(ref sub_task) => {
@@ -408,144 +773,179 @@ pub(crate) fn run_task_with_options(
                             &step.name,
                             start_time,
                         );
+                        events::task_finished(&step.name, 0, start_time);
+
+                        let mut sub_task_result = Ok(());
+                        do_in_task_working_directory(&updated_step, || {
+                            sub_task_result =
+                                run_sub_task(&flow_info, flow_state.clone(), sub_task);
+                        })?;
+                        sub_task_result?;
 
-                        run_sub_task(&flow_info, flow_state, sub_task);
+                        store_fingerprint(&flow_info, &updated_step)?;
                     }
                     None => {
-                        do_in_task_working_directory(&step, || {
-                            // run script
-                            let script_runner_done = scriptengine::invoke(
-                                &updated_step.config,
-                                flow_info,
-                                flow_state.clone(),
-                            );
-
-                            // run command
-                            if !script_runner_done {
-                                command::run(&updated_step);
-                            };
-                        });
+                        run_task_action(&flow_info, flow_state.clone(), &updated_step, start_time)?;
 
-                        time_summary::add(
-                            &mut flow_state.borrow_mut().time_summary,
-                            &step.name,
-                            start_time,
-                        );
+                        store_fingerprint(&flow_info, &updated_step)?;
+
+                        events::task_finished(&step.name, 0, start_time);
                     }
                 };
             }
-        } else {
-            let fail_message = match step.config.condition {
-                Some(ref condition) => match condition.fail_message {
-                    Some(ref value) => value.to_string(),
-                    None => "".to_string(),
-                },
-                None => "".to_string(),
-            };
+        } else if let Err(ref failure) = condition_result {
+            let fail_message = get_skip_message(&step, failure);
 
-            if logger::should_reduce_output(&flow_info) && step.config.script.is_none() {
+            if logger::should_reduce_output(&flow_info)
+                && step.config.script.is_none()
+                && !condition::should_explain_skips()
+            {
                 debug!("Skipping Task: {} {}", &step.name, &fail_message);
             } else {
                 info!("Skipping Task: {} {}", &step.name, &fail_message);
             }
+
+            events::task_skipped(&step.name, &fail_message);
         }
     } else {
         debug!("Ignoring Empty Task: {}", &step.name);
     }
-}
 
-fn run_task_flow(
-    flow_info: &FlowInfo,
-    flow_state: Rc<RefCell<FlowState>>,
-    execution_plan: &ExecutionPlan,
-) {
-    for step in &execution_plan.steps {
-        run_task(&flow_info, flow_state.clone(), &step);
-    }
+    Ok(())
 }
 
-fn create_watch_task(task: &str, options: Option<TaskWatchOptions>, flow_info: &FlowInfo) -> Task {
-    let mut task_config =
-        create_proxy_task(&task, true, true, None, flow_info.cli_arguments.clone());
-
-    let mut env_map = task_config.env.unwrap_or(IndexMap::new());
-    env_map.insert(
-        "CARGO_MAKE_DISABLE_WATCH".to_string(),
-        EnvValue::Value("true".to_string()),
-    );
-    task_config.env = Some(env_map);
-
-    let make_args = task_config.args.unwrap();
-    let mut make_command = String::new();
-    for make_arg in make_args {
-        if make_arg.contains(" ") {
-            make_command.push_str("\"");
-            make_command.push_str(&make_arg);
-            make_command.push_str("\"");
-        } else {
-            make_command.push_str(&make_arg);
-        }
-
-        make_command.push(' ');
+fn get_parallelism(flow_info: &FlowInfo) -> usize {
+    match flow_info.config.config.parallelism {
+        Some(value) if value > 1 => value,
+        _ => 1,
     }
-    make_command = make_command.trim().to_string();
+}
 
-    let mut watch_args = vec!["watch".to_string(), "-q".to_string()];
+/// Returns the function which runs the step in a parallel thread and returns its time summary
+fn create_step_function(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+) -> Box<dyn FnOnce() -> Result<Vec<(String, u128)>, CargoMakeError> + Send> {
+    let run_flow_info = flow_info.clone();
+    let run_step = step.clone();
+    // we do not support merging changes back to parent, except for the time summary
+    let mut cloned_flow_state = flow_state.borrow().clone();
+    cloned_flow_state.time_summary = vec![];
+    let task_context = task_env::get_current_context();
+
+    Box::new(move || {
+        let _task_context_guard = task_env::set_current_context(task_context);
+
+        let thread_flow_state = Rc::new(RefCell::new(cloned_flow_state));
+        run_task(&run_flow_info, thread_flow_state.clone(), &run_step)?;
+
+        let time_summary = thread_flow_state.borrow().time_summary.clone();
+        Ok(time_summary)
+    })
+}
 
-    match options {
-        Some(task_watch_options) => match task_watch_options {
-            TaskWatchOptions::Options(watch_options) => {
-                let watch_version = match watch_options.version {
-                    Some(value) => value.to_string(),
-                    _ => "8.1.0".to_string(), // current version
-                };
-                task_config.install_crate_args = Some(vec!["--version".to_string(), watch_version]);
+/// Runs the execution plan steps in parallel (up to the given amount of jobs) while
+/// making sure each step only starts after all its dependencies are done.
+fn run_task_flow_in_parallel(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    execution_plan: &ExecutionPlan,
+    jobs: usize,
+) -> Result<(), CargoMakeError> {
+    let mut failed_step = None;
 
-                match watch_options.postpone {
-                    Some(value) => {
-                        if value {
-                            watch_args.push("--postpone".to_string());
-                        }
-                    }
-                    _ => (),
-                };
+    let skip_reasons = scheduler::run(
+        &execution_plan.steps_dependencies,
+        jobs,
+        true,
+        |index| {
+            let step = &execution_plan.steps[index];
+            debug!("Starting parallel step: {}", &step.name);
 
-                match watch_options.ignore_pattern {
-                    Some(value) => watch_args.extend_from_slice(&["-i".to_string(), value]),
-                    _ => (),
-                };
+            create_step_function(&flow_info, flow_state.clone(), step)
+        },
+        |index, output| match output {
+            Some(Ok(time_summary)) => {
+                flow_state.borrow_mut().time_summary.extend(time_summary);
+                true
+            }
+            Some(Err(error)) => {
+                if failed_step.is_none() {
+                    failed_step = Some((execution_plan.steps[index].name.clone(), error));
+                }
+                false
+            }
+            None => {
+                if failed_step.is_none() {
+                    let step_name = execution_plan.steps[index].name.clone();
+                    let error = CargoMakeError::TaskFailed(format!(
+                        "Task: {} failed, no further tasks will be invoked.",
+                        &step_name
+                    ));
+                    failed_step = Some((step_name, error));
+                }
+                false
+            }
+        },
+    );
 
-                match watch_options.no_git_ignore {
-                    Some(value) => {
-                        if value {
-                            watch_args.push("--no-gitignore".to_string());
-                        }
-                    }
-                    _ => (),
-                };
+    match failed_step {
+        Some((step_name, error)) => {
+            warn!(
+                "Task: {} failed, no further tasks will be invoked.",
+                &step_name
+            );
 
-                match watch_options.watch {
-                    Some(paths) => {
-                        for watch_path in paths {
-                            watch_args.extend_from_slice(&["-w".to_string(), watch_path])
-                        }
-                    }
-                    _ => (),
-                };
+            Err(error)
+        }
+        None => {
+            let circular_steps: Vec<String> = skip_reasons
+                .iter()
+                .enumerate()
+                .filter(|(_, reason)| **reason == Some(SkipReason::CircularDependency))
+                .map(|(index, _)| execution_plan.steps[index].name.clone())
+                .collect();
+
+            if circular_steps.is_empty() {
+                Ok(())
+            } else {
+                Err(CargoMakeError::InvalidTask(format!(
+                    "Circular dependency detected for tasks: {}",
+                    circular_steps.join(", ")
+                )))
             }
-            _ => (),
-        },
-        _ => (),
+        }
     }
+}
 
-    watch_args.extend_from_slice(&["-x".to_string(), make_command.to_string()]);
+fn run_task_flow(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    execution_plan: &ExecutionPlan,
+) -> Result<(), CargoMakeError> {
+    let jobs = get_parallelism(&flow_info);
 
-    task_config.args = Some(watch_args);
+    // parallel steps would wait for the env lock held by this thread (for example when invoked
+    // from a duckscript), so they are invoked sequentially instead
+    if jobs > 1 && execution_plan.steps.len() > 1 && !task_env::is_env_locked() {
+        debug!("Running flow with up to {} parallel jobs.", jobs);
 
-    task_config
+        run_task_flow_in_parallel(&flow_info, flow_state, &execution_plan, jobs)
+    } else {
+        for step in &execution_plan.steps {
+            run_task(&flow_info, flow_state.clone(), &step)?;
+        }
+
+        Ok(())
+    }
 }
 
-pub(crate) fn run_flow(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>, sub_flow: bool) {
+pub(crate) fn run_flow(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    sub_flow: bool,
+) -> Result<(), CargoMakeError> {
     let allow_private = sub_flow || flow_info.allow_private;
 
     let execution_plan = create_execution_plan(
@@ -555,13 +955,16 @@ pub(crate) fn run_flow(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>,
         allow_private,
         sub_flow,
         &flow_info.skip_tasks_pattern,
-    );
+    )?;
     debug!("Created execution plan: {:#?}", &execution_plan);
 
-    run_task_flow(&flow_info, flow_state, &execution_plan);
+    run_task_flow(&flow_info, flow_state, &execution_plan)
 }
 
-fn run_protected_flow(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>) {
+fn run_protected_flow(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+) -> Result<(), CargoMakeError> {
     let proxy_task = create_proxy_task(
         &flow_info.task,
         flow_info.allow_private,
@@ -570,7 +973,7 @@ fn run_protected_flow(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>)
         flow_info.cli_arguments.clone(),
     );
 
-    let exit_code = command::run_command(&proxy_task.command.unwrap(), &proxy_task.args, false);
+    let exit_code = command::run_command(&proxy_task.command.unwrap(), &proxy_task.args, false)?;
 
     if exit_code != 0 {
         match flow_info.config.config.on_error_task {
@@ -579,29 +982,32 @@ fn run_protected_flow(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>)
                 error_flow_info.disable_on_error = true;
                 error_flow_info.task = on_error_task.clone();
 
-                run_flow(&error_flow_info, flow_state, false);
+                run_flow(&error_flow_info, flow_state, false)?;
             }
             _ => (),
         };
 
-        error!("Task error detected, exit code: {}", &exit_code);
+        // keep the exit code of the failed cargo-make sub process
+        return Err(CargoMakeError::from_exit_code(
+            exit_code,
+            &format!("Task error detected, exit code: {}", &exit_code),
+        ));
     }
+
+    Ok(())
 }
 
-/// Runs the requested tasks.<br>
-/// The flow is as follows:
-///
-/// * Create an execution plan based on the requested task and its dependencies
-/// * Run all tasks defined in the execution plan
-pub(crate) fn run(
-    config: Config,
+/// Creates the flow info of the requested task based on the cli arguments
+pub(crate) fn create_flow_info(
+    mut config: Config,
     task: &str,
     env_info: EnvInfo,
     cli_args: &CliArgs,
-    start_time: SystemTime,
-    time_summary_vec: Vec<(String, u128)>,
-) {
-    time_summary::init(&config, &cli_args);
+) -> FlowInfo {
+    // cli jobs value overrides the makefile parallelism value
+    if cli_args.jobs.is_some() {
+        config.config.parallelism = cli_args.jobs;
+    }
 
     let skip_tasks_pattern = match cli_args.skip_tasks_pattern {
         Some(ref pattern) => match Regex::new(pattern) {
@@ -614,7 +1020,7 @@ pub(crate) fn run(
         None => None,
     };
 
-    let flow_info = FlowInfo {
+    FlowInfo {
         config,
         task: task.to_string(),
         env_info,
@@ -624,16 +1030,39 @@ pub(crate) fn run(
         skip_init_end_tasks: cli_args.skip_init_end_tasks,
         skip_tasks_pattern,
         cli_arguments: cli_args.arguments.clone(),
-    };
+    }
+}
+
+/// Runs the requested tasks.<br>
+/// The flow is as follows:
+///
+/// * Create an execution plan based on the requested task and its dependencies
+/// * Run all tasks defined in the execution plan
+pub(crate) fn run(
+    config: Config,
+    task: &str,
+    env_info: EnvInfo,
+    cli_args: &CliArgs,
+    start_time: SystemTime,
+    time_summary_vec: Vec<(String, u128)>,
+) -> Result<(), CargoMakeError> {
+    time_summary::init(&config, &cli_args);
+    condition::init(&cli_args);
+    fingerprint::init(&cli_args);
+    proxy_task::init(&cli_args);
+    events::init(&cli_args);
+    report::init(&cli_args)?;
+
+    let flow_info = create_flow_info(config, task, env_info, cli_args);
     let mut flow_state = FlowState::new();
     flow_state.time_summary = time_summary_vec;
 
     let flow_state_rc = Rc::new(RefCell::new(flow_state));
 
     if flow_info.disable_on_error || flow_info.config.config.on_error_task.is_none() {
-        run_flow(&flow_info, flow_state_rc.clone(), false);
+        run_flow(&flow_info, flow_state_rc.clone(), false)?;
     } else {
-        run_protected_flow(&flow_info, flow_state_rc.clone());
+        run_protected_flow(&flow_info, flow_state_rc.clone())?;
     }
 
     let time_string = match start_time.elapsed() {
@@ -646,5 +1075,10 @@ pub(crate) fn run(
 
     time_summary::print(&flow_state_rc.borrow().time_summary);
 
+    events::flow_finished(&task, true, Some(start_time));
+    report::write(&task);
+
     info!("Build Done{}.", &time_string);
+
+    Ok(())
 }
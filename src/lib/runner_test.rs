@@ -1,16 +1,17 @@
 use super::*;
+use crate::events;
+use crate::profile;
 use crate::test;
 use crate::types::{
     ConfigSection, CrateInfo, DeprecationInfo, EnvFile, EnvInfo, EnvValue, FlowInfo,
-    RunTaskDetails, RunTaskInfo, ScriptValue, Step, Task, TaskCondition,
+    RunTaskDetails, RunTaskInfo, ScriptValue, Step, Task, TaskCondition, TaskToolchain,
 };
 use ci_info;
 use git_info::types::GitInfo;
 use indexmap::IndexMap;
 use rust_info::types::RustInfo;
-
-#[cfg(target_os = "linux")]
-use crate::types::WatchOptions;
+use std::env;
+use std::path::Path;
 
 #[test]
 #[ignore]
@@ -47,7 +48,43 @@ fn run_flow_private() {
         cli_arguments: None,
     };
 
-    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false);
+    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
+}
+
+#[test]
+fn run_flow_task_not_found() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    };
+
+    let output = run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false);
+
+    assert_eq!(
+        output.unwrap_err(),
+        CargoMakeError::TaskNotFound("Task test not found".to_string())
+    );
 }
 
 #[test]
@@ -84,7 +121,7 @@ fn run_flow_private_skipped() {
         cli_arguments: None,
     };
 
-    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false);
+    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
 }
 
 #[test]
@@ -121,7 +158,7 @@ fn run_flow_private_sub_task() {
         cli_arguments: None,
     };
 
-    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), true);
+    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), true).unwrap();
 }
 
 #[test]
@@ -158,7 +195,69 @@ fn run_flow_allow_private() {
         cli_arguments: None,
     };
 
-    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false);
+    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
+}
+
+#[test]
+fn get_parallelism_none() {
+    let flow_info = test::create_empty_flow_info();
+
+    let jobs = get_parallelism(&flow_info);
+
+    assert_eq!(jobs, 1);
+}
+
+#[test]
+fn get_parallelism_zero() {
+    let mut flow_info = test::create_empty_flow_info();
+    flow_info.config.config.parallelism = Some(0);
+
+    let jobs = get_parallelism(&flow_info);
+
+    assert_eq!(jobs, 1);
+}
+
+#[test]
+fn get_parallelism_defined() {
+    let mut flow_info = test::create_empty_flow_info();
+    flow_info.config.config.parallelism = Some(4);
+
+    let jobs = get_parallelism(&flow_info);
+
+    assert_eq!(jobs, 4);
+}
+
+#[test]
+#[ignore]
+fn run_flow_parallel() {
+    let mut config_section = ConfigSection::new();
+    config_section.parallelism = Some(2);
+    let mut config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec!["exit 0".to_string()]));
+    task.dependencies = Some(vec!["dependency1".into(), "dependency2".into()]);
+    config.tasks.insert("test".to_string(), task);
+
+    let mut dependency = Task::new();
+    dependency.script = Some(ScriptValue::Text(vec!["exit 0".to_string()]));
+    config
+        .tasks
+        .insert("dependency1".to_string(), dependency.clone());
+    config.tasks.insert("dependency2".to_string(), dependency);
+
+    let mut flow_info = test::create_empty_flow_info();
+    flow_info.config = config;
+    flow_info.task = "test".to_string();
+
+    run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
 }
 
 #[test]
@@ -197,7 +296,7 @@ fn run_task_bad_script() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -237,7 +336,7 @@ fn run_task_script_with_args_error() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -276,7 +375,7 @@ fn run_task_script_with_args_valid() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -315,7 +414,7 @@ fn run_task_command() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -355,7 +454,7 @@ fn run_task_bad_command_valid_script() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -393,7 +492,7 @@ fn run_task_no_command_valid_script() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -440,7 +539,7 @@ fn run_task_bad_run_task_valid_command() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -484,7 +583,7 @@ fn run_task_valid_run_task() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -524,7 +623,59 @@ fn run_task_invalid_task() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+}
+
+#[test]
+#[ignore]
+fn run_task_invalid_task_failed_event() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    };
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec!["exit 0".to_string()]));
+    task.command = Some("echo".to_string());
+    let step = Step {
+        name: "runner_test_invalid_task_failed_event".to_string(),
+        config: task,
+    };
+
+    events::start_collecting();
+    let result = run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    let failed_events: Vec<events::Event> = events::stop_collecting()
+        .into_iter()
+        .filter(|event| {
+            event.task == "runner_test_invalid_task_failed_event"
+                && event.event == events::EventType::TaskFinished
+        })
+        .collect();
+
+    let error = result.unwrap_err();
+    assert_eq!(failed_events.len(), 1);
+    assert_eq!(failed_events[0].exit_code, Some(error.exit_code()));
+    assert_eq!(failed_events[0].fail_message, Some(error.to_string()));
 }
 
 #[test]
@@ -567,13 +718,14 @@ fn run_task_set_env_file() {
     task.env_files = Some(vec![EnvFile::Path(
         "./src/lib/test/test_files/env.env".to_string(),
     )]);
+    task.export_env = Some(true);
 
     let step = Step {
         name: "test".to_string(),
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 
     assert_eq!(envmnt::get_or_panic("CARGO_MAKE_ENV_FILE_TEST1"), "1");
 
@@ -619,6 +771,7 @@ fn run_task_set_env() {
     let mut task = Task::new();
     task.script = Some(ScriptValue::Text(vec!["exit 0".to_string()]));
     task.env = Some(env);
+    task.export_env = Some(true);
 
     let step = Step {
         name: "test".to_string(),
@@ -627,11 +780,68 @@ fn run_task_set_env() {
 
     envmnt::set("TEST_RUN_TASK_SET_ENV", "EMPTY");
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 
     assert_eq!(envmnt::get_or_panic("TEST_RUN_TASK_SET_ENV"), "VALID");
 }
 
+#[test]
+#[ignore]
+fn run_task_set_env_isolated() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    };
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "TEST_RUN_TASK_SET_ENV_ISOLATED".to_string(),
+        EnvValue::Value("VALID".to_string()),
+    );
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "#!@duckscript".to_string(),
+        "value = get_env TEST_RUN_TASK_SET_ENV_ISOLATED".to_string(),
+        "assert_eq ${value} VALID".to_string(),
+    ]));
+    task.env = Some(env);
+
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::set("TEST_RUN_TASK_SET_ENV_ISOLATED", "EMPTY");
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(
+        envmnt::get_or_panic("TEST_RUN_TASK_SET_ENV_ISOLATED"),
+        "EMPTY"
+    );
+}
+
 #[test]
 #[ignore]
 #[should_panic]
@@ -669,7 +879,7 @@ fn run_task_cwd_no_such_dir() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -708,7 +918,7 @@ fn run_task_cwd_dir_exists() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -747,7 +957,149 @@ fn run_task_cwd_env_expansion() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+}
+
+#[test]
+#[ignore]
+fn run_task_cwd_process_cwd_not_modified() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+    };
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "#!@duckscript".to_string(),
+        "exists = is_path_exists ./lib/mod.rs".to_string(),
+        "assert ${exists}".to_string(),
+    ]));
+    task.cwd = Some("./src".to_string());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let directory = env::current_dir().unwrap();
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(env::current_dir().unwrap(), directory);
+}
+
+#[test]
+fn get_task_working_directory_none() {
+    let step = Step {
+        name: "test".to_string(),
+        config: Task::new(),
+    };
+
+    assert!(get_task_working_directory(&step).unwrap().is_none());
+}
+
+#[test]
+fn get_task_working_directory_relative() {
+    let mut task = Task::new();
+    task.cwd = Some("./src".to_string());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let directory = get_task_working_directory(&step).unwrap().unwrap();
+
+    assert!(Path::new(&directory).is_absolute());
+    assert!(Path::new(&directory).join("lib/mod.rs").exists());
+}
+
+#[test]
+fn get_task_working_directory_invalid() {
+    let mut task = Task::new();
+    task.cwd = Some("./bad_directory".to_string());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let result = get_task_working_directory(&step);
+
+    match result {
+        Err(CargoMakeError::InvalidTask(message)) => {
+            assert!(message.starts_with("Unable to set current working directory to: "));
+            assert!(message.ends_with("bad_directory"));
+        }
+        _ => panic!("expected an invalid task error"),
+    };
+}
+
+#[test]
+fn run_task_invalid_task_working_directory() {
+    let flow_info = test::create_empty_flow_info();
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.cwd = Some("./bad_directory".to_string());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let result = run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+
+    assert!(matches!(result, Err(CargoMakeError::InvalidTask(_))));
+}
+
+#[test]
+fn run_task_missing_toolchain() {
+    let flow_info = test::create_empty_flow_info();
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["--version".to_string()]);
+    task.toolchain = Some("invalid-chain".into());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let result = run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+
+    assert!(matches!(result, Err(CargoMakeError::TaskFailed(_))));
+}
+
+#[test]
+fn get_task_working_directory_relative_to_current_task() {
+    let mut task = Task::new();
+    task.cwd = Some("lib".to_string());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let current_directory = env::current_dir().unwrap().join("src");
+    let _guard = task_env::set_current_cwd(Some(current_directory.to_string_lossy().into_owned()));
+
+    let directory = get_task_working_directory(&step).unwrap().unwrap();
+
+    assert!(Path::new(&directory).join("mod.rs").exists());
 }
 
 #[test]
@@ -787,7 +1139,7 @@ fn run_task_deprecated_message() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -827,7 +1179,7 @@ fn run_task_deprecated_flag() {
         config: task,
     };
 
-    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
 
 #[test]
@@ -926,373 +1278,6 @@ fn should_watch_true_and_env_true() {
     assert!(!watch);
 }
 
-#[test]
-#[ignore]
-fn create_watch_task_name_valid() {
-    let output = create_watch_task_name("test_task");
-
-    assert_eq!(output, "test_task-watch");
-}
-
-#[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn create_watch_task_with_makefile() {
-    let makefile = envmnt::get_or("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
-    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &makefile);
-
-    let flow_info = test::create_empty_flow_info();
-
-    let task = create_watch_task("some_task", None, &flow_info);
-
-    match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
-        EnvValue::Value(value) => assert_eq!(value, "true"),
-        _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
-    };
-
-    assert_eq!(task.command.unwrap(), "cargo".to_string());
-
-    let log_level = logger::get_log_level();
-    let mut make_command_line =
-        "make --disable-check-for-updates --no-on-error --loglevel=".to_string();
-    make_command_line.push_str(&log_level);
-    make_command_line.push_str(" --profile=");
-    make_command_line.push_str(&profile::get());
-    make_command_line.push_str(" --allow-private --skip-init-end-tasks --makefile ");
-    make_command_line.push_str(&makefile.clone());
-    make_command_line.push_str(" some_task");
-
-    let args = task.args.unwrap();
-    assert_eq!(args.len(), 4);
-    assert_eq!(args[0], "watch".to_string());
-    assert_eq!(args[1], "-q".to_string());
-    assert_eq!(args[2], "-x".to_string());
-    assert_eq!(args[3], make_command_line.to_string());
-}
-
-#[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn create_watch_task_with_makefile_with_spaces_in_path() {
-    let makefile = envmnt::get_or("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
-    let test_makefile = "/path with spaces/mymakefile.toml";
-    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &test_makefile);
-
-    let flow_info = test::create_empty_flow_info();
-
-    let task = create_watch_task("some_task", None, &flow_info);
-    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &makefile);
-
-    match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
-        EnvValue::Value(value) => assert_eq!(value, "true"),
-        _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
-    };
-
-    assert_eq!(task.command.unwrap(), "cargo".to_string());
-
-    let log_level = logger::get_log_level();
-    let mut make_command_line =
-        "make --disable-check-for-updates --no-on-error --loglevel=".to_string();
-    make_command_line.push_str(&log_level);
-    make_command_line.push_str(" --profile=");
-    make_command_line.push_str(&profile::get());
-    make_command_line.push_str(
-        " --allow-private --skip-init-end-tasks --makefile \"/path with spaces/mymakefile.toml\" some_task"
-    );
-
-    let args = task.args.unwrap();
-    assert_eq!(args.len(), 4);
-    assert_eq!(args[0], "watch".to_string());
-    assert_eq!(args[1], "-q".to_string());
-    assert_eq!(args[2], "-x".to_string());
-    assert_eq!(args[3], make_command_line.to_string());
-}
-
-#[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn create_watch_task_with_makefile_and_bool_options() {
-    let makefile = envmnt::get_or("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
-    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &makefile);
-
-    let flow_info = test::create_empty_flow_info();
-
-    let task = create_watch_task(
-        "some_task",
-        Some(TaskWatchOptions::Boolean(true)),
-        &flow_info,
-    );
-
-    match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
-        EnvValue::Value(value) => assert_eq!(value, "true"),
-        _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
-    };
-
-    assert_eq!(task.command.unwrap(), "cargo".to_string());
-
-    let log_level = logger::get_log_level();
-    let mut make_command_line =
-        "make --disable-check-for-updates --no-on-error --loglevel=".to_string();
-    make_command_line.push_str(&log_level);
-    make_command_line.push_str(" --profile=");
-    make_command_line.push_str(&profile::get());
-    make_command_line.push_str(" --allow-private --skip-init-end-tasks --makefile ");
-    make_command_line.push_str(&makefile.clone());
-    make_command_line.push_str(" some_task");
-
-    let args = task.args.unwrap();
-    assert_eq!(args.len(), 4);
-    assert_eq!(args[0], "watch".to_string());
-    assert_eq!(args[1], "-q".to_string());
-    assert_eq!(args[2], "-x".to_string());
-    assert_eq!(args[3], make_command_line.to_string());
-}
-
-#[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn create_watch_task_with_makefile_and_empty_object_options() {
-    let makefile = envmnt::get_or("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
-    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &makefile);
-
-    let watch_options = WatchOptions {
-        version: None,
-        postpone: None,
-        ignore_pattern: None,
-        no_git_ignore: None,
-        watch: None,
-    };
-
-    let flow_info = test::create_empty_flow_info();
-
-    let task = create_watch_task(
-        "some_task",
-        Some(TaskWatchOptions::Options(watch_options)),
-        &flow_info,
-    );
-
-    assert!(task.install_crate_args.is_some());
-    assert_eq!(task.install_crate_args.unwrap().len(), 2);
-
-    match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
-        EnvValue::Value(value) => assert_eq!(value, "true"),
-        _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
-    };
-
-    assert_eq!(task.command.unwrap(), "cargo".to_string());
-
-    let log_level = logger::get_log_level();
-    let mut make_command_line =
-        "make --disable-check-for-updates --no-on-error --loglevel=".to_string();
-    make_command_line.push_str(&log_level);
-    make_command_line.push_str(" --profile=");
-    make_command_line.push_str(&profile::get());
-    make_command_line.push_str(" --allow-private --skip-init-end-tasks --makefile ");
-    make_command_line.push_str(&makefile.clone());
-    make_command_line.push_str(" some_task");
-
-    let args = task.args.unwrap();
-    assert_eq!(args.len(), 4);
-    assert_eq!(args[0], "watch".to_string());
-    assert_eq!(args[1], "-q".to_string());
-    assert_eq!(args[2], "-x".to_string());
-    assert_eq!(args[3], make_command_line.to_string());
-}
-
-#[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn create_watch_task_with_makefile_and_all_object_options_and_cli_args() {
-    let makefile = envmnt::get_or("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
-    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &makefile);
-
-    let watch_options = WatchOptions {
-        version: Some("100.200.300.400".to_string()),
-        postpone: Some(true),
-        ignore_pattern: Some("tools/*".to_string()),
-        no_git_ignore: Some(true),
-        watch: Some(vec!["dir1".to_string(), "dir2".to_string()]),
-    };
-
-    let mut flow_info = test::create_empty_flow_info();
-    flow_info.cli_arguments = Some(vec!["1".to_string(), "2".to_string(), "3 4".to_string()]);
-
-    let task = create_watch_task(
-        "some_task",
-        Some(TaskWatchOptions::Options(watch_options)),
-        &flow_info,
-    );
-
-    assert!(task.install_crate_args.is_some());
-    let install_crate_args = task.install_crate_args.unwrap();
-    assert_eq!(install_crate_args[0], "--version");
-    assert_eq!(install_crate_args[1], "100.200.300.400");
-
-    match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
-        EnvValue::Value(value) => assert_eq!(value, "true"),
-        _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
-    };
-
-    assert_eq!(task.command.unwrap(), "cargo".to_string());
-
-    let log_level = logger::get_log_level();
-    let mut make_command_line =
-        "make --disable-check-for-updates --no-on-error --loglevel=".to_string();
-    make_command_line.push_str(&log_level);
-    make_command_line.push_str(" --profile=");
-    make_command_line.push_str(&profile::get());
-    make_command_line.push_str(" --allow-private --skip-init-end-tasks --makefile ");
-    make_command_line.push_str(&makefile.clone());
-    make_command_line.push_str(" some_task 1 2 \"3 4\"");
-
-    let args = task.args.unwrap();
-    assert_eq!(args.len(), 12);
-    assert_eq!(args[0], "watch".to_string());
-    assert_eq!(args[1], "-q".to_string());
-    assert_eq!(args[2], "--postpone".to_string());
-    assert_eq!(args[3], "-i".to_string());
-    assert_eq!(args[4], "tools/*".to_string());
-    assert_eq!(args[5], "--no-gitignore".to_string());
-    assert_eq!(args[6], "-w".to_string());
-    assert_eq!(args[7], "dir1".to_string());
-    assert_eq!(args[8], "-w".to_string());
-    assert_eq!(args[9], "dir2".to_string());
-    assert_eq!(args[10], "-x".to_string());
-    assert_eq!(args[11], make_command_line.to_string());
-}
-
-#[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn create_watch_task_with_makefile_and_all_object_options() {
-    let makefile = envmnt::get_or("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
-    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &makefile);
-
-    let watch_options = WatchOptions {
-        version: Some("100.200.300.400".to_string()),
-        postpone: Some(true),
-        ignore_pattern: Some("tools/*".to_string()),
-        no_git_ignore: Some(true),
-        watch: Some(vec!["dir1".to_string(), "dir2".to_string()]),
-    };
-
-    let flow_info = test::create_empty_flow_info();
-
-    let task = create_watch_task(
-        "some_task",
-        Some(TaskWatchOptions::Options(watch_options)),
-        &flow_info,
-    );
-
-    assert!(task.install_crate_args.is_some());
-    let install_crate_args = task.install_crate_args.unwrap();
-    assert_eq!(install_crate_args[0], "--version");
-    assert_eq!(install_crate_args[1], "100.200.300.400");
-
-    match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
-        EnvValue::Value(value) => assert_eq!(value, "true"),
-        _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
-    };
-
-    assert_eq!(task.command.unwrap(), "cargo".to_string());
-
-    let log_level = logger::get_log_level();
-    let mut make_command_line =
-        "make --disable-check-for-updates --no-on-error --loglevel=".to_string();
-    make_command_line.push_str(&log_level);
-    make_command_line.push_str(" --profile=");
-    make_command_line.push_str(&profile::get());
-    make_command_line.push_str(" --allow-private --skip-init-end-tasks --makefile ");
-    make_command_line.push_str(&makefile.clone());
-    make_command_line.push_str(" some_task");
-
-    let args = task.args.unwrap();
-    assert_eq!(args.len(), 12);
-    assert_eq!(args[0], "watch".to_string());
-    assert_eq!(args[1], "-q".to_string());
-    assert_eq!(args[2], "--postpone".to_string());
-    assert_eq!(args[3], "-i".to_string());
-    assert_eq!(args[4], "tools/*".to_string());
-    assert_eq!(args[5], "--no-gitignore".to_string());
-    assert_eq!(args[6], "-w".to_string());
-    assert_eq!(args[7], "dir1".to_string());
-    assert_eq!(args[8], "-w".to_string());
-    assert_eq!(args[9], "dir2".to_string());
-    assert_eq!(args[10], "-x".to_string());
-    assert_eq!(args[11], make_command_line.to_string());
-}
-
-#[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn create_watch_task_with_makefile_and_false_object_options() {
-    let makefile = envmnt::get_or("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
-    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &makefile);
-
-    let watch_options = WatchOptions {
-        version: None,
-        postpone: Some(false),
-        ignore_pattern: None,
-        no_git_ignore: Some(false),
-        watch: None,
-    };
-
-    let flow_info = test::create_empty_flow_info();
-
-    let task = create_watch_task(
-        "some_task",
-        Some(TaskWatchOptions::Options(watch_options)),
-        &flow_info,
-    );
-
-    assert!(task.install_crate_args.is_some());
-    assert_eq!(task.install_crate_args.unwrap().len(), 2);
-
-    match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
-        EnvValue::Value(value) => assert_eq!(value, "true"),
-        _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
-    };
-
-    assert_eq!(task.command.unwrap(), "cargo".to_string());
-
-    let log_level = logger::get_log_level();
-    let mut make_command_line =
-        "make --disable-check-for-updates --no-on-error --loglevel=".to_string();
-    make_command_line.push_str(&log_level);
-    make_command_line.push_str(" --profile=");
-    make_command_line.push_str(&profile::get());
-    make_command_line.push_str(" --allow-private --skip-init-end-tasks --makefile ");
-    make_command_line.push_str(&makefile.clone());
-    make_command_line.push_str(" some_task");
-
-    let args = task.args.unwrap();
-    assert_eq!(args.len(), 4);
-    assert_eq!(args[0], "watch".to_string());
-    assert_eq!(args[1], "-q".to_string());
-    assert_eq!(args[2], "-x".to_string());
-    assert_eq!(args[3], make_command_line.to_string());
-}
-
-#[test]
-fn create_watch_step_valid() {
-    let flow_info = test::create_empty_flow_info();
-
-    let step = create_watch_step("test_watch_step", None, &flow_info);
-    let task = step.config;
-
-    assert_eq!(&step.name, "test_watch_step-watch");
-
-    match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
-        EnvValue::Value(value) => assert_eq!(value, "true"),
-        _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
-    };
-
-    assert_eq!(task.command.unwrap(), "cargo".to_string());
-
-    let args = task.args.unwrap();
-    assert_eq!(args.len(), 4);
-}
-
 #[test]
 #[ignore]
 fn run_sub_task_and_report_for_name() {
@@ -1333,7 +1318,8 @@ fn run_sub_task_and_report_for_name() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 
     assert!(output);
 }
@@ -1379,7 +1365,8 @@ fn run_sub_task_and_report_for_name_not_found() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -1427,7 +1414,8 @@ fn run_sub_task_and_report_for_details_single() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 
     assert!(output);
 }
@@ -1478,7 +1466,8 @@ fn run_sub_task_and_report_for_details_multiple() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 
     assert!(output);
 }
@@ -1523,7 +1512,8 @@ fn run_sub_task_and_report_routing_empty() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 
     assert!(!output);
 }
@@ -1575,7 +1565,8 @@ fn run_sub_task_and_report_routing_no_condition() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 
     assert!(output);
 }
@@ -1633,6 +1624,17 @@ fn run_sub_task_and_report_routing_condition_not_met() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: None,
     }]);
@@ -1641,7 +1643,8 @@ fn run_sub_task_and_report_routing_condition_not_met() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 
     assert!(!output);
 }
@@ -1694,7 +1697,8 @@ fn run_sub_task_and_report_routing_not_found() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -1743,7 +1747,8 @@ fn run_sub_task_and_report_with_cleanup_task_but_no_fork() {
         &flow_info,
         Rc::new(RefCell::new(FlowState::new())),
         &sub_task,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -1830,6 +1835,17 @@ fn get_sub_task_info_for_routing_info_condition_not_met() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: None,
         }],
@@ -1889,6 +1905,17 @@ fn get_sub_task_info_for_routing_info_condition_found() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: None,
         }],
@@ -1948,6 +1975,17 @@ fn get_sub_task_info_for_routing_info_condition_found_multiple_tasks() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: None,
         }],
@@ -2098,6 +2136,17 @@ fn get_sub_task_info_for_routing_info_multiple_found() {
                     rust_version: None,
                     files_exist: None,
                     files_not_exist: None,
+                    arch: None,
+                    target_triples: None,
+                    commands_exist: None,
+                    rustup_components: None,
+                    git_branches: None,
+                    git_dirty: None,
+                    git_tag_on_head: None,
+                    files_changed_since: None,
+                    any: None,
+                    all: None,
+                    not: None,
                 }),
                 condition_script: None,
             },
@@ -2167,6 +2216,17 @@ fn get_sub_task_info_for_routing_info_default() {
                     rust_version: None,
                     files_exist: None,
                     files_not_exist: None,
+                    arch: None,
+                    target_triples: None,
+                    commands_exist: None,
+                    rustup_components: None,
+                    git_branches: None,
+                    git_dirty: None,
+                    git_tag_on_head: None,
+                    files_changed_since: None,
+                    any: None,
+                    all: None,
+                    not: None,
                 }),
                 condition_script: None,
             },
@@ -2244,6 +2304,17 @@ fn get_sub_task_info_for_routing_info_multiple() {
                     rust_version: None,
                     files_exist: None,
                     files_not_exist: None,
+                    arch: None,
+                    target_triples: None,
+                    commands_exist: None,
+                    rustup_components: None,
+                    git_branches: None,
+                    git_dirty: None,
+                    git_tag_on_head: None,
+                    files_changed_since: None,
+                    any: None,
+                    all: None,
+                    not: None,
                 }),
                 condition_script: None,
             },
@@ -2328,6 +2399,17 @@ fn get_sub_task_info_for_routing_info_fork_false() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: None,
         }],
@@ -2387,6 +2469,17 @@ fn get_sub_task_info_for_routing_info_fork_true() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: None,
         }],
@@ -2453,3 +2546,161 @@ fn create_fork_step_valid() {
     assert_eq!(args[8], makefile);
     assert_eq!(args[9], "test".to_string());
 }
+
+#[test]
+fn get_skip_message_with_fail_message() {
+    let mut task = Task::new();
+    task.condition = Some(TaskCondition {
+        fail_message: Some("not supported".to_string()),
+        profiles: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        arch: None,
+        target_triples: None,
+        commands_exist: None,
+        rustup_components: None,
+        git_branches: None,
+        git_dirty: None,
+        git_tag_on_head: None,
+        files_changed_since: None,
+        any: None,
+        all: None,
+        not: None,
+    });
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let message = get_skip_message(
+        &step,
+        &ConditionFailure::new("env_set", "env var: TEST is not defined"),
+    );
+
+    assert_eq!(message, "not supported");
+}
+
+#[test]
+#[ignore]
+fn get_skip_message_explain_skips() {
+    envmnt::set_bool("CARGO_MAKE_EXPLAIN_SKIPS", true);
+
+    let step = Step {
+        name: "test".to_string(),
+        config: Task::new(),
+    };
+
+    let message = get_skip_message(
+        &step,
+        &ConditionFailure::new("env_set", "env var: TEST is not defined"),
+    );
+
+    envmnt::remove("CARGO_MAKE_EXPLAIN_SKIPS");
+
+    assert_eq!(message, "(reason: env_set: env var: TEST is not defined)");
+}
+
+#[test]
+#[ignore]
+fn run_task_toolchain_script_env() {
+    let flow_info = test::create_empty_flow_info();
+    let toolchain = test::get_toolchain();
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![format!(
+        "test \"$RUSTUP_TOOLCHAIN\" = \"{}\"",
+        toolchain.channel()
+    )]));
+    task.toolchain = Some(toolchain.into());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+}
+
+#[test]
+#[ignore]
+fn run_task_toolchains_all_valid() {
+    let flow_info = test::create_empty_flow_info();
+    let toolchain = test::get_toolchain();
+
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["--version".to_string()]);
+    task.toolchain = Some(TaskToolchain::Multiple(vec![
+        toolchain.clone(),
+        toolchain.clone(),
+    ]));
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let flow_state = Rc::new(RefCell::new(FlowState::new()));
+    run_task(&flow_info, flow_state.clone(), &step).unwrap();
+
+    let summary_name = format!("test (toolchain: {})", toolchain.channel());
+    let time_summary = flow_state.borrow().time_summary.clone();
+    assert_eq!(time_summary.len(), 2);
+    assert!(time_summary.iter().all(|(name, _)| name == &summary_name));
+}
+
+#[test]
+#[ignore]
+fn run_task_toolchains_missing_toolchain() {
+    let flow_info = test::create_empty_flow_info();
+
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["--version".to_string()]);
+    task.toolchain = Some(TaskToolchain::Multiple(vec![
+        test::get_toolchain(),
+        "invalid-chain".into(),
+    ]));
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let result = run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+
+    match result {
+        Err(CargoMakeError::TaskFailed(message)) => {
+            assert_eq!(message, "Task: test failed for toolchains: invalid-chain")
+        }
+        _ => panic!("expected a task failure"),
+    };
+}
+
+#[test]
+#[ignore]
+fn run_task_toolchains_failed_ignore_errors() {
+    let flow_info = test::create_empty_flow_info();
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "test \"$RUSTUP_TOOLCHAIN\" != \"invalid-chain\"".to_string(),
+    ]));
+    task.toolchain = Some(TaskToolchain::Multiple(vec![
+        test::get_toolchain(),
+        "invalid-chain".into(),
+    ]));
+    task.ignore_errors = Some(true);
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+}
@@ -0,0 +1,140 @@
+//! # scheduler
+//!
+//! Invokes dependent nodes (flow steps, workspace members) in parallel threads (up to the given
+//! amount of jobs) while making sure each node only starts after all its dependencies are done.
+//!
+
+#[cfg(test)]
+#[path = "scheduler_test.rs"]
+mod scheduler_test;
+
+use std::collections::VecDeque;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::mpsc::channel;
+use std::thread;
+
+#[derive(Debug, Clone, PartialEq)]
+/// The reason a node was not invoked
+pub(crate) enum SkipReason {
+    /// A node dependency did not succeed (holds the dependency index)
+    DependencyFailed(usize),
+    /// The node is part of (or depends on) a circular dependency
+    CircularDependency,
+    /// No further nodes were invoked after a node did not succeed (fail fast mode)
+    Stopped,
+}
+
+/// Invokes the nodes based on the provided dependencies (the dependency indexes of each node).<br>
+/// The prepare function is invoked on the calling thread and returns the node function, which is
+/// invoked in a new thread.<br>
+/// The done function is invoked on the calling thread with the node function output (None in case
+/// it panicked) and returns true if the node succeeded.<br>
+/// Nodes which depend on a node that did not succeed are not invoked and in fail fast mode, no
+/// further nodes are invoked once a node did not succeed.<br>
+/// Returns the skip reason of each node (None for invoked nodes).
+pub(crate) fn run<T, P, D>(
+    dependencies: &Vec<Vec<usize>>,
+    jobs: usize,
+    fail_fast: bool,
+    mut prepare: P,
+    mut done: D,
+) -> Vec<Option<SkipReason>>
+where
+    T: Send + 'static,
+    P: FnMut(usize) -> Box<dyn FnOnce() -> T + Send>,
+    D: FnMut(usize, Option<T>) -> bool,
+{
+    let nodes_count = dependencies.len();
+    let jobs = if jobs > 1 { jobs } else { 1 };
+
+    let mut pending_dependencies = vec![0; nodes_count];
+    let mut dependents = vec![vec![]; nodes_count];
+    for (index, node_dependencies) in dependencies.iter().enumerate() {
+        pending_dependencies[index] = node_dependencies.len();
+        for dependency in node_dependencies {
+            dependents[*dependency].push(index);
+        }
+    }
+
+    let mut ready: VecDeque<usize> = (0..nodes_count)
+        .filter(|index| pending_dependencies[*index] == 0)
+        .collect();
+    let mut started = vec![false; nodes_count];
+    let mut failed_dependency: Vec<Option<usize>> = vec![None; nodes_count];
+    let mut skip_reasons: Vec<Option<SkipReason>> = vec![None; nodes_count];
+    let mut stopped = false;
+    let mut running = 0;
+    let (sender, receiver) = channel();
+
+    loop {
+        while !stopped && running < jobs {
+            match ready.pop_front() {
+                Some(index) => {
+                    started[index] = true;
+                    running = running + 1;
+
+                    let node_function = prepare(index);
+                    let sender = sender.clone();
+                    thread::spawn(move || {
+                        let output = panic::catch_unwind(AssertUnwindSafe(node_function)).ok();
+
+                        sender.send((index, output)).unwrap_or(());
+                    });
+                }
+                None => break,
+            }
+        }
+
+        // no node is running and no node is ready, so the remaining nodes (if any) are stopped
+        // or part of a circular dependency
+        if running == 0 {
+            break;
+        }
+
+        let (index, output) = match receiver.recv() {
+            Ok(value) => value,
+            Err(_) => break,
+        };
+        running = running - 1;
+
+        let succeeded = done(index, output);
+        if !succeeded && fail_fast {
+            stopped = true;
+        }
+
+        let mut finished = VecDeque::from(vec![(index, succeeded)]);
+        while let Some((index, succeeded)) = finished.pop_front() {
+            for dependent in &dependents[index] {
+                pending_dependencies[*dependent] = pending_dependencies[*dependent] - 1;
+
+                if !succeeded && failed_dependency[*dependent].is_none() {
+                    failed_dependency[*dependent] = Some(index);
+                }
+
+                if pending_dependencies[*dependent] == 0 {
+                    match failed_dependency[*dependent] {
+                        Some(dependency) => {
+                            skip_reasons[*dependent] =
+                                Some(SkipReason::DependencyFailed(dependency));
+                            finished.push_back((*dependent, false));
+                        }
+                        None => ready.push_back(*dependent),
+                    }
+                }
+            }
+        }
+    }
+
+    for index in 0..nodes_count {
+        if !started[index] && skip_reasons[index].is_none() {
+            skip_reasons[index] = if stopped {
+                Some(SkipReason::Stopped)
+            } else {
+                Some(SkipReason::CircularDependency)
+            };
+        }
+    }
+
+    skip_reasons
+}
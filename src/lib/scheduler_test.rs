@@ -0,0 +1,163 @@
+use super::*;
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+fn run_nodes(
+    dependencies: &Vec<Vec<usize>>,
+    jobs: usize,
+    fail_fast: bool,
+    failed: Vec<usize>,
+) -> (Vec<Option<SkipReason>>, Vec<usize>) {
+    let mut done_nodes = vec![];
+
+    let skip_reasons = run(
+        dependencies,
+        jobs,
+        fail_fast,
+        |index| {
+            let failed = failed.contains(&index);
+            Box::new(move || {
+                sleep(Duration::from_millis(10));
+                !failed
+            })
+        },
+        |index, output| {
+            done_nodes.push(index);
+            output.unwrap_or(false)
+        },
+    );
+
+    (skip_reasons, done_nodes)
+}
+
+#[test]
+fn run_empty() {
+    let (skip_reasons, done_nodes) = run_nodes(&vec![], 2, false, vec![]);
+
+    assert!(skip_reasons.is_empty());
+    assert!(done_nodes.is_empty());
+}
+
+#[test]
+fn run_dependencies_order() {
+    let dependencies = vec![vec![1], vec![], vec![0, 1], vec![]];
+
+    let (skip_reasons, done_nodes) = run_nodes(&dependencies, 4, false, vec![]);
+
+    let position = |index: usize| done_nodes.iter().position(|value| *value == index).unwrap();
+    assert_eq!(done_nodes.len(), 4);
+    assert!(position(1) < position(0));
+    assert!(position(0) < position(2));
+    assert_eq!(skip_reasons, vec![None, None, None, None]);
+}
+
+#[test]
+fn run_jobs_limit() {
+    let running = Arc::new(Mutex::new((0, 0)));
+
+    run(
+        &vec![vec![], vec![], vec![], vec![]],
+        2,
+        false,
+        |_| {
+            let running = running.clone();
+            Box::new(move || {
+                {
+                    let mut running = running.lock().unwrap();
+                    running.0 = running.0 + 1;
+                    running.1 = running.1.max(running.0);
+                }
+                sleep(Duration::from_millis(10));
+                running.lock().unwrap().0 -= 1;
+            })
+        },
+        |_, _| true,
+    );
+
+    assert_eq!(running.lock().unwrap().1, 2);
+}
+
+#[test]
+fn run_failed_dependency() {
+    let dependencies = vec![vec![], vec![0], vec![1], vec![]];
+
+    let (skip_reasons, done_nodes) = run_nodes(&dependencies, 2, false, vec![0]);
+
+    assert_eq!(done_nodes.len(), 2);
+    assert_eq!(
+        skip_reasons,
+        vec![
+            None,
+            Some(SkipReason::DependencyFailed(0)),
+            Some(SkipReason::DependencyFailed(1)),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn run_panic() {
+    let mut outputs = vec![];
+
+    let skip_reasons = run(
+        &vec![vec![], vec![0]],
+        2,
+        false,
+        |_| Box::new(|| panic!("test")),
+        |index, output: Option<()>| {
+            outputs.push((index, output.is_some()));
+            false
+        },
+    );
+
+    assert_eq!(outputs, vec![(0, false)]);
+    assert_eq!(
+        skip_reasons,
+        vec![None, Some(SkipReason::DependencyFailed(0))]
+    );
+}
+
+#[test]
+fn run_fail_fast() {
+    let dependencies = vec![vec![], vec![0], vec![1]];
+
+    let (skip_reasons, done_nodes) = run_nodes(&dependencies, 1, true, vec![1]);
+
+    assert_eq!(done_nodes, vec![0, 1]);
+    assert_eq!(
+        skip_reasons,
+        vec![None, None, Some(SkipReason::DependencyFailed(1))]
+    );
+}
+
+#[test]
+fn run_fail_fast_stopped() {
+    let dependencies = vec![vec![], vec![], vec![]];
+
+    let (skip_reasons, done_nodes) = run_nodes(&dependencies, 1, true, vec![0]);
+
+    assert_eq!(done_nodes, vec![0]);
+    assert_eq!(
+        skip_reasons,
+        vec![None, Some(SkipReason::Stopped), Some(SkipReason::Stopped)]
+    );
+}
+
+#[test]
+fn run_circular_dependencies() {
+    let dependencies = vec![vec![1], vec![0], vec![0], vec![]];
+
+    let (skip_reasons, done_nodes) = run_nodes(&dependencies, 2, false, vec![]);
+
+    assert_eq!(done_nodes, vec![3]);
+    assert_eq!(
+        skip_reasons,
+        vec![
+            Some(SkipReason::CircularDependency),
+            Some(SkipReason::CircularDependency),
+            Some(SkipReason::CircularDependency),
+            None,
+        ]
+    );
+}
@@ -10,6 +10,7 @@ mod mod_test;
 mod sdk;
 
 use crate::environment;
+use crate::error::CargoMakeError;
 use crate::types::{FlowInfo, FlowState};
 use duckscript::runner;
 use duckscript::types::command::Commands;
@@ -26,7 +27,7 @@ pub(crate) fn execute(
     flow_info: Option<&FlowInfo>,
     flow_state: Option<Rc<RefCell<FlowState>>>,
     validate: bool,
-) {
+) -> Result<i32, CargoMakeError> {
     let mut script_text = script.join("\n");
     script_text.insert_str(0, "exit_on_error true\n");
 
@@ -36,26 +37,44 @@ pub(crate) fn execute(
         Ok(_) => {
             let directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "");
 
-            match runner::run_script(&script_text, context) {
-                Ok(_) => (),
+            let result = match runner::run_script(&script_text, context) {
+                Ok(_) => Ok(0),
                 Err(error) => {
                     if validate {
-                        error!("Error while running duckscript: {}", error);
+                        Err(CargoMakeError::TaskFailed(format!(
+                            "Error while running duckscript: {}",
+                            error
+                        )))
+                    } else {
+                        Ok(1)
                     }
                 }
             };
 
             // revert to originl working directory
             if !directory.is_empty() {
-                environment::setup_cwd(Some(&directory));
+                environment::setup_cwd(Some(&directory))?;
             }
+
+            result
         }
         Err(error) => {
             if validate {
-                error!("Unable to load duckscript SDK: {}", error);
+                Err(CargoMakeError::TaskFailed(format!(
+                    "Unable to load duckscript SDK: {}",
+                    error
+                )))
+            } else {
+                Ok(1)
             }
         }
-    };
+    }
+}
+
+/// Waits for all async tasks started by the duckscripts running in this thread (via
+/// cm_run_task --async) and returns the first async task error (if any)
+pub(crate) fn wait_for_async_tasks() -> Result<(), CargoMakeError> {
+    sdk::wait_for_async_tasks()
 }
 
 pub(crate) fn create_common_context(cli_arguments: &Vec<String>) -> Context {
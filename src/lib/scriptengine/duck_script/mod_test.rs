@@ -11,7 +11,8 @@ fn execute_duckscript() {
         Some(&test::create_empty_flow_info()),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -22,7 +23,8 @@ fn execute_duckscript_error_no_validate() {
         Some(&test::create_empty_flow_info()),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         false,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -34,7 +36,8 @@ fn execute_duckscript_error_with_validate() {
         Some(&test::create_empty_flow_info()),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -45,7 +48,8 @@ fn execute_duckscript_cli_arguments() {
         Some(&test::create_empty_flow_info()),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -57,7 +61,8 @@ fn execute_duckscript_cli_arguments2() {
         Some(&test::create_empty_flow_info()),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -69,7 +74,8 @@ fn execute_duckscript_crash() {
         Some(&test::create_empty_flow_info()),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -81,7 +87,8 @@ fn execute_duckscript_crash2() {
         Some(&test::create_empty_flow_info()),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -113,7 +120,8 @@ fn cm_run_task_valid() {
         Some(&flow_info),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         true,
-    );
+    )
+    .unwrap();
 
     assert!(envmnt::is_equal("CM_RUN_TASK_VALID_TEST", "3"));
 }
@@ -134,5 +142,6 @@ fn cm_run_task_error() {
         Some(&flow_info),
         Some(Rc::new(RefCell::new(FlowState::new()))),
         true,
-    );
+    )
+    .unwrap();
 }
@@ -3,12 +3,20 @@
 //! Enables to run cargo-make tasks from within duckscript.
 //!
 
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
 use crate::runner;
 use crate::types::{FlowInfo, FlowState};
 use duckscript::types::command::{Command, CommandResult};
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::thread;
+use std::thread::JoinHandle;
+
+thread_local! {
+    /// Holds the async tasks started by the duckscripts running in this thread
+    static ASYNC_TASKS: RefCell<Vec<JoinHandle<Result<(), CargoMakeError>>>> = RefCell::new(vec![]);
+}
 
 #[derive(Clone)]
 pub(crate) struct CommandImpl {
@@ -41,19 +49,26 @@ impl Command for CommandImpl {
 
                 if async_run {
                     let cloned_flow_state = self.flow_state.borrow().clone();
+                    let task_context = task_env::get_current_context();
+
+                    let handle = thread::spawn(move || {
+                        let _task_context_guard = task_env::set_current_context(task_context);
 
-                    thread::spawn(move || {
                         runner::run_flow(
                             &sub_flow_info,
                             Rc::new(RefCell::new(cloned_flow_state)),
                             true,
-                        );
+                        )
                     });
+                    ASYNC_TASKS.with(|async_tasks| async_tasks.borrow_mut().push(handle));
+
+                    CommandResult::Continue(Some("true".to_string()))
                 } else {
-                    runner::run_flow(&sub_flow_info, self.flow_state.clone(), true);
+                    match runner::run_flow(&sub_flow_info, self.flow_state.clone(), true) {
+                        Ok(_) => CommandResult::Continue(Some("true".to_string())),
+                        Err(error) => CommandResult::Error(error.to_string()),
+                    }
                 }
-
-                CommandResult::Continue(Some("true".to_string()))
             } else {
                 CommandResult::Error(format!("Task: {} not found.", &arguments[0]).to_string())
             }
@@ -61,6 +76,28 @@ impl Command for CommandImpl {
     }
 }
 
+/// Waits for all async tasks started by the duckscripts running in this thread and returns the
+/// first async task error (if any)
+pub(crate) fn wait_for_async_tasks() -> Result<(), CargoMakeError> {
+    let handles = ASYNC_TASKS.with(|async_tasks| async_tasks.replace(vec![]));
+
+    let mut result = Ok(());
+    for handle in handles {
+        let task_result = match handle.join() {
+            Ok(task_result) => task_result,
+            Err(_) => Err(CargoMakeError::TaskFailed(
+                "Async task invocation failed.".to_string(),
+            )),
+        };
+
+        if result.is_ok() {
+            result = task_result;
+        }
+    }
+
+    result
+}
+
 pub(crate) fn create(flow_info: &FlowInfo, flow_state: Rc<RefCell<FlowState>>) -> Box<dyn Command> {
     Box::new(CommandImpl {
         flow_info: flow_info.clone(),
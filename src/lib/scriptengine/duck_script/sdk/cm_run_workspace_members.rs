@@ -0,0 +1,90 @@
+//! # cm_run_workspace_members
+//!
+//! Enables to run a cargo-make task on the workspace members from within duckscript.
+//!
+
+#[cfg(test)]
+#[path = "cm_run_workspace_members_test.rs"]
+mod cm_run_workspace_members_test;
+
+use crate::workspace;
+use crate::workspace::WorkspaceRunOptions;
+use duckscript::types::command::{Command, CommandResult};
+
+#[derive(Clone)]
+pub(crate) struct CommandImpl {}
+
+/// Parses the command arguments into the members list and the run options
+fn parse_arguments(arguments: &Vec<String>) -> Result<(Vec<String>, WorkspaceRunOptions), String> {
+    let mut members = vec![];
+    let mut options = WorkspaceRunOptions {
+        jobs: 1,
+        log_level: "info".to_string(),
+        profile: "development".to_string(),
+        task: "".to_string(),
+        arguments: vec![],
+    };
+
+    let mut index = 0;
+    while index < arguments.len() {
+        let argument = &arguments[index];
+
+        if argument == "--" {
+            if index + 1 < arguments.len() {
+                options.task = arguments[index + 1].clone();
+                options.arguments = arguments[index + 2..].to_vec();
+            }
+            break;
+        }
+
+        let value = match arguments.get(index + 1) {
+            Some(value) => value,
+            None => return Err(format!("Missing value for argument: {}", argument)),
+        };
+
+        match argument.as_str() {
+            "--jobs" => {
+                options.jobs = match value.parse() {
+                    Ok(jobs) => jobs,
+                    Err(_) => return Err(format!("Invalid jobs value: {}", value)),
+                }
+            }
+            "--loglevel" => options.log_level = value.clone(),
+            "--profile" => options.profile = value.clone(),
+            "--member" => members.push(value.clone()),
+            _ => return Err(format!("Unknown argument: {}", argument)),
+        };
+
+        index = index + 2;
+    }
+
+    if options.task.is_empty() {
+        Err("No task name provided.".to_string())
+    } else {
+        Ok((members, options))
+    }
+}
+
+impl Command for CommandImpl {
+    fn name(&self) -> String {
+        "cm_run_workspace_members".to_string()
+    }
+
+    fn clone_and_box(&self) -> Box<dyn Command> {
+        Box::new((*self).clone())
+    }
+
+    fn run(&self, arguments: Vec<String>) -> CommandResult {
+        match parse_arguments(&arguments) {
+            Ok((members, options)) => match workspace::run(&members, &options) {
+                Ok(_) => CommandResult::Continue(Some("true".to_string())),
+                Err(error) => CommandResult::Error(error),
+            },
+            Err(error) => CommandResult::Error(error),
+        }
+    }
+}
+
+pub(crate) fn create() -> Box<dyn Command> {
+    Box::new(CommandImpl {})
+}
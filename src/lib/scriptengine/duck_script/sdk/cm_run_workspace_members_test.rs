@@ -0,0 +1,69 @@
+use super::*;
+
+fn create_arguments(arguments: Vec<&str>) -> Vec<String> {
+    arguments
+        .into_iter()
+        .map(|argument| argument.to_string())
+        .collect()
+}
+
+#[test]
+fn parse_arguments_all() {
+    let (members, options) = parse_arguments(&create_arguments(vec![
+        "--jobs",
+        "4",
+        "--loglevel",
+        "verbose",
+        "--profile",
+        "ci",
+        "--member",
+        "member1",
+        "--member",
+        "dir1/member2",
+        "--",
+        "build",
+        "arg1",
+        "arg2",
+    ]))
+    .unwrap();
+
+    assert_eq!(members, vec!["member1", "dir1/member2"]);
+    assert_eq!(
+        options,
+        WorkspaceRunOptions {
+            jobs: 4,
+            log_level: "verbose".to_string(),
+            profile: "ci".to_string(),
+            task: "build".to_string(),
+            arguments: vec!["arg1".to_string(), "arg2".to_string()],
+        }
+    );
+}
+
+#[test]
+fn parse_arguments_no_task() {
+    let result = parse_arguments(&create_arguments(vec!["--member", "member1", "--"]));
+
+    assert_eq!(result.unwrap_err(), "No task name provided.");
+}
+
+#[test]
+fn parse_arguments_invalid_jobs() {
+    let result = parse_arguments(&create_arguments(vec!["--jobs", "x", "--", "build"]));
+
+    assert_eq!(result.unwrap_err(), "Invalid jobs value: x");
+}
+
+#[test]
+fn parse_arguments_missing_value() {
+    let result = parse_arguments(&create_arguments(vec!["--member"]));
+
+    assert_eq!(result.unwrap_err(), "Missing value for argument: --member");
+}
+
+#[test]
+fn parse_arguments_unknown() {
+    let result = parse_arguments(&create_arguments(vec!["--bad", "1", "--", "build"]));
+
+    assert_eq!(result.unwrap_err(), "Unknown argument: --bad");
+}
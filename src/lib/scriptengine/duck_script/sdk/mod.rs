@@ -4,7 +4,9 @@
 //!
 
 mod cm_run_task;
+mod cm_run_workspace_members;
 
+use crate::error::CargoMakeError;
 use crate::types::{FlowInfo, FlowState};
 use duckscript::types::command::Commands;
 use duckscript::types::error::ScriptError;
@@ -17,9 +19,16 @@ pub(crate) fn load(
     flow_info_option: Option<&FlowInfo>,
     flow_state_option: Option<Rc<RefCell<FlowState>>>,
 ) -> Result<(), ScriptError> {
+    commands.set(cm_run_workspace_members::create())?;
+
     if let (Some(flow_info), Some(flow_state)) = (flow_info_option, flow_state_option) {
         commands.set(cm_run_task::create(flow_info, flow_state))?;
     }
 
     Ok(())
 }
+
+/// Waits for all async tasks started by the duckscripts running in this thread
+pub(crate) fn wait_for_async_tasks() -> Result<(), CargoMakeError> {
+    cm_run_task::wait_for_async_tasks()
+}
@@ -10,7 +10,8 @@ fn execute_shell() {
         None,
         &vec![],
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -23,7 +24,8 @@ fn execute_shell_error() {
         None,
         &vec![],
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -35,7 +37,8 @@ fn execute_shell_error_no_validate() {
         None,
         &vec![],
         false,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -47,7 +50,8 @@ fn execute_shell_empty_arguments() {
         Some(vec![]),
         &vec![],
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -60,7 +64,8 @@ fn execute_shell_cli_arguments() {
         Some(vec![]),
         &vec!["0".to_string()],
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -74,5 +79,6 @@ fn execute_shell_cli_arguments_error() {
         Some(vec![]),
         &vec!["1".to_string()],
         true,
-    );
+    )
+    .unwrap();
 }
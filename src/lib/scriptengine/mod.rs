@@ -16,6 +16,8 @@ mod shell_to_batch;
 mod mod_test;
 
 use crate::environment;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
 use crate::io;
 use crate::types::{FlowInfo, FlowState, ScriptValue, Task};
 use std::cell::RefCell;
@@ -41,10 +43,10 @@ pub(crate) enum EngineType {
     Unsupported,
 }
 
-pub(crate) fn get_script_text(script: &ScriptValue) -> Vec<String> {
+pub(crate) fn get_script_text(script: &ScriptValue) -> Result<Vec<String>, CargoMakeError> {
     match script {
-        ScriptValue::SingleLine(text) => vec![text.clone()],
-        ScriptValue::Text(text) => text.clone(),
+        ScriptValue::SingleLine(text) => Ok(vec![text.clone()]),
+        ScriptValue::Text(text) => Ok(text.clone()),
         ScriptValue::File(info) => {
             let mut file_path_string = String::new();
             if !info.absolute_path.unwrap_or(false) {
@@ -58,7 +60,7 @@ pub(crate) fn get_script_text(script: &ScriptValue) -> Vec<String> {
             let mut file_path = PathBuf::new();
             file_path.push(expanded_value);
 
-            let script_text = io::read_text_file(&file_path);
+            let script_text = io::read_text_file(&file_path)?;
             let lines: Vec<&str> = script_text.split('\n').collect();
 
             let mut script_lines: Vec<String> = vec![];
@@ -67,7 +69,7 @@ pub(crate) fn get_script_text(script: &ScriptValue) -> Vec<String> {
                 script_lines.push(line.to_string());
             }
 
-            script_lines
+            Ok(script_lines)
         }
         ScriptValue::Sections(sections) => {
             let mut script_lines = vec![];
@@ -82,7 +84,7 @@ pub(crate) fn get_script_text(script: &ScriptValue) -> Vec<String> {
                 script_lines.push(text.to_string());
             }
 
-            script_lines
+            Ok(script_lines)
         }
     }
 }
@@ -130,7 +132,8 @@ pub(crate) fn get_engine_type(
         }
         None => {
             // if no runner specified, try to extract it from script content
-            let script_text = get_script_text(&script);
+            // (script file errors are returned once the script is invoked)
+            let script_text = get_script_text(&script).unwrap_or_default();
 
             let shebang = shebang_script::get_shebang(&script_text);
             match shebang.runner {
@@ -155,16 +158,23 @@ pub(crate) fn get_engine_type(
     }
 }
 
+/// Invokes the task script and returns the script exit code.<br>
+/// None is returned in case the task has no script or the script engine is not supported.
 pub(crate) fn invoke(
     task: &Task,
     flow_info: &FlowInfo,
     flow_state: Rc<RefCell<FlowState>>,
-) -> bool {
+) -> Result<Option<i32>, CargoMakeError> {
     match task.script {
         Some(ref script) => {
             let validate = !task.should_ignore_errors();
 
-            invoke_script_in_flow_context(
+            let cli_arguments = match flow_info.cli_arguments {
+                Some(ref args) => args.clone(),
+                None => vec![],
+            };
+
+            invoke_script(
                 script,
                 task.script_runner.clone(),
                 task.script_runner_args.clone(),
@@ -172,9 +182,10 @@ pub(crate) fn invoke(
                 validate,
                 Some(flow_info),
                 Some(flow_state),
+                &cli_arguments,
             )
         }
-        None => false,
+        None => Ok(None),
     }
 }
 
@@ -186,7 +197,7 @@ pub(crate) fn invoke_script_in_flow_context(
     validate: bool,
     flow_info: Option<&FlowInfo>,
     flow_state: Option<Rc<RefCell<FlowState>>>,
-) -> bool {
+) -> Result<bool, CargoMakeError> {
     let cli_arguments = match flow_info {
         Some(info) => match info.cli_arguments {
             Some(ref args) => args.clone(),
@@ -205,6 +216,7 @@ pub(crate) fn invoke_script_in_flow_context(
         flow_state,
         &cli_arguments,
     )
+    .map(|exit_code| exit_code.is_some())
 }
 
 pub(crate) fn invoke_script_pre_flow(
@@ -214,7 +226,7 @@ pub(crate) fn invoke_script_pre_flow(
     script_extension: Option<String>,
     validate: bool,
     cli_arguments: &Vec<String>,
-) -> bool {
+) -> Result<bool, CargoMakeError> {
     invoke_script(
         script,
         script_runner,
@@ -225,6 +237,7 @@ pub(crate) fn invoke_script_pre_flow(
         None,
         cli_arguments,
     )
+    .map(|exit_code| exit_code.is_some())
 }
 
 fn invoke_script(
@@ -236,55 +249,66 @@ fn invoke_script(
     flow_info: Option<&FlowInfo>,
     flow_state: Option<Rc<RefCell<FlowState>>>,
     cli_arguments: &Vec<String>,
-) -> bool {
+) -> Result<Option<i32>, CargoMakeError> {
     let engine_type = get_engine_type(script, &script_runner, &script_extension);
 
     match engine_type {
         EngineType::OS => {
-            let script_text = get_script_text(script);
-            os_script::execute(&script_text, script_runner, cli_arguments, validate);
+            let script_text = get_script_text(script)?;
+            let exit_code =
+                os_script::execute(&script_text, script_runner, cli_arguments, validate)?;
 
-            true
+            Ok(Some(exit_code))
         }
         EngineType::Duckscript => {
-            let script_text = get_script_text(script);
-            duck_script::execute(&script_text, cli_arguments, flow_info, flow_state, validate);
+            let script_text = get_script_text(script)?;
+            // duckscript runs within the cargo-make process and reads the process env
+            let result = task_env::apply_current(|| {
+                duck_script::execute(&script_text, cli_arguments, flow_info, flow_state, validate)
+            });
+
+            // async tasks wait for the env lock, so they can only be awaited once it is released
+            if !task_env::is_env_locked() {
+                duck_script::wait_for_async_tasks()?;
+            }
+            let exit_code = result?;
 
-            true
+            Ok(Some(exit_code))
         }
         EngineType::Rust => {
-            let script_text = get_script_text(script);
-            rsscript::execute(&script_text, cli_arguments, validate);
+            let script_text = get_script_text(script)?;
+            let exit_code = rsscript::execute(&script_text, cli_arguments, validate)?;
 
-            true
+            Ok(Some(exit_code))
         }
         EngineType::Shell2Batch => {
-            let script_text = get_script_text(script);
-            shell_to_batch::execute(&script_text, cli_arguments, validate);
+            let script_text = get_script_text(script)?;
+            let exit_code = shell_to_batch::execute(&script_text, cli_arguments, validate)?;
 
-            true
+            Ok(Some(exit_code))
         }
         EngineType::Generic => {
-            let script_text = get_script_text(script);
+            let script_text = get_script_text(script)?;
             let extension = script_extension.clone().unwrap();
-            generic_script::execute(
+            let exit_code = generic_script::execute(
                 &script_text,
                 script_runner.unwrap(),
                 extension,
                 script_runner_args.clone(),
                 cli_arguments,
                 validate,
-            );
+            )?;
 
-            true
+            Ok(Some(exit_code))
         }
         EngineType::Shebang => {
-            let script_text = get_script_text(script);
+            let script_text = get_script_text(script)?;
             let extension = script_extension.clone();
-            shebang_script::execute(&script_text, &extension, cli_arguments, validate);
+            let exit_code =
+                shebang_script::execute(&script_text, &extension, cli_arguments, validate)?;
 
-            true
+            Ok(Some(exit_code))
         }
-        EngineType::Unsupported => false,
+        EngineType::Unsupported => Ok(None),
     }
 }
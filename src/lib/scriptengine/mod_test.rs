@@ -4,7 +4,9 @@ use crate::types::{FileScriptValue, ScriptSections};
 
 #[test]
 fn get_script_text_single_line() {
-    let output = get_script_text(&ScriptValue::SingleLine("test".to_string())).join("\n");
+    let output = get_script_text(&ScriptValue::SingleLine("test".to_string()))
+        .unwrap()
+        .join("\n");
 
     assert_eq!(output, "test");
 }
@@ -15,6 +17,7 @@ fn get_script_text_vector() {
         "line 1".to_string(),
         "line 2".to_string(),
     ]))
+    .unwrap()
     .join("\n");
 
     assert_eq!(output, "line 1\nline 2");
@@ -26,7 +29,9 @@ fn get_script_text_file() {
         file: "src/lib/test/test_files/text_file.txt".to_string(),
         absolute_path: None,
     };
-    let output = get_script_text(&ScriptValue::File(file_info)).join("\n");
+    let output = get_script_text(&ScriptValue::File(file_info))
+        .unwrap()
+        .join("\n");
 
     assert_eq!(output, "text 1\ntext 2");
 }
@@ -37,7 +42,9 @@ fn get_script_text_file_relative() {
         file: "src/lib/test/test_files/text_file.txt".to_string(),
         absolute_path: Some(false),
     };
-    let output = get_script_text(&ScriptValue::File(file_info)).join("\n");
+    let output = get_script_text(&ScriptValue::File(file_info))
+        .unwrap()
+        .join("\n");
 
     assert_eq!(output, "text 1\ntext 2");
 }
@@ -48,7 +55,9 @@ fn get_script_text_file_absolute() {
         file: "${CARGO_MAKE_WORKING_DIRECTORY}/src/lib/test/test_files/text_file.txt".to_string(),
         absolute_path: Some(true),
     };
-    let output = get_script_text(&ScriptValue::File(file_info)).join("\n");
+    let output = get_script_text(&ScriptValue::File(file_info))
+        .unwrap()
+        .join("\n");
 
     assert_eq!(output, "text 1\ntext 2");
 }
@@ -60,6 +69,7 @@ fn get_script_text_script_content_sections() {
         main: Some("main".to_string()),
         post: Some("post".to_string()),
     }))
+    .unwrap()
     .join("\n");
 
     assert_eq!(output, "pre\nmain\npost");
@@ -71,7 +81,8 @@ fn get_script_text_script_content_sections_empty() {
         pre: None,
         main: None,
         post: None,
-    }));
+    }))
+    .unwrap();
 
     assert!(output.is_empty());
 }
@@ -187,9 +198,10 @@ fn invoke_no_runner() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
-    assert!(output);
+    assert_eq!(output, Some(0));
 }
 
 #[test]
@@ -200,9 +212,10 @@ fn invoke_no_script_no_runner() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
-    assert!(!output);
+    assert!(output.is_none());
 }
 
 #[test]
@@ -214,9 +227,10 @@ fn invoke_no_script() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
-    assert!(!output);
+    assert!(output.is_none());
 }
 
 #[test]
@@ -229,9 +243,10 @@ fn invoke_os_runner() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
-    assert!(output);
+    assert_eq!(output, Some(0));
 }
 
 #[test]
@@ -245,9 +260,10 @@ fn invoke_duckscript_runner() {
             &task,
             &test::create_empty_flow_info(),
             Rc::new(RefCell::new(FlowState::new())),
-        );
+        )
+        .unwrap();
 
-        assert!(output);
+        assert_eq!(output, Some(0));
     }
 }
 
@@ -263,9 +279,10 @@ fn invoke_duckscript_runner_error() {
             &task,
             &test::create_empty_flow_info(),
             Rc::new(RefCell::new(FlowState::new())),
-        );
+        )
+        .unwrap();
 
-        assert!(output);
+        assert_eq!(output, Some(0));
     }
 }
 
@@ -282,9 +299,10 @@ fn invoke_rust_runner() {
             &task,
             &test::create_empty_flow_info(),
             Rc::new(RefCell::new(FlowState::new())),
-        );
+        )
+        .unwrap();
 
-        assert!(output);
+        assert_eq!(output, Some(0));
     }
 }
 
@@ -302,9 +320,10 @@ fn invoke_rust_runner_error() {
             &task,
             &test::create_empty_flow_info(),
             Rc::new(RefCell::new(FlowState::new())),
-        );
+        )
+        .unwrap();
 
-        assert!(output);
+        assert_eq!(output, Some(0));
     }
 }
 
@@ -318,9 +337,10 @@ fn invoke_shell_to_batch_runner() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
-    assert!(output);
+    assert_eq!(output, Some(0));
 }
 
 #[test]
@@ -334,9 +354,10 @@ fn invoke_shell_to_batch_runner_error() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
-    assert!(output);
+    assert_eq!(output, Some(0));
 }
 
 #[test]
@@ -350,9 +371,10 @@ fn invoke_generic_runner() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
-    assert!(output);
+    assert_eq!(output, Some(0));
 }
 
 #[test]
@@ -367,7 +389,8 @@ fn invoke_generic_runner_error() {
         &task,
         &test::create_empty_flow_info(),
         Rc::new(RefCell::new(FlowState::new())),
-    );
+    )
+    .unwrap();
 
-    assert!(output);
+    assert_eq!(output, Some(0));
 }
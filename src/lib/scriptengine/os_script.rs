@@ -8,12 +8,13 @@
 mod os_script_test;
 
 use crate::command;
+use crate::error::CargoMakeError;
 
 pub(crate) fn execute(
     script_text: &Vec<String>,
     runner: Option<String>,
     cli_arguments: &Vec<String>,
     validate: bool,
-) {
-    command::run_script_get_exit_code(&script_text, runner, &cli_arguments, validate);
+) -> Result<i32, CargoMakeError> {
+    command::run_script_get_exit_code(&script_text, runner, &cli_arguments, validate)
 }
@@ -8,6 +8,8 @@
 mod rsscript_test;
 
 use crate::command;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
 use crate::installer::{cargo_plugin_installer, crate_installer};
 use crate::io::delete_file;
 use crate::scriptengine::script_utils::create_script_file;
@@ -21,7 +23,8 @@ enum ScriptRunner {
 }
 
 fn get_script_runner() -> ScriptRunner {
-    let provider = envmnt::get_or("CARGO_MAKE_RUST_SCRIPT_PROVIDER", "rust-script");
+    let provider =
+        task_env::get_var("CARGO_MAKE_RUST_SCRIPT_PROVIDER").unwrap_or("rust-script".to_string());
 
     match provider.as_str() {
         "rust-script" => ScriptRunner::RustScript,
@@ -31,7 +34,7 @@ fn get_script_runner() -> ScriptRunner {
     }
 }
 
-fn install_crate(provider: &ScriptRunner) {
+fn install_crate(provider: &ScriptRunner) -> Result<(), CargoMakeError> {
     // install dependencies
     match provider {
         ScriptRunner::RustScript => {
@@ -50,7 +53,7 @@ fn install_crate(provider: &ScriptRunner) {
             // due to fornwall/rust-script/issues/42
             let rust_script_install_args = vec!["--version".to_string(), "0.7.0".to_string()];
 
-            crate_installer::install(&None, &info, &Some(rust_script_install_args), false);
+            crate_installer::install(&None, &info, &Some(rust_script_install_args), false)
         }
         ScriptRunner::CargoScript => cargo_plugin_installer::install_crate(
             &None,
@@ -70,14 +73,14 @@ fn install_crate(provider: &ScriptRunner) {
             &None,
             &None,
         ),
-    };
+    }
 }
 
-fn create_rust_file(rust_script: &Vec<String>) -> String {
+fn create_rust_file(rust_script: &Vec<String>) -> Result<String, CargoMakeError> {
     create_script_file(rust_script, "rs")
 }
 
-fn run_file(file: &str, cli_arguments: &Vec<String>, provider: &ScriptRunner) -> bool {
+fn run_file(file: &str, cli_arguments: &Vec<String>, provider: &ScriptRunner) -> i32 {
     let (use_cargo, command) = match provider {
         ScriptRunner::RustScript => (false, "rust-script"),
         ScriptRunner::CargoScript => (true, "script"),
@@ -96,24 +99,33 @@ fn run_file(file: &str, cli_arguments: &Vec<String>, provider: &ScriptRunner) ->
         command::run_command("cargo", &Some(args), false)
     } else {
         command::run_command(command, &Some(args), false)
-    };
+    }
+    .unwrap_or(-1);
     debug!("Executed rust code, exit code: {}", exit_code);
 
-    exit_code == 0
+    exit_code
 }
 
-pub(crate) fn execute(rust_script: &Vec<String>, cli_arguments: &Vec<String>, validate: bool) {
+pub(crate) fn execute(
+    rust_script: &Vec<String>,
+    cli_arguments: &Vec<String>,
+    validate: bool,
+) -> Result<i32, CargoMakeError> {
     let provider = get_script_runner();
 
-    install_crate(&provider);
+    install_crate(&provider)?;
 
-    let file = create_rust_file(rust_script);
+    let file = create_rust_file(rust_script)?;
 
-    let valid = run_file(&file, &cli_arguments, &provider);
+    let exit_code = run_file(&file, &cli_arguments, &provider);
 
     delete_file(&file);
 
-    if validate && !valid {
-        error!("Unable to execute rust code.");
+    if validate && exit_code != 0 {
+        Err(CargoMakeError::TaskFailed(
+            "Unable to execute rust code.".to_string(),
+        ))
+    } else {
+        Ok(exit_code)
     }
 }
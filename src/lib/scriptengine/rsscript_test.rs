@@ -60,7 +60,8 @@ fn execute_default_valid() {
             &vec!["fn main() {println!(\"test\");}".to_string()],
             &vec![],
             true,
-        );
+        )
+        .unwrap();
     }
 }
 
@@ -75,7 +76,8 @@ fn execute_default_not_compile() {
             &vec!["fn main() {donotcompile();}".to_string()],
             &vec![],
             true,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -92,7 +94,8 @@ fn execute_default_runtime_panic() {
             &vec!["fn main() {panic!(\"error\");}".to_string()],
             &vec![],
             true,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -108,7 +111,8 @@ fn execute_default_runtime_panic_no_validate() {
             &vec!["fn main() {panic!(\"error\");}".to_string()],
             &vec![],
             false,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -124,7 +128,8 @@ fn execute_rust_script_valid() {
             &vec!["fn main() {println!(\"test\");}".to_string()],
             &vec![],
             true,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -141,7 +146,8 @@ fn execute_rust_script_not_compile() {
             &vec!["fn main() {donotcompile();}".to_string()],
             &vec![],
             true,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -158,7 +164,8 @@ fn execute_rust_script_runtime_panic() {
             &vec!["fn main() {panic!(\"error\");}".to_string()],
             &vec![],
             true,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -174,7 +181,8 @@ fn execute_rust_script_runtime_panic_no_validate() {
             &vec!["fn main() {panic!(\"error\");}".to_string()],
             &vec![],
             false,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -191,7 +199,8 @@ fn execute_cargo_play_not_compile() {
             &vec!["fn main() {donotcompile();}".to_string()],
             &vec![],
             true,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -208,7 +217,8 @@ fn execute_cargo_play_runtime_panic() {
             &vec!["fn main() {panic!(\"error\");}".to_string()],
             &vec![],
             true,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
@@ -224,7 +234,8 @@ fn execute_cargo_play_runtime_panic_no_validate() {
             &vec!["fn main() {panic!(\"error\");}".to_string()],
             &vec![],
             false,
-        );
+        )
+        .unwrap();
 
         envmnt::remove("CARGO_MAKE_RUST_SCRIPT_PROVIDER");
     }
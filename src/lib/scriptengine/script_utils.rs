@@ -7,9 +7,13 @@
 #[path = "script_utils_test.rs"]
 mod script_utils_test;
 
+use crate::error::CargoMakeError;
 use crate::io;
 
-pub(crate) fn create_script_file(script_text: &Vec<String>, extension: &str) -> String {
+pub(crate) fn create_script_file(
+    script_text: &Vec<String>,
+    extension: &str,
+) -> Result<String, CargoMakeError> {
     let text = script_text.join("\n");
 
     io::create_text_file(&text, &extension)
@@ -4,7 +4,8 @@ use fsio;
 
 #[test]
 fn create_script_file_text() {
-    let file = create_script_file(&vec!["test".to_string(), "end".to_string()], ".testfile");
+    let file =
+        create_script_file(&vec!["test".to_string(), "end".to_string()], ".testfile").unwrap();
     assert!(file.ends_with(".testfile"));
 
     let text = fsio::file::read_text_file(&file).unwrap();
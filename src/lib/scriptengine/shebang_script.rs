@@ -7,6 +7,7 @@
 #[path = "shebang_script_test.rs"]
 mod shebang_script_test;
 
+use crate::error::CargoMakeError;
 use crate::scriptengine::generic_script;
 
 #[derive(Debug, Clone)]
@@ -76,7 +77,7 @@ pub(crate) fn execute(
     extension: &Option<String>,
     cli_arguments: &Vec<String>,
     validate: bool,
-) {
+) -> Result<i32, CargoMakeError> {
     let shebang = get_shebang(&script_text);
 
     match shebang.runner {
@@ -93,12 +94,16 @@ pub(crate) fn execute(
                 shebang.arguments,
                 &cli_arguments,
                 validate,
-            );
+            )
         }
         None => {
             if validate {
-                error!("Unable to execute script using shebang.");
+                Err(CargoMakeError::TaskFailed(
+                    "Unable to execute script using shebang.".to_string(),
+                ))
+            } else {
+                Ok(1)
             }
         }
-    };
+    }
 }
@@ -82,7 +82,8 @@ fn execute_sh() {
         &None,
         &vec!["0".to_string()],
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
@@ -94,5 +95,6 @@ fn execute_sh_error() {
         &None,
         &vec!["1".to_string()],
         true,
-    );
+    )
+    .unwrap();
 }
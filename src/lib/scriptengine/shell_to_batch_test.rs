@@ -6,16 +6,17 @@ fn execute_valid() {
         &vec!["echo test".to_string()],
         &vec!["test".to_string()],
         true,
-    );
+    )
+    .unwrap();
 }
 
 #[test]
 #[should_panic]
 fn execute_error() {
-    execute(&vec!["exit 1".to_string()], &vec![], true);
+    execute(&vec!["exit 1".to_string()], &vec![], true).unwrap();
 }
 
 #[test]
 fn execute_error_no_validate() {
-    execute(&vec!["exit 1".to_string()], &vec![], false);
+    execute(&vec!["exit 1".to_string()], &vec![], false).unwrap();
 }
@@ -1,3 +1,4 @@
+use crate::git;
 use crate::logger;
 use crate::logger::LoggerOptions;
 use crate::types::{Config, ConfigSection, CrateInfo, EnvInfo, FlowInfo, ToolchainSpecifier};
@@ -9,12 +10,13 @@ use indexmap::IndexMap;
 use rust_info;
 use rust_info::types::{RustChannel, RustInfo};
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn on_test_startup() {
     logger::init(&LoggerOptions {
         level: "error".to_string(),
         color: true,
+        to_stderr: false,
     });
 }
 
@@ -91,6 +93,40 @@ pub(crate) fn get_temp_test_directory() -> PathBuf {
     directory
 }
 
+/// Creates a new git repository (in a clean temp directory) with a single committed file
+pub(crate) fn create_git_repo(name: &str) -> PathBuf {
+    on_test_startup();
+
+    let path = env::current_dir().unwrap();
+    let directory = path.join("target/_cargo_make_temp/git").join(name);
+
+    if directory.exists() {
+        fsio::directory::delete(&directory).unwrap();
+    }
+    fsio::file::write_text_file(&directory.join("README.md"), "test").unwrap();
+
+    run_git_commands(
+        &directory,
+        vec![
+            vec!["init", "-q"],
+            vec!["add", "-A"],
+            vec!["commit", "-q", "-m", "init"],
+        ],
+    );
+
+    directory
+}
+
+/// Runs the git commands (with a test user) in the given directory, panicking on any failure
+pub(crate) fn run_git_commands(directory: &Path, commands: Vec<Vec<&str>>) {
+    for command_args in commands {
+        let mut args = vec!["-c", "user.name=test", "-c", "user.email=test@test.com"];
+        args.extend(command_args);
+
+        git::run_git(&directory, &args).unwrap();
+    }
+}
+
 pub(crate) fn is_not_rust_stable() -> bool {
     on_test_startup();
 
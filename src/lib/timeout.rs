@@ -0,0 +1,164 @@
+//! # timeout
+//!
+//! Parses the task timeout values and holds the timeout of the task currently running in this
+//! thread, so any process started by the task can be killed once the timeout is exceeded.
+//!
+
+#[cfg(test)]
+#[path = "timeout_test.rs"]
+mod timeout_test;
+
+use crate::types::{ConfigSection, Task};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+struct TaskTimeout {
+    /// The task name
+    task: String,
+    /// The timeout value as defined in the makefile
+    value: String,
+    /// The time in which the timeout is exceeded
+    deadline: Instant,
+    /// True once the timeout was exceeded
+    timed_out: bool,
+}
+
+thread_local! {
+    static CURRENT_TIMEOUT: RefCell<Option<TaskTimeout>> = RefCell::new(None);
+}
+
+/// Parses the duration value (for example 500ms, 30s, 10m, 1h or 1h30m).<br>
+/// Values without units are in seconds.
+pub(crate) fn parse(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    let mut duration = Duration::from_millis(0);
+    let mut number = String::new();
+    let mut unit = String::new();
+    let mut parts = vec![];
+
+    for character in value.chars() {
+        if character.is_ascii_digit() {
+            if !unit.is_empty() {
+                parts.push((number.clone(), unit.clone()));
+                number.clear();
+                unit.clear();
+            }
+            number.push(character);
+        } else if character.is_ascii_alphabetic() && !number.is_empty() {
+            unit.push(character);
+        } else {
+            return None;
+        }
+    }
+    if number.is_empty() {
+        return None;
+    }
+    parts.push((number, unit));
+
+    for (number, unit) in parts {
+        let number = number.parse::<u64>().ok()?;
+
+        duration += match unit.as_str() {
+            "ms" => Duration::from_millis(number),
+            "" | "s" => Duration::from_secs(number),
+            "m" => Duration::from_secs(number * 60),
+            "h" => Duration::from_secs(number * 60 * 60),
+            _ => return None,
+        };
+    }
+
+    Some(duration)
+}
+
+/// Returns the task timeout value (defaults to the config default task timeout)
+pub(crate) fn get_value(task: &Task, config: &ConfigSection) -> Option<String> {
+    match task.timeout {
+        Some(ref value) => Some(value.to_string()),
+        None => config.default_task_timeout.clone(),
+    }
+}
+
+/// Sets the timeout of the task which is about to run in the current thread.<br>
+/// A zero timeout means no timeout.<br>
+/// In case of an invalid timeout value, no timeout is set and an error is returned.
+pub(crate) fn start(task: &str, value: &Option<String>) -> Result<(), String> {
+    let task_timeout = match value {
+        Some(value) => match parse(value) {
+            Some(duration) => {
+                if duration.as_millis() > 0 {
+                    Some(TaskTimeout {
+                        task: task.to_string(),
+                        value: value.trim().to_string(),
+                        deadline: Instant::now() + duration,
+                        timed_out: false,
+                    })
+                } else {
+                    None
+                }
+            }
+            None => {
+                CURRENT_TIMEOUT.with(|current| *current.borrow_mut() = None);
+
+                return Err(format!(
+                    "Invalid timeout value: {} for task: {}",
+                    value, task
+                ));
+            }
+        },
+        None => None,
+    };
+
+    CURRENT_TIMEOUT.with(|current| *current.borrow_mut() = task_timeout);
+
+    Ok(())
+}
+
+/// Clears the current task timeout and returns the timeout message in case the timeout was
+/// exceeded and not yet reported.
+pub(crate) fn end() -> Option<String> {
+    let message = take_timed_out_message();
+
+    CURRENT_TIMEOUT.with(|current| *current.borrow_mut() = None);
+
+    message
+}
+
+/// Returns the time left until the current task timeout is exceeded (None if no timeout is set)
+pub(crate) fn get_remaining() -> Option<Duration> {
+    CURRENT_TIMEOUT.with(|current| {
+        current.borrow().as_ref().map(|task_timeout| {
+            task_timeout
+                .deadline
+                .saturating_duration_since(Instant::now())
+        })
+    })
+}
+
+/// Marks the current task as timed out
+pub(crate) fn set_timed_out() {
+    CURRENT_TIMEOUT.with(|current| {
+        if let Some(ref mut task_timeout) = *current.borrow_mut() {
+            task_timeout.timed_out = true;
+        }
+    });
+}
+
+/// Returns the timeout message if the current task timed out (only returned once)
+pub(crate) fn take_timed_out_message() -> Option<String> {
+    CURRENT_TIMEOUT.with(|current| match *current.borrow_mut() {
+        Some(ref mut task_timeout) if task_timeout.timed_out => {
+            task_timeout.timed_out = false;
+
+            Some(format!(
+                "Task {} timed out after {}",
+                &task_timeout.task, &task_timeout.value
+            ))
+        }
+        _ => None,
+    })
+}
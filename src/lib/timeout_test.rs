@@ -0,0 +1,118 @@
+use super::*;
+
+#[test]
+fn parse_empty() {
+    assert!(parse("").is_none());
+}
+
+#[test]
+fn parse_seconds_without_unit() {
+    assert_eq!(parse("90").unwrap(), Duration::from_secs(90));
+}
+
+#[test]
+fn parse_units() {
+    assert_eq!(parse("500ms").unwrap(), Duration::from_millis(500));
+    assert_eq!(parse("30s").unwrap(), Duration::from_secs(30));
+    assert_eq!(parse("10m").unwrap(), Duration::from_secs(600));
+    assert_eq!(parse(" 2h ").unwrap(), Duration::from_secs(7200));
+}
+
+#[test]
+fn parse_combined() {
+    assert_eq!(parse("1h30m10s").unwrap(), Duration::from_secs(5410));
+}
+
+#[test]
+fn parse_invalid() {
+    assert!(parse("10x").is_none());
+    assert!(parse("m").is_none());
+    assert!(parse("10 m").is_none());
+    assert!(parse("-10s").is_none());
+}
+
+#[test]
+fn get_value_from_task() {
+    let mut task = Task::new();
+    task.timeout = Some("10s".to_string());
+    let mut config = ConfigSection::new();
+    config.default_task_timeout = Some("1m".to_string());
+
+    let value = get_value(&task, &config);
+
+    assert_eq!(value.unwrap(), "10s");
+}
+
+#[test]
+fn get_value_from_config() {
+    let task = Task::new();
+    let mut config = ConfigSection::new();
+    config.default_task_timeout = Some("1m".to_string());
+
+    let value = get_value(&task, &config);
+
+    assert_eq!(value.unwrap(), "1m");
+}
+
+#[test]
+fn get_value_none() {
+    let value = get_value(&Task::new(), &ConfigSection::new());
+
+    assert!(value.is_none());
+}
+
+#[test]
+fn start_and_end() {
+    start("test", &Some("10m".to_string())).unwrap();
+
+    let remaining = get_remaining().unwrap();
+    assert!(remaining > Duration::from_secs(590));
+    assert!(remaining <= Duration::from_secs(600));
+
+    assert!(end().is_none());
+    assert!(get_remaining().is_none());
+}
+
+#[test]
+fn start_without_timeout() {
+    start("test", &None).unwrap();
+
+    assert!(get_remaining().is_none());
+}
+
+#[test]
+fn start_zero_timeout() {
+    start("test", &Some("0".to_string())).unwrap();
+
+    assert!(get_remaining().is_none());
+}
+
+#[test]
+fn start_invalid_timeout() {
+    start("test", &Some("10m".to_string())).unwrap();
+
+    let result = start("test", &Some("bad".to_string()));
+
+    assert_eq!(
+        result.unwrap_err(),
+        "Invalid timeout value: bad for task: test"
+    );
+    assert!(get_remaining().is_none());
+}
+
+#[test]
+fn timed_out_message() {
+    start("test", &Some("10m".to_string())).unwrap();
+    assert!(take_timed_out_message().is_none());
+
+    set_timed_out();
+
+    assert_eq!(
+        take_timed_out_message().unwrap(),
+        "Task test timed out after 10m"
+    );
+    assert!(take_timed_out_message().is_none());
+
+    set_timed_out();
+    assert_eq!(end().unwrap(), "Task test timed out after 10m");
+}
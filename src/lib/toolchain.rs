@@ -10,6 +10,7 @@ mod toolchain_test;
 use cargo_metadata::Version;
 use semver::Prerelease;
 
+use crate::error::CargoMakeError;
 use crate::types::{CommandSpec, ToolchainSpecifier};
 use std::process::{Command, Stdio};
 
@@ -17,9 +18,19 @@ pub(crate) fn wrap_command(
     toolchain: &ToolchainSpecifier,
     command: &str,
     args: &Option<Vec<String>>,
-) -> CommandSpec {
-    check_toolchain(toolchain);
+) -> Result<CommandSpec, CargoMakeError> {
+    check_toolchain(toolchain)?;
+
+    Ok(create_wrapped_command(toolchain, command, args))
+}
 
+/// Creates the rustup command which runs the given command with the toolchain, without
+/// validating the toolchain is installed
+pub(crate) fn create_wrapped_command(
+    toolchain: &ToolchainSpecifier,
+    command: &str,
+    args: &Option<Vec<String>>,
+) -> CommandSpec {
     let mut rustup_args = vec![
         "run".to_string(),
         toolchain.channel().to_string(),
@@ -50,7 +61,9 @@ fn get_specified_min_version(toolchain: &ToolchainSpecifier) -> Option<Version>
     spec_min_version.ok()
 }
 
-fn check_toolchain(toolchain: &ToolchainSpecifier) {
+/// Validates the toolchain is installed and satisfies the min version (if defined), returning
+/// the validation error message otherwise
+pub(crate) fn validate_toolchain(toolchain: &ToolchainSpecifier) -> Result<(), String> {
     let output = Command::new("rustup")
         .args(&["run", toolchain.channel(), "rustc", "--version"])
         .stderr(Stdio::null())
@@ -58,11 +71,10 @@ fn check_toolchain(toolchain: &ToolchainSpecifier) {
         .output()
         .expect("Failed to check rustup toolchain");
     if !output.status.success() {
-        error!(
+        return Err(format!(
             "Missing toolchain {}! Please install it using rustup.",
             &toolchain
-        );
-        return;
+        ));
     }
 
     let spec_min_version = get_specified_min_version(toolchain);
@@ -85,12 +97,20 @@ fn check_toolchain(toolchain: &ToolchainSpecifier) {
         rustc_version.pre = Prerelease::EMPTY;
 
         if &rustc_version < spec_min_version {
-            error!(
+            return Err(format!(
                 "Installed toolchain {} is required to satisfy version {}, found {}! Please upgrade it using rustup.",
                 toolchain.channel(),
                 &spec_min_version,
                 rustc_version,
-            );
+            ));
         }
     }
+
+    Ok(())
+}
+
+/// Validates the toolchain is installed and satisfies the min version (if defined), returning a
+/// task failed error otherwise
+pub(crate) fn check_toolchain(toolchain: &ToolchainSpecifier) -> Result<(), CargoMakeError> {
+    validate_toolchain(toolchain).map_err(CargoMakeError::TaskFailed)
 }
@@ -13,25 +13,32 @@ fn get_test_env_toolchain() -> ToolchainSpecifier {
 }
 
 #[test]
-#[should_panic]
 fn wrap_command_invalid_toolchain() {
-    wrap_command(&"invalid-chain".into(), "true", &None);
+    let result = wrap_command(&"invalid-chain".into(), "true", &None);
+
+    assert_eq!(
+        result.unwrap_err(),
+        CargoMakeError::TaskFailed(
+            "Missing toolchain invalid-chain! Please install it using rustup.".to_string()
+        )
+    );
 }
 
 #[test]
-#[should_panic]
 fn wrap_command_unreachable_version() {
     let toolchain = ToolchainSpecifier::Bounded(ToolchainBoundedSpecifier {
         channel: envmnt::get_or_panic("CARGO_MAKE_RUST_CHANNEL"),
         min_version: "9999.9.9".to_string(), // If we ever reach this version, add another 9
     });
-    wrap_command(&toolchain, "true", &None);
+    let result = wrap_command(&toolchain, "true", &None);
+
+    assert!(result.is_err());
 }
 
 #[test]
 fn wrap_command_none_args() {
     let toolchain = get_test_env_toolchain();
-    let output = wrap_command(&toolchain, "true", &None);
+    let output = wrap_command(&toolchain, "true", &None).unwrap();
 
     assert_eq!(output.command, "rustup".to_string());
 
@@ -45,7 +52,7 @@ fn wrap_command_none_args() {
 #[test]
 fn wrap_command_empty_args() {
     let toolchain = get_test_env_toolchain();
-    let output = wrap_command(&toolchain, "true", &Some(vec![]));
+    let output = wrap_command(&toolchain, "true", &Some(vec![])).unwrap();
 
     assert_eq!(output.command, "rustup".to_string());
 
@@ -63,7 +70,8 @@ fn wrap_command_with_args() {
         &toolchain,
         "true",
         &Some(vec!["echo".to_string(), "test".to_string()]),
-    );
+    )
+    .unwrap();
 
     assert_eq!(output.command, "rustup".to_string());
 
@@ -75,3 +83,49 @@ fn wrap_command_with_args() {
     assert_eq!(args[3], "echo".to_string());
     assert_eq!(args[4], "test".to_string());
 }
+
+#[test]
+fn create_wrapped_command_missing_toolchain() {
+    let output = create_wrapped_command(
+        &"invalid-chain".into(),
+        "cargo",
+        &Some(vec!["build".to_string()]),
+    );
+
+    assert_eq!(output.command, "rustup".to_string());
+    assert_eq!(
+        output.args.unwrap(),
+        vec!["run", "invalid-chain", "cargo", "build"]
+    );
+}
+
+#[test]
+fn validate_toolchain_valid() {
+    let toolchain = get_test_env_toolchain();
+    let result = validate_toolchain(&toolchain);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn validate_toolchain_invalid_toolchain() {
+    let result = validate_toolchain(&"invalid-chain".into());
+
+    assert_eq!(
+        result.unwrap_err(),
+        "Missing toolchain invalid-chain! Please install it using rustup."
+    );
+}
+
+#[test]
+fn validate_toolchain_unreachable_version() {
+    let toolchain = ToolchainSpecifier::Bounded(ToolchainBoundedSpecifier {
+        channel: envmnt::get_or_panic("CARGO_MAKE_RUST_CHANNEL"),
+        min_version: "9999.9.9".to_string(),
+    });
+    let result = validate_toolchain(&toolchain);
+
+    assert!(result
+        .unwrap_err()
+        .contains("is required to satisfy version 9999.9.9"));
+}
@@ -14,6 +14,7 @@ use git_info::types::GitInfo;
 use indexmap::IndexMap;
 use regex::Regex;
 use rust_info::types::RustInfo;
+use schemars::JsonSchema;
 use std::collections::HashMap;
 
 /// Returns the platform name
@@ -27,6 +28,20 @@ pub fn get_platform_name() -> String {
     }
 }
 
+/// Returns the platform family name (unix or windows)
+pub fn get_platform_family_name() -> String {
+    if cfg!(windows) {
+        "windows".to_string()
+    } else {
+        "unix".to_string()
+    }
+}
+
+/// Returns the CPU architecture name (x86_64, aarch64, ...)
+pub fn get_arch_name() -> String {
+    std::env::consts::ARCH.to_string()
+}
+
 fn get_namespaced_task_name(namespace: &str, task: &str) -> String {
     let mut namespaced_task = String::new();
 
@@ -109,6 +124,10 @@ pub struct CliArgs {
     pub skip_tasks_pattern: Option<String>,
     /// Only print the execution plan
     pub print_only: bool,
+    /// Print the resolved actions of each task without invoking them
+    pub dry_run: bool,
+    /// Print the condition criterion which caused each task to be skipped
+    pub explain_skips: bool,
     /// List all known steps
     pub list_all_steps: bool,
     /// List steps for a given category
@@ -127,6 +146,22 @@ pub struct CliArgs {
     pub output_file: Option<String>,
     /// Print time summary at end of the flow
     pub print_time_summary: bool,
+    /// The maximum amount of steps to run in parallel
+    pub jobs: Option<usize>,
+    /// Ignore the tasks inputs/outputs fingerprints and run all tasks
+    pub force_rerun: bool,
+    /// The file to append the flow events to (JSON lines)
+    pub events_file: Option<String>,
+    /// The flow report to write (format=file, for example junit=report.xml)
+    pub report: Option<String>,
+    /// Validates the makefile and its extended makefiles without running any task
+    pub check_makefile: bool,
+    /// Prints the makefile JSON schema
+    pub print_schema: bool,
+    /// Only use the cached git/url extend sources
+    pub offline: bool,
+    /// Only run the workspace flow on the members affected by changes since the given git ref
+    pub affected_since: Option<String>,
 }
 
 impl CliArgs {
@@ -148,6 +183,8 @@ impl CliArgs {
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
             print_only: false,
+            dry_run: false,
+            explain_skips: false,
             list_all_steps: false,
             list_category_steps: None,
             diff_execution_plan: false,
@@ -157,6 +194,14 @@ impl CliArgs {
             output_format: "default".to_string(),
             output_file: None,
             print_time_summary: false,
+            jobs: None,
+            force_rerun: false,
+            events_file: None,
+            report: None,
+            check_makefile: false,
+            print_schema: false,
+            offline: false,
+            affected_since: None,
         }
     }
 }
@@ -166,7 +211,7 @@ pub(crate) struct RunTaskOptions {
     pub(crate) plugins_enabled: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds persisted data used by cargo-make
 pub struct Cache {
     /// File from which the cache file was loaded from
@@ -183,7 +228,7 @@ impl Cache {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds configuration info for cargo-make
 pub struct GlobalConfig {
     /// File from which the global config was loaded from
@@ -211,13 +256,15 @@ impl GlobalConfig {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds crate workspace info, see <http://doc.crates.io/manifest.html#the-workspace-section>
 pub struct Workspace {
     /// members paths
     pub members: Option<Vec<String>>,
     /// exclude paths
     pub exclude: Option<Vec<String>>,
+    /// dependencies which members can inherit
+    pub dependencies: Option<IndexMap<String, CrateDependency>>,
 }
 
 impl Workspace {
@@ -227,7 +274,7 @@ impl Workspace {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds crate package information loaded from the Cargo.toml file package section.
 pub struct PackageInfo {
     /// name
@@ -253,14 +300,16 @@ impl PackageInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds crate dependency info.
 pub struct CrateDependencyInfo {
     /// Holds the dependency path
     pub path: Option<String>,
+    /// True if the dependency is inherited from the workspace dependencies
+    pub workspace: Option<bool>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds crate dependency info.
 pub enum CrateDependency {
@@ -270,7 +319,7 @@ pub enum CrateDependency {
     Info(CrateDependencyInfo),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds crate information loaded from the Cargo.toml file.
 pub struct CrateInfo {
     /// package info
@@ -279,6 +328,12 @@ pub struct CrateInfo {
     pub workspace: Option<Workspace>,
     /// crate dependencies
     pub dependencies: Option<IndexMap<String, CrateDependency>>,
+    /// crate dev dependencies
+    #[serde(rename = "dev-dependencies")]
+    pub dev_dependencies: Option<IndexMap<String, CrateDependency>>,
+    /// crate build dependencies
+    #[serde(rename = "build-dependencies")]
+    pub build_dependencies: Option<IndexMap<String, CrateDependency>>,
 }
 
 impl CrateInfo {
@@ -340,7 +395,7 @@ impl FlowState {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Rust version condition structure
 pub struct RustVersionCondition {
     /// min version number
@@ -351,14 +406,24 @@ pub struct RustVersionCondition {
     pub equal: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+/// Git changed files condition structure
+pub struct FilesChangedSinceCondition {
+    /// The git ref (branch, tag or commit) to compare the current HEAD against
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// Globs (relative to the task working directory) which at least one changed file must match
+    pub globs: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds condition attributes
 pub struct TaskCondition {
     /// Failure message
     pub fail_message: Option<String>,
     /// Profile names (development, ...)
     pub profiles: Option<Vec<String>>,
-    /// Platform names (linux, windows, mac)
+    /// Platform names (linux, windows, mac) or platform family names (unix, windows)
     pub platforms: Option<Vec<String>>,
     /// Channel names (stable, beta, nightly)
     pub channels: Option<Vec<String>>,
@@ -380,9 +445,31 @@ pub struct TaskCondition {
     pub files_exist: Option<Vec<String>>,
     /// Files which do not exist
     pub files_not_exist: Option<Vec<String>>,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
+    /// CPU architecture names (x86_64, aarch64, ...)
+    pub arch: Option<Vec<String>>,
+    /// Target triples which the crate target triple must be one of
+    pub target_triples: Option<Vec<String>>,
+    /// Commands which must be found on the PATH
+    pub commands_exist: Option<Vec<String>>,
+    /// Rustup components which must be installed
+    pub rustup_components: Option<Vec<String>>,
+    /// Git branch names or glob patterns (main, release/*, ...) which the current branch must match
+    pub git_branches: Option<Vec<String>>,
+    /// Whether the git working tree must have (true) or must not have (false) uncommitted changes
+    pub git_dirty: Option<bool>,
+    /// Whether the current git HEAD must have (true) or must not have (false) a tag pointing at it
+    pub git_tag_on_head: Option<bool>,
+    /// Files which must have changed since the given git ref
+    pub files_changed_since: Option<FilesChangedSinceCondition>,
+    /// Conditions which at least one of them must be met
+    pub any: Option<Vec<TaskCondition>>,
+    /// Conditions which all of them must be met
+    pub all: Option<Vec<TaskCondition>>,
+    /// Condition which must not be met
+    pub not: Option<Box<TaskCondition>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env file path and attributes
 pub struct EnvFileInfo {
     /// The file path as string
@@ -404,7 +491,7 @@ impl EnvFileInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds the env file path and attributes
 pub enum EnvFile {
@@ -414,7 +501,7 @@ pub enum EnvFile {
     Info(EnvFileInfo),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env value provided by a script
 pub struct EnvValueScript {
     /// The script to execute to get the env value
@@ -423,7 +510,7 @@ pub struct EnvValueScript {
     pub multi_line: Option<bool>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env value provided by decoding other values
 pub struct EnvValueDecode {
     /// The source value (can be an env expression)
@@ -434,14 +521,14 @@ pub struct EnvValueDecode {
     pub mapping: HashMap<String, String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy)]
 /// Enables to unset env variables
 pub struct EnvValueUnset {
     /// If true, the env variable will be unset, else ignored
     pub unset: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env value set if condition is met
 pub struct EnvValueConditioned {
     /// The value to set (can be an env expression)
@@ -450,7 +537,7 @@ pub struct EnvValueConditioned {
     pub condition: Option<TaskCondition>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Env value holding a list of paths based on given glob definitions
 pub struct EnvValuePathGlob {
     /// The glob used to fetch all paths
@@ -463,7 +550,7 @@ pub struct EnvValuePathGlob {
     pub ignore_type: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds the env value or script
 pub enum EnvValue {
@@ -551,7 +638,24 @@ impl<'de> serde::de::Deserialize<'de> for TestArg {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl JsonSchema for TestArg {
+    fn schema_name() -> String {
+        "TestArg".to_string()
+    }
+
+    fn json_schema(generator: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        // same as the deserializer, a single string or a sequence of strings
+        let mut schema = schemars::schema::SchemaObject::default();
+        schema.subschemas().any_of = Some(vec![
+            generator.subschema_for::<String>(),
+            generator.subschema_for::<Vec<String>>(),
+        ]);
+
+        schema.into()
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds instructions how to install the cargo plugin
 pub struct InstallCargoPluginInfo {
     /// The provided crate to install
@@ -605,7 +709,7 @@ impl PartialEq for InstallCargoPluginInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds instructions how to install the crate
 pub struct InstallCrateInfo {
     /// The provided crate to install
@@ -694,7 +798,7 @@ impl PartialEq for InstallCrateInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds instructions how to install a rustup component
 pub struct InstallRustupComponentInfo {
     /// The component to install via rustup
@@ -730,7 +834,7 @@ impl PartialEq for InstallRustupComponentInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Install crate name or params
 pub enum InstallCrate {
@@ -773,7 +877,7 @@ impl PartialEq for InstallCrate {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(untagged)]
 /// Holds the run task name/s
 pub enum RunTaskName {
@@ -783,7 +887,7 @@ pub enum RunTaskName {
     Multiple(Vec<String>),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds the run task information
 pub struct RunTaskDetails {
     /// The task name
@@ -796,7 +900,7 @@ pub struct RunTaskDetails {
     pub cleanup_task: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds the run task routing information
 pub struct RunTaskRoutingInfo {
     /// The task name
@@ -813,7 +917,7 @@ pub struct RunTaskRoutingInfo {
     pub condition_script: Option<Vec<String>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Run task info
 pub enum RunTaskInfo {
@@ -825,10 +929,10 @@ pub enum RunTaskInfo {
     Routing(Vec<RunTaskRoutingInfo>),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds watch options
 pub struct WatchOptions {
-    /// Watch version to install if not already installed
+    /// DEPRECATED, no longer used as the watcher is built in
     pub version: Option<String>,
     /// Postpone first run until a file changes
     pub postpone: Option<bool>,
@@ -915,7 +1019,7 @@ impl PartialEq for WatchOptions {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds watch options or simple true/false value
 pub enum TaskWatchOptions {
@@ -940,7 +1044,7 @@ impl PartialEq for TaskWatchOptions {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds deprecation info such as true/false/message
 pub enum DeprecationInfo {
@@ -965,7 +1069,7 @@ impl PartialEq for DeprecationInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Script file name
 pub struct FileScriptValue {
     /// Script file name
@@ -974,7 +1078,7 @@ pub struct FileScriptValue {
     pub absolute_path: Option<bool>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Script content split to parts to enable a more fine tuned extension capability
 pub struct ScriptSections {
     /// Script section
@@ -985,7 +1089,7 @@ pub struct ScriptSections {
     pub post: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Script value (text, file name, ...)
 pub enum ScriptValue {
@@ -999,7 +1103,83 @@ pub enum ScriptValue {
     Sections(ScriptSections),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+/// Holds the task retry options
+pub struct RetryOptions {
+    /// The maximum amount of retries after the first failed attempt
+    pub count: u32,
+    /// The delay before the first retry (for example 500ms, 5s or 1m, defaults to no delay)
+    pub delay: Option<String>,
+    /// The delay backoff strategy between retries: fixed (default), linear or exponential
+    pub backoff: Option<String>,
+    /// Only failures with one of these exit codes are retried (defaults to all failures)
+    pub on_exit_codes: Option<Vec<i32>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+/// Holds a task parameter value
+pub enum TaskParameterValue {
+    /// The value as string
+    Value(String),
+    /// The value as boolean
+    Boolean(bool),
+    /// The value as number
+    Number(i64),
+}
+
+impl std::fmt::Display for TaskParameterValue {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Value(ref value) => write!(formatter, "{}", value),
+            Self::Boolean(value) => write!(formatter, "{}", value),
+            Self::Number(value) => write!(formatter, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+/// Holds a task parameter definition, used to parse and validate the task command line arguments
+pub struct TaskParameter {
+    /// The parameter type: string (default), int, bool, enum or path
+    #[serde(rename = "type")]
+    pub parameter_type: Option<String>,
+    /// The possible values of an enum parameter
+    pub values: Option<Vec<String>>,
+    /// The value used in case the parameter is not provided
+    pub default: Option<TaskParameterValue>,
+    /// True if the parameter must be provided (ignored if a default is defined)
+    pub required: Option<bool>,
+    /// The parameter description, printed as part of the task usage message
+    pub description: Option<String>,
+}
+
+impl TaskParameter {
+    /// Creates and returns a new instance.
+    pub fn new() -> TaskParameter {
+        TaskParameter {
+            parameter_type: None,
+            values: None,
+            default: None,
+            required: None,
+            description: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+/// Holds the task matrix, which runs the task once for each combination of the matrix values
+pub struct TaskMatrix {
+    /// Combinations (or partial combinations) which are removed from the matrix
+    pub exclude: Option<Vec<IndexMap<String, String>>>,
+    /// Combinations which are added to the matrix or extend the matching combinations with additional values
+    pub include: Option<Vec<IndexMap<String, String>>>,
+    /// The matrix value names and their possible values (for example features and target)
+    #[serde(flatten)]
+    pub values: IndexMap<String, Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds a single task configuration such as command and dependencies list
 pub struct Task {
     /// if true, it should ignore all data in base task
@@ -1034,6 +1214,8 @@ pub struct Task {
     pub env_files: Option<Vec<EnvFile>>,
     /// The env vars to setup before running the task commands
     pub env: Option<IndexMap<String, EnvValue>>,
+    /// if true, the task env files and env vars are also set for all following tasks
+    pub export_env: Option<bool>,
     /// The working directory for the task to execute its command/script
     pub cwd: Option<String>,
     /// if defined, task points to another task and all other properties are ignored
@@ -1067,18 +1249,34 @@ pub struct Task {
     /// A list of tasks to execute before this task
     pub dependencies: Option<Vec<DependencyIdentifier>>,
     /// The rust toolchain used to invoke the command or install the needed crates/components
-    pub toolchain: Option<ToolchainSpecifier>,
+    pub toolchain: Option<TaskToolchain>,
+    /// The maximum duration of the task command/script (for example 30s, 10m or 1h), after which it is killed
+    pub timeout: Option<String>,
+    /// Retry the task command/script in case it fails
+    pub retries: Option<RetryOptions>,
+    /// The input file globs (if the inputs did not change since the last successful run, the task is skipped)
+    pub inputs: Option<Vec<String>>,
+    /// The output file globs (the task is skipped only if all outputs exist)
+    pub outputs: Option<Vec<String>>,
+    /// Runs the task for each combination of the matrix values (exported as CARGO_MAKE_MATRIX_* env vars)
+    pub matrix: Option<TaskMatrix>,
+    /// The task parameters, parsed from the task command line arguments (exported as CARGO_MAKE_PARAM_* env vars)
+    pub parameters: Option<IndexMap<String, TaskParameter>>,
     /// override task if runtime OS is Linux (takes precedence over alias)
     pub linux: Option<PlatformOverrideTask>,
     /// override task if runtime OS is Windows (takes precedence over alias)
     pub windows: Option<PlatformOverrideTask>,
     /// override task if runtime OS is Mac (takes precedence over alias)
     pub mac: Option<PlatformOverrideTask>,
+    /// override task if runtime OS is Linux or Mac and no OS specific override is defined
+    pub unix: Option<PlatformOverrideTask>,
+    /// override task by runtime CPU architecture name (x86_64, aarch64, ...) if no OS override is defined
+    pub arch: Option<IndexMap<String, PlatformOverrideTask>>,
 }
 
 /// A toolchain, defined either as a string (following the rustup syntax)
 /// or a ToolchainBoundedSpecifier.
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum ToolchainSpecifier {
     /// A string specifying the channel name of the toolchain
@@ -1128,8 +1326,72 @@ impl ToolchainSpecifier {
     }
 }
 
+/// The toolchain of a task, defined either as a single toolchain or as a list of toolchains
+/// in which case the task is invoked once for each toolchain
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum TaskToolchain {
+    // defined first as a two values list is also a valid bounded toolchain
+    /// Multiple toolchains
+    Multiple(Vec<ToolchainSpecifier>),
+    /// A single toolchain
+    Single(ToolchainSpecifier),
+}
+
+impl From<ToolchainSpecifier> for TaskToolchain {
+    fn from(toolchain: ToolchainSpecifier) -> Self {
+        Self::Single(toolchain)
+    }
+}
+
+impl From<String> for TaskToolchain {
+    fn from(channel: String) -> Self {
+        Self::Single(channel.into())
+    }
+}
+
+impl From<&str> for TaskToolchain {
+    fn from(channel: &str) -> Self {
+        Self::Single(channel.into())
+    }
+}
+
+impl std::fmt::Display for TaskToolchain {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Single(ref toolchain) => write!(formatter, "{}", toolchain),
+            Self::Multiple(ref toolchains) => {
+                let names: Vec<String> = toolchains
+                    .iter()
+                    .map(|toolchain| toolchain.to_string())
+                    .collect();
+                write!(formatter, "{}", names.join(", "))
+            }
+        }
+    }
+}
+
+impl TaskToolchain {
+    /// Returns all the defined toolchains
+    pub fn get_toolchains(&self) -> Vec<ToolchainSpecifier> {
+        match self {
+            Self::Single(ref toolchain) => vec![toolchain.clone()],
+            Self::Multiple(ref toolchains) => toolchains.clone(),
+        }
+    }
+
+    /// Returns the toolchain in case only a single toolchain is defined
+    pub fn get_single(&self) -> Option<&ToolchainSpecifier> {
+        match self {
+            Self::Single(ref toolchain) => Some(toolchain),
+            Self::Multiple(ref toolchains) if toolchains.len() == 1 => toolchains.first(),
+            Self::Multiple(_) => None,
+        }
+    }
+}
+
 /// A toolchain with a minumum version bound
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 pub struct ToolchainBoundedSpecifier {
     /// The channel of the toolchain to use
     pub channel: String,
@@ -1144,7 +1406,7 @@ impl std::fmt::Display for ToolchainBoundedSpecifier {
 }
 
 /// A dependency, defined either as a string or as a Dependency object
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum DependencyIdentifier {
     /// A full dependency definion (potentially in a different file)
@@ -1183,7 +1445,7 @@ impl DependencyIdentifier {
 }
 
 /// An identifier for a task
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 pub struct TaskIdentifier {
     /// The task name to execute
     pub name: String,
@@ -1303,7 +1565,7 @@ impl Task {
                                 RunTaskInfo::Details(run_task_details)
                             }
                             RunTaskInfo::Routing(mut routing_info_vector) => {
-                                for mut routing_info in &mut routing_info_vector {
+                                for routing_info in &mut routing_info_vector {
                                     match routing_info.name {
                                         RunTaskName::Single(ref name) => {
                                             routing_info.name = RunTaskName::Single(
@@ -1450,6 +1712,12 @@ impl Task {
             self.env = None;
         }
 
+        if task.export_env.is_some() {
+            self.export_env = task.export_env.clone();
+        } else if override_values {
+            self.export_env = None;
+        }
+
         if task.cwd.is_some() {
             self.cwd = task.cwd.clone();
         } else if override_values {
@@ -1553,6 +1821,42 @@ impl Task {
             self.toolchain = None;
         }
 
+        if task.timeout.is_some() {
+            self.timeout = task.timeout.clone();
+        } else if override_values {
+            self.timeout = None;
+        }
+
+        if task.retries.is_some() {
+            self.retries = task.retries.clone();
+        } else if override_values {
+            self.retries = None;
+        }
+
+        if task.inputs.is_some() {
+            self.inputs = task.inputs.clone();
+        } else if override_values {
+            self.inputs = None;
+        }
+
+        if task.outputs.is_some() {
+            self.outputs = task.outputs.clone();
+        } else if override_values {
+            self.outputs = None;
+        }
+
+        if task.matrix.is_some() {
+            self.matrix = task.matrix.clone();
+        } else if override_values {
+            self.matrix = None;
+        }
+
+        if task.parameters.is_some() {
+            self.parameters = task.parameters.clone();
+        } else if override_values {
+            self.parameters = None;
+        }
+
         if task.linux.is_some() {
             self.linux = task.linux.clone();
         } else if override_values {
@@ -1570,6 +1874,26 @@ impl Task {
         } else if override_values {
             self.mac = None;
         }
+
+        if task.unix.is_some() {
+            self.unix = task.unix.clone();
+        } else if override_values {
+            self.unix = None;
+        }
+
+        if task.arch.is_some() {
+            self.arch = task.arch.clone();
+        } else if override_values {
+            self.arch = None;
+        }
+    }
+
+    /// Returns the task toolchain in case a single toolchain is defined
+    pub fn get_toolchain(self: &Task) -> Option<ToolchainSpecifier> {
+        match self.toolchain {
+            Some(ref toolchain) => toolchain.get_single().cloned(),
+            None => None,
+        }
     }
 
     /// Returns true if the task ignore_errors attribute is defined and true
@@ -1587,23 +1911,35 @@ impl Task {
         }
     }
 
-    /// Returns the override task definition based on the current platform.
+    /// Returns the override task definition based on the current platform.<br>
+    /// The OS override takes precedence over the unix family override, which takes precedence
+    /// over the CPU architecture override.
     fn get_override(self: &Task) -> Option<PlatformOverrideTask> {
         let platform_name = get_platform_name();
-        if platform_name == "windows" {
-            match self.windows {
-                Some(ref value) => Some(value.clone()),
-                _ => None,
-            }
+        let os_override = if platform_name == "windows" {
+            &self.windows
         } else if platform_name == "mac" {
-            match self.mac {
-                Some(ref value) => Some(value.clone()),
-                _ => None,
-            }
+            &self.mac
         } else {
-            match self.linux {
-                Some(ref value) => Some(value.clone()),
-                _ => None,
+            &self.linux
+        };
+
+        match os_override {
+            Some(ref value) => Some(value.clone()),
+            None => {
+                let family_override = if get_platform_family_name() == "unix" {
+                    self.unix.clone()
+                } else {
+                    None
+                };
+
+                match family_override {
+                    Some(value) => Some(value),
+                    None => match self.arch {
+                        Some(ref arch_overrides) => arch_overrides.get(&get_arch_name()).cloned(),
+                        None => None,
+                    },
+                }
             }
         }
     }
@@ -1631,6 +1967,7 @@ impl Task {
                     force: override_task.force.clone(),
                     env_files: override_task.env_files.clone(),
                     env: override_task.env.clone(),
+                    export_env: self.export_env.clone(),
                     cwd: override_task.cwd.clone(),
                     alias: None,
                     linux_alias: None,
@@ -1648,9 +1985,17 @@ impl Task {
                     run_task: override_task.run_task.clone(),
                     dependencies: override_task.dependencies.clone(),
                     toolchain: override_task.toolchain.clone(),
+                    timeout: override_task.timeout.clone(),
+                    retries: self.retries.clone(),
+                    inputs: self.inputs.clone(),
+                    outputs: self.outputs.clone(),
+                    matrix: self.matrix.clone(),
+                    parameters: self.parameters.clone(),
                     linux: None,
                     windows: None,
                     mac: None,
+                    unix: None,
+                    arch: None,
                 }
             }
             None => self.clone(),
@@ -1765,7 +2110,7 @@ impl Task {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds a single task configuration for a specific platform as an override of another task
 pub struct PlatformOverrideTask {
     /// if true, it should ignore all data in base task
@@ -1819,7 +2164,9 @@ pub struct PlatformOverrideTask {
     /// A list of tasks to execute before this task
     pub dependencies: Option<Vec<DependencyIdentifier>>,
     /// The rust toolchain used to invoke the command or install the needed crates/components
-    pub toolchain: Option<ToolchainSpecifier>,
+    pub toolchain: Option<TaskToolchain>,
+    /// The maximum duration of the task command/script (for example 30s, 10m or 1h), after which it is killed
+    pub timeout: Option<String>,
 }
 
 impl PlatformOverrideTask {
@@ -1935,20 +2282,32 @@ impl PlatformOverrideTask {
             if self.toolchain.is_none() && task.toolchain.is_some() {
                 self.toolchain = task.toolchain.clone();
             }
+
+            if self.timeout.is_none() && task.timeout.is_some() {
+                self.timeout = task.timeout.clone();
+            }
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Extend with more fine tuning options
 pub struct ExtendOptions {
-    /// Path to another makefile
-    pub path: String,
+    /// Path to another makefile (when extending a git repository, the makefile path inside the repository)
+    pub path: Option<String>,
     /// Enable optional extend (default to false)
     pub optional: Option<bool>,
+    /// Git repository holding the makefile
+    pub git: Option<String>,
+    /// The git revision (branch, tag or commit) to checkout
+    pub rev: Option<String>,
+    /// URL of the makefile
+    pub url: Option<String>,
+    /// The expected sha256 checksum of the remote makefile
+    pub checksum: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 /// Holds makefile extend value
 pub enum Extend {
@@ -1960,7 +2319,7 @@ pub enum Extend {
     List(Vec<ExtendOptions>),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds properties to modify the core tasks
 pub struct ModifyConfig {
     /// If true, all core tasks will be set to private (default false)
@@ -1991,7 +2350,7 @@ impl ModifyConfig {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds the configuration found in the makefile toml config section.
 pub struct ConfigSection {
     /// If true, the default core tasks will not be loaded
@@ -2022,6 +2381,10 @@ pub struct ConfigSection {
     pub reduce_output: Option<bool>,
     /// True to print time summary at the end of the flow
     pub time_summary: Option<bool>,
+    /// The maximum amount of steps to run in parallel (default 1)
+    pub parallelism: Option<usize>,
+    /// The default timeout of all tasks (can be overridden by the task timeout attribute)
+    pub default_task_timeout: Option<String>,
     /// Automatically load cargo aliases as cargo-make tasks
     pub load_cargo_aliases: Option<bool>,
     /// The project information member (used by workspaces)
@@ -2140,6 +2503,14 @@ impl ConfigSection {
             self.time_summary = extended.time_summary.clone();
         }
 
+        if extended.parallelism.is_some() {
+            self.parallelism = extended.parallelism.clone();
+        }
+
+        if extended.default_task_timeout.is_some() {
+            self.default_task_timeout = extended.default_task_timeout.clone();
+        }
+
         if extended.load_cargo_aliases.is_some() {
             self.load_cargo_aliases = extended.load_cargo_aliases.clone();
         }
@@ -2201,7 +2572,7 @@ impl ConfigSection {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 /// Holds the entire configuration such as task definitions and env vars
 pub struct Config {
     /// Runtime config
@@ -2243,7 +2614,7 @@ impl Config {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
 /// Holds the entire externally read configuration such as task definitions and env vars where all values are optional
 pub struct ExternalConfig {
     /// Path to another toml file to extend
@@ -2283,6 +2654,26 @@ pub struct Step {
 pub struct ExecutionPlan {
     /// A list of steps to execute
     pub steps: Vec<Step>,
+    /// The indexes of the steps each step depends on (same order as the steps list)
+    pub steps_dependencies: Vec<Vec<usize>>,
+}
+
+impl ExecutionPlan {
+    /// Creates and returns a new instance.
+    pub fn new() -> ExecutionPlan {
+        ExecutionPlan {
+            steps: vec![],
+            steps_dependencies: vec![],
+        }
+    }
+
+    /// Adds the step to the plan and returns its index
+    pub fn add_step(self: &mut ExecutionPlan, step: Step, dependencies: Vec<usize>) -> usize {
+        self.steps.push(step);
+        self.steps_dependencies.push(dependencies);
+
+        self.steps.len() - 1
+    }
 }
 
 #[derive(Debug)]
@@ -184,6 +184,8 @@ fn cli_args_new() {
     assert!(cli_args.skip_tasks_pattern.is_none());
     assert!(!cli_args.disable_check_for_updates);
     assert!(!cli_args.print_only);
+    assert!(!cli_args.dry_run);
+    assert!(!cli_args.explain_skips);
     assert!(!cli_args.list_all_steps);
     assert!(!cli_args.diff_execution_plan);
     assert!(!cli_args.experimental);
@@ -191,6 +193,13 @@ fn cli_args_new() {
     assert_eq!(cli_args.output_format, "default");
     assert!(cli_args.output_file.is_none());
     assert!(!cli_args.print_time_summary);
+    assert!(cli_args.jobs.is_none());
+    assert!(!cli_args.force_rerun);
+    assert!(cli_args.events_file.is_none());
+    assert!(cli_args.report.is_none());
+    assert!(!cli_args.check_makefile);
+    assert!(!cli_args.print_schema);
+    assert!(!cli_args.offline);
 }
 
 #[test]
@@ -1468,6 +1477,27 @@ fn env_value_deserialize_unset() {
     }
 }
 
+#[test]
+fn task_deserialize_retries() {
+    let task: Task = toml::from_str(
+        r#"
+        command = "cargo"
+        retries = { count = 3, delay = "5s", backoff = "exponential", on_exit_codes = [1, 101] }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        task.retries.unwrap(),
+        RetryOptions {
+            count: 3,
+            delay: Some("5s".to_string()),
+            backoff: Some("exponential".to_string()),
+            on_exit_codes: Some(vec![1, 101]),
+        }
+    );
+}
+
 #[test]
 fn toolchain_specifier_deserialize_string() {
     #[derive(Deserialize)]
@@ -1509,6 +1539,163 @@ fn toolchain_specifier_deserialize_min_version() {
     );
 }
 
+#[test]
+fn task_toolchain_deserialize_single() {
+    #[derive(Deserialize)]
+    struct Value {
+        toolchain: TaskToolchain,
+    }
+
+    let v: Value = toml::from_str(
+        r#"
+        toolchain = { channel = "beta", min_version = "1.56" }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        v.toolchain,
+        TaskToolchain::Single(ToolchainSpecifier::Bounded(ToolchainBoundedSpecifier {
+            channel: "beta".to_string(),
+            min_version: "1.56".to_string(),
+        }))
+    );
+}
+
+#[test]
+fn task_toolchain_deserialize_multiple_channels() {
+    #[derive(Deserialize)]
+    struct Value {
+        toolchain: TaskToolchain,
+    }
+
+    let v: Value = toml::from_str(
+        r#"
+        toolchain = ["stable", "1.60"]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        v.toolchain,
+        TaskToolchain::Multiple(vec!["stable".into(), "1.60".into()])
+    );
+}
+
+#[test]
+fn task_toolchain_deserialize_multiple() {
+    #[derive(Deserialize)]
+    struct Value {
+        toolchain: TaskToolchain,
+    }
+
+    let v: Value = toml::from_str(
+        r#"
+        toolchain = ["stable", { channel = "beta", min_version = "1.56" }]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        v.toolchain,
+        TaskToolchain::Multiple(vec![
+            "stable".into(),
+            ToolchainSpecifier::Bounded(ToolchainBoundedSpecifier {
+                channel: "beta".to_string(),
+                min_version: "1.56".to_string(),
+            })
+        ])
+    );
+    assert_eq!(v.toolchain.to_string(), "stable, beta >= 1.56");
+}
+
+#[test]
+fn task_toolchain_get_toolchains() {
+    let single: TaskToolchain = "stable".into();
+    assert_eq!(single.get_toolchains(), vec!["stable".into()]);
+
+    let multiple = TaskToolchain::Multiple(vec!["stable".into(), "nightly".into()]);
+    assert_eq!(
+        multiple.get_toolchains(),
+        vec!["stable".into(), "nightly".into()]
+    );
+}
+
+#[test]
+fn task_toolchain_get_single() {
+    let single: TaskToolchain = "stable".into();
+    assert_eq!(single.get_single(), Some(&"stable".into()));
+
+    let multiple = TaskToolchain::Multiple(vec!["nightly".into()]);
+    assert_eq!(multiple.get_single(), Some(&"nightly".into()));
+
+    let multiple = TaskToolchain::Multiple(vec!["stable".into(), "nightly".into()]);
+    assert!(multiple.get_single().is_none());
+}
+
+#[test]
+fn task_get_toolchain() {
+    let mut task = Task::new();
+    assert!(task.get_toolchain().is_none());
+
+    task.toolchain = Some("stable".into());
+    assert_eq!(task.get_toolchain(), Some("stable".into()));
+
+    task.toolchain = Some(TaskToolchain::Multiple(vec![
+        "stable".into(),
+        "nightly".into(),
+    ]));
+    assert!(task.get_toolchain().is_none());
+}
+
+#[test]
+fn task_parameters_deserialize() {
+    let task: Task = toml::from_str(
+        r#"
+        command = "echo"
+
+        [parameters]
+        env = { type = "enum", values = ["dev", "prod"], required = true }
+        count = { type = "int", default = 2, description = "The count" }
+        "#,
+    )
+    .unwrap();
+
+    let parameters = task.parameters.unwrap();
+    assert_eq!(parameters.len(), 2);
+
+    let env = parameters.get("env").unwrap();
+    assert_eq!(env.parameter_type, Some("enum".to_string()));
+    assert_eq!(
+        env.values,
+        Some(vec!["dev".to_string(), "prod".to_string()])
+    );
+    assert_eq!(env.required, Some(true));
+    assert!(env.default.is_none());
+
+    let count = parameters.get("count").unwrap();
+    assert_eq!(count.parameter_type, Some("int".to_string()));
+    assert_eq!(count.default, Some(TaskParameterValue::Number(2)));
+    assert_eq!(count.description, Some("The count".to_string()));
+}
+
+#[test]
+fn task_extend_parameters() {
+    let mut parameters = IndexMap::new();
+    parameters.insert("env".to_string(), TaskParameter::new());
+
+    let mut base = Task::new();
+    base.parameters = Some(parameters.clone());
+
+    base.extend(&Task::new());
+    assert_eq!(base.parameters, Some(parameters.clone()));
+
+    let mut extended_parameters = IndexMap::new();
+    extended_parameters.insert("count".to_string(), TaskParameter::new());
+    let mut extended = Task::new();
+    extended.parameters = Some(extended_parameters.clone());
+
+    base.extend(&extended);
+    assert_eq!(base.parameters, Some(extended_parameters));
+}
+
 #[test]
 fn task_new() {
     let task = Task::new();
@@ -1636,6 +1823,7 @@ fn task_extend_both_have_misc_data() {
         force: Some(true),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        export_env: None,
         cwd: None,
         alias: Some("alias2".to_string()),
         linux_alias: None,
@@ -1651,9 +1839,17 @@ fn task_extend_both_have_misc_data() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        timeout: None,
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: None,
         windows: None,
         mac: None,
+        unix: None,
+        arch: None,
     };
 
     base.extend(&extended);
@@ -1736,6 +1932,7 @@ fn task_extend_extended_have_all_fields() {
         force: Some(true),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        export_env: None,
         cwd: None,
         alias: None,
         linux_alias: None,
@@ -1751,9 +1948,17 @@ fn task_extend_extended_have_all_fields() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: None,
         toolchain: None,
+        timeout: None,
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: None,
         windows: None,
         mac: None,
+        unix: None,
+        arch: None,
     };
 
     let mut env = IndexMap::new();
@@ -1786,12 +1991,24 @@ fn task_extend_extended_have_all_fields() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: Some(vec!["exit 0".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![EnvFile::Path("extended".to_string())]),
         env: Some(env.clone()),
+        export_env: Some(true),
         cwd: Some("cwd".to_string()),
         alias: Some("alias2".to_string()),
         linux_alias: Some("linux".to_string()),
@@ -1810,6 +2027,17 @@ fn task_extend_extended_have_all_fields() {
         run_task: Some(RunTaskInfo::Name("task2".to_string())),
         dependencies: Some(vec!["A".into()]),
         toolchain: Some("toolchain".into()),
+        timeout: Some("10m".to_string()),
+        retries: Some(RetryOptions {
+            count: 3,
+            delay: Some("5s".to_string()),
+            backoff: Some("exponential".to_string()),
+            on_exit_codes: Some(vec![2]),
+        }),
+        inputs: Some(vec!["src/**/*.rs".to_string()]),
+        outputs: Some(vec!["target/out".to_string()]),
+        matrix: None,
+        parameters: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(true),
             install_crate: Some(InstallCrate::Value("my crate2".to_string())),
@@ -1835,6 +2063,17 @@ fn task_extend_extended_have_all_fields() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -1855,6 +2094,7 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -1881,6 +2121,17 @@ fn task_extend_extended_have_all_fields() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -1901,6 +2152,7 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -1927,6 +2179,17 @@ fn task_extend_extended_have_all_fields() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -1947,7 +2210,10 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
+        unix: None,
+        arch: None,
     };
 
     base.extend(&extended);
@@ -1971,6 +2237,7 @@ fn task_extend_extended_have_all_fields() {
     assert!(base.force.is_some());
     assert!(base.env_files.is_some());
     assert!(base.env.is_some());
+    assert!(base.export_env.unwrap());
     assert!(base.cwd.is_some());
     assert!(base.alias.is_some());
     assert!(base.linux_alias.is_some());
@@ -1985,6 +2252,10 @@ fn task_extend_extended_have_all_fields() {
     assert!(base.run_task.is_some());
     assert!(base.dependencies.is_some());
     assert!(base.toolchain.is_some());
+    assert!(base.timeout.is_some());
+    assert!(base.retries.is_some());
+    assert!(base.inputs.is_some());
+    assert!(base.outputs.is_some());
     assert!(base.linux.is_some());
     assert!(base.windows.is_some());
     assert!(base.mac.is_some());
@@ -2027,6 +2298,18 @@ fn task_extend_extended_have_all_fields() {
     assert_eq!(run_task_name, "task2".to_string());
     assert_eq!(base.dependencies.unwrap().len(), 1);
     assert_eq!(base.toolchain.unwrap(), "toolchain".into());
+    assert_eq!(base.timeout.unwrap(), "10m");
+    assert_eq!(
+        base.retries.unwrap(),
+        RetryOptions {
+            count: 3,
+            delay: Some("5s".to_string()),
+            backoff: Some("exponential".to_string()),
+            on_exit_codes: Some(vec![2]),
+        }
+    );
+    assert_eq!(base.inputs.unwrap(), vec!["src/**/*.rs".to_string()]);
+    assert_eq!(base.outputs.unwrap(), vec!["target/out".to_string()]);
     assert!(base.linux.unwrap().clear.unwrap());
     assert!(!base.windows.unwrap().clear.unwrap());
     assert!(base.mac.unwrap().clear.is_none());
@@ -2067,12 +2350,24 @@ fn task_extend_clear_with_no_data() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: Some(vec!["exit 0".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(env.clone()),
+        export_env: None,
         cwd: Some("cwd".to_string()),
         alias: Some("alias2".to_string()),
         linux_alias: Some("linux".to_string()),
@@ -2091,6 +2386,12 @@ fn task_extend_clear_with_no_data() {
         run_task: Some(RunTaskInfo::Name("task2".to_string())),
         dependencies: Some(vec!["A".into()]),
         toolchain: Some("toolchain".into()),
+        timeout: None,
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(true),
             install_crate: Some(InstallCrate::Value("my crate2".to_string())),
@@ -2116,6 +2417,17 @@ fn task_extend_clear_with_no_data() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -2136,6 +2448,7 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -2162,6 +2475,17 @@ fn task_extend_clear_with_no_data() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -2182,6 +2506,7 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -2208,6 +2533,17 @@ fn task_extend_clear_with_no_data() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -2228,7 +2564,10 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
+        unix: None,
+        arch: None,
     };
 
     let mut extended = Task::new();
@@ -2307,12 +2646,24 @@ fn task_extend_clear_with_all_data() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: Some(vec!["exit 0".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(env.clone()),
+        export_env: None,
         cwd: Some("cwd".to_string()),
         alias: Some("alias2".to_string()),
         linux_alias: Some("linux".to_string()),
@@ -2331,6 +2682,12 @@ fn task_extend_clear_with_all_data() {
         run_task: Some(RunTaskInfo::Name("task2".to_string())),
         dependencies: Some(vec!["A".into()]),
         toolchain: Some("toolchain".into()),
+        timeout: None,
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(true),
             install_crate: Some(InstallCrate::Value("my crate2".to_string())),
@@ -2356,6 +2713,17 @@ fn task_extend_clear_with_all_data() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -2376,6 +2744,7 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -2402,6 +2771,17 @@ fn task_extend_clear_with_all_data() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -2422,6 +2802,7 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -2448,6 +2829,17 @@ fn task_extend_clear_with_all_data() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -2468,7 +2860,10 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            timeout: None,
         }),
+        unix: None,
+        arch: None,
     };
 
     base.extend(&extended);
@@ -2568,6 +2963,7 @@ fn task_get_normalized_task_undefined() {
         force: None,
         env_files: None,
         env: None,
+        export_env: None,
         cwd: None,
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -2582,13 +2978,21 @@ fn task_get_normalized_task_undefined() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain2".into()),
+        timeout: None,
         description: Some("description".to_string()),
         category: Some("category".to_string()),
         workspace: Some(false),
         plugin: Some("bplugin".to_string()),
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: None,
         windows: None,
         mac: None,
+        unix: None,
+        arch: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -2705,12 +3109,24 @@ fn task_get_normalized_task_with_override_no_clear() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: Some(vec!["exit 0".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        export_env: None,
         cwd: Some("cwd".to_string()),
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -2725,6 +3141,12 @@ fn task_get_normalized_task_with_override_no_clear() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain1".into()),
+        timeout: None,
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: Some(PlatformOverrideTask {
             clear: None,
             install_crate: Some(InstallCrate::Value("linux_crate".to_string())),
@@ -2750,6 +3172,17 @@ fn task_get_normalized_task_with_override_no_clear() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["exit 0".to_string()]),
             ignore_errors: Some(true),
@@ -2779,9 +3212,12 @@ fn task_get_normalized_task_with_override_no_clear() {
             run_task: Some(RunTaskInfo::Name("task2".to_string())),
             dependencies: Some(vec!["1".into(), "2".into()]),
             toolchain: Some("toolchain2".into()),
+            timeout: None,
         }),
         windows: None,
         mac: None,
+        unix: None,
+        arch: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -2907,12 +3343,24 @@ fn task_get_normalized_task_with_override_clear_false() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: Some(vec!["exit 0".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        export_env: None,
         cwd: Some("cwd".to_string()),
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -2927,6 +3375,12 @@ fn task_get_normalized_task_with_override_clear_false() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain1".into()),
+        timeout: None,
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(false),
             install_crate: Some(InstallCrate::Value("linux_crate".to_string())),
@@ -2955,6 +3409,17 @@ fn task_get_normalized_task_with_override_clear_false() {
                 rust_version: None,
                 files_exist: None,
                 files_not_exist: None,
+                arch: None,
+                target_triples: None,
+                commands_exist: None,
+                rustup_components: None,
+                git_branches: None,
+                git_dirty: None,
+                git_tag_on_head: None,
+                files_changed_since: None,
+                any: None,
+                all: None,
+                not: None,
             }),
             condition_script: Some(vec!["echo test".to_string(), "exit 1".to_string()]),
             ignore_errors: Some(true),
@@ -2985,9 +3450,12 @@ fn task_get_normalized_task_with_override_clear_false() {
             run_task: Some(RunTaskInfo::Name("task2".to_string())),
             dependencies: Some(vec!["1".into(), "2".into()]),
             toolchain: Some("toolchain2".into()),
+            timeout: None,
         }),
         windows: None,
         mac: None,
+        unix: None,
+        arch: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3106,12 +3574,24 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: Some(vec!["exit 0".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        export_env: None,
         cwd: Some("cwd".to_string()),
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -3126,10 +3606,16 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain1".into()),
+        timeout: Some("10m".to_string()),
         description: None,
         category: None,
         workspace: None,
         plugin: None,
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(false),
             install_crate: None,
@@ -3157,9 +3643,12 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
             run_task: None,
             dependencies: None,
             toolchain: None,
+            timeout: None,
         }),
         windows: None,
         mac: None,
+        unix: None,
+        arch: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3193,6 +3682,7 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
     assert!(normalized_task.run_task.is_some());
     assert!(normalized_task.dependencies.is_some());
     assert!(normalized_task.toolchain.is_some());
+    assert!(normalized_task.timeout.is_some());
     assert!(normalized_task.description.is_none());
     assert!(normalized_task.category.is_none());
     assert!(normalized_task.workspace.is_none());
@@ -3207,6 +3697,7 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
         InstallCrate::Value("install_crate".to_string())
     );
     assert_eq!(normalized_task.command.unwrap(), "command");
+    assert_eq!(normalized_task.timeout.unwrap(), "10m");
     assert!(!normalized_task.disabled.unwrap());
     assert!(normalized_task.private.unwrap());
     assert_eq!(
@@ -3269,12 +3760,24 @@ fn task_get_normalized_task_with_override_clear_true() {
             rust_version: None,
             files_exist: None,
             files_not_exist: None,
+            arch: None,
+            target_triples: None,
+            commands_exist: None,
+            rustup_components: None,
+            git_branches: None,
+            git_dirty: None,
+            git_tag_on_head: None,
+            files_changed_since: None,
+            any: None,
+            all: None,
+            not: None,
         }),
         condition_script: Some(vec!["exit 0".to_string()]),
         ignore_errors: Some(false),
         force: Some(false),
         env_files: Some(vec![]),
         env: Some(IndexMap::new()),
+        export_env: None,
         cwd: Some("cwd".to_string()),
         install_script: Some(ScriptValue::Text(vec![
             "A".to_string(),
@@ -3289,10 +3792,16 @@ fn task_get_normalized_task_with_override_clear_true() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain1".into()),
+        timeout: None,
         description: Some("description".to_string()),
         category: Some("category".to_string()),
         workspace: Some(false),
         plugin: Some("plugin".to_string()),
+        retries: None,
+        inputs: None,
+        outputs: None,
+        matrix: None,
+        parameters: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(true),
             install_crate: Some(InstallCrate::Value("linux_crate".to_string())),
@@ -3320,9 +3829,12 @@ fn task_get_normalized_task_with_override_clear_true() {
             run_task: None,
             dependencies: None,
             toolchain: None,
+            timeout: None,
         }),
         windows: None,
         mac: None,
+        unix: None,
+        arch: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3373,6 +3885,129 @@ fn task_get_normalized_task_with_override_clear_true() {
     assert_eq!(normalized_task.category.unwrap(), "category");
 }
 
+#[test]
+fn get_platform_family_name_valid() {
+    let family_name = get_platform_family_name();
+
+    if cfg!(windows) {
+        assert_eq!(family_name, "windows");
+    } else {
+        assert_eq!(family_name, "unix");
+    }
+}
+
+#[test]
+fn get_arch_name_valid() {
+    assert_eq!(get_arch_name(), std::env::consts::ARCH);
+}
+
+#[test]
+#[cfg(unix)]
+fn task_get_normalized_task_with_unix_override() {
+    let mut task: Task = toml::from_str(
+        r#"
+        command = "base"
+        unix = { command = "unix" }
+        windows = { command = "windows" }
+        "#,
+    )
+    .unwrap();
+
+    let normalized_task = task.get_normalized_task();
+
+    assert_eq!(normalized_task.command.unwrap(), "unix");
+    assert!(normalized_task.unix.is_none());
+    assert!(normalized_task.arch.is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn task_get_normalized_task_os_override_before_unix_override() {
+    let mut task: Task = toml::from_str(
+        r#"
+        command = "base"
+        linux = { command = "linux" }
+        unix = { command = "unix" }
+        "#,
+    )
+    .unwrap();
+
+    let normalized_task = task.get_normalized_task();
+
+    assert_eq!(normalized_task.command.unwrap(), "linux");
+}
+
+#[test]
+fn task_get_normalized_task_with_arch_override() {
+    let mut task: Task = toml::from_str(&format!(
+        r#"
+        command = "base"
+        [arch.bad_arch]
+        command = "bad"
+        [arch.{}]
+        command = "arch"
+        "#,
+        get_arch_name()
+    ))
+    .unwrap();
+
+    let normalized_task = task.get_normalized_task();
+
+    assert_eq!(normalized_task.command.unwrap(), "arch");
+    assert!(normalized_task.arch.is_none());
+}
+
+#[test]
+fn task_get_normalized_task_with_other_arch_override() {
+    let mut task: Task = toml::from_str(
+        r#"
+        command = "base"
+        [arch.bad_arch]
+        command = "bad"
+        "#,
+    )
+    .unwrap();
+
+    let normalized_task = task.get_normalized_task();
+
+    assert_eq!(normalized_task.command.unwrap(), "base");
+}
+
+#[test]
+#[cfg(unix)]
+fn task_get_normalized_task_unix_override_before_arch_override() {
+    let mut task: Task = toml::from_str(&format!(
+        r#"
+        command = "base"
+        unix = {{ command = "unix" }}
+        arch = {{ {} = {{ command = "arch" }} }}
+        "#,
+        get_arch_name()
+    ))
+    .unwrap();
+
+    let normalized_task = task.get_normalized_task();
+
+    assert_eq!(normalized_task.command.unwrap(), "unix");
+}
+
+#[test]
+fn task_extend_unix_and_arch_overrides() {
+    let mut base: Task = toml::from_str(
+        r#"
+        unix = { command = "base" }
+        arch = { x86_64 = { command = "base" } }
+        "#,
+    )
+    .unwrap();
+    let extended: Task = toml::from_str(r#"unix = { command = "extended" }"#).unwrap();
+
+    base.extend(&extended);
+
+    assert_eq!(base.unix.unwrap().command.unwrap(), "extended");
+    assert!(base.arch.unwrap().contains_key("x86_64"));
+}
+
 #[test]
 fn task_is_valid_all_none() {
     let task = Task::new();
@@ -3586,6 +4221,8 @@ fn config_section_new() {
     assert!(config.skip_crate_env_info.is_none());
     assert!(config.reduce_output.is_none());
     assert!(config.time_summary.is_none());
+    assert!(config.parallelism.is_none());
+    assert!(config.default_task_timeout.is_none());
     assert!(config.load_cargo_aliases.is_none());
     assert!(config.main_project_member.is_none());
     assert!(config.load_script.is_none());
@@ -3616,6 +4253,8 @@ fn config_section_extend_all_values() {
     base.skip_crate_env_info = Some(true);
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
+    base.parallelism = Some(2);
+    base.default_task_timeout = Some("1m".to_string());
     base.load_cargo_aliases = Some(true);
     base.load_script = Some(ScriptValue::Text(vec!["base_info".to_string()]));
     base.linux_load_script = Some(ScriptValue::Text(vec![
@@ -3649,6 +4288,8 @@ fn config_section_extend_all_values() {
     extended.skip_crate_env_info = Some(false);
     extended.reduce_output = Some(false);
     extended.time_summary = Some(false);
+    extended.parallelism = Some(4);
+    extended.default_task_timeout = Some("2m".to_string());
     extended.load_cargo_aliases = Some(false);
     extended.load_script = Some(ScriptValue::Text(vec![
         "extended_info".to_string(),
@@ -3682,6 +4323,8 @@ fn config_section_extend_all_values() {
     assert!(!base.skip_crate_env_info.unwrap());
     assert!(!base.reduce_output.unwrap());
     assert!(!base.time_summary.unwrap());
+    assert_eq!(base.parallelism.unwrap(), 4);
+    assert_eq!(base.default_task_timeout.unwrap(), "2m");
     assert!(!base.load_cargo_aliases.unwrap());
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 1);
@@ -3711,6 +4354,8 @@ fn config_section_extend_no_values() {
     base.skip_crate_env_info = Some(true);
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
+    base.parallelism = Some(2);
+    base.default_task_timeout = Some("1m".to_string());
     base.load_cargo_aliases = Some(true);
     base.load_script = Some(ScriptValue::Text(vec![
         "base_info".to_string(),
@@ -3753,6 +4398,8 @@ fn config_section_extend_no_values() {
     assert!(base.skip_crate_env_info.unwrap());
     assert!(base.reduce_output.unwrap());
     assert!(base.time_summary.unwrap());
+    assert_eq!(base.parallelism.unwrap(), 2);
+    assert_eq!(base.default_task_timeout.unwrap(), "1m");
     assert!(base.load_cargo_aliases.unwrap());
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 2);
@@ -3782,6 +4429,7 @@ fn config_section_extend_some_values() {
     base.skip_crate_env_info = Some(true);
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
+    base.parallelism = Some(2);
     base.load_cargo_aliases = Some(true);
     base.load_script = Some(ScriptValue::Text(vec![
         "base_info".to_string(),
@@ -3827,6 +4475,7 @@ fn config_section_extend_some_values() {
     assert!(base.skip_crate_env_info.unwrap());
     assert!(base.reduce_output.unwrap());
     assert!(base.time_summary.unwrap());
+    assert_eq!(base.parallelism.unwrap(), 2);
     assert!(base.load_cargo_aliases.unwrap());
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 2);
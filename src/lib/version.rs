@@ -39,7 +39,7 @@ fn get_latest_version() -> Option<String> {
 
     match result {
         Ok(output) => {
-            let exit_code = command::get_exit_code(Ok(output.status), false);
+            let exit_code = command::get_exit_code(Ok(output.status), false).unwrap_or(-1);
             if exit_code == 0 {
                 let stdout = String::from_utf8_lossy(&output.stdout);
                 let lines: Vec<&str> = stdout.split('\n').collect();
@@ -0,0 +1,218 @@
+//! # watch
+//!
+//! Watches for file changes and invokes the task on every change.<br>
+//! The task is invoked as a sub process which is cancelled in case files change while it is still running.<br>
+//! The watched paths (relative to the task working directory) are polled for changes, which means
+//! every poll walks all the watched files.
+//!
+
+#[cfg(test)]
+#[path = "watch_test.rs"]
+mod watch_test;
+
+use crate::command;
+use crate::environment::task_env;
+use crate::error::CargoMakeError;
+use crate::proxy_task::create_proxy_task;
+use crate::types::{FlowInfo, Task, TaskWatchOptions};
+use ignore::overrides::OverrideBuilder;
+use ignore::WalkBuilder;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// The interval in which the watched files are checked for changes
+const POLL_INTERVAL_MILLIS: u64 = 500;
+/// The time to wait for additional changes before invoking the task
+const DEBOUNCE_MILLIS: u64 = 200;
+
+type Snapshot = HashMap<PathBuf, (Option<SystemTime>, u64)>;
+
+#[derive(Debug, Clone, PartialEq)]
+struct WatchConfig {
+    /// Postpone first run until a file changes
+    postpone: bool,
+    /// Ignore a glob/gitignore-style pattern
+    ignore_pattern: Option<String>,
+    /// Use .gitignore files
+    git_ignore: bool,
+    /// The files/folders to watch
+    paths: Vec<String>,
+}
+
+fn get_watch_config(options: &Option<TaskWatchOptions>) -> WatchConfig {
+    let mut watch_config = WatchConfig {
+        postpone: false,
+        ignore_pattern: None,
+        git_ignore: true,
+        paths: vec![],
+    };
+
+    if let Some(TaskWatchOptions::Options(ref watch_options)) = options {
+        if watch_options.version.is_some() {
+            debug!("Watch version attribute is no longer used.");
+        }
+
+        watch_config.postpone = watch_options.postpone.unwrap_or(false);
+        watch_config.ignore_pattern = watch_options.ignore_pattern.clone();
+        watch_config.git_ignore = !watch_options.no_git_ignore.unwrap_or(false);
+
+        if let Some(ref paths) = watch_options.watch {
+            watch_config.paths = paths.clone();
+        }
+    }
+
+    if watch_config.paths.is_empty() {
+        watch_config.paths.push(".".to_string());
+    }
+
+    watch_config
+}
+
+fn create_watch_task(task: &str, flow_info: &FlowInfo) -> Task {
+    create_proxy_task(&task, true, true, None, flow_info.cli_arguments.clone())
+}
+
+fn get_snapshot(watch_config: &WatchConfig) -> Snapshot {
+    let mut snapshot = HashMap::new();
+
+    let root = match task_env::get_working_directory() {
+        Some(directory) => PathBuf::from(directory),
+        None => PathBuf::from("."),
+    };
+    let mut override_builder = OverrideBuilder::new(&root);
+    let mut ignore_patterns = vec!["!.git/".to_string(), "!target/".to_string()];
+    if let Some(ref ignore_pattern) = watch_config.ignore_pattern {
+        ignore_patterns.push(format!("!{}", ignore_pattern));
+    }
+    for pattern in &ignore_patterns {
+        if let Err(error) = override_builder.add(pattern) {
+            warn!("Invalid watch ignore pattern: {} error: {}", pattern, error);
+        }
+    }
+
+    let mut walk_builder = WalkBuilder::new(task_env::resolve_path(&watch_config.paths[0]));
+    for path in watch_config.paths.iter().skip(1) {
+        walk_builder.add(task_env::resolve_path(path));
+    }
+    walk_builder
+        .hidden(false)
+        .parents(watch_config.git_ignore)
+        .ignore(watch_config.git_ignore)
+        .git_ignore(watch_config.git_ignore)
+        .git_global(watch_config.git_ignore)
+        .git_exclude(watch_config.git_ignore)
+        .require_git(false);
+    match override_builder.build() {
+        Ok(overrides) => {
+            walk_builder.overrides(overrides);
+        }
+        Err(error) => warn!("Unable to setup watch ignore patterns, error: {}", error),
+    };
+
+    for entry in walk_builder.build() {
+        if let Ok(entry) = entry {
+            if let Ok(metadata) = entry.metadata() {
+                if metadata.is_file() {
+                    snapshot.insert(
+                        entry.into_path(),
+                        (metadata.modified().ok(), metadata.len()),
+                    );
+                }
+            }
+        }
+    }
+
+    snapshot
+}
+
+/// Waits until no more changes are detected and returns the latest snapshot
+fn wait_for_changes_to_settle(watch_config: &WatchConfig, mut snapshot: Snapshot) -> Snapshot {
+    loop {
+        thread::sleep(Duration::from_millis(DEBOUNCE_MILLIS));
+
+        let current_snapshot = get_snapshot(watch_config);
+        if current_snapshot == snapshot {
+            return snapshot;
+        }
+
+        snapshot = current_snapshot;
+    }
+}
+
+fn spawn_task(task: &Task) -> Result<Child, CargoMakeError> {
+    let mut command = Command::new(task.command.clone().unwrap());
+    if let Some(ref args) = task.args {
+        command.args(args);
+    }
+    task_env::apply_to_command(&mut command);
+    command.env("CARGO_MAKE_DISABLE_WATCH", "true");
+
+    info!(
+        "Execute Command: {}",
+        command::get_command_description(&command)
+    );
+
+    task_env::spawn(&mut command).map_err(|error| {
+        CargoMakeError::TaskFailed(format!(
+            "Unable to invoke watched task, error: {:#?}",
+            error
+        ))
+    })
+}
+
+fn is_running(child: &mut Option<Child>) -> bool {
+    match child {
+        Some(ref mut child_process) => match child_process.try_wait() {
+            Ok(None) => true,
+            _ => false,
+        },
+        None => false,
+    }
+}
+
+/// Invokes the task every time the watched files change.<br>
+/// This function only returns in case the task could not be invoked, otherwise the process needs
+/// to be killed in order to stop the watch.
+pub(crate) fn watch(
+    task: &str,
+    options: Option<TaskWatchOptions>,
+    flow_info: &FlowInfo,
+) -> Result<(), CargoMakeError> {
+    let watch_config = get_watch_config(&options);
+    debug!("Watch config: {:#?}", &watch_config);
+
+    let watch_task = create_watch_task(&task, flow_info);
+
+    let mut snapshot = get_snapshot(&watch_config);
+    let mut child = if watch_config.postpone {
+        None
+    } else {
+        Some(spawn_task(&watch_task)?)
+    };
+
+    info!("Watching for changes in: {:?}", &watch_config.paths);
+
+    loop {
+        thread::sleep(Duration::from_millis(POLL_INTERVAL_MILLIS));
+
+        let current_snapshot = get_snapshot(&watch_config);
+        if current_snapshot != snapshot {
+            snapshot = wait_for_changes_to_settle(&watch_config, current_snapshot);
+
+            if is_running(&mut child) {
+                info!("Files changed, cancelling current task invocation.");
+
+                if let Some(ref mut child_process) = child {
+                    command::kill_process_tree(child_process);
+                }
+            } else {
+                debug!("Files changed, invoking task.");
+            }
+
+            child = Some(spawn_task(&watch_task)?);
+        }
+    }
+}
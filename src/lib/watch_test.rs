@@ -0,0 +1,184 @@
+use super::*;
+use crate::logger;
+use crate::profile;
+use crate::test;
+use crate::types::WatchOptions;
+use fsio::file::write_text_file;
+
+#[test]
+fn get_watch_config_none() {
+    let watch_config = get_watch_config(&None);
+
+    assert_eq!(
+        watch_config,
+        WatchConfig {
+            postpone: false,
+            ignore_pattern: None,
+            git_ignore: true,
+            paths: vec![".".to_string()],
+        }
+    );
+}
+
+#[test]
+fn get_watch_config_boolean() {
+    let watch_config = get_watch_config(&Some(TaskWatchOptions::Boolean(true)));
+
+    assert_eq!(
+        watch_config,
+        WatchConfig {
+            postpone: false,
+            ignore_pattern: None,
+            git_ignore: true,
+            paths: vec![".".to_string()],
+        }
+    );
+}
+
+#[test]
+fn get_watch_config_empty_options() {
+    let watch_options = WatchOptions {
+        version: None,
+        postpone: None,
+        ignore_pattern: None,
+        no_git_ignore: None,
+        watch: None,
+    };
+
+    let watch_config = get_watch_config(&Some(TaskWatchOptions::Options(watch_options)));
+
+    assert_eq!(
+        watch_config,
+        WatchConfig {
+            postpone: false,
+            ignore_pattern: None,
+            git_ignore: true,
+            paths: vec![".".to_string()],
+        }
+    );
+}
+
+#[test]
+fn get_watch_config_all_options() {
+    let watch_options = WatchOptions {
+        version: Some("100.200.300.400".to_string()),
+        postpone: Some(true),
+        ignore_pattern: Some("tools/*".to_string()),
+        no_git_ignore: Some(true),
+        watch: Some(vec!["dir1".to_string(), "dir2".to_string()]),
+    };
+
+    let watch_config = get_watch_config(&Some(TaskWatchOptions::Options(watch_options)));
+
+    assert_eq!(
+        watch_config,
+        WatchConfig {
+            postpone: true,
+            ignore_pattern: Some("tools/*".to_string()),
+            git_ignore: false,
+            paths: vec!["dir1".to_string(), "dir2".to_string()],
+        }
+    );
+}
+
+#[test]
+#[ignore]
+#[cfg(target_os = "linux")]
+fn create_watch_task_with_makefile_and_cli_args() {
+    let makefile = envmnt::get_or("CARGO_MAKE_MAKEFILE_PATH", "EMPTY");
+    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", &makefile);
+
+    let mut flow_info = test::create_empty_flow_info();
+    flow_info.cli_arguments = Some(vec!["1".to_string(), "2".to_string(), "3 4".to_string()]);
+
+    let task = create_watch_task("some_task", &flow_info);
+
+    assert_eq!(task.command.unwrap(), "cargo".to_string());
+
+    let log_level = logger::get_log_level();
+    let args = task.args.unwrap();
+    assert_eq!(
+        args,
+        vec![
+            "make".to_string(),
+            "--disable-check-for-updates".to_string(),
+            "--no-on-error".to_string(),
+            format!("--loglevel={}", log_level),
+            format!("--profile={}", profile::get()),
+            "--allow-private".to_string(),
+            "--skip-init-end-tasks".to_string(),
+            "--makefile".to_string(),
+            makefile,
+            "some_task".to_string(),
+            "1".to_string(),
+            "2".to_string(),
+            "3 4".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn get_snapshot_with_ignore_pattern() {
+    let directory = "./target/_cargo_make_temp/watch/snapshot";
+    write_text_file(&format!("{}/src/file.txt", directory), "test").unwrap();
+    write_text_file(&format!("{}/tools/file.txt", directory), "test").unwrap();
+
+    let mut watch_config = get_watch_config(&None);
+    watch_config.paths = vec![directory.to_string()];
+
+    let snapshot = get_snapshot(&watch_config);
+    assert_eq!(snapshot.len(), 2);
+
+    watch_config.ignore_pattern = Some("tools/".to_string());
+
+    let snapshot = get_snapshot(&watch_config);
+    assert_eq!(snapshot.len(), 1);
+    assert!(snapshot.contains_key(&PathBuf::from(format!("{}/src/file.txt", directory))));
+}
+
+#[test]
+fn get_snapshot_changed() {
+    let directory = "./target/_cargo_make_temp/watch/changed";
+    let file = format!("{}/file.txt", directory);
+    write_text_file(&file, "test").unwrap();
+
+    let mut watch_config = get_watch_config(&None);
+    watch_config.paths = vec![directory.to_string()];
+
+    let snapshot1 = get_snapshot(&watch_config);
+    let snapshot2 = get_snapshot(&watch_config);
+    assert_eq!(snapshot1, snapshot2);
+
+    write_text_file(&file, "test changed").unwrap();
+
+    let snapshot3 = get_snapshot(&watch_config);
+    assert_ne!(snapshot1, snapshot3);
+}
+
+#[test]
+fn is_running_none() {
+    let mut child = None;
+
+    assert!(!is_running(&mut child));
+}
+
+#[test]
+fn get_snapshot_task_working_directory() {
+    let directory = "./target/_cargo_make_temp/watch/cwd";
+    write_text_file(&format!("{}/src/file.txt", directory), "test").unwrap();
+    write_text_file(&format!("{}/other/file.txt", directory), "test").unwrap();
+
+    let _cwd_guard = task_env::set_current_cwd(Some(directory.to_string()));
+    let mut watch_config = get_watch_config(&None);
+    watch_config.paths = vec!["src".to_string()];
+
+    let snapshot = get_snapshot(&watch_config);
+    assert_eq!(snapshot.len(), 1);
+    assert!(snapshot.contains_key(&PathBuf::from(format!("{}/src/file.txt", directory))));
+
+    watch_config.paths = vec![".".to_string()];
+    watch_config.ignore_pattern = Some("other/".to_string());
+
+    let snapshot = get_snapshot(&watch_config);
+    assert_eq!(snapshot.len(), 1);
+}
@@ -0,0 +1,626 @@
+//! # workspace
+//!
+//! Runs the requested task on the workspace members.<br>
+//! Members run in parallel (up to the given amount of jobs) while making sure each member only
+//! starts after all the members it depends on (via path or inherited workspace dependencies) are done.<br>
+//! In addition, it detects which members are affected by the git changes since a given ref.
+//!
+
+#[cfg(test)]
+#[path = "workspace_test.rs"]
+mod workspace_test;
+
+use crate::environment::crateinfo;
+use crate::environment::task_env;
+use crate::events;
+use crate::git;
+use crate::io;
+use crate::output;
+use crate::scheduler;
+use crate::scheduler::SkipReason;
+use crate::types::CrateDependency;
+use indexmap::IndexMap;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds a workspace member
+pub(crate) struct WorkspaceMember {
+    /// The member path (relative to the workspace root)
+    pub(crate) path: String,
+    /// The member name
+    pub(crate) name: String,
+    /// The member directory (resolved from the task working directory)
+    pub(crate) directory: String,
+}
+
+impl WorkspaceMember {
+    /// Creates and returns a new instance.
+    pub(crate) fn new(path: &str) -> WorkspaceMember {
+        let path = path.replace("\\", "/");
+        let name = match Path::new(&path).file_name() {
+            Some(name) => String::from(name.to_string_lossy()),
+            None => path.clone(),
+        };
+
+        let directory = task_env::resolve_path(&path).to_string_lossy().into_owned();
+
+        WorkspaceMember {
+            path,
+            name,
+            directory,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The member run status
+pub(crate) enum MemberStatus {
+    /// The task finished successfully on the member
+    Succeeded,
+    /// The task failed on the member (holds the exit code if available)
+    Failed(Option<i32>),
+    /// The member was skipped (holds the reason)
+    Skipped(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds the result of running the task on a single member
+pub(crate) struct MemberResult {
+    /// The member name
+    pub(crate) name: String,
+    /// The member run status
+    pub(crate) status: MemberStatus,
+    /// The run duration in millies
+    pub(crate) duration: u128,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds the options used to invoke cargo-make on the workspace members
+pub(crate) struct WorkspaceRunOptions {
+    /// The maximum amount of members to run in parallel
+    pub(crate) jobs: usize,
+    /// The log level passed to the members
+    pub(crate) log_level: String,
+    /// The profile passed to the members
+    pub(crate) profile: String,
+    /// The task name
+    pub(crate) task: String,
+    /// The task arguments
+    pub(crate) arguments: Vec<String>,
+}
+
+/// Returns the workspace dependencies (defined in the workspace root Cargo.toml) which members
+/// can inherit, with their paths resolved from the workspace root directory
+fn get_workspace_dependencies(root_directory: &Path) -> Result<IndexMap<String, String>, String> {
+    let crate_info = crateinfo::load_from(root_directory.join("Cargo.toml"))
+        .map_err(|error| error.to_string())?;
+
+    let mut paths = IndexMap::new();
+    if let Some(ref dependencies) = crate_info
+        .workspace
+        .and_then(|workspace| workspace.dependencies)
+    {
+        for (name, dependency) in dependencies {
+            if let CrateDependency::Info(ref info) = dependency {
+                if let Some(ref path) = info.path {
+                    let path = root_directory.join(path).to_string_lossy().into_owned();
+                    paths.insert(name.to_string(), io::canonicalize_to_string(&path));
+                }
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Returns the paths of the members the crate at the given directory depends on (including dev,
+/// build and inherited workspace dependencies)
+fn get_path_dependencies(
+    directory: &Path,
+    workspace_dependencies: &IndexMap<String, String>,
+) -> Result<Vec<String>, String> {
+    let crate_info =
+        crateinfo::load_from(directory.join("Cargo.toml")).map_err(|error| error.to_string())?;
+
+    let mut paths = vec![];
+    for dependencies in [
+        &crate_info.dependencies,
+        &crate_info.dev_dependencies,
+        &crate_info.build_dependencies,
+    ]
+    .iter()
+    .filter_map(|dependencies| dependencies.as_ref())
+    {
+        for (name, dependency) in dependencies {
+            if let CrateDependency::Info(ref info) = dependency {
+                if let Some(ref path) = info.path {
+                    let path = directory.join(path).to_string_lossy().into_owned();
+                    paths.push(io::canonicalize_to_string(&path));
+                } else if info.workspace.unwrap_or(false) {
+                    if let Some(path) = workspace_dependencies.get(name) {
+                        paths.push(path.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Returns the indexes of the members each member depends on
+fn get_members_dependencies(
+    members: &Vec<WorkspaceMember>,
+    root_directory: &Path,
+) -> Result<Vec<Vec<usize>>, String> {
+    let workspace_dependencies = get_workspace_dependencies(root_directory)?;
+    let directories: Vec<String> = members
+        .iter()
+        .map(|member| io::canonicalize_to_string(&member.directory))
+        .collect();
+
+    directories
+        .iter()
+        .enumerate()
+        .map(|(index, directory)| {
+            let mut dependencies = vec![];
+
+            for path in get_path_dependencies(Path::new(directory), &workspace_dependencies)? {
+                match directories.iter().position(|member| *member == path) {
+                    Some(dependency) if dependency != index => {
+                        if !dependencies.contains(&dependency) {
+                            dependencies.push(dependency);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            Ok(dependencies)
+        })
+        .collect()
+}
+
+/// Returns true if the given file (which is not part of any member) may change the build of all
+/// the members, which is the case for the workspace Cargo.toml, Cargo.lock, cargo config, rust
+/// toolchain file and the makefile
+fn is_workspace_build_file(file: &Path, root_directory: &Path, makefile: &Option<PathBuf>) -> bool {
+    if let Some(ref makefile) = *makefile {
+        if file == makefile {
+            return true;
+        }
+    }
+
+    match file.strip_prefix(root_directory) {
+        Ok(relative_file) => {
+            let relative_file = relative_file.to_string_lossy().replace("\\", "/");
+
+            relative_file.starts_with(".cargo/")
+                || [
+                    "Cargo.toml",
+                    "Cargo.lock",
+                    "rust-toolchain",
+                    "rust-toolchain.toml",
+                    "Makefile.toml",
+                ]
+                .contains(&relative_file.as_str())
+        }
+        Err(_) => false,
+    }
+}
+
+/// Returns the affected members indexes (and the reason each member is affected) based on the
+/// changed files.<br>
+/// A member is affected if any of its files changed or if it depends on an affected member.<br>
+/// All members are affected if a workspace build file (for example the workspace Cargo.toml)
+/// changed, while any other file outside of the members is ignored.
+fn get_affected_members_from_files(
+    members: &Vec<WorkspaceMember>,
+    dependencies: &Vec<Vec<usize>>,
+    changed_files: &Vec<PathBuf>,
+    root_directory: &Path,
+    makefile: &Option<PathBuf>,
+) -> IndexMap<usize, String> {
+    let directories: Vec<PathBuf> = members
+        .iter()
+        .map(|member| PathBuf::from(io::canonicalize_to_string(&member.directory)))
+        .collect();
+
+    let mut member_changed_files: Vec<Vec<&PathBuf>> = vec![vec![]; members.len()];
+    let mut build_file = None;
+    for file in changed_files {
+        // nested members own their files and not the parent member
+        let owner = directories
+            .iter()
+            .enumerate()
+            .filter(|(_, directory)| file.starts_with(directory))
+            .max_by_key(|(_, directory)| directory.components().count());
+
+        match owner {
+            Some((index, _)) => member_changed_files[index].push(file),
+            None => {
+                if is_workspace_build_file(file, root_directory, makefile) {
+                    debug!("Changed file: {:?} is a workspace build file.", file);
+                    if build_file.is_none() {
+                        build_file = Some(file);
+                    }
+                } else {
+                    info!(
+                        "Changed file: {} is not part of any member, ignoring it.",
+                        file.to_string_lossy().replace("\\", "/")
+                    );
+                }
+            }
+        }
+    }
+
+    let mut affected = IndexMap::new();
+
+    if let Some(file) = build_file {
+        let file_string = file.to_string_lossy().replace("\\", "/");
+        info!(
+            "Changed file: {} is a workspace build file, all members are affected.",
+            &file_string
+        );
+
+        for index in 0..members.len() {
+            affected.insert(
+                index,
+                format!("changed file outside of members: {}", &file_string),
+            );
+        }
+
+        return affected;
+    }
+
+    for (index, files) in member_changed_files.iter().enumerate() {
+        if !files.is_empty() {
+            let first_file = files[0]
+                .strip_prefix(&directories[index])
+                .unwrap_or(files[0])
+                .to_string_lossy()
+                .replace("\\", "/");
+
+            let reason = if files.len() == 1 {
+                format!("changed file: {}", first_file)
+            } else {
+                format!("changed files: {} and {} more", first_file, files.len() - 1)
+            };
+            affected.insert(index, reason);
+        }
+    }
+
+    // add the reverse dependents until no more members are added
+    let mut updated = true;
+    while updated {
+        updated = false;
+
+        for (index, member_dependencies) in dependencies.iter().enumerate() {
+            if !affected.contains_key(&index) {
+                if let Some(dependency) = member_dependencies
+                    .iter()
+                    .find(|dependency| affected.contains_key(*dependency))
+                {
+                    affected.insert(
+                        index,
+                        format!("depends on affected member: {}", &members[*dependency].name),
+                    );
+                    updated = true;
+                }
+            }
+        }
+    }
+
+    affected
+}
+
+/// Returns the members (and the reason each member is affected) which are affected by the git
+/// changes since the given git ref
+pub(crate) fn get_affected_members(
+    members: &Vec<String>,
+    git_ref: &str,
+) -> Result<IndexMap<String, String>, String> {
+    let workspace_members: Vec<WorkspaceMember> = members
+        .iter()
+        .map(|member| WorkspaceMember::new(member))
+        .collect();
+
+    let root_directory = task_env::resolve_path(".");
+    let changed_files = git::get_changed_files(&root_directory, git_ref)?;
+    debug!("Changed files since: {} {:#?}", git_ref, &changed_files);
+
+    let root_directory = PathBuf::from(io::canonicalize_to_string(
+        &root_directory.to_string_lossy(),
+    ));
+    let makefile = task_env::get_var("CARGO_MAKE_MAKEFILE_PATH")
+        .map(|makefile| PathBuf::from(io::canonicalize_to_string(&makefile)));
+
+    let dependencies = get_members_dependencies(&workspace_members, &root_directory)?;
+    let affected = get_affected_members_from_files(
+        &workspace_members,
+        &dependencies,
+        &changed_files,
+        &root_directory,
+        &makefile,
+    );
+
+    Ok(members
+        .iter()
+        .enumerate()
+        .filter_map(|(index, member)| {
+            affected
+                .get(&index)
+                .map(|reason| (member.to_string(), reason.to_string()))
+        })
+        .collect())
+}
+
+/// Runs the member function on all members in parallel (up to the given amount of jobs) based on
+/// the members dependencies.<br>
+/// Members which depend on a member that did not succeed or which are part of a circular
+/// dependency are skipped.<br>
+/// The results are returned in the members order.
+fn run_members<F>(
+    members: &Vec<WorkspaceMember>,
+    dependencies: &Vec<Vec<usize>>,
+    jobs: usize,
+    run_member: F,
+) -> Vec<MemberResult>
+where
+    F: Fn(&WorkspaceMember) -> MemberStatus + Send + Sync + 'static,
+{
+    let run_member = Arc::new(run_member);
+    let mut results: Vec<Option<MemberResult>> = vec![None; members.len()];
+
+    let skip_reasons = scheduler::run(
+        dependencies,
+        jobs,
+        false,
+        |index| {
+            let member = members[index].clone();
+            let run_member = run_member.clone();
+            debug!("Starting workspace member: {}", &member.name);
+
+            Box::new(move || {
+                let start_time = SystemTime::now();
+                let status = run_member(&member);
+                let duration = match start_time.elapsed() {
+                    Ok(elapsed) => elapsed.as_millis(),
+                    Err(_) => 0,
+                };
+
+                (status, duration)
+            })
+        },
+        |index, output| {
+            let (status, duration) = output.unwrap_or((MemberStatus::Failed(None), 0));
+            let succeeded = status == MemberStatus::Succeeded;
+
+            results[index] = Some(MemberResult {
+                name: members[index].name.clone(),
+                status,
+                duration,
+            });
+
+            succeeded
+        },
+    );
+
+    results
+        .into_iter()
+        .zip(skip_reasons)
+        .enumerate()
+        .map(|(index, (result, skip_reason))| {
+            result.unwrap_or_else(|| {
+                let reason = match skip_reason {
+                    Some(SkipReason::DependencyFailed(dependency)) => {
+                        format!("dependency {} failed", &members[dependency].name)
+                    }
+                    Some(SkipReason::CircularDependency) => "circular dependency".to_string(),
+                    Some(SkipReason::Stopped) | None => "not invoked".to_string(),
+                };
+
+                MemberResult {
+                    name: members[index].name.clone(),
+                    status: MemberStatus::Skipped(reason),
+                    duration: 0,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Returns the cargo-make arguments used to run the task on the member
+fn create_member_args(member: &WorkspaceMember, options: &WorkspaceRunOptions) -> Vec<String> {
+    let mut args = vec![
+        "make".to_string(),
+        "--disable-check-for-updates".to_string(),
+        "--allow-private".to_string(),
+        "--no-on-error".to_string(),
+        format!("--loglevel={}", &options.log_level),
+        "--env".to_string(),
+        format!("CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER={}", &member.name),
+        "--profile".to_string(),
+        options.profile.clone(),
+        "--".to_string(),
+        options.task.clone(),
+    ];
+    args.extend(options.arguments.iter().cloned());
+
+    args
+}
+
+/// Returns the member output line to write and whether it should be written to the stderr.<br>
+/// Events emitted by the member are written as is, so the stdout stays a valid stream of JSON
+/// lines, while any other line gets the member name prefix. In json-events output format, other
+/// stdout lines are written to the stderr.
+fn format_member_line(
+    line: &[u8],
+    prefix: &str,
+    to_stderr: bool,
+    json_events: bool,
+) -> (Vec<u8>, bool) {
+    if !to_stderr && events::is_event_line(line) {
+        return (line.to_vec(), false);
+    }
+
+    let mut data = prefix.as_bytes().to_vec();
+    data.extend_from_slice(line);
+
+    (data, to_stderr || json_events)
+}
+
+/// Writes the output lines with the member name prefix (see [format_member_line])
+fn forward_output<R>(reader: R, prefix: String, to_stderr: bool) -> JoinHandle<()>
+where
+    R: Read + Send + 'static,
+{
+    let json_events = events::is_json_events();
+
+    thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        let mut line = vec![];
+
+        loop {
+            line.clear();
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    if !line.ends_with(b"\n") {
+                        line.push(b'\n');
+                    }
+
+                    let (data, data_to_stderr) =
+                        format_member_line(&line, &prefix, to_stderr, json_events);
+                    output::write(&data, data_to_stderr);
+                }
+            }
+        }
+    })
+}
+
+/// Runs the task on the member via a cargo-make sub process
+fn run_member(member: &WorkspaceMember, options: &WorkspaceRunOptions) -> MemberStatus {
+    debug!("Running member: {} Path: {}", &member.name, &member.path);
+
+    let mut command = Command::new("cargo");
+    command
+        .args(create_member_args(member, options))
+        .current_dir(&member.directory)
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    match command.spawn() {
+        Ok(mut child) => {
+            let prefix = format!("[{}] ", &member.name);
+            let mut handles = vec![];
+            if let Some(stdout) = child.stdout.take() {
+                handles.push(forward_output(stdout, prefix.clone(), false));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                handles.push(forward_output(stderr, prefix, true));
+            }
+
+            let result = child.wait();
+
+            for handle in handles {
+                handle.join().unwrap_or(());
+            }
+
+            match result {
+                Ok(status) if status.success() => MemberStatus::Succeeded,
+                Ok(status) => MemberStatus::Failed(status.code()),
+                Err(_) => MemberStatus::Failed(None),
+            }
+        }
+        Err(error) => {
+            warn!(
+                "Unable to run task on member: {}, error: {}",
+                &member.name, error
+            );
+            MemberStatus::Failed(None)
+        }
+    }
+}
+
+fn get_status_text(status: &MemberStatus) -> String {
+    match status {
+        MemberStatus::Succeeded => "Succeeded".to_string(),
+        MemberStatus::Failed(Some(exit_code)) => format!("Failed (exit code: {})", exit_code),
+        MemberStatus::Failed(None) => "Failed".to_string(),
+        MemberStatus::Skipped(reason) => format!("Skipped ({})", reason),
+    }
+}
+
+/// Returns the summary table lines
+fn create_summary(results: &Vec<MemberResult>) -> Vec<String> {
+    let max_name_size = results
+        .iter()
+        .map(|result| result.name.len())
+        .max()
+        .unwrap_or(0);
+
+    results
+        .iter()
+        .map(|result| {
+            let name_gap = format!("{: <1$}", "", max_name_size - result.name.len() + 3);
+            let seconds = result.duration as f64 / 1000.0;
+
+            format!(
+                "{}:{}{} {:.2} seconds",
+                &result.name,
+                name_gap,
+                get_status_text(&result.status),
+                seconds
+            )
+        })
+        .collect()
+}
+
+fn print_summary(results: &Vec<MemberResult>) {
+    info!("===============Workspace Summary================");
+    for line in create_summary(results) {
+        info!("{}", line);
+    }
+    info!("================================================");
+}
+
+/// Runs the task on all provided members and prints a summary of all member results.<br>
+/// Returns an error listing the members which did not succeed.
+pub(crate) fn run(members: &Vec<String>, options: &WorkspaceRunOptions) -> Result<(), String> {
+    let members: Vec<WorkspaceMember> = members
+        .iter()
+        .map(|member| WorkspaceMember::new(member))
+        .collect();
+    let dependencies = get_members_dependencies(&members, &task_env::resolve_path("."))?;
+    debug!("Workspace members dependencies: {:#?}", &dependencies);
+
+    let member_options = options.clone();
+    let results = run_members(&members, &dependencies, options.jobs, move |member| {
+        run_member(member, &member_options)
+    });
+
+    print_summary(&results);
+
+    let failed_members: Vec<String> = results
+        .iter()
+        .filter(|result| result.status != MemberStatus::Succeeded)
+        .map(|result| result.name.clone())
+        .collect();
+
+    if failed_members.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Workspace members failed: {}",
+            failed_members.join(", ")
+        ))
+    }
+}
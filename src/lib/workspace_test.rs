@@ -0,0 +1,520 @@
+use super::*;
+use crate::git::{get_changed_files, run_git};
+
+use std::env;
+use std::sync::Mutex;
+use std::thread::sleep;
+use std::time::Duration;
+
+fn create_members(paths: Vec<&str>) -> Vec<WorkspaceMember> {
+    paths
+        .into_iter()
+        .map(|path| WorkspaceMember::new(path))
+        .collect()
+}
+
+fn create_crate(directory: &Path, name: &str, dependencies: &str) {
+    let text = format!(
+        "[package]\nname = \"{}\"\nversion = \"0.1.0\"\n\n[dependencies]\n{}\n",
+        name, dependencies
+    );
+    fsio::file::write_text_file(&directory.join(name).join("Cargo.toml"), &text).unwrap();
+}
+
+#[test]
+fn workspace_member_new() {
+    let member = WorkspaceMember::new("dir1\\member1");
+
+    assert_eq!(member.path, "dir1/member1");
+    assert_eq!(member.name, "member1");
+    assert!(member.directory.ends_with("dir1/member1"));
+}
+
+#[test]
+fn get_members_dependencies_path_dependencies() {
+    let directory = env::current_dir()
+        .unwrap()
+        .join("target/_cargo_make_temp/workspace/dependencies");
+    if directory.exists() {
+        fsio::directory::delete(&directory).unwrap();
+    }
+
+    create_crate(&directory, "member1", "member2 = { path = \"../member2\" }");
+    create_crate(&directory, "member2", "");
+    create_crate(
+        &directory,
+        "member3",
+        "member1 = { path = \"../member1\" }\nmember2 = { path = \"../member2\" }\nother = { path = \"../../other\" }\nversioned = \"1.0.0\"",
+    );
+    fsio::directory::create(&directory.join("member4")).unwrap();
+
+    let members = create_members(vec![
+        &directory.join("member1").to_string_lossy(),
+        &directory.join("member2").to_string_lossy(),
+        &directory.join("member3").to_string_lossy(),
+        &directory.join("member4").to_string_lossy(),
+    ]);
+
+    let dependencies = get_members_dependencies(&members, &directory).unwrap();
+
+    assert_eq!(dependencies, vec![vec![1], vec![], vec![0, 1], vec![]]);
+}
+
+#[test]
+fn get_members_dependencies_dev_build_and_workspace_dependencies() {
+    let directory = env::current_dir()
+        .unwrap()
+        .join("target/_cargo_make_temp/workspace/inherited_dependencies");
+    if directory.exists() {
+        fsio::directory::delete(&directory).unwrap();
+    }
+
+    fsio::file::write_text_file(
+        &directory.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"member1\", \"member2\", \"member3\"]\n\n[workspace.dependencies]\nmember1 = { path = \"member1\" }\nversioned = \"1.0.0\"\n",
+    )
+    .unwrap();
+    create_crate(
+        &directory,
+        "member1",
+        "\n[dev-dependencies]\nmember2 = { path = \"../member2\" }",
+    );
+    create_crate(
+        &directory,
+        "member2",
+        "versioned = { workspace = true }\n\n[build-dependencies]\nmember3 = { path = \"../member3\" }",
+    );
+    create_crate(&directory, "member3", "member1 = { workspace = true }");
+
+    let members = create_members(vec![
+        &directory.join("member1").to_string_lossy(),
+        &directory.join("member2").to_string_lossy(),
+        &directory.join("member3").to_string_lossy(),
+    ]);
+
+    let dependencies = get_members_dependencies(&members, &directory).unwrap();
+
+    assert_eq!(dependencies, vec![vec![1], vec![2], vec![0]]);
+}
+
+#[test]
+fn get_affected_members_from_files_changed_and_dependents() {
+    let members = create_members(vec![
+        "/workspace/member1",
+        "/workspace/member2",
+        "/workspace/member2/nested",
+        "/workspace/member3",
+        "/workspace/member4",
+    ]);
+    let dependencies = vec![vec![], vec![], vec![], vec![4], vec![0]];
+    let changed_files = vec![
+        PathBuf::from("/workspace/member1/src/lib.rs"),
+        PathBuf::from("/workspace/member1/Cargo.toml"),
+        PathBuf::from("/workspace/member2/nested/src/lib.rs"),
+    ];
+
+    let affected = get_affected_members_from_files(
+        &members,
+        &dependencies,
+        &changed_files,
+        Path::new("/workspace"),
+        &None,
+    );
+
+    let mut expected = IndexMap::new();
+    expected.insert(0, "changed files: src/lib.rs and 1 more".to_string());
+    expected.insert(2, "changed file: src/lib.rs".to_string());
+    expected.insert(4, "depends on affected member: member1".to_string());
+    expected.insert(3, "depends on affected member: member4".to_string());
+    assert_eq!(affected, expected);
+}
+
+#[test]
+fn get_affected_members_from_files_outside_members() {
+    let members = create_members(vec!["/workspace/member1", "/workspace/member2"]);
+    let changed_files = vec![
+        PathBuf::from("/workspace/member1/src/lib.rs"),
+        PathBuf::from("/workspace/Cargo.lock"),
+    ];
+
+    let affected = get_affected_members_from_files(
+        &members,
+        &vec![vec![], vec![]],
+        &changed_files,
+        Path::new("/workspace"),
+        &None,
+    );
+
+    let mut expected = IndexMap::new();
+    expected.insert(
+        0,
+        "changed file outside of members: /workspace/Cargo.lock".to_string(),
+    );
+    expected.insert(
+        1,
+        "changed file outside of members: /workspace/Cargo.lock".to_string(),
+    );
+    assert_eq!(affected, expected);
+}
+
+#[test]
+fn get_affected_members_from_files_outside_members_cargo_config() {
+    let members = create_members(vec!["/workspace/member1", "/workspace/member2"]);
+    let changed_files = vec![PathBuf::from("/workspace/.cargo/config.toml")];
+
+    let affected = get_affected_members_from_files(
+        &members,
+        &vec![vec![], vec![]],
+        &changed_files,
+        Path::new("/workspace"),
+        &None,
+    );
+
+    assert_eq!(affected.len(), 2);
+}
+
+#[test]
+fn get_affected_members_from_files_outside_members_makefile() {
+    let members = create_members(vec!["/workspace/member1", "/workspace/member2"]);
+    let changed_files = vec![PathBuf::from("/workspace/build/tasks.toml")];
+
+    let affected = get_affected_members_from_files(
+        &members,
+        &vec![vec![], vec![]],
+        &changed_files,
+        Path::new("/workspace"),
+        &Some(PathBuf::from("/workspace/build/tasks.toml")),
+    );
+
+    assert_eq!(affected.len(), 2);
+}
+
+#[test]
+fn get_affected_members_from_files_outside_members_readme() {
+    let members = create_members(vec!["/workspace/member1", "/workspace/member2"]);
+    let changed_files = vec![
+        PathBuf::from("/workspace/member1/src/lib.rs"),
+        PathBuf::from("/workspace/README.md"),
+        PathBuf::from("/workspace/docs/Cargo.toml"),
+    ];
+
+    let affected = get_affected_members_from_files(
+        &members,
+        &vec![vec![], vec![0]],
+        &changed_files,
+        Path::new("/workspace"),
+        &None,
+    );
+
+    let mut expected = IndexMap::new();
+    expected.insert(0, "changed file: src/lib.rs".to_string());
+    expected.insert(1, "depends on affected member: member1".to_string());
+    assert_eq!(affected, expected);
+}
+
+#[test]
+fn get_affected_members_from_files_no_changes() {
+    let members = create_members(vec!["/workspace/member1", "/workspace/member2"]);
+
+    let affected = get_affected_members_from_files(
+        &members,
+        &vec![vec![], vec![0]],
+        &vec![],
+        Path::new("/workspace"),
+        &None,
+    );
+
+    assert!(affected.is_empty());
+}
+
+#[test]
+fn get_changed_files_uncommitted_and_untracked() {
+    let directory = env::current_dir()
+        .unwrap()
+        .join("target/_cargo_make_temp/workspace/affected");
+    if directory.exists() {
+        fsio::directory::delete(&directory).unwrap();
+    }
+
+    create_crate(&directory, "member1", "");
+    create_crate(&directory, "member2", "member1 = { path = \"../member1\" }");
+    create_crate(&directory, "member3", "");
+    create_crate(&directory, "member4", "");
+
+    for args in vec![
+        vec!["init", "-q"],
+        vec!["add", "-A"],
+        vec![
+            "-c",
+            "user.name=test",
+            "-c",
+            "user.email=test@test.com",
+            "commit",
+            "-q",
+            "-m",
+            "init",
+        ],
+    ] {
+        run_git(&directory, &args).unwrap();
+    }
+
+    fsio::file::write_text_file(&directory.join("member1/src/lib.rs"), "").unwrap();
+    fsio::file::write_text_file(&directory.join("member4/Cargo.toml"), "").unwrap();
+
+    let mut changed_files = get_changed_files(&directory, "HEAD").unwrap();
+    changed_files.sort();
+    assert_eq!(
+        changed_files,
+        vec![
+            directory.join("member1/src/lib.rs"),
+            directory.join("member4/Cargo.toml")
+        ]
+    );
+
+    let members = create_members(vec![
+        &directory.join("member1").to_string_lossy(),
+        &directory.join("member2").to_string_lossy(),
+        &directory.join("member3").to_string_lossy(),
+        &directory.join("member4").to_string_lossy(),
+    ]);
+    let dependencies = get_members_dependencies(&members, &directory).unwrap();
+    let affected =
+        get_affected_members_from_files(&members, &dependencies, &changed_files, &directory, &None);
+
+    let mut expected = IndexMap::new();
+    expected.insert(0, "changed file: src/lib.rs".to_string());
+    expected.insert(3, "changed file: Cargo.toml".to_string());
+    expected.insert(1, "depends on affected member: member1".to_string());
+    assert_eq!(affected, expected);
+}
+
+#[test]
+fn get_affected_members_invalid_ref() {
+    let result = get_affected_members(&vec!["member1".to_string()], "bad-ref-not-found");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn run_members_empty() {
+    let results = run_members(&vec![], &vec![], 2, |_| MemberStatus::Succeeded);
+
+    assert!(results.is_empty());
+}
+
+#[test]
+fn run_members_dependencies_order() {
+    let members = create_members(vec!["member1", "member2", "member3", "member4"]);
+    let dependencies = vec![vec![1], vec![], vec![0, 1], vec![]];
+    let order = Arc::new(Mutex::new(vec![]));
+
+    let run_order = order.clone();
+    let results = run_members(&members, &dependencies, 4, move |member| {
+        sleep(Duration::from_millis(10));
+        run_order.lock().unwrap().push(member.name.clone());
+        MemberStatus::Succeeded
+    });
+
+    let order = order.lock().unwrap().clone();
+    let position = |name: &str| order.iter().position(|value| value == name).unwrap();
+    assert_eq!(order.len(), 4);
+    assert!(position("member2") < position("member1"));
+    assert!(position("member1") < position("member3"));
+
+    let names: Vec<String> = results.iter().map(|result| result.name.clone()).collect();
+    assert_eq!(names, vec!["member1", "member2", "member3", "member4"]);
+    assert!(results
+        .iter()
+        .all(|result| result.status == MemberStatus::Succeeded));
+}
+
+#[test]
+fn run_members_serial() {
+    let members = create_members(vec!["member1", "member2", "member3"]);
+    let running = Arc::new(Mutex::new((0, 0)));
+
+    let run_running = running.clone();
+    run_members(&members, &vec![vec![], vec![], vec![]], 1, move |_| {
+        {
+            let mut running = run_running.lock().unwrap();
+            running.0 = running.0 + 1;
+            running.1 = running.1.max(running.0);
+        }
+        sleep(Duration::from_millis(10));
+        run_running.lock().unwrap().0 -= 1;
+        MemberStatus::Succeeded
+    });
+
+    assert_eq!(running.lock().unwrap().1, 1);
+}
+
+#[test]
+fn run_members_failed_dependency() {
+    let members = create_members(vec!["member1", "member2", "member3", "member4"]);
+    let dependencies = vec![vec![], vec![0], vec![1], vec![]];
+
+    let results = run_members(&members, &dependencies, 2, |member| {
+        if member.name == "member1" {
+            MemberStatus::Failed(Some(1))
+        } else if member.name == "member4" {
+            panic!("test");
+        } else {
+            MemberStatus::Succeeded
+        }
+    });
+
+    let statuses: Vec<MemberStatus> = results.into_iter().map(|result| result.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            MemberStatus::Failed(Some(1)),
+            MemberStatus::Skipped("dependency member1 failed".to_string()),
+            MemberStatus::Skipped("dependency member2 failed".to_string()),
+            MemberStatus::Failed(None),
+        ]
+    );
+}
+
+#[test]
+fn run_members_circular_dependencies() {
+    let members = create_members(vec!["member1", "member2", "member3"]);
+    let dependencies = vec![vec![1], vec![0], vec![]];
+
+    let results = run_members(&members, &dependencies, 2, |_| MemberStatus::Succeeded);
+
+    let statuses: Vec<MemberStatus> = results.into_iter().map(|result| result.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            MemberStatus::Skipped("circular dependency".to_string()),
+            MemberStatus::Skipped("circular dependency".to_string()),
+            MemberStatus::Succeeded,
+        ]
+    );
+}
+
+#[test]
+fn create_summary_circular_dependency() {
+    let members = create_members(vec!["member1", "member2"]);
+
+    let results = run_members(&members, &vec![vec![1], vec![0]], 2, |_| {
+        MemberStatus::Succeeded
+    });
+
+    assert_eq!(
+        create_summary(&results),
+        vec![
+            "member1:   Skipped (circular dependency) 0.00 seconds",
+            "member2:   Skipped (circular dependency) 0.00 seconds",
+        ]
+    );
+}
+
+#[test]
+fn create_member_args_with_arguments() {
+    let member = WorkspaceMember::new("dir1/member1");
+    let options = WorkspaceRunOptions {
+        jobs: 2,
+        log_level: "info".to_string(),
+        profile: "development".to_string(),
+        task: "build".to_string(),
+        arguments: vec!["arg1".to_string(), "arg2".to_string()],
+    };
+
+    let args = create_member_args(&member, &options);
+
+    assert_eq!(
+        args,
+        vec![
+            "make",
+            "--disable-check-for-updates",
+            "--allow-private",
+            "--no-on-error",
+            "--loglevel=info",
+            "--env",
+            "CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=member1",
+            "--profile",
+            "development",
+            "--",
+            "build",
+            "arg1",
+            "arg2"
+        ]
+    );
+}
+
+#[test]
+fn create_summary_all_statuses() {
+    let results = vec![
+        MemberResult {
+            name: "member1".to_string(),
+            status: MemberStatus::Succeeded,
+            duration: 1500,
+        },
+        MemberResult {
+            name: "m2".to_string(),
+            status: MemberStatus::Failed(Some(1)),
+            duration: 20,
+        },
+        MemberResult {
+            name: "m3".to_string(),
+            status: MemberStatus::Skipped("dependency m2 failed".to_string()),
+            duration: 0,
+        },
+    ];
+
+    let summary = create_summary(&results);
+
+    assert_eq!(
+        summary,
+        vec![
+            "member1:   Succeeded 1.50 seconds",
+            "m2:        Failed (exit code: 1) 0.02 seconds",
+            "m3:        Skipped (dependency m2 failed) 0.00 seconds",
+        ]
+    );
+}
+
+#[test]
+fn format_member_line_json_events() {
+    let member_output = [
+        "{\"event\":\"task_started\",\"task\":\"build\",\"timestamp\":1,\"level\":1,\"member\":\"member1\"}\n",
+        "compiling member1\n",
+        "{\"event\":\"task_finished\",\"task\":\"build\",\"timestamp\":2,\"level\":1,\"member\":\"member1\",\"exit_code\":0,\"duration\":1}\n",
+        "{\"not\":\"an event\"}\n",
+    ];
+
+    let mut stdout = vec![];
+    let mut stderr = vec![];
+    for line in member_output.iter() {
+        let (data, to_stderr) = format_member_line(line.as_bytes(), "[member1] ", false, true);
+        if to_stderr {
+            stderr.extend(data);
+        } else {
+            stdout.extend(data);
+        }
+    }
+
+    let stdout = String::from_utf8(stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    for line in lines {
+        let event: events::Event = serde_json::from_str(line).unwrap();
+        assert_eq!(event.member.unwrap(), "member1");
+    }
+    assert_eq!(
+        String::from_utf8(stderr).unwrap(),
+        "[member1] compiling member1\n[member1] {\"not\":\"an event\"}\n"
+    );
+}
+
+#[test]
+fn format_member_line_prefix() {
+    let (data, to_stderr) = format_member_line(b"output\n", "[member1] ", false, false);
+    assert_eq!(data, b"[member1] output\n");
+    assert!(!to_stderr);
+
+    let (data, to_stderr) = format_member_line(b"error\n", "[member1] ", true, false);
+    assert_eq!(data, b"[member1] error\n");
+    assert!(to_stderr);
+}